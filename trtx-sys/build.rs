@@ -7,37 +7,49 @@ fn main() {
     // Check if we're in mock mode
     if env::var("CARGO_FEATURE_MOCK").is_ok() {
         println!("cargo:warning=Building in MOCK mode - no TensorRT-RTX required");
+        println!("cargo:rerun-if-changed=mock.c");
 
         // Build mock C implementation
         cc::Build::new().file("mock.c").compile("trtx_mock");
 
         generate_mock_bindings(&out_path);
+        emit_version((0, 0, 0));
         return;
     }
 
     println!("cargo:rerun-if-changed=wrapper.hpp");
     println!("cargo:rerun-if-changed=wrapper.cpp");
+    println!("cargo:rerun-if-changed=mock.c");
     println!("cargo:rerun-if-env-changed=TENSORRT_RTX_DIR");
     println!("cargo:rerun-if-env-changed=CUDA_ROOT");
+    println!("cargo:rerun-if-env-changed=CUDA_PATH");
     println!("cargo:rerun-if-env-changed=LIBCLANG_PATH");
 
     // Look for TensorRT-RTX installation
     // Users can override with TENSORRT_RTX_DIR environment variable
-    let trtx_dir = match env::var("TENSORRT_RTX_DIR") {
-        Ok(dir) => {
-            println!("cargo:warning=Using TENSORRT_RTX_DIR={}", dir);
-            dir
+    let trtx_dir = find_tensorrt_rtx_dir();
+
+    let include_dir = trtx_dir.join("include").display().to_string();
+    let lib_dir = trtx_dir.join("lib").display().to_string();
+
+    let version_header = trtx_dir.join("include").join("NvInferVersion.h");
+    println!("cargo:rerun-if-changed={}", version_header.display());
+    match parse_version_header(&version_header) {
+        Some(version) => {
+            println!(
+                "cargo:warning=Detected TensorRT-RTX {}.{}.{}",
+                version.0, version.1, version.2
+            );
+            emit_version(version);
         }
-        Err(_) => {
+        None => {
             println!(
-                "cargo:warning=TENSORRT_RTX_DIR not set, using default: /usr/local/tensorrt-rtx"
+                "cargo:warning=Could not read the TensorRT-RTX version from {}",
+                version_header.display()
             );
-            "/usr/local/tensorrt-rtx".to_string()
+            emit_version((0, 0, 0));
         }
-    };
-
-    let include_dir = format!("{}/include", trtx_dir);
-    let lib_dir = format!("{}/lib", trtx_dir);
+    }
 
     println!("cargo:rustc-link-search=native={}", lib_dir);
     // TensorRT 10.x uses versioned library names
@@ -100,6 +112,119 @@ fn main() {
         .expect("Couldn't write bindings!");
 }
 
+/// Locate the TensorRT-RTX installation directory
+///
+/// `TENSORRT_RTX_DIR` wins if set. Otherwise common install locations are
+/// searched. A directory only qualifies if it has both `include` and `lib`
+/// subdirectories, so a bad path fails here instead of at link time.
+fn find_tensorrt_rtx_dir() -> PathBuf {
+    if let Ok(dir) = env::var("TENSORRT_RTX_DIR") {
+        println!("cargo:warning=Using TENSORRT_RTX_DIR={}", dir);
+        let dir = PathBuf::from(dir);
+        if let Some(missing) = missing_subdirs(&dir) {
+            panic!(
+                "TENSORRT_RTX_DIR={} does not look like a TensorRT-RTX installation (missing {})",
+                dir.display(),
+                missing.join(" and ")
+            );
+        }
+        return dir;
+    }
+
+    let mut candidates = if cfg!(target_os = "windows") {
+        vec![PathBuf::from("C:\\Program Files\\NVIDIA\\TensorRT-RTX")]
+    } else {
+        vec![
+            PathBuf::from("/usr/local/tensorrt-rtx"),
+            PathBuf::from("/opt/tensorrt-rtx"),
+        ]
+    };
+    // TensorRT-RTX is often unpacked next to the CUDA toolkit
+    if let Ok(cuda_path) = env::var("CUDA_PATH") {
+        if let Some(parent) = Path::new(&cuda_path).parent() {
+            candidates.push(parent.join("TensorRT-RTX"));
+        }
+    }
+
+    for dir in &candidates {
+        if missing_subdirs(dir).is_none() {
+            println!(
+                "cargo:warning=TENSORRT_RTX_DIR not set, found TensorRT-RTX at {}",
+                dir.display()
+            );
+            return dir.clone();
+        }
+    }
+
+    let searched: Vec<String> = candidates.iter().map(|d| d.display().to_string()).collect();
+    println!(
+        "cargo:warning=TensorRT-RTX not found. Searched: {}",
+        searched.join(", ")
+    );
+    panic!(
+        "Could not find TensorRT-RTX (searched: {}). Set TENSORRT_RTX_DIR to the installation \
+         directory, or build with --features mock to develop without it.",
+        searched.join(", ")
+    );
+}
+
+/// Extract `(major, minor, patch)` from `NvInferVersion.h`
+///
+/// The `NV_TENSORRT_*` macros may be defined in terms of other macros (e.g.
+/// `TRT_MAJOR_RTX`), so definitions are resolved through a few levels of
+/// indirection.
+fn parse_version_header(path: &Path) -> Option<(u32, u32, u32)> {
+    let contents = std::fs::read_to_string(path).ok()?;
+
+    let defines: std::collections::HashMap<&str, &str> = contents
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.trim().strip_prefix("#define")?.split_whitespace();
+            Some((parts.next()?, parts.next()?))
+        })
+        .collect();
+
+    let resolve = |name: &str| -> Option<u32> {
+        let mut value = *defines.get(name)?;
+        for _ in 0..4 {
+            if let Ok(number) = value.parse() {
+                return Some(number);
+            }
+            value = defines.get(value)?;
+        }
+        None
+    };
+
+    Some((
+        resolve("NV_TENSORRT_MAJOR")?,
+        resolve("NV_TENSORRT_MINOR")?,
+        resolve("NV_TENSORRT_PATCH")?,
+    ))
+}
+
+/// Expose the version to this crate (`env!`) and to dependents (`DEP_TENSORRT_RTX_*`)
+fn emit_version((major, minor, patch): (u32, u32, u32)) {
+    println!("cargo:rustc-env=TRTX_VERSION_MAJOR={}", major);
+    println!("cargo:rustc-env=TRTX_VERSION_MINOR={}", minor);
+    println!("cargo:rustc-env=TRTX_VERSION_PATCH={}", patch);
+    println!("cargo:version_major={}", major);
+    println!("cargo:version_minor={}", minor);
+    println!("cargo:version_patch={}", patch);
+}
+
+/// Return the required subdirectories missing from `dir`, if any
+fn missing_subdirs(dir: &Path) -> Option<Vec<&'static str>> {
+    let missing: Vec<&'static str> = ["include", "lib"]
+        .into_iter()
+        .filter(|sub| !dir.join(sub).is_dir())
+        .collect();
+    if missing.is_empty() {
+        None
+    } else {
+        Some(missing)
+    }
+}
+
 fn generate_mock_bindings(out_path: &Path) {
     let mock_bindings = r#"
 // Mock bindings for development without TensorRT-RTX
@@ -111,6 +236,7 @@ pub const TRTX_ERROR_OUT_OF_MEMORY: i32 = 2;
 pub const TRTX_ERROR_RUNTIME_ERROR: i32 = 3;
 pub const TRTX_ERROR_CUDA_ERROR: i32 = 4;
 pub const TRTX_ERROR_UNKNOWN: i32 = 99;
+pub const TRTX_MAX_DIMS: i32 = 8;
 
 // Logger severity levels
 #[repr(u32)]
@@ -144,6 +270,16 @@ pub struct TrtxNetworkDefinition {
     _unused: [u8; 0],
 }
 
+#[repr(C)]
+pub struct TrtxTensor {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+pub struct TrtxLayer {
+    _unused: [u8; 0],
+}
+
 #[repr(C)]
 pub struct TrtxRuntime {
     _unused: [u8; 0],
@@ -159,11 +295,69 @@ pub struct TrtxExecutionContext {
     _unused: [u8; 0],
 }
 
+#[repr(C)]
+pub struct TrtxEngineInspector {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+pub struct TrtxErrorRecorder {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+pub struct TrtxProfiler {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+pub struct TrtxDebugListener {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+pub struct TrtxProgressMonitor {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+pub struct TrtxOutputAllocator {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+pub struct TrtxTimingCache {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+pub struct TrtxOptimizationProfile {
+    _unused: [u8; 0],
+}
+
 #[repr(C)]
 pub struct TrtxOnnxParser {
     _unused: [u8; 0],
 }
 
+#[repr(C)]
+pub struct TrtxRefitter {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+pub struct TrtxPluginCreator {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct TrtxPluginField {
+    pub name: *const ::std::os::raw::c_char,
+    pub data: *const ::std::os::raw::c_void,
+    pub size: usize,
+}
+
 // Logger callback type
 pub type TrtxLoggerCallback = ::std::option::Option<
     unsafe extern "C" fn(
@@ -173,6 +367,104 @@ pub type TrtxLoggerCallback = ::std::option::Option<
     ),
 >;
 
+// Profiler callback type
+pub type TrtxProfilerCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        user_data: *mut ::std::os::raw::c_void,
+        layer_name: *const ::std::os::raw::c_char,
+        ms: f32,
+    ),
+>;
+
+// Debug listener callback type
+pub type TrtxDebugTensorCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        user_data: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        addr: *const ::std::os::raw::c_void,
+        data_type: i32,
+        dims: *const i64,
+        nb_dims: i32,
+    ),
+>;
+
+// Output allocator callback types
+pub type TrtxReallocateOutputCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        user_data: *mut ::std::os::raw::c_void,
+        tensor_name: *const ::std::os::raw::c_char,
+        current_memory: *mut ::std::os::raw::c_void,
+        size: u64,
+        alignment: u64,
+    ) -> *mut ::std::os::raw::c_void,
+>;
+
+pub type TrtxNotifyShapeCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        user_data: *mut ::std::os::raw::c_void,
+        tensor_name: *const ::std::os::raw::c_char,
+        dims: *const i64,
+        nb_dims: i32,
+    ),
+>;
+
+pub type TrtxPhaseStartCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        user_data: *mut ::std::os::raw::c_void,
+        phase_name: *const ::std::os::raw::c_char,
+        parent_phase: *const ::std::os::raw::c_char,
+        nb_steps: i32,
+    ),
+>;
+
+pub type TrtxStepCompleteCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        user_data: *mut ::std::os::raw::c_void,
+        phase_name: *const ::std::os::raw::c_char,
+        step: i32,
+    ) -> bool,
+>;
+
+pub type TrtxPhaseFinishCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        user_data: *mut ::std::os::raw::c_void,
+        phase_name: *const ::std::os::raw::c_char,
+    ),
+>;
+
+// Host function callback type
+pub type TrtxHostFnCallback =
+    ::std::option::Option<unsafe extern "C" fn(user_data: *mut ::std::os::raw::c_void)>;
+
+// Plugin callback types
+pub type TrtxPluginCreateCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        user_data: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        fields: *const TrtxPluginField,
+        nb_fields: i32,
+    ) -> *mut ::std::os::raw::c_void,
+>;
+
+pub type TrtxPluginCloneCallback = ::std::option::Option<
+    unsafe extern "C" fn(instance: *mut ::std::os::raw::c_void) -> *mut ::std::os::raw::c_void,
+>;
+
+pub type TrtxPluginEnqueueCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        instance: *mut ::std::os::raw::c_void,
+        input: *const ::std::os::raw::c_void,
+        output: *mut ::std::os::raw::c_void,
+        data_type: i32,
+        dims: *const i64,
+        nb_dims: i32,
+        stream: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+
+pub type TrtxPluginDestroyCallback =
+    ::std::option::Option<unsafe extern "C" fn(instance: *mut ::std::os::raw::c_void)>;
+
 // Stub implementations that return success
 extern "C" {
     pub fn trtx_logger_create(
@@ -194,6 +486,26 @@ extern "C" {
 
     pub fn trtx_builder_destroy(builder: *mut TrtxBuilder);
 
+    pub fn trtx_builder_set_max_threads(
+        builder: *mut TrtxBuilder,
+        nb_threads: i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_builder_get_max_threads(
+        builder: *mut TrtxBuilder,
+        out_nb_threads: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_builder_reset(
+        builder: *mut TrtxBuilder,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
     pub fn trtx_builder_create_network(
         builder: *mut TrtxBuilder,
         flags: u32,
@@ -202,6 +514,13 @@ extern "C" {
         error_msg_len: usize,
     ) -> i32;
 
+    pub fn trtx_builder_create_optimization_profile(
+        builder: *mut TrtxBuilder,
+        out_profile: *mut *mut TrtxOptimizationProfile,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
     pub fn trtx_builder_create_builder_config(
         builder: *mut TrtxBuilder,
         out_config: *mut *mut TrtxBuilderConfig,
@@ -219,6 +538,38 @@ extern "C" {
         error_msg_len: usize,
     ) -> i32;
 
+    pub fn trtx_builder_set_error_recorder(
+        builder: *mut TrtxBuilder,
+        recorder: *mut TrtxErrorRecorder,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_error_recorder_create(
+        out_recorder: *mut *mut TrtxErrorRecorder,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_error_recorder_destroy(recorder: *mut TrtxErrorRecorder);
+
+    pub fn trtx_error_recorder_get_nb_errors(
+        recorder: *mut TrtxErrorRecorder,
+        out_count: *mut i32,
+    ) -> i32;
+
+    pub fn trtx_error_recorder_get_error(
+        recorder: *mut TrtxErrorRecorder,
+        index: i32,
+        out_code: *mut i32,
+        desc_buf: *mut ::std::os::raw::c_char,
+        desc_buf_len: usize,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_error_recorder_clear(recorder: *mut TrtxErrorRecorder);
+
     pub fn trtx_builder_config_destroy(config: *mut TrtxBuilderConfig);
 
     pub fn trtx_builder_config_set_memory_pool_limit(
@@ -229,117 +580,1187 @@ extern "C" {
         error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_network_destroy(network: *mut TrtxNetworkDefinition);
-
-    pub fn trtx_runtime_create(
-        logger: *mut TrtxLogger,
-        out_runtime: *mut *mut TrtxRuntime,
+    pub fn trtx_builder_config_set_flag(
+        config: *mut TrtxBuilderConfig,
+        flag: i32,
+        enabled: bool,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_runtime_destroy(runtime: *mut TrtxRuntime);
-
-    pub fn trtx_runtime_deserialize_cuda_engine(
-        runtime: *mut TrtxRuntime,
-        data: *const ::std::os::raw::c_void,
-        size: usize,
-        out_engine: *mut *mut TrtxCudaEngine,
+    pub fn trtx_builder_config_get_memory_pool_limit(
+        config: *mut TrtxBuilderConfig,
+        pool_type: i32,
+        out_pool_size: *mut usize,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_cuda_engine_destroy(engine: *mut TrtxCudaEngine);
-
-    pub fn trtx_cuda_engine_create_execution_context(
-        engine: *mut TrtxCudaEngine,
-        out_context: *mut *mut TrtxExecutionContext,
+    pub fn trtx_builder_config_get_flag(
+        config: *mut TrtxBuilderConfig,
+        flag: i32,
+        out_enabled: *mut bool,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_cuda_engine_get_tensor_name(
-        engine: *mut TrtxCudaEngine,
-        index: i32,
-        out_name: *mut *const ::std::os::raw::c_char,
+    pub fn trtx_builder_config_set_preview_feature(
+        config: *mut TrtxBuilderConfig,
+        feature: i32,
+        enabled: bool,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_cuda_engine_get_nb_io_tensors(
-        engine: *mut TrtxCudaEngine,
-        out_count: *mut i32,
+    pub fn trtx_builder_config_get_preview_feature(
+        config: *mut TrtxBuilderConfig,
+        feature: i32,
+        out_enabled: *mut bool,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_execution_context_destroy(context: *mut TrtxExecutionContext);
-
-    pub fn trtx_execution_context_set_tensor_address(
-        context: *mut TrtxExecutionContext,
-        tensor_name: *const ::std::os::raw::c_char,
-        data: *mut ::std::os::raw::c_void,
+    pub fn trtx_builder_config_set_max_aux_streams(
+        config: *mut TrtxBuilderConfig,
+        nb_streams: i32,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_execution_context_enqueue_v3(
-        context: *mut TrtxExecutionContext,
-        cuda_stream: *mut ::std::os::raw::c_void,
+    pub fn trtx_builder_config_get_max_aux_streams(
+        config: *mut TrtxBuilderConfig,
+        out_nb_streams: *mut i32,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_free_buffer(buffer: *mut ::std::os::raw::c_void);
+    pub fn trtx_builder_config_add_optimization_profile(
+        config: *mut TrtxBuilderConfig,
+        profile: *mut TrtxOptimizationProfile,
+        out_index: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
 
-    // ONNX Parser functions
-    pub fn trtx_onnx_parser_create(
-        network: *mut TrtxNetworkDefinition,
-        logger: *mut TrtxLogger,
-        out_parser: *mut *mut TrtxOnnxParser,
+    pub fn trtx_builder_config_get_nb_optimization_profiles(
+        config: *mut TrtxBuilderConfig,
+        out_count: *mut i32,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_onnx_parser_destroy(parser: *mut TrtxOnnxParser);
+    pub fn trtx_builder_config_set_runtime_platform(
+        config: *mut TrtxBuilderConfig,
+        platform: i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
 
-    pub fn trtx_onnx_parser_parse(
-        parser: *mut TrtxOnnxParser,
-        model_data: *const ::std::os::raw::c_void,
-        model_size: usize,
+    pub fn trtx_builder_config_get_runtime_platform(
+        config: *mut TrtxBuilderConfig,
+        out_platform: *mut i32,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
 
-    // CUDA Memory Management functions
-    pub fn trtx_cuda_malloc(
-        ptr: *mut *mut ::std::os::raw::c_void,
-        size: usize,
+    pub fn trtx_builder_config_set_engine_capability(
+        config: *mut TrtxBuilderConfig,
+        capability: i32,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_cuda_free(
-        ptr: *mut ::std::os::raw::c_void,
+    pub fn trtx_builder_config_get_engine_capability(
+        config: *mut TrtxBuilderConfig,
+        out_capability: *mut i32,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_cuda_memcpy_host_to_device(
-        dst: *mut ::std::os::raw::c_void,
-        src: *const ::std::os::raw::c_void,
-        size: usize,
+    pub fn trtx_builder_config_set_builder_optimization_level(
+        config: *mut TrtxBuilderConfig,
+        level: i32,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_cuda_memcpy_device_to_host(
-        dst: *mut ::std::os::raw::c_void,
-        src: *const ::std::os::raw::c_void,
-        size: usize,
+    pub fn trtx_builder_config_get_builder_optimization_level(
+        config: *mut TrtxBuilderConfig,
+        out_level: *mut i32,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
 
-    pub fn trtx_cuda_synchronize(
+    pub fn trtx_builder_config_set_profiling_verbosity(
+        config: *mut TrtxBuilderConfig,
+        verbosity: i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_destroy(network: *mut TrtxNetworkDefinition);
+
+    pub fn trtx_network_mark_debug(
+        network: *mut TrtxNetworkDefinition,
+        name: *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_set_name(
+        network: *mut TrtxNetworkDefinition,
+        name: *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_get_name(
+        network: *mut TrtxNetworkDefinition,
+        out_name: *mut *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_get_flags(
+        network: *mut TrtxNetworkDefinition,
+        out_flags: *mut u32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_set_output_type(
+        network: *mut TrtxNetworkDefinition,
+        tensor_name: *const ::std::os::raw::c_char,
+        data_type: i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_get_output_type(
+        network: *mut TrtxNetworkDefinition,
+        tensor_name: *const ::std::os::raw::c_char,
+        out_data_type: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_set_layer_precision(
+        network: *mut TrtxNetworkDefinition,
+        layer_name: *const ::std::os::raw::c_char,
+        data_type: i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_get_layer_precision(
+        network: *mut TrtxNetworkDefinition,
+        layer_name: *const ::std::os::raw::c_char,
+        out_data_type: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_get_nb_inputs(
+        network: *mut TrtxNetworkDefinition,
+        out_count: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_get_input_name(
+        network: *mut TrtxNetworkDefinition,
+        index: i32,
+        out_name: *mut *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_get_input_shape(
+        network: *mut TrtxNetworkDefinition,
+        index: i32,
+        out_dims: *mut i64,
+        max_dims: i32,
+        out_nb_dims: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_add_input(
+        network: *mut TrtxNetworkDefinition,
+        name: *const ::std::os::raw::c_char,
+        data_type: i32,
+        dims: *const i64,
+        nb_dims: i32,
+        out_tensor: *mut *mut TrtxTensor,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_add_constant(
+        network: *mut TrtxNetworkDefinition,
+        dims: *const i64,
+        nb_dims: i32,
+        data_type: i32,
+        values: *const ::std::os::raw::c_void,
+        count: i64,
+        out_layer: *mut *mut TrtxLayer,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_add_activation(
+        network: *mut TrtxNetworkDefinition,
+        input: *mut TrtxTensor,
+        activation: i32,
+        out_layer: *mut *mut TrtxLayer,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_add_elementwise(
+        network: *mut TrtxNetworkDefinition,
+        a: *mut TrtxTensor,
+        b: *mut TrtxTensor,
+        op: i32,
+        out_layer: *mut *mut TrtxLayer,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_add_matrix_multiply(
+        network: *mut TrtxNetworkDefinition,
+        a: *mut TrtxTensor,
+        op_a: i32,
+        b: *mut TrtxTensor,
+        op_b: i32,
+        out_layer: *mut *mut TrtxLayer,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_network_mark_output(
+        network: *mut TrtxNetworkDefinition,
+        tensor: *mut TrtxTensor,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_tensor_get_name(
+        tensor: *mut TrtxTensor,
+        out_name: *mut *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_tensor_get_dimensions(
+        tensor: *mut TrtxTensor,
+        out_dims: *mut i64,
+        max_dims: i32,
+        out_nb_dims: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_tensor_get_type(
+        tensor: *mut TrtxTensor,
+        out_data_type: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_layer_get_name(
+        layer: *mut TrtxLayer,
+        out_name: *mut *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_layer_get_nb_outputs(
+        layer: *mut TrtxLayer,
+        out_count: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_layer_get_output(
+        layer: *mut TrtxLayer,
+        index: i32,
+        out_tensor: *mut *mut TrtxTensor,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_optimization_profile_set_dimensions(
+        profile: *mut TrtxOptimizationProfile,
+        input_name: *const ::std::os::raw::c_char,
+        select: i32,
+        dims: *const i64,
+        nb_dims: i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_optimization_profile_get_dimensions(
+        profile: *mut TrtxOptimizationProfile,
+        input_name: *const ::std::os::raw::c_char,
+        select: i32,
+        out_dims: *mut i64,
+        max_dims: i32,
+        out_nb_dims: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_runtime_create(
+        logger: *mut TrtxLogger,
+        out_runtime: *mut *mut TrtxRuntime,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_runtime_destroy(runtime: *mut TrtxRuntime);
+
+    pub fn trtx_runtime_set_engine_host_code_allowed(
+        runtime: *mut TrtxRuntime,
+        allowed: bool,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_runtime_get_engine_host_code_allowed(
+        runtime: *mut TrtxRuntime,
+        out_allowed: *mut bool,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_runtime_set_max_threads(
+        runtime: *mut TrtxRuntime,
+        nb_threads: i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_runtime_get_max_threads(
+        runtime: *mut TrtxRuntime,
+        out_nb_threads: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_runtime_get_engine_validity(
+        runtime: *mut TrtxRuntime,
+        data: *const ::std::os::raw::c_void,
+        size: usize,
+        out_validity: *mut i32,
+        out_diagnostics: *mut u64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_runtime_load_runtime(
+        runtime: *mut TrtxRuntime,
+        path: *const ::std::os::raw::c_char,
+        out_runtime: *mut *mut TrtxRuntime,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_runtime_deserialize_cuda_engine(
+        runtime: *mut TrtxRuntime,
+        data: *const ::std::os::raw::c_void,
+        size: usize,
+        out_engine: *mut *mut TrtxCudaEngine,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_destroy(engine: *mut TrtxCudaEngine);
+
+    pub fn trtx_cuda_engine_create_execution_context(
+        engine: *mut TrtxCudaEngine,
+        out_context: *mut *mut TrtxExecutionContext,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_tensor_name(
+        engine: *mut TrtxCudaEngine,
+        index: i32,
+        out_name: *mut *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_nb_io_tensors(
+        engine: *mut TrtxCudaEngine,
+        out_count: *mut i32,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_nb_layers(
+        engine: *mut TrtxCudaEngine,
+        out_count: *mut i32,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_name(
+        engine: *mut TrtxCudaEngine,
+        out_name: *mut *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_nb_optimization_profiles(
+        engine: *mut TrtxCudaEngine,
+        out_count: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_tensor_dtype(
+        engine: *mut TrtxCudaEngine,
+        tensor_name: *const ::std::os::raw::c_char,
+        out_dtype: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_tensor_shape(
+        engine: *mut TrtxCudaEngine,
+        tensor_name: *const ::std::os::raw::c_char,
+        out_dims: *mut i64,
+        max_dims: i32,
+        out_nb_dims: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_is_shape_inference_io(
+        engine: *mut TrtxCudaEngine,
+        tensor_name: *const ::std::os::raw::c_char,
+        out_is_shape_io: *mut bool,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_tensor_io_mode(
+        engine: *mut TrtxCudaEngine,
+        tensor_name: *const ::std::os::raw::c_char,
+        out_mode: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_profile_shape(
+        engine: *mut TrtxCudaEngine,
+        tensor_name: *const ::std::os::raw::c_char,
+        profile_index: i32,
+        select: i32,
+        out_dims: *mut i64,
+        max_dims: i32,
+        out_nb_dims: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_device_memory_size(
+        engine: *mut TrtxCudaEngine,
+        out_size: *mut i64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_streamable_weights_size(
+        engine: *mut TrtxCudaEngine,
+        out_size: *mut i64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_is_weight_streaming_enabled(
+        engine: *mut TrtxCudaEngine,
+        out_enabled: *mut bool,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_minimum_weight_streaming_budget(
+        engine: *mut TrtxCudaEngine,
+        out_budget: *mut i64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_set_weight_streaming_budget(
+        engine: *mut TrtxCudaEngine,
+        budget: i64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_weight_streaming_budget(
+        engine: *mut TrtxCudaEngine,
+        out_budget: *mut i64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_get_weight_streaming_automatic_budget(
+        engine: *mut TrtxCudaEngine,
+        out_budget: *mut i64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_create_engine_inspector(
+        engine: *mut TrtxCudaEngine,
+        out_inspector: *mut *mut TrtxEngineInspector,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_engine_is_refittable(
+        engine: *mut TrtxCudaEngine,
+        out_refittable: *mut bool,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_refitter_create(
+        engine: *mut TrtxCudaEngine,
+        logger: *mut TrtxLogger,
+        out_refitter: *mut *mut TrtxRefitter,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_refitter_destroy(refitter: *mut TrtxRefitter);
+
+    pub fn trtx_refitter_get_all_weights(
+        refitter: *mut TrtxRefitter,
+        out_names: *mut *const ::std::os::raw::c_char,
+        max_names: i32,
+        out_count: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_refitter_set_named_weights(
+        refitter: *mut TrtxRefitter,
+        name: *const ::std::os::raw::c_char,
+        data_type: i32,
+        values: *const ::std::os::raw::c_void,
+        count: i64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_refitter_refit_cuda_engine(
+        refitter: *mut TrtxRefitter,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_engine_inspector_destroy(inspector: *mut TrtxEngineInspector);
+
+    pub fn trtx_engine_inspector_get_engine_information(
+        inspector: *mut TrtxEngineInspector,
+        format: i32,
+        out_info: *mut *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_engine_inspector_get_layer_information(
+        inspector: *mut TrtxEngineInspector,
+        layer_index: i32,
+        format: i32,
+        out_info: *mut *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_progress_monitor_create(
+        phase_start: TrtxPhaseStartCallback,
+        step_complete: TrtxStepCompleteCallback,
+        phase_finish: TrtxPhaseFinishCallback,
+        user_data: *mut ::std::os::raw::c_void,
+        out_monitor: *mut *mut TrtxProgressMonitor,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_progress_monitor_destroy(monitor: *mut TrtxProgressMonitor);
+
+    pub fn trtx_builder_config_set_progress_monitor(
+        config: *mut TrtxBuilderConfig,
+        monitor: *mut TrtxProgressMonitor,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_builder_config_create_timing_cache(
+        config: *mut TrtxBuilderConfig,
+        blob: *const ::std::os::raw::c_void,
+        size: usize,
+        out_cache: *mut *mut TrtxTimingCache,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_builder_config_set_timing_cache(
+        config: *mut TrtxBuilderConfig,
+        cache: *mut TrtxTimingCache,
+        ignore_mismatch: bool,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_timing_cache_destroy(cache: *mut TrtxTimingCache);
+
+    pub fn trtx_timing_cache_serialize(
+        cache: *mut TrtxTimingCache,
+        out_data: *mut *mut ::std::os::raw::c_void,
+        out_size: *mut usize,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_timing_cache_combine(
+        cache: *mut TrtxTimingCache,
+        input_cache: *mut TrtxTimingCache,
+        ignore_mismatch: bool,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_profiler_create(
+        callback: TrtxProfilerCallback,
+        user_data: *mut ::std::os::raw::c_void,
+        out_profiler: *mut *mut TrtxProfiler,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_profiler_destroy(profiler: *mut TrtxProfiler);
+
+    pub fn trtx_debug_listener_create(
+        callback: TrtxDebugTensorCallback,
+        user_data: *mut ::std::os::raw::c_void,
+        out_listener: *mut *mut TrtxDebugListener,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_debug_listener_destroy(listener: *mut TrtxDebugListener);
+
+    pub fn trtx_output_allocator_create(
+        reallocate: TrtxReallocateOutputCallback,
+        notify_shape: TrtxNotifyShapeCallback,
+        user_data: *mut ::std::os::raw::c_void,
+        out_allocator: *mut *mut TrtxOutputAllocator,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_output_allocator_destroy(allocator: *mut TrtxOutputAllocator);
+
+    pub fn trtx_execution_context_destroy(context: *mut TrtxExecutionContext);
+
+    pub fn trtx_execution_context_set_profiler(
+        context: *mut TrtxExecutionContext,
+        profiler: *mut TrtxProfiler,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_set_debug_listener(
+        context: *mut TrtxExecutionContext,
+        listener: *mut TrtxDebugListener,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_set_name(
+        context: *mut TrtxExecutionContext,
+        name: *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_get_name(
+        context: *mut TrtxExecutionContext,
+        out_name: *mut *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_set_tensor_address(
+        context: *mut TrtxExecutionContext,
+        tensor_name: *const ::std::os::raw::c_char,
+        data: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_get_tensor_address(
+        context: *mut TrtxExecutionContext,
+        tensor_name: *const ::std::os::raw::c_char,
+        out_data: *mut *const ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_set_input_shape(
+        context: *mut TrtxExecutionContext,
+        tensor_name: *const ::std::os::raw::c_char,
+        dims: *const i64,
+        nb_dims: i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_get_optimization_profile(
+        context: *mut TrtxExecutionContext,
+        out_profile_index: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_set_optimization_profile(
+        context: *mut TrtxExecutionContext,
+        profile_index: i32,
+        cuda_stream: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_set_nvtx_verbosity(
+        context: *mut TrtxExecutionContext,
+        verbosity: i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_get_nvtx_verbosity(
+        context: *mut TrtxExecutionContext,
+        out_verbosity: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_set_aux_streams(
+        context: *mut TrtxExecutionContext,
+        streams: *mut *mut ::std::os::raw::c_void,
+        nb_streams: i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_get_tensor_shape(
+        context: *mut TrtxExecutionContext,
+        tensor_name: *const ::std::os::raw::c_char,
+        out_dims: *mut i64,
+        max_dims: i32,
+        out_nb_dims: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_get_tensor_strides(
+        context: *mut TrtxExecutionContext,
+        tensor_name: *const ::std::os::raw::c_char,
+        out_strides: *mut i64,
+        max_dims: i32,
+        out_nb_dims: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_get_max_output_size(
+        context: *mut TrtxExecutionContext,
+        tensor_name: *const ::std::os::raw::c_char,
+        out_size: *mut i64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_set_output_allocator(
+        context: *mut TrtxExecutionContext,
+        tensor_name: *const ::std::os::raw::c_char,
+        allocator: *mut TrtxOutputAllocator,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_execution_context_enqueue_v3(
+        context: *mut TrtxExecutionContext,
+        cuda_stream: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_free_buffer(buffer: *mut ::std::os::raw::c_void);
+
+    // Plugin registry functions
+    pub fn trtx_load_plugin_library(
+        path: *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_plugin_creator_create(
+        name: *const ::std::os::raw::c_char,
+        version: *const ::std::os::raw::c_char,
+        create: TrtxPluginCreateCallback,
+        clone: TrtxPluginCloneCallback,
+        enqueue: TrtxPluginEnqueueCallback,
+        destroy: TrtxPluginDestroyCallback,
+        user_data: *mut ::std::os::raw::c_void,
+        out_creator: *mut *mut TrtxPluginCreator,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_plugin_creator_destroy(creator: *mut TrtxPluginCreator);
+
+    pub fn trtx_runtime_register_plugin_creator(
+        runtime: *mut TrtxRuntime,
+        creator: *mut TrtxPluginCreator,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    // ONNX Parser functions
+    pub fn trtx_onnx_parser_create(
+        network: *mut TrtxNetworkDefinition,
+        logger: *mut TrtxLogger,
+        out_parser: *mut *mut TrtxOnnxParser,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_onnx_parser_destroy(parser: *mut TrtxOnnxParser);
+
+    pub fn trtx_onnx_parser_parse(
+        parser: *mut TrtxOnnxParser,
+        model_data: *const ::std::os::raw::c_void,
+        model_size: usize,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_onnx_parser_supports_model(
+        parser: *mut TrtxOnnxParser,
+        model_data: *const ::std::os::raw::c_void,
+        model_size: usize,
+        out_supported: *mut bool,
+        out_nb_subgraphs: *mut i64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_onnx_parser_get_subgraph(
+        parser: *mut TrtxOnnxParser,
+        index: i64,
+        out_supported: *mut bool,
+        out_nodes: *mut *const i64,
+        out_nb_nodes: *mut i64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_onnx_parser_set_flag(
+        parser: *mut TrtxOnnxParser,
+        flag: i32,
+        enabled: bool,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_onnx_parser_get_flag(
+        parser: *mut TrtxOnnxParser,
+        flag: i32,
+        out_enabled: *mut bool,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    // CUDA Memory Management functions
+    pub fn trtx_cuda_malloc(
+        ptr: *mut *mut ::std::os::raw::c_void,
+        size: usize,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_free(
+        ptr: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_memcpy_host_to_device(
+        dst: *mut ::std::os::raw::c_void,
+        src: *const ::std::os::raw::c_void,
+        size: usize,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_memcpy_device_to_host(
+        dst: *mut ::std::os::raw::c_void,
+        src: *const ::std::os::raw::c_void,
+        size: usize,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_memcpy_device_to_device(
+        dst: *mut ::std::os::raw::c_void,
+        src: *const ::std::os::raw::c_void,
+        size: usize,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_memset(
+        ptr: *mut ::std::os::raw::c_void,
+        value: i32,
+        size: usize,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_synchronize(
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_mem_get_info(
+        free_bytes: *mut usize,
+        total_bytes: *mut usize,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_set_device(
+        device: i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_ensure_context(
+        out_cuda_error: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_get_device(
+        device: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_get_last_error(
+        peek: bool,
+        out_code: *mut i32,
+        out_string: *mut *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_get_error_string(
+        code: i32,
+        out_string: *mut *const ::std::os::raw::c_char,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_stream_create(
+        stream: *mut *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_stream_create_blocking(
+        stream: *mut *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_stream_create_with_priority(
+        stream: *mut *mut ::std::os::raw::c_void,
+        priority: i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_stream_get_priority(
+        stream: *mut ::std::os::raw::c_void,
+        out_priority: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_stream_priority_range(
+        out_least: *mut i32,
+        out_greatest: *mut i32,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_stream_destroy(
+        stream: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_stream_synchronize(
+        stream: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_stream_launch_host_fn(
+        stream: *mut ::std::os::raw::c_void,
+        callback: TrtxHostFnCallback,
+        user_data: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_stream_begin_capture(
+        stream: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_stream_end_capture(
+        stream: *mut ::std::os::raw::c_void,
+        graph_exec: *mut *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_graph_launch(
+        graph_exec: *mut ::std::os::raw::c_void,
+        stream: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_graph_exec_destroy(
+        graph_exec: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_event_create(
+        event: *mut *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_event_destroy(
+        event: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_event_record(
+        event: *mut ::std::os::raw::c_void,
+        stream: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_event_query(
+        event: *mut ::std::os::raw::c_void,
+        out_complete: *mut bool,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_event_synchronize(
+        event: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_malloc_managed(
+        ptr: *mut *mut ::std::os::raw::c_void,
+        size: usize,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_mem_prefetch_async(
+        ptr: *const ::std::os::raw::c_void,
+        size: usize,
+        stream: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_malloc_async(
+        ptr: *mut *mut ::std::os::raw::c_void,
+        size: usize,
+        pool: *mut ::std::os::raw::c_void,
+        stream: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_free_async(
+        ptr: *mut ::std::os::raw::c_void,
+        stream: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_mem_pool_create(
+        pool: *mut *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_mem_pool_destroy(
+        pool: *mut ::std::os::raw::c_void,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_mem_pool_set_release_threshold(
+        pool: *mut ::std::os::raw::c_void,
+        threshold: u64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_mem_pool_get_release_threshold(
+        pool: *mut ::std::os::raw::c_void,
+        out_threshold: *mut u64,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_device_get_limit(
+        limit: i32,
+        value: *mut usize,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_device_set_limit(
+        limit: i32,
+        value: usize,
+        error_msg: *mut ::std::os::raw::c_char,
+        error_msg_len: usize,
+    ) -> i32;
+
+    pub fn trtx_cuda_device_get_attribute(
+        attribute: i32,
+        value: *mut i32,
         error_msg: *mut ::std::os::raw::c_char,
         error_msg_len: usize,
     ) -> i32;
@@ -1,19 +1,267 @@
 // Mock implementations for development without TensorRT-RTX
 // These are stubs that allow compilation and basic testing
 
+#include <stdatomic.h>
+#include <stdbool.h>
 #include <stdint.h>
 #include <stddef.h>
+#include <stdio.h>
 #include <stdlib.h>
 #include <string.h>
 
 // Mock handles (just use integers)
 typedef struct { int dummy; } TrtxLogger;
-typedef struct { int dummy; } TrtxBuilder;
-typedef struct { int dummy; } TrtxBuilderConfig;
-typedef struct { int dummy; } TrtxNetworkDefinition;
-typedef struct { int dummy; } TrtxRuntime;
-typedef struct { int dummy; } TrtxCudaEngine;
-typedef struct { int dummy; } TrtxExecutionContext;
+typedef struct {
+    int32_t code;
+    char desc[256];
+} MockRecordedError;
+typedef struct {
+    int32_t ref_count;
+    int32_t nb_errors;
+    MockRecordedError errors[16];
+} TrtxErrorRecorder;
+// Mock optimization profiles hold min/opt/max dimensions for a few inputs
+#define MOCK_PROFILE_MAX_INPUTS 4
+#define MOCK_MAX_PROFILES 16
+typedef struct {
+    char name[64];
+    int32_t nb_dims;
+    bool set[3];
+    int64_t dims[3][8];
+} MockProfileInput;
+typedef struct {
+    int32_t nb_inputs;
+    MockProfileInput inputs[MOCK_PROFILE_MAX_INPUTS];
+} TrtxOptimizationProfile;
+typedef struct {
+    TrtxErrorRecorder* recorder;
+    int32_t max_threads;
+    // Profiles belong to the builder, as in TensorRT
+    int32_t nb_profiles;
+    TrtxOptimizationProfile* profiles[MOCK_MAX_PROFILES];
+} TrtxBuilder;
+typedef void (*TrtxPhaseStartCallback)(
+    void* user_data, const char* phase_name, const char* parent_phase, int32_t nb_steps);
+typedef bool (*TrtxStepCompleteCallback)(void* user_data, const char* phase_name, int32_t step);
+typedef void (*TrtxPhaseFinishCallback)(void* user_data, const char* phase_name);
+typedef struct {
+    TrtxPhaseStartCallback phase_start;
+    TrtxStepCompleteCallback step_complete;
+    TrtxPhaseFinishCallback phase_finish;
+    void* user_data;
+} TrtxProgressMonitor;
+// Mock timing caches hold a bitmask of timed tactics; every build times a new one
+typedef struct { uint32_t tactics; } TrtxTimingCache;
+static atomic_uint mock_next_tactic;
+typedef struct {
+    uint32_t flags;
+    int32_t runtime_platform;
+    bool zero_workspace;
+    size_t pool_limits[4];
+    uint32_t preview_features;
+    TrtxProgressMonitor* progress_monitor;
+    TrtxTimingCache* timing_cache;
+    int32_t max_aux_streams;
+    int32_t nb_profiles;
+    int32_t profiling_verbosity;
+    int32_t engine_capability;
+    int32_t optimization_level;
+} TrtxBuilderConfig;
+// Mock networks hold one layer, "mock_layer", producing "output" from "input",
+// plus whatever inputs and constant layers are added
+#define MOCK_NETWORK_MAX_TENSORS 8
+typedef struct {
+    char name[64];
+    int32_t data_type;
+    int32_t nb_dims;
+    int64_t dims[8];
+    bool is_output;
+} TrtxTensor;
+typedef struct {
+    char name[64];
+    TrtxTensor output;
+} TrtxLayer;
+typedef struct {
+    uint32_t flags;
+    int32_t output_type;
+    int32_t layer_precision;
+    char name[256];
+    int32_t nb_added_inputs;
+    TrtxTensor added_inputs[MOCK_NETWORK_MAX_TENSORS];
+    int32_t nb_layers;
+    TrtxLayer layers[MOCK_NETWORK_MAX_TENSORS];
+} TrtxNetworkDefinition;
+typedef struct { bool host_code_allowed; int32_t max_threads; } TrtxRuntime;
+typedef struct {
+    int64_t weight_streaming_budget;
+    int32_t nb_inputs;
+    int32_t nb_outputs;
+    bool data_dependent;
+    bool refittable;
+    bool dynamic_width;
+    bool shape_input;
+    int64_t streamable_weights;
+    char name[256];
+} TrtxCudaEngine;
+typedef void (*TrtxHostFnCallback)(void* user_data);
+typedef void (*TrtxProfilerCallback)(void* user_data, const char* layer_name, float ms);
+typedef struct {
+    TrtxProfilerCallback callback;
+    void* user_data;
+} TrtxProfiler;
+typedef void (*TrtxDebugTensorCallback)(
+    void* user_data, const char* name, const void* addr, int32_t data_type,
+    const int64_t* dims, int32_t nb_dims);
+typedef struct {
+    TrtxDebugTensorCallback callback;
+    void* user_data;
+} TrtxDebugListener;
+typedef void* (*TrtxReallocateOutputCallback)(
+    void* user_data, const char* tensor_name, void* current_memory, uint64_t size, uint64_t alignment);
+typedef void (*TrtxNotifyShapeCallback)(
+    void* user_data, const char* tensor_name, const int64_t* dims, int32_t nb_dims);
+typedef struct {
+    TrtxReallocateOutputCallback reallocate;
+    TrtxNotifyShapeCallback notify_shape;
+    void* user_data;
+} TrtxOutputAllocator;
+typedef struct {
+    TrtxCudaEngine* engine;
+    TrtxProfiler* profiler;
+    TrtxDebugListener* debug_listener;
+    void* addresses[5];
+    TrtxOutputAllocator* output_allocators[5];
+    int64_t input_dims[8];
+    int32_t input_nb_dims;
+    int32_t profile;
+    int32_t nvtx_verbosity;
+    int32_t nb_aux_streams;
+    char name[256];
+} TrtxExecutionContext;
+typedef struct { int dummy; } TrtxEngineInspector;
+
+// Mock streams record enqueued contexts while capturing instead of running them
+#define MOCK_MAX_CAPTURED 16
+typedef struct {
+    bool capturing;
+    int32_t nb_captured;
+    TrtxExecutionContext* captured[MOCK_MAX_CAPTURED];
+    int32_t priority;
+} MockStream;
+
+// Mock: the priority range of current NVIDIA GPUs
+#define MOCK_STREAM_PRIORITY_LEAST 0
+#define MOCK_STREAM_PRIORITY_GREATEST -5
+typedef struct {
+    int32_t nb_contexts;
+    TrtxExecutionContext* contexts[MOCK_MAX_CAPTURED];
+} MockGraphExec;
+// Mock events report one query as pending after each record, so callers
+// exercise their wait path even though mock work completes immediately
+typedef struct {
+    atomic_int pending_queries;
+} MockEvent;
+
+// Mock engine: by default one input and one output, both float32 with shape
+// [-1, 4]. The output is a copy of the input.
+//
+// Serialized engines starting with "TRTXMOCK" followed by two bytes
+// (input count 0-1, output count 1-4) override the I/O layout. Without an
+// input, outputs have the static shape [1, 4] and are filled with 1.0.
+//
+// An optional third byte of 1 makes the outputs data-dependent, like NonZero:
+// they keep only the input rows whose first element is non-zero, so their
+// first dimension stays -1 until execution. Bit 1 of that byte makes the
+// engine refittable, bit 2 makes both dimensions of the input and output
+// dynamic ([-1, -1]), and bit 3 makes the input a shape tensor. Any bytes
+// after it are the engine's name.
+//
+// Plans starting with "TRTXOLDV" stand for plans built by another TensorRT
+// version and fail to deserialize.
+#define MOCK_ENGINE_MAGIC "TRTXMOCK"
+#define MOCK_FOREIGN_MAGIC "TRTXOLDV"
+#define MOCK_MAX_OUTPUTS 4
+
+static const int64_t MOCK_TENSOR_DIMS[] = {-1, 4};
+static const int64_t MOCK_STATIC_DIMS[] = {1, 4};
+static const int64_t MOCK_DYNAMIC_DIMS[] = {-1, -1};
+static const int32_t MOCK_TENSOR_NB_DIMS = 2;
+// Min, opt and max shapes of the input in the engine's only profile
+static const int64_t MOCK_PROFILE_DIMS[3][2] = {{1, 4}, {8, 4}, {64, 4}};
+static const char* MOCK_OUTPUT_NAMES[] = {"output", "output_1", "output_2", "output_3"};
+
+static const char* mock_tensor_name(const TrtxCudaEngine* engine, int32_t index) {
+    if (index < 0 || index >= engine->nb_inputs + engine->nb_outputs) {
+        return NULL;
+    }
+    return index < engine->nb_inputs ? "input" : MOCK_OUTPUT_NAMES[index - engine->nb_inputs];
+}
+
+static int mock_tensor_index(const TrtxCudaEngine* engine, const char* name) {
+    for (int32_t i = 0; name && i < engine->nb_inputs + engine->nb_outputs; ++i) {
+        if (strcmp(name, mock_tensor_name(engine, i)) == 0) return i;
+    }
+    return -1;
+}
+
+static const int64_t* mock_engine_dims(const TrtxCudaEngine* engine) {
+    if (engine->nb_inputs == 0) return MOCK_STATIC_DIMS;
+    return engine->dynamic_width ? MOCK_DYNAMIC_DIMS : MOCK_TENSOR_DIMS;
+}
+
+static int32_t mock_copy_dims(
+    const int64_t* dims,
+    int32_t nb_dims,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims
+) {
+    if (nb_dims > max_dims) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    memcpy(out_dims, dims, nb_dims * sizeof(int64_t));
+    *out_nb_dims = nb_dims;
+    return 0;
+}
+
+// Mock device allocations are 256-byte aligned like cudaMalloc, and store
+// their size in a header so that enqueue can bound its copy
+#define MOCK_ALLOC_ALIGNMENT 256
+
+typedef struct {
+    void* block;
+    size_t size;
+} MockAllocHeader;
+
+static MockAllocHeader* mock_alloc_header(const void* ptr) {
+    return (MockAllocHeader*)((char*)ptr - sizeof(MockAllocHeader));
+}
+
+static size_t mock_alloc_size(const void* ptr) {
+    return mock_alloc_header(ptr)->size;
+}
+
+static void mock_copy_error(const char* msg, char* error_msg, size_t error_msg_len) {
+    if (error_msg && error_msg_len > 0) {
+        strncpy(error_msg, msg, error_msg_len - 1);
+        error_msg[error_msg_len - 1] = '\0';
+    }
+}
+
+static void mock_recorder_release(TrtxErrorRecorder* recorder) {
+    if (recorder && --recorder->ref_count == 0) {
+        free(recorder);
+    }
+}
+
+static void mock_recorder_report(TrtxErrorRecorder* recorder, int32_t code, const char* desc) {
+    if (!recorder || recorder->nb_errors >= 16) {
+        return;
+    }
+    MockRecordedError* error = &recorder->errors[recorder->nb_errors++];
+    error->code = code;
+    mock_copy_error(desc, error->desc, sizeof(error->desc));
+}
 
 // Mock implementations - all return success
 
@@ -38,14 +286,54 @@ int32_t trtx_builder_create(
     char* error_msg,
     size_t error_msg_len
 ) {
-    *out_builder = malloc(sizeof(TrtxBuilder));
+    *out_builder = calloc(1, sizeof(TrtxBuilder));
+    (*out_builder)->max_threads = 1;
     return 0;
 }
 
 void trtx_builder_destroy(TrtxBuilder* builder) {
+    if (builder) {
+        mock_recorder_release(builder->recorder);
+        for (int32_t i = 0; i < builder->nb_profiles; ++i) {
+            free(builder->profiles[i]);
+        }
+    }
     free(builder);
 }
 
+int32_t trtx_builder_set_max_threads(
+    TrtxBuilder* builder,
+    int32_t nb_threads,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (nb_threads < 1) {
+        mock_copy_error("Invalid thread count", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    builder->max_threads = nb_threads;
+    return 0;
+}
+
+int32_t trtx_builder_get_max_threads(
+    TrtxBuilder* builder,
+    int32_t* out_nb_threads,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_nb_threads = builder->max_threads;
+    return 0;
+}
+
+int32_t trtx_builder_reset(
+    TrtxBuilder* builder,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Mock: the builder holds no per-network state, and its error recorder is kept
+    return 0;
+}
+
 int32_t trtx_builder_create_network(
     TrtxBuilder* builder,
     uint32_t flags,
@@ -53,7 +341,28 @@ int32_t trtx_builder_create_network(
     char* error_msg,
     size_t error_msg_len
 ) {
-    *out_network = malloc(sizeof(TrtxNetworkDefinition));
+    // kEXPLICIT_BATCH and kSTRONGLY_TYPED
+    if (flags & ~3u) {
+        mock_copy_error("Unknown network creation flags", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_network = calloc(1, sizeof(TrtxNetworkDefinition));
+    (*out_network)->flags = flags;
+    return 0;
+}
+
+int32_t trtx_builder_create_optimization_profile(
+    TrtxBuilder* builder,
+    TrtxOptimizationProfile** out_profile,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (builder->nb_profiles >= MOCK_MAX_PROFILES) {
+        mock_copy_error("Too many optimization profiles", error_msg, error_msg_len);
+        return 3; // TRTX_ERROR_RUNTIME_ERROR
+    }
+    *out_profile = calloc(1, sizeof(TrtxOptimizationProfile));
+    builder->profiles[builder->nb_profiles++] = *out_profile;
     return 0;
 }
 
@@ -63,10 +372,31 @@ int32_t trtx_builder_create_builder_config(
     char* error_msg,
     size_t error_msg_len
 ) {
-    *out_config = malloc(sizeof(TrtxBuilderConfig));
+    *out_config = calloc(1, sizeof(TrtxBuilderConfig));
+    (*out_config)->max_aux_streams = -1;
+    (*out_config)->optimization_level = 3;
     return 0;
 }
 
+// Mock build phases, reported as steps of a top-level phase
+static const char* MOCK_BUILD_PHASE = "Building engine";
+static const char* MOCK_BUILD_STEPS[] = {"Optimizing network", "Timing tactics", "Serializing engine"};
+
+// Returns false if the monitor cancelled the build
+static bool mock_report_build_phases(TrtxProgressMonitor* monitor) {
+    const int32_t nb_steps = sizeof(MOCK_BUILD_STEPS) / sizeof(MOCK_BUILD_STEPS[0]);
+    bool keep_going = true;
+
+    monitor->phase_start(monitor->user_data, MOCK_BUILD_PHASE, NULL, nb_steps);
+    for (int32_t step = 0; step < nb_steps && keep_going; ++step) {
+        monitor->phase_start(monitor->user_data, MOCK_BUILD_STEPS[step], MOCK_BUILD_PHASE, 0);
+        monitor->phase_finish(monitor->user_data, MOCK_BUILD_STEPS[step]);
+        keep_going = monitor->step_complete(monitor->user_data, MOCK_BUILD_PHASE, step);
+    }
+    monitor->phase_finish(monitor->user_data, MOCK_BUILD_PHASE);
+    return keep_going;
+}
+
 int32_t trtx_builder_build_serialized_network(
     TrtxBuilder* builder,
     TrtxNetworkDefinition* network,
@@ -76,6 +406,57 @@ int32_t trtx_builder_build_serialized_network(
     char* error_msg,
     size_t error_msg_len
 ) {
+    // Mock: a zero workspace leaves no tactics to choose from
+    if (config->zero_workspace) {
+        mock_recorder_report(builder->recorder, 4, // kINVALID_CONFIG
+            "Workspace memory pool limit is 0 bytes; no tactics available");
+        mock_copy_error("Failed to build serialized network", error_msg, error_msg_len);
+        return 3; // TRTX_ERROR_RUNTIME_ERROR
+    }
+
+    // Mock: a workspace larger than the free device memory (see
+    // trtx_cuda_mem_get_info) cannot be allocated
+    if (config->pool_limits[0] > ((size_t)6 << 30)) {
+        mock_recorder_report(builder->recorder, 5, // kFAILED_ALLOCATION
+            "Requested workspace exceeds available device memory");
+        mock_copy_error("Out of memory building serialized network", error_msg, error_msg_len);
+        return 2; // TRTX_ERROR_OUT_OF_MEMORY
+    }
+
+    // Mock: mock_layer has no FP16 implementation, so obeying FP16
+    // precision constraints fails like an unsupported op would. Strongly
+    // typed networks ignore precision flags.
+    bool strongly_typed = (network->flags & (1u << 1)) != 0;
+    if (!strongly_typed && (config->flags & (1u << 0)) && (config->flags & (1u << 9))) { // kFP16, kOBEY_PRECISION_CONSTRAINTS
+        mock_recorder_report(builder->recorder, 10, // kUNSUPPORTED_STATE
+            "No implementation of mock_layer obeys the requested FP16 precision");
+        mock_copy_error("Failed to build serialized network", error_msg, error_msg_len);
+        return 3; // TRTX_ERROR_RUNTIME_ERROR
+    }
+
+    if (config->progress_monitor && !mock_report_build_phases(config->progress_monitor)) {
+        mock_copy_error("Failed to build serialized network", error_msg, error_msg_len);
+        return 3; // TRTX_ERROR_RUNTIME_ERROR
+    }
+
+    if (config->timing_cache) {
+        config->timing_cache->tactics |= 1u << (atomic_fetch_add(&mock_next_tactic, 1) % 32);
+    }
+
+    // Named or refittable networks build the identity engine in its explicit
+    // layout, to carry the name and flags
+    size_t name_len = strlen(network->name);
+    bool refit = (config->flags & (1u << 4)) != 0; // kREFIT
+    if (name_len > 0 || refit) {
+        const size_t magic_len = strlen(MOCK_ENGINE_MAGIC);
+        *out_size = magic_len + 3 + name_len;
+        *out_data = malloc(*out_size);
+        memcpy(*out_data, MOCK_ENGINE_MAGIC, magic_len);
+        memcpy((char*)*out_data + magic_len, refit ? "\x01\x01\x02" : "\x01\x01\x00", 3);
+        memcpy((char*)*out_data + magic_len + 3, network->name, name_len);
+        return 0;
+    }
+
     // Return a small dummy buffer
     *out_size = 16;
     *out_data = malloc(16);
@@ -83,6 +464,60 @@ int32_t trtx_builder_build_serialized_network(
     return 0;
 }
 
+int32_t trtx_builder_set_error_recorder(
+    TrtxBuilder* builder,
+    TrtxErrorRecorder* recorder,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (recorder) {
+        recorder->ref_count++;
+    }
+    mock_recorder_release(builder->recorder);
+    builder->recorder = recorder;
+    return 0;
+}
+
+int32_t trtx_error_recorder_create(
+    TrtxErrorRecorder** out_recorder,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_recorder = calloc(1, sizeof(TrtxErrorRecorder));
+    (*out_recorder)->ref_count = 1;
+    return 0;
+}
+
+void trtx_error_recorder_destroy(TrtxErrorRecorder* recorder) {
+    mock_recorder_release(recorder);
+}
+
+int32_t trtx_error_recorder_get_nb_errors(TrtxErrorRecorder* recorder, int32_t* out_count) {
+    *out_count = recorder->nb_errors;
+    return 0;
+}
+
+int32_t trtx_error_recorder_get_error(
+    TrtxErrorRecorder* recorder,
+    int32_t index,
+    int32_t* out_code,
+    char* desc_buf,
+    size_t desc_buf_len,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (index < 0 || index >= recorder->nb_errors) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_code = recorder->errors[index].code;
+    mock_copy_error(recorder->errors[index].desc, desc_buf, desc_buf_len);
+    return 0;
+}
+
+void trtx_error_recorder_clear(TrtxErrorRecorder* recorder) {
+    recorder->nb_errors = 0;
+}
+
 void trtx_builder_config_destroy(TrtxBuilderConfig* config) {
     free(config);
 }
@@ -94,182 +529,2431 @@ int32_t trtx_builder_config_set_memory_pool_limit(
     char* error_msg,
     size_t error_msg_len
 ) {
+    if (pool_type < 0 || pool_type >= 4) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    if (pool_type == 0) { // kWORKSPACE
+        config->zero_workspace = pool_size == 0;
+    }
+    config->pool_limits[pool_type] = pool_size;
     return 0;
 }
 
-void trtx_network_destroy(TrtxNetworkDefinition* network) {
-    free(network);
-}
-
-int32_t trtx_runtime_create(
-    TrtxLogger* logger,
-    TrtxRuntime** out_runtime,
+int32_t trtx_builder_config_get_memory_pool_limit(
+    TrtxBuilderConfig* config,
+    int32_t pool_type,
+    size_t* out_pool_size,
     char* error_msg,
     size_t error_msg_len
 ) {
-    *out_runtime = malloc(sizeof(TrtxRuntime));
+    if (pool_type < 0 || pool_type >= 4) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_pool_size = config->pool_limits[pool_type];
     return 0;
 }
 
-void trtx_runtime_destroy(TrtxRuntime* runtime) {
-    free(runtime);
+int32_t trtx_builder_config_set_flag(
+    TrtxBuilderConfig* config,
+    int32_t flag,
+    bool enabled,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (flag < 0 || flag >= 32) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    if (enabled) {
+        config->flags |= (1u << flag);
+    } else {
+        config->flags &= ~(1u << flag);
+    }
+    return 0;
 }
 
-int32_t trtx_runtime_deserialize_cuda_engine(
-    TrtxRuntime* runtime,
-    const void* data,
-    size_t size,
-    TrtxCudaEngine** out_engine,
+int32_t trtx_builder_config_get_flag(
+    TrtxBuilderConfig* config,
+    int32_t flag,
+    bool* out_enabled,
     char* error_msg,
     size_t error_msg_len
 ) {
-    *out_engine = malloc(sizeof(TrtxCudaEngine));
+    if (flag < 0 || flag >= 32) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_enabled = (config->flags & (1u << flag)) != 0;
     return 0;
 }
 
-void trtx_cuda_engine_destroy(TrtxCudaEngine* engine) {
-    free(engine);
+// Mock: the library predates kRUNTIME_ACTIVATION_RESIZE_10_10
+#define MOCK_NB_PREVIEW_FEATURES 2
+
+int32_t trtx_builder_config_set_preview_feature(
+    TrtxBuilderConfig* config,
+    int32_t feature,
+    bool enabled,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (feature < 0) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    if (feature >= MOCK_NB_PREVIEW_FEATURES) {
+        return 0;
+    }
+    if (enabled) {
+        config->preview_features |= (1u << feature);
+    } else {
+        config->preview_features &= ~(1u << feature);
+    }
+    return 0;
 }
 
-int32_t trtx_cuda_engine_create_execution_context(
-    TrtxCudaEngine* engine,
-    TrtxExecutionContext** out_context,
+int32_t trtx_builder_config_get_preview_feature(
+    TrtxBuilderConfig* config,
+    int32_t feature,
+    bool* out_enabled,
     char* error_msg,
     size_t error_msg_len
 ) {
-    *out_context = malloc(sizeof(TrtxExecutionContext));
+    if (feature < 0) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_enabled = feature < MOCK_NB_PREVIEW_FEATURES
+        && (config->preview_features & (1u << feature)) != 0;
     return 0;
 }
 
-int32_t trtx_cuda_engine_get_tensor_name(
-    TrtxCudaEngine* engine,
-    int32_t index,
-    const char** out_name,
+int32_t trtx_builder_config_set_max_aux_streams(
+    TrtxBuilderConfig* config,
+    int32_t nb_streams,
     char* error_msg,
     size_t error_msg_len
 ) {
-    static const char* mock_names[] = {"input", "output"};
-    if (index < 0 || index >= 2) {
+    if (nb_streams < -1) {
         return 1; // TRTX_ERROR_INVALID_ARGUMENT
     }
-    *out_name = mock_names[index];
+    config->max_aux_streams = nb_streams;
     return 0;
 }
 
-int32_t trtx_cuda_engine_get_nb_io_tensors(
-    TrtxCudaEngine* engine,
-    int32_t* out_count
+int32_t trtx_builder_config_get_max_aux_streams(
+    TrtxBuilderConfig* config,
+    int32_t* out_nb_streams,
+    char* error_msg,
+    size_t error_msg_len
 ) {
-    *out_count = 2; // Mock: 1 input, 1 output
+    *out_nb_streams = config->max_aux_streams;
     return 0;
 }
 
-void trtx_execution_context_destroy(TrtxExecutionContext* context) {
-    free(context);
+// A profile is valid when every input has min <= opt <= max in each dimension
+static bool mock_profile_is_valid(const TrtxOptimizationProfile* profile) {
+    for (int32_t i = 0; i < profile->nb_inputs; ++i) {
+        const MockProfileInput* input = &profile->inputs[i];
+        if (!input->set[0] || !input->set[1] || !input->set[2]) return false;
+        for (int32_t d = 0; d < input->nb_dims; ++d) {
+            if (input->dims[0][d] > input->dims[1][d] || input->dims[1][d] > input->dims[2][d]) {
+                return false;
+            }
+        }
+    }
+    return true;
 }
 
-int32_t trtx_execution_context_set_tensor_address(
-    TrtxExecutionContext* context,
-    const char* tensor_name,
-    void* data,
+int32_t trtx_builder_config_add_optimization_profile(
+    TrtxBuilderConfig* config,
+    TrtxOptimizationProfile* profile,
+    int32_t* out_index,
     char* error_msg,
     size_t error_msg_len
 ) {
+    if (!mock_profile_is_valid(profile)) {
+        mock_copy_error("Invalid optimization profile", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_index = config->nb_profiles++;
     return 0;
 }
 
-int32_t trtx_execution_context_enqueue_v3(
-    TrtxExecutionContext* context,
-    void* cuda_stream,
+int32_t trtx_builder_config_get_nb_optimization_profiles(
+    TrtxBuilderConfig* config,
+    int32_t* out_count,
     char* error_msg,
     size_t error_msg_len
 ) {
+    *out_count = config->nb_profiles;
     return 0;
 }
 
-void trtx_free_buffer(void* buffer) {
-    free(buffer);
+int32_t trtx_builder_config_set_runtime_platform(
+    TrtxBuilderConfig* config,
+    int32_t platform,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    config->runtime_platform = platform;
+    return 0;
 }
 
-// ONNX Parser mock implementations
-typedef struct { int dummy; } TrtxOnnxParser;
+int32_t trtx_builder_config_get_runtime_platform(
+    TrtxBuilderConfig* config,
+    int32_t* out_platform,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_platform = config->runtime_platform;
+    return 0;
+}
 
-int32_t trtx_onnx_parser_create(
-    void* network,
-    void* logger,
-    TrtxOnnxParser** out_parser,
+int32_t trtx_builder_config_set_engine_capability(
+    TrtxBuilderConfig* config,
+    int32_t capability,
     char* error_msg,
     size_t error_msg_len
 ) {
-    *out_parser = malloc(sizeof(TrtxOnnxParser));
+    if (capability < 0 || capability > 2) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    config->engine_capability = capability;
     return 0;
 }
 
-void trtx_onnx_parser_destroy(TrtxOnnxParser* parser) {
-    free(parser);
+int32_t trtx_builder_config_get_engine_capability(
+    TrtxBuilderConfig* config,
+    int32_t* out_capability,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_capability = config->engine_capability;
+    return 0;
 }
 
-int32_t trtx_onnx_parser_parse(
-    TrtxOnnxParser* parser,
-    const void* model_data,
-    size_t model_size,
+int32_t trtx_builder_config_set_builder_optimization_level(
+    TrtxBuilderConfig* config,
+    int32_t level,
     char* error_msg,
     size_t error_msg_len
 ) {
-    // Mock: always succeeds
+    if (level < 0 || level > 5) {
+        mock_copy_error("Optimization level must be between 0 and 5", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    config->optimization_level = level;
     return 0;
 }
 
-// CUDA Memory Management mock implementations
-int32_t trtx_cuda_malloc(
-    void** ptr,
-    size_t size,
+int32_t trtx_builder_config_get_builder_optimization_level(
+    TrtxBuilderConfig* config,
+    int32_t* out_level,
     char* error_msg,
     size_t error_msg_len
 ) {
-    *ptr = malloc(size);
-    return *ptr ? 0 : 2; // 0 = success, 2 = out of memory
+    *out_level = config->optimization_level;
+    return 0;
 }
 
-int32_t trtx_cuda_free(
-    void* ptr,
+// Mock: engines report detailed layer information whatever the verbosity
+int32_t trtx_builder_config_set_profiling_verbosity(
+    TrtxBuilderConfig* config,
+    int32_t verbosity,
     char* error_msg,
     size_t error_msg_len
 ) {
-    free(ptr);
+    if (verbosity < 0 || verbosity > 2) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    config->profiling_verbosity = verbosity;
     return 0;
 }
 
-int32_t trtx_cuda_memcpy_host_to_device(
-    void* dst,
-    const void* src,
-    size_t size,
+void trtx_network_destroy(TrtxNetworkDefinition* network) {
+    free(network);
+}
+
+int32_t trtx_network_set_name(
+    TrtxNetworkDefinition* network,
+    const char* name,
     char* error_msg,
     size_t error_msg_len
 ) {
-    memcpy(dst, src, size);
+    mock_copy_error(name, network->name, sizeof(network->name));
     return 0;
 }
 
-int32_t trtx_cuda_memcpy_device_to_host(
-    void* dst,
-    const void* src,
-    size_t size,
+int32_t trtx_network_get_name(
+    TrtxNetworkDefinition* network,
+    const char** out_name,
     char* error_msg,
     size_t error_msg_len
 ) {
-    memcpy(dst, src, size);
+    *out_name = network->name;
     return 0;
 }
 
-int32_t trtx_cuda_synchronize(
+int32_t trtx_network_get_flags(
+    TrtxNetworkDefinition* network,
+    uint32_t* out_flags,
     char* error_msg,
     size_t error_msg_len
 ) {
-    // Mock: nothing to synchronize
+    *out_flags = network->flags;
     return 0;
 }
 
+int32_t trtx_network_set_output_type(
+    TrtxNetworkDefinition* network,
+    const char* tensor_name,
+    int32_t data_type,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (strcmp(tensor_name, "output") != 0) {
+        mock_copy_error("No layer output with that name", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    network->output_type = data_type;
+    return 0;
+}
+
+int32_t trtx_network_get_output_type(
+    TrtxNetworkDefinition* network,
+    const char* tensor_name,
+    int32_t* out_data_type,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (strcmp(tensor_name, "output") != 0) {
+        mock_copy_error("No layer output with that name", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_data_type = network->output_type;
+    return 0;
+}
+
+int32_t trtx_network_set_layer_precision(
+    TrtxNetworkDefinition* network,
+    const char* layer_name,
+    int32_t data_type,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (strcmp(layer_name, "mock_layer") != 0) {
+        mock_copy_error("No layer with that name", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    network->layer_precision = data_type;
+    return 0;
+}
+
+int32_t trtx_network_get_layer_precision(
+    TrtxNetworkDefinition* network,
+    const char* layer_name,
+    int32_t* out_data_type,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (strcmp(layer_name, "mock_layer") != 0) {
+        mock_copy_error("No layer with that name", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_data_type = network->layer_precision;
+    return 0;
+}
+
+// Mock networks have the mock engine's default input and output
+int32_t trtx_network_mark_debug(
+    TrtxNetworkDefinition* network,
+    const char* name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (strcmp(name, "input") != 0 && strcmp(name, "output") != 0) {
+        mock_copy_error("No tensor with that name", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    return 0;
+}
+
+int32_t trtx_network_get_nb_inputs(
+    TrtxNetworkDefinition* network,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_count = 1 + network->nb_added_inputs;
+    return 0;
+}
+
+int32_t trtx_network_get_input_name(
+    TrtxNetworkDefinition* network,
+    int32_t index,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (index < 0 || index > network->nb_added_inputs) {
+        mock_copy_error("Invalid input index", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_name = index == 0 ? "input" : network->added_inputs[index - 1].name;
+    return 0;
+}
+
+int32_t trtx_network_get_input_shape(
+    TrtxNetworkDefinition* network,
+    int32_t index,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (index < 0 || index > network->nb_added_inputs) {
+        mock_copy_error("Invalid input index", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    if (index > 0) {
+        TrtxTensor* input = &network->added_inputs[index - 1];
+        return mock_copy_dims(input->dims, input->nb_dims, out_dims, max_dims, out_nb_dims);
+    }
+    return mock_copy_dims(MOCK_TENSOR_DIMS, MOCK_TENSOR_NB_DIMS, out_dims, max_dims, out_nb_dims);
+}
+
+static bool mock_network_has_tensor(TrtxNetworkDefinition* network, const char* name) {
+    if (strcmp(name, "input") == 0 || strcmp(name, "output") == 0) return true;
+    for (int32_t i = 0; i < network->nb_added_inputs; ++i) {
+        if (strcmp(network->added_inputs[i].name, name) == 0) return true;
+    }
+    return false;
+}
+
+int32_t trtx_network_add_input(
+    TrtxNetworkDefinition* network,
+    const char* name,
+    int32_t data_type,
+    const int64_t* dims,
+    int32_t nb_dims,
+    TrtxTensor** out_tensor,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (name[0] == '\0' || nb_dims < 0 || nb_dims > 8 || mock_network_has_tensor(network, name)) {
+        mock_copy_error("Failed to add input", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    if (network->nb_added_inputs >= MOCK_NETWORK_MAX_TENSORS) {
+        mock_copy_error("Too many network inputs", error_msg, error_msg_len);
+        return 3; // TRTX_ERROR_RUNTIME_ERROR
+    }
+    TrtxTensor* tensor = &network->added_inputs[network->nb_added_inputs++];
+    mock_copy_error(name, tensor->name, sizeof(tensor->name));
+    tensor->data_type = data_type;
+    tensor->nb_dims = nb_dims;
+    memcpy(tensor->dims, dims, nb_dims * sizeof(int64_t));
+    *out_tensor = tensor;
+    return 0;
+}
+
+// Adds a layer with a single output of the given type and shape
+static int32_t mock_network_add_layer(
+    TrtxNetworkDefinition* network,
+    const char* kind,
+    int32_t data_type,
+    const int64_t* dims,
+    int32_t nb_dims,
+    TrtxLayer** out_layer,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (network->nb_layers >= MOCK_NETWORK_MAX_TENSORS) {
+        mock_copy_error("Too many network layers", error_msg, error_msg_len);
+        return 3; // TRTX_ERROR_RUNTIME_ERROR
+    }
+    int32_t index = network->nb_layers++;
+    TrtxLayer* layer = &network->layers[index];
+    // Unnamed layers get the same generated names as in TensorRT
+    snprintf(layer->name, sizeof(layer->name), "(Unnamed Layer* %d) [%s]", index + 1, kind);
+    snprintf(layer->output.name, sizeof(layer->output.name), "%s_output", layer->name);
+    layer->output.data_type = data_type;
+    layer->output.nb_dims = nb_dims;
+    memcpy(layer->output.dims, dims, nb_dims * sizeof(int64_t));
+    *out_layer = layer;
+    return 0;
+}
+
+int32_t trtx_network_add_constant(
+    TrtxNetworkDefinition* network,
+    const int64_t* dims,
+    int32_t nb_dims,
+    int32_t data_type,
+    const void* values,
+    int64_t count,
+    TrtxLayer** out_layer,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    int64_t volume = 1;
+    for (int32_t i = 0; i < nb_dims; ++i) {
+        volume *= dims[i];
+    }
+    if (nb_dims < 0 || nb_dims > 8 || volume != count) {
+        mock_copy_error("Weight count does not match dimensions", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    return mock_network_add_layer(network, "Constant", data_type, dims, nb_dims, out_layer,
+        error_msg, error_msg_len);
+}
+
+int32_t trtx_network_add_activation(
+    TrtxNetworkDefinition* network,
+    TrtxTensor* input,
+    int32_t activation,
+    TrtxLayer** out_layer,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (activation < 0 || activation > 13) {
+        mock_copy_error("Unknown activation type", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    return mock_network_add_layer(network, "Activation", input->data_type, input->dims,
+        input->nb_dims, out_layer, error_msg, error_msg_len);
+}
+
+int32_t trtx_network_add_elementwise(
+    TrtxNetworkDefinition* network,
+    TrtxTensor* a,
+    TrtxTensor* b,
+    int32_t op,
+    TrtxLayer** out_layer,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Inputs need the same rank and type; dimensions of 1 broadcast
+    if (op < 0 || op > 13 || a->nb_dims != b->nb_dims || a->data_type != b->data_type) {
+        mock_copy_error("Invalid elementwise inputs", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    int64_t dims[8];
+    for (int32_t i = 0; i < a->nb_dims; ++i) {
+        if (a->dims[i] != b->dims[i] && a->dims[i] != 1 && b->dims[i] != 1) {
+            mock_copy_error("Elementwise inputs cannot be broadcast", error_msg, error_msg_len);
+            return 1; // TRTX_ERROR_INVALID_ARGUMENT
+        }
+        dims[i] = a->dims[i] == 1 ? b->dims[i] : a->dims[i];
+    }
+    // Logical and comparison operations (kAND onwards) produce booleans
+    int32_t data_type = op >= 8 ? 4 : a->data_type;
+    return mock_network_add_layer(network, "ElementWise", data_type, dims, a->nb_dims,
+        out_layer, error_msg, error_msg_len);
+}
+
+// Splits a matrix multiply operand into batch dimensions and the (rows, cols)
+// of its matrix after op; vectors (op 2) are a row of a and a column of b, with
+// the missing dimension reported as -2
+static bool mock_matrix_operand(
+    TrtxTensor* t, int32_t op, bool is_b, int32_t* nb_batch, int64_t* rows, int64_t* cols
+) {
+    if (op == 2) {
+        if (t->nb_dims < 1) return false;
+        *nb_batch = t->nb_dims - 1;
+        *rows = is_b ? t->dims[t->nb_dims - 1] : -2;
+        *cols = is_b ? -2 : t->dims[t->nb_dims - 1];
+        return true;
+    }
+    if (op < 0 || op > 2 || t->nb_dims < 2) return false;
+    *nb_batch = t->nb_dims - 2;
+    *rows = t->dims[t->nb_dims - 2];
+    *cols = t->dims[t->nb_dims - 1];
+    if (op == 1) {
+        int64_t swap = *rows;
+        *rows = *cols;
+        *cols = swap;
+    }
+    return true;
+}
+
+int32_t trtx_network_add_matrix_multiply(
+    TrtxNetworkDefinition* network,
+    TrtxTensor* a,
+    int32_t op_a,
+    TrtxTensor* b,
+    int32_t op_b,
+    TrtxLayer** out_layer,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // a is [..., M, K] and b is [..., K, N] after their operations
+    int32_t nb_batch_a, nb_batch_b;
+    int64_t m, k_a, k_b, n;
+    if (!mock_matrix_operand(a, op_a, false, &nb_batch_a, &m, &k_a)
+        || !mock_matrix_operand(b, op_b, true, &nb_batch_b, &k_b, &n)) {
+        mock_copy_error("Invalid matrix multiply operands", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    if (nb_batch_a != nb_batch_b || a->data_type != b->data_type
+        || (k_a != k_b && k_a != -1 && k_b != -1)) {
+        mock_copy_error("Matrix multiply operands do not match", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    int64_t dims[8];
+    int32_t nb_dims = 0;
+    for (int32_t i = 0; i < nb_batch_a; ++i) {
+        if (a->dims[i] != b->dims[i] && a->dims[i] != 1 && b->dims[i] != 1) {
+            mock_copy_error("Matrix multiply batch dimensions cannot be broadcast",
+                error_msg, error_msg_len);
+            return 1; // TRTX_ERROR_INVALID_ARGUMENT
+        }
+        dims[nb_dims++] = a->dims[i] == 1 ? b->dims[i] : a->dims[i];
+    }
+    if (m != -2) dims[nb_dims++] = m;
+    if (n != -2) dims[nb_dims++] = n;
+    return mock_network_add_layer(network, "MatrixMultiply", a->data_type, dims, nb_dims,
+        out_layer, error_msg, error_msg_len);
+}
+
+int32_t trtx_network_mark_output(
+    TrtxNetworkDefinition* network,
+    TrtxTensor* tensor,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    tensor->is_output = true;
+    return 0;
+}
+
+int32_t trtx_tensor_get_name(
+    TrtxTensor* tensor,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_name = tensor->name;
+    return 0;
+}
+
+int32_t trtx_tensor_get_dimensions(
+    TrtxTensor* tensor,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    return mock_copy_dims(tensor->dims, tensor->nb_dims, out_dims, max_dims, out_nb_dims);
+}
+
+int32_t trtx_tensor_get_type(
+    TrtxTensor* tensor,
+    int32_t* out_data_type,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_data_type = tensor->data_type;
+    return 0;
+}
+
+int32_t trtx_layer_get_name(
+    TrtxLayer* layer,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_name = layer->name;
+    return 0;
+}
+
+int32_t trtx_layer_get_nb_outputs(
+    TrtxLayer* layer,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_count = 1;
+    return 0;
+}
+
+int32_t trtx_layer_get_output(
+    TrtxLayer* layer,
+    int32_t index,
+    TrtxTensor** out_tensor,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (index != 0) {
+        mock_copy_error("Invalid output index", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_tensor = &layer->output;
+    return 0;
+}
+
+static MockProfileInput* mock_profile_input(TrtxOptimizationProfile* profile, const char* name) {
+    for (int32_t i = 0; i < profile->nb_inputs; ++i) {
+        if (strcmp(profile->inputs[i].name, name) == 0) return &profile->inputs[i];
+    }
+    return NULL;
+}
+
+int32_t trtx_optimization_profile_set_dimensions(
+    TrtxOptimizationProfile* profile,
+    const char* input_name,
+    int32_t select,
+    const int64_t* dims,
+    int32_t nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    MockProfileInput* input = mock_profile_input(profile, input_name);
+    if (!input && profile->nb_inputs < MOCK_PROFILE_MAX_INPUTS
+        && strlen(input_name) < sizeof(input->name)) {
+        input = &profile->inputs[profile->nb_inputs++];
+        strcpy(input->name, input_name);
+        input->nb_dims = nb_dims;
+    }
+    if (!input || select < 0 || select > 2 || nb_dims < 0 || nb_dims > 8
+        || nb_dims != input->nb_dims) {
+        mock_copy_error("Invalid profile dimensions", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    memcpy(input->dims[select], dims, nb_dims * sizeof(int64_t));
+    input->set[select] = true;
+    return 0;
+}
+
+int32_t trtx_optimization_profile_get_dimensions(
+    TrtxOptimizationProfile* profile,
+    const char* input_name,
+    int32_t select,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    MockProfileInput* input = mock_profile_input(profile, input_name);
+    if (!input || select < 0 || select > 2 || !input->set[select]) {
+        mock_copy_error("No dimensions set for input", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    return mock_copy_dims(input->dims[select], input->nb_dims, out_dims, max_dims, out_nb_dims);
+}
+
+int32_t trtx_runtime_create(
+    TrtxLogger* logger,
+    TrtxRuntime** out_runtime,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_runtime = calloc(1, sizeof(TrtxRuntime));
+    (*out_runtime)->max_threads = 1;
+    return 0;
+}
+
+void trtx_runtime_destroy(TrtxRuntime* runtime) {
+    free(runtime);
+}
+
+int32_t trtx_runtime_set_engine_host_code_allowed(
+    TrtxRuntime* runtime,
+    bool allowed,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    runtime->host_code_allowed = allowed;
+    return 0;
+}
+
+int32_t trtx_runtime_get_engine_host_code_allowed(
+    TrtxRuntime* runtime,
+    bool* out_allowed,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_allowed = runtime->host_code_allowed;
+    return 0;
+}
+
+int32_t trtx_runtime_set_max_threads(
+    TrtxRuntime* runtime,
+    int32_t nb_threads,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (nb_threads < 1) {
+        mock_copy_error("Invalid thread count", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    runtime->max_threads = nb_threads;
+    return 0;
+}
+
+int32_t trtx_runtime_get_max_threads(
+    TrtxRuntime* runtime,
+    int32_t* out_nb_threads,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_nb_threads = runtime->max_threads;
+    return 0;
+}
+
+// Mock: any non-empty file loads as a lean runtime
+int32_t trtx_runtime_load_runtime(
+    TrtxRuntime* runtime,
+    const char* path,
+    TrtxRuntime** out_runtime,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    FILE* file = fopen(path, "rb");
+    bool loaded = file && fgetc(file) != EOF;
+    if (file) {
+        fclose(file);
+    }
+    if (!loaded) {
+        mock_copy_error("Failed to load lean runtime", error_msg, error_msg_len);
+        return 3; // TRTX_ERROR_RUNTIME_ERROR
+    }
+    *out_runtime = calloc(1, sizeof(TrtxRuntime));
+    (*out_runtime)->max_threads = 1;
+    return 0;
+}
+
+// EngineInvalidityDiagnostics bits of a plan, 0 if it loads
+static uint64_t mock_plan_diagnostics(const void* data, size_t size) {
+    const size_t magic_len = strlen(MOCK_ENGINE_MAGIC);
+    // Empty or truncated plans are malformed like they are in TensorRT
+    if (size == 0 ||
+        (size >= magic_len && size < magic_len + 2 && memcmp(data, MOCK_ENGINE_MAGIC, magic_len) == 0)) {
+        return 1ull << 5; // kMALFORMED_ENGINE
+    }
+    if (size >= strlen(MOCK_FOREIGN_MAGIC) &&
+        memcmp(data, MOCK_FOREIGN_MAGIC, strlen(MOCK_FOREIGN_MAGIC)) == 0) {
+        return 1ull << 0; // kVERSION_MISMATCH
+    }
+    return 0;
+}
+
+int32_t trtx_runtime_get_engine_validity(
+    TrtxRuntime* runtime,
+    const void* data,
+    size_t size,
+    int32_t* out_validity,
+    uint64_t* out_diagnostics,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_diagnostics = mock_plan_diagnostics(data, size);
+    *out_validity = *out_diagnostics ? 2 : 0; // kINVALID : kVALID
+    return 0;
+}
+
+int32_t trtx_runtime_deserialize_cuda_engine(
+    TrtxRuntime* runtime,
+    const void* data,
+    size_t size,
+    TrtxCudaEngine** out_engine,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    const size_t magic_len = strlen(MOCK_ENGINE_MAGIC);
+    if (mock_plan_diagnostics(data, size) != 0) {
+        mock_copy_error("Failed to deserialize engine", error_msg, error_msg_len);
+        return 3; // TRTX_ERROR_RUNTIME_ERROR
+    }
+
+    TrtxCudaEngine* engine = calloc(1, sizeof(TrtxCudaEngine));
+    engine->nb_inputs = 1;
+    engine->nb_outputs = 1;
+
+    if (size >= magic_len + 2 && memcmp(data, MOCK_ENGINE_MAGIC, magic_len) == 0) {
+        const uint8_t* counts = (const uint8_t*)data + magic_len;
+        if (counts[0] > 1 || counts[1] < 1 || counts[1] > MOCK_MAX_OUTPUTS) {
+            free(engine);
+            mock_copy_error("Unsupported mock engine layout", error_msg, error_msg_len);
+            return 1; // TRTX_ERROR_INVALID_ARGUMENT
+        }
+        engine->nb_inputs = counts[0];
+        engine->nb_outputs = counts[1];
+        // Third byte: bit 0 marks data-dependent outputs, bit 1 refittable,
+        // bit 2 a dynamic second dimension, bit 3 a shape tensor input,
+        // bit 4 streamable weights
+        const uint8_t flags = size >= magic_len + 3 ? counts[2] : 0;
+        engine->data_dependent = (flags & 1) && counts[0] == 1;
+        engine->refittable = (flags & 2) != 0;
+        engine->dynamic_width = (flags & 4) && counts[0] == 1;
+        engine->shape_input = (flags & 8) && counts[0] == 1;
+        if (flags & 16) {
+            // Mock: 1 MiB of streamable weights, all resident by default
+            engine->streamable_weights = 1 << 20;
+            engine->weight_streaming_budget = engine->streamable_weights;
+        }
+        if (size > magic_len + 3) {
+            size_t name_len = size - magic_len - 3;
+            if (name_len >= sizeof(engine->name)) name_len = sizeof(engine->name) - 1;
+            memcpy(engine->name, counts + 3, name_len);
+        }
+    }
+
+    *out_engine = engine;
+    return 0;
+}
+
+void trtx_cuda_engine_destroy(TrtxCudaEngine* engine) {
+    free(engine);
+}
+
+int32_t trtx_cuda_engine_create_execution_context(
+    TrtxCudaEngine* engine,
+    TrtxExecutionContext** out_context,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_context = calloc(1, sizeof(TrtxExecutionContext));
+    (*out_context)->engine = engine;
+    return 0;
+}
+
+int32_t trtx_cuda_engine_get_tensor_name(
+    TrtxCudaEngine* engine,
+    int32_t index,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    const char* name = mock_tensor_name(engine, index);
+    if (!name) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_name = name;
+    return 0;
+}
+
+int32_t trtx_cuda_engine_get_nb_io_tensors(
+    TrtxCudaEngine* engine,
+    int32_t* out_count
+) {
+    *out_count = engine->nb_inputs + engine->nb_outputs;
+    return 0;
+}
+
+int32_t trtx_cuda_engine_get_nb_layers(
+    TrtxCudaEngine* engine,
+    int32_t* out_count
+) {
+    *out_count = 1;
+    return 0;
+}
+
+int32_t trtx_cuda_engine_get_name(
+    TrtxCudaEngine* engine,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_name = engine->name;
+    return 0;
+}
+
+// Mock engines have a single optimization profile
+int32_t trtx_cuda_engine_get_nb_optimization_profiles(
+    TrtxCudaEngine* engine,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_count = 1;
+    return 0;
+}
+
+int32_t trtx_cuda_engine_get_tensor_dtype(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    int32_t* out_dtype,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_dtype = 0; // Mock: every tensor is kFLOAT
+    return 0;
+}
+
+int32_t trtx_cuda_engine_get_tensor_shape(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (mock_tensor_index(engine, tensor_name) < 0) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    return mock_copy_dims(mock_engine_dims(engine), MOCK_TENSOR_NB_DIMS,
+        out_dims, max_dims, out_nb_dims);
+}
+
+int32_t trtx_cuda_engine_get_tensor_io_mode(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    int32_t* out_mode,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // kINPUT = 1, kOUTPUT = 2, kNONE = 0 for unknown names
+    int index = mock_tensor_index(engine, tensor_name);
+    *out_mode = index < 0 ? 0 : (index < engine->nb_inputs ? 1 : 2);
+    return 0;
+}
+
+int32_t trtx_cuda_engine_is_shape_inference_io(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    bool* out_is_shape_io,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Mock engines only have a shape tensor if their header asks for one
+    int index = mock_tensor_index(engine, tensor_name);
+    if (index < 0) {
+        mock_copy_error("Unknown tensor", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_is_shape_io = engine->shape_input && index < engine->nb_inputs;
+    return 0;
+}
+
+int32_t trtx_cuda_engine_get_profile_shape(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    int32_t profile_index,
+    int32_t select,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    int index = mock_tensor_index(engine, tensor_name);
+    if (index < 0 || index >= engine->nb_inputs || profile_index != 0 || select < 0 || select > 2) {
+        mock_copy_error("Not an input tensor, or invalid profile index", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    return mock_copy_dims(MOCK_PROFILE_DIMS[select], MOCK_TENSOR_NB_DIMS,
+        out_dims, max_dims, out_nb_dims);
+}
+
+int32_t trtx_cuda_engine_get_device_memory_size(
+    TrtxCudaEngine* engine,
+    int64_t* out_size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_size = 1 << 20; // Mock: 1 MiB of activation memory
+    return 0;
+}
+
+int32_t trtx_cuda_engine_get_streamable_weights_size(
+    TrtxCudaEngine* engine,
+    int64_t* out_size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_size = engine->streamable_weights;
+    return 0;
+}
+
+int32_t trtx_cuda_engine_is_weight_streaming_enabled(
+    TrtxCudaEngine* engine,
+    bool* out_enabled,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_enabled = engine->streamable_weights > 0
+        && engine->weight_streaming_budget < engine->streamable_weights;
+    return 0;
+}
+
+int32_t trtx_cuda_engine_get_minimum_weight_streaming_budget(
+    TrtxCudaEngine* engine,
+    int64_t* out_budget,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_budget = 0; // The V2 API accepts any non-negative budget
+    return 0;
+}
+
+int32_t trtx_cuda_engine_set_weight_streaming_budget(
+    TrtxCudaEngine* engine,
+    int64_t budget,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Like setWeightStreamingBudgetV2, budgets above the streamable size keep everything resident
+    engine->weight_streaming_budget =
+        budget < engine->streamable_weights ? budget : engine->streamable_weights;
+    return 0;
+}
+
+int32_t trtx_cuda_engine_get_weight_streaming_budget(
+    TrtxCudaEngine* engine,
+    int64_t* out_budget,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_budget = engine->weight_streaming_budget;
+    return 0;
+}
+
+int32_t trtx_cuda_engine_get_weight_streaming_automatic_budget(
+    TrtxCudaEngine* engine,
+    int64_t* out_budget,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Mock: enough free memory to keep every streamable weight resident
+    *out_budget = engine->streamable_weights;
+    return 0;
+}
+
+int32_t trtx_cuda_engine_create_engine_inspector(
+    TrtxCudaEngine* engine,
+    TrtxEngineInspector** out_inspector,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_inspector = malloc(sizeof(TrtxEngineInspector));
+    return 0;
+}
+
+void trtx_engine_inspector_destroy(TrtxEngineInspector* inspector) {
+    free(inspector);
+}
+
+int32_t trtx_cuda_engine_is_refittable(
+    TrtxCudaEngine* engine,
+    bool* out_refittable,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_refittable = engine->refittable;
+    return 0;
+}
+
+// Mock: refittable engines have the weights of their single layer
+static const char* MOCK_REFIT_WEIGHTS[] = {"mock_layer.weight", "mock_layer.bias"};
+#define MOCK_NB_REFIT_WEIGHTS 2
+
+typedef struct {
+    TrtxCudaEngine* engine;
+    int32_t nb_set;
+} TrtxRefitter;
+
+int32_t trtx_refitter_create(
+    TrtxCudaEngine* engine,
+    TrtxLogger* logger,
+    TrtxRefitter** out_refitter,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine->refittable) {
+        mock_copy_error("Engine is not refittable", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_refitter = calloc(1, sizeof(TrtxRefitter));
+    (*out_refitter)->engine = engine;
+    return 0;
+}
+
+void trtx_refitter_destroy(TrtxRefitter* refitter) {
+    free(refitter);
+}
+
+int32_t trtx_refitter_get_all_weights(
+    TrtxRefitter* refitter,
+    const char** out_names,
+    int32_t max_names,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    for (int32_t i = 0; i < MOCK_NB_REFIT_WEIGHTS && i < max_names; ++i) {
+        out_names[i] = MOCK_REFIT_WEIGHTS[i];
+    }
+    *out_count = MOCK_NB_REFIT_WEIGHTS;
+    return 0;
+}
+
+int32_t trtx_refitter_set_named_weights(
+    TrtxRefitter* refitter,
+    const char* name,
+    int32_t data_type,
+    const void* values,
+    int64_t count,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    for (int32_t i = 0; i < MOCK_NB_REFIT_WEIGHTS; ++i) {
+        if (strcmp(name, MOCK_REFIT_WEIGHTS[i]) == 0) {
+            refitter->nb_set |= 1 << i;
+            return 0;
+        }
+    }
+    mock_copy_error("Failed to set named weights", error_msg, error_msg_len);
+    return 1; // TRTX_ERROR_INVALID_ARGUMENT
+}
+
+int32_t trtx_refitter_refit_cuda_engine(
+    TrtxRefitter* refitter,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Mock: like TensorRT, every weight must be set before the first refit
+    if (refitter->nb_set != (1 << MOCK_NB_REFIT_WEIGHTS) - 1) {
+        mock_copy_error("Failed to refit engine; check that every required weight is set",
+            error_msg, error_msg_len);
+        return 3; // TRTX_ERROR_RUNTIME_ERROR
+    }
+    return 0;
+}
+
+// Mock: a single-layer engine, in the shape TensorRT reports
+#define MOCK_LAYER_ONELINE "\"mock_layer\""
+#define MOCK_LAYER_JSON \
+    "{\"Name\": \"mock_layer\", \"LayerType\": \"Identity\", " \
+    "\"Inputs\": [{\"Name\": \"input\", \"Format/Datatype\": \"Row major linear FP32\"}], " \
+    "\"Outputs\": [{\"Name\": \"output\", \"Format/Datatype\": \"Row major linear FP32\"}], " \
+    "\"TacticName\": \"mock_identity_kernel\", \"TacticValue\": \"0x0000000000000000\"}"
+
+int32_t trtx_engine_inspector_get_engine_information(
+    TrtxEngineInspector* inspector,
+    int32_t format,
+    const char** out_info,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    static const char* mock_oneline =
+        "{\"Layers\": [" MOCK_LAYER_ONELINE "],\n\"Bindings\": [\"input\", \"output\"]}";
+    static const char* mock_json =
+        "{\"Layers\": [" MOCK_LAYER_JSON "],\n\"Bindings\": [\"input\", \"output\"]}";
+    *out_info = format == 1 ? mock_json : mock_oneline;
+    return 0;
+}
+
+int32_t trtx_engine_inspector_get_layer_information(
+    TrtxEngineInspector* inspector,
+    int32_t layer_index,
+    int32_t format,
+    const char** out_info,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (layer_index != 0) {
+        mock_copy_error("Layer index out of range", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_info = format == 1 ? MOCK_LAYER_JSON : MOCK_LAYER_ONELINE;
+    return 0;
+}
+
+int32_t trtx_progress_monitor_create(
+    TrtxPhaseStartCallback phase_start,
+    TrtxStepCompleteCallback step_complete,
+    TrtxPhaseFinishCallback phase_finish,
+    void* user_data,
+    TrtxProgressMonitor** out_monitor,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_monitor = malloc(sizeof(TrtxProgressMonitor));
+    (*out_monitor)->phase_start = phase_start;
+    (*out_monitor)->step_complete = step_complete;
+    (*out_monitor)->phase_finish = phase_finish;
+    (*out_monitor)->user_data = user_data;
+    return 0;
+}
+
+void trtx_progress_monitor_destroy(TrtxProgressMonitor* monitor) {
+    free(monitor);
+}
+
+int32_t trtx_builder_config_set_progress_monitor(
+    TrtxBuilderConfig* config,
+    TrtxProgressMonitor* monitor,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    config->progress_monitor = monitor;
+    return 0;
+}
+
+int32_t trtx_builder_config_create_timing_cache(
+    TrtxBuilderConfig* config,
+    const void* blob,
+    size_t size,
+    TrtxTimingCache** out_cache,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (size != 0 && size != sizeof(uint32_t)) {
+        mock_copy_error("Failed to create timing cache", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_cache = calloc(1, sizeof(TrtxTimingCache));
+    if (size) {
+        memcpy(&(*out_cache)->tactics, blob, size);
+    }
+    return 0;
+}
+
+int32_t trtx_builder_config_set_timing_cache(
+    TrtxBuilderConfig* config,
+    TrtxTimingCache* cache,
+    bool ignore_mismatch,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    config->timing_cache = cache;
+    return 0;
+}
+
+void trtx_timing_cache_destroy(TrtxTimingCache* cache) {
+    free(cache);
+}
+
+int32_t trtx_timing_cache_serialize(
+    TrtxTimingCache* cache,
+    void** out_data,
+    size_t* out_size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_size = sizeof(uint32_t);
+    *out_data = malloc(sizeof(uint32_t));
+    memcpy(*out_data, &cache->tactics, sizeof(uint32_t));
+    return 0;
+}
+
+int32_t trtx_timing_cache_combine(
+    TrtxTimingCache* cache,
+    TrtxTimingCache* input_cache,
+    bool ignore_mismatch,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cache->tactics |= input_cache->tactics;
+    return 0;
+}
+
+int32_t trtx_profiler_create(
+    TrtxProfilerCallback callback,
+    void* user_data,
+    TrtxProfiler** out_profiler,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_profiler = malloc(sizeof(TrtxProfiler));
+    (*out_profiler)->callback = callback;
+    (*out_profiler)->user_data = user_data;
+    return 0;
+}
+
+void trtx_profiler_destroy(TrtxProfiler* profiler) {
+    free(profiler);
+}
+
+int32_t trtx_debug_listener_create(
+    TrtxDebugTensorCallback callback,
+    void* user_data,
+    TrtxDebugListener** out_listener,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_listener = malloc(sizeof(TrtxDebugListener));
+    (*out_listener)->callback = callback;
+    (*out_listener)->user_data = user_data;
+    return 0;
+}
+
+void trtx_debug_listener_destroy(TrtxDebugListener* listener) {
+    free(listener);
+}
+
+int32_t trtx_output_allocator_create(
+    TrtxReallocateOutputCallback reallocate,
+    TrtxNotifyShapeCallback notify_shape,
+    void* user_data,
+    TrtxOutputAllocator** out_allocator,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_allocator = malloc(sizeof(TrtxOutputAllocator));
+    (*out_allocator)->reallocate = reallocate;
+    (*out_allocator)->notify_shape = notify_shape;
+    (*out_allocator)->user_data = user_data;
+    return 0;
+}
+
+void trtx_output_allocator_destroy(TrtxOutputAllocator* allocator) {
+    free(allocator);
+}
+
+void trtx_execution_context_destroy(TrtxExecutionContext* context) {
+    free(context);
+}
+
+int32_t trtx_execution_context_set_profiler(
+    TrtxExecutionContext* context,
+    TrtxProfiler* profiler,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    context->profiler = profiler;
+    return 0;
+}
+
+int32_t trtx_execution_context_set_debug_listener(
+    TrtxExecutionContext* context,
+    TrtxDebugListener* listener,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    context->debug_listener = listener;
+    return 0;
+}
+
+int32_t trtx_execution_context_set_name(
+    TrtxExecutionContext* context,
+    const char* name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    mock_copy_error(name, context->name, sizeof(context->name));
+    return 0;
+}
+
+int32_t trtx_execution_context_get_name(
+    TrtxExecutionContext* context,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_name = context->name;
+    return 0;
+}
+
+int32_t trtx_execution_context_set_tensor_address(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    void* data,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    int index = mock_tensor_index(context->engine, tensor_name);
+    if (index < 0) {
+        return 3; // TRTX_ERROR_RUNTIME_ERROR
+    }
+    context->addresses[index] = data;
+    return 0;
+}
+
+int32_t trtx_execution_context_get_tensor_address(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    const void** out_data,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    int index = mock_tensor_index(context->engine, tensor_name);
+    *out_data = index < 0 ? NULL : context->addresses[index];
+    return 0;
+}
+
+int32_t trtx_execution_context_set_input_shape(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    const int64_t* dims,
+    int32_t nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (context->engine->nb_inputs == 0 || mock_tensor_index(context->engine, tensor_name) != 0
+        || nb_dims != MOCK_TENSOR_NB_DIMS
+        || dims[0] < 1 || dims[1] != MOCK_TENSOR_DIMS[1]) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    memcpy(context->input_dims, dims, nb_dims * sizeof(int64_t));
+    context->input_nb_dims = nb_dims;
+    return 0;
+}
+
+int32_t trtx_execution_context_get_optimization_profile(
+    TrtxExecutionContext* context,
+    int32_t* out_profile_index,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_profile_index = context->profile;
+    return 0;
+}
+
+int32_t trtx_execution_context_set_optimization_profile(
+    TrtxExecutionContext* context,
+    int32_t profile_index,
+    void* cuda_stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (profile_index != 0) {
+        mock_copy_error("Invalid optimization profile", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    context->profile = profile_index;
+    return 0;
+}
+
+int32_t trtx_execution_context_set_nvtx_verbosity(
+    TrtxExecutionContext* context,
+    int32_t verbosity,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (verbosity < 0 || verbosity > 2) {
+        mock_copy_error("Failed to set NVTX verbosity", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    context->nvtx_verbosity = verbosity;
+    return 0;
+}
+
+int32_t trtx_execution_context_get_nvtx_verbosity(
+    TrtxExecutionContext* context,
+    int32_t* out_verbosity,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_verbosity = context->nvtx_verbosity;
+    return 0;
+}
+
+int32_t trtx_execution_context_set_aux_streams(
+    TrtxExecutionContext* context,
+    void** streams,
+    int32_t nb_streams,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (nb_streams < 0) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    context->nb_aux_streams = nb_streams;
+    return 0;
+}
+
+int32_t trtx_execution_context_get_tensor_shape(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (mock_tensor_index(context->engine, tensor_name) < 0) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    // Outputs mirror the input, so all tensors resolve to the input shape once
+    // set, unless the output row count depends on the data
+    bool is_output = mock_tensor_index(context->engine, tensor_name) >= context->engine->nb_inputs;
+    if (context->input_nb_dims > 0 && !(is_output && context->engine->data_dependent)) {
+        return mock_copy_dims(context->input_dims, context->input_nb_dims,
+            out_dims, max_dims, out_nb_dims);
+    }
+    return mock_copy_dims(mock_engine_dims(context->engine), MOCK_TENSOR_NB_DIMS,
+        out_dims, max_dims, out_nb_dims);
+}
+
+int32_t trtx_execution_context_get_tensor_strides(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    int64_t* out_strides,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Mock: every tensor is linear, so strides are row-major over its shape,
+    // and unknown while a trailing dimension is dynamic
+    int32_t status = trtx_execution_context_get_tensor_shape(context, tensor_name,
+        out_strides, max_dims, out_nb_dims, error_msg, error_msg_len);
+    if (status != 0) {
+        return status;
+    }
+    int64_t stride = 1;
+    for (int32_t i = *out_nb_dims - 1; i >= 0; --i) {
+        int64_t dim = out_strides[i];
+        out_strides[i] = stride;
+        stride = (stride < 0 || dim < 0) ? -1 : stride * dim;
+    }
+    return 0;
+}
+
+// Data-dependent outputs: copy the input rows whose first element is non-zero
+static void mock_execute_nonzero(TrtxExecutionContext* context, int32_t index) {
+    const float* input = context->addresses[0];
+    const int64_t row_len = MOCK_TENSOR_DIMS[1];
+    int64_t nb_rows = 0;
+    for (int64_t row = 0; row < context->input_dims[0]; ++row) {
+        if (input[row * row_len] != 0.0f) ++nb_rows;
+    }
+
+    size_t size = nb_rows * row_len * sizeof(float);
+    const char* name = mock_tensor_name(context->engine, index);
+    TrtxOutputAllocator* allocator = context->output_allocators[index];
+    if (allocator) {
+        context->addresses[index] = allocator->reallocate(
+            allocator->user_data, name, context->addresses[index], size, MOCK_ALLOC_ALIGNMENT);
+    }
+    float* output = context->addresses[index];
+    if (!output) return;
+
+    size_t capacity = mock_alloc_size(output);
+    int64_t written = 0;
+    for (int64_t row = 0; row < context->input_dims[0]; ++row) {
+        if (input[row * row_len] == 0.0f) continue;
+        if ((written + 1) * row_len * sizeof(float) > capacity) break;
+        memcpy(output + written * row_len, input + row * row_len, row_len * sizeof(float));
+        ++written;
+    }
+
+    if (allocator) {
+        int64_t dims[] = {nb_rows, row_len};
+        allocator->notify_shape(allocator->user_data, name, dims, MOCK_TENSOR_NB_DIMS);
+    }
+}
+
+static void mock_execute(TrtxExecutionContext* context) {
+    const TrtxCudaEngine* engine = context->engine;
+    int32_t nb_tensors = engine->nb_inputs + engine->nb_outputs;
+    for (int32_t i = engine->nb_inputs; i < nb_tensors; ++i) {
+        if (engine->data_dependent) {
+            mock_execute_nonzero(context, i);
+            continue;
+        }
+        void* output = context->addresses[i];
+        size_t output_size = mock_alloc_size(output);
+        if (engine->nb_inputs > 0) {
+            // Identity: copy the input to every output
+            void* input = context->addresses[0];
+            size_t input_size = mock_alloc_size(input);
+            memcpy(output, input, input_size < output_size ? input_size : output_size);
+        } else {
+            // Constant generator: fill with 1.0f
+            for (size_t j = 0; j < output_size / sizeof(float); ++j) {
+                ((float*)output)[j] = 1.0f;
+            }
+        }
+    }
+
+    // Mock: report the single layer listed by the engine inspector
+    if (context->profiler) {
+        context->profiler->callback(context->profiler->user_data, "mock_layer", 0.5f);
+    }
+
+    // Mock: the first output is a float32 debug tensor
+    if (context->debug_listener && !engine->data_dependent) {
+        int32_t output = engine->nb_inputs;
+        int64_t dims[2];
+        memcpy(dims, engine->nb_inputs > 0 ? context->input_dims : MOCK_STATIC_DIMS, sizeof(dims));
+        context->debug_listener->callback(
+            context->debug_listener->user_data, mock_tensor_name(engine, output),
+            context->addresses[output], 0, dims, MOCK_TENSOR_NB_DIMS);
+    }
+}
+
+int32_t trtx_execution_context_get_max_output_size(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    int64_t* out_size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    const TrtxCudaEngine* engine = context->engine;
+    int index = mock_tensor_index(engine, tensor_name);
+    if (index < engine->nb_inputs) {
+        mock_copy_error("Not an output tensor", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    if (engine->nb_inputs == 0) {
+        *out_size = MOCK_STATIC_DIMS[0] * MOCK_STATIC_DIMS[1] * sizeof(float);
+        return 0;
+    }
+    if (context->input_nb_dims == 0) {
+        mock_copy_error("Input shapes are not set", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    // At most every input row is kept
+    *out_size = context->input_dims[0] * context->input_dims[1] * sizeof(float);
+    return 0;
+}
+
+int32_t trtx_execution_context_set_output_allocator(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    TrtxOutputAllocator* allocator,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    int index = mock_tensor_index(context->engine, tensor_name);
+    if (index < context->engine->nb_inputs) {
+        mock_copy_error("Not an output tensor", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    context->output_allocators[index] = allocator;
+    return 0;
+}
+
+int32_t trtx_execution_context_enqueue_v3(
+    TrtxExecutionContext* context,
+    void* cuda_stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    const TrtxCudaEngine* engine = context->engine;
+    int32_t nb_tensors = engine->nb_inputs + engine->nb_outputs;
+    for (int32_t i = 0; i < nb_tensors; ++i) {
+        if (!context->addresses[i] && !context->output_allocators[i]) {
+            return 3; // TRTX_ERROR_RUNTIME_ERROR: address not set
+        }
+    }
+    if (engine->nb_inputs > 0 && context->input_nb_dims == 0) {
+        return 3; // TRTX_ERROR_RUNTIME_ERROR: input shape not set
+    }
+
+    MockStream* stream = (MockStream*)cuda_stream;
+    if (stream && stream->capturing) {
+        if (stream->nb_captured >= MOCK_MAX_CAPTURED) {
+            mock_copy_error("Too many captured enqueues", error_msg, error_msg_len);
+            return 3; // TRTX_ERROR_RUNTIME_ERROR
+        }
+        stream->captured[stream->nb_captured++] = context;
+        return 0;
+    }
+
+    mock_execute(context);
+    return 0;
+}
+
+void trtx_free_buffer(void* buffer) {
+    free(buffer);
+}
+
+// Mock: any non-empty file loads as a plugin library
+int32_t trtx_load_plugin_library(
+    const char* path,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    FILE* file = fopen(path, "rb");
+    bool loaded = file && fgetc(file) != EOF;
+    if (file) {
+        fclose(file);
+    }
+    if (!loaded) {
+        mock_copy_error("Failed to load plugin library", error_msg, error_msg_len);
+        return 3; // TRTX_ERROR_RUNTIME_ERROR
+    }
+    return 0;
+}
+
+// Mock plugin creators keep their identity; mock engines never run plugins
+typedef struct {
+    char name[256];
+    char version[64];
+} TrtxPluginCreator;
+
+#define MOCK_MAX_PLUGIN_CREATORS 64
+static TrtxPluginCreator* mock_plugin_creators[MOCK_MAX_PLUGIN_CREATORS];
+static int32_t mock_nb_plugin_creators;
+
+int32_t trtx_plugin_creator_create(
+    const char* name,
+    const char* version,
+    void* create,
+    void* clone,
+    void* enqueue,
+    void* destroy,
+    void* user_data,
+    TrtxPluginCreator** out_creator,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    TrtxPluginCreator* creator = calloc(1, sizeof(TrtxPluginCreator));
+    mock_copy_error(name, creator->name, sizeof(creator->name));
+    mock_copy_error(version, creator->version, sizeof(creator->version));
+    *out_creator = creator;
+    return 0;
+}
+
+void trtx_plugin_creator_destroy(TrtxPluginCreator* creator) {
+    free(creator);
+}
+
+int32_t trtx_runtime_register_plugin_creator(
+    TrtxRuntime* runtime,
+    TrtxPluginCreator* creator,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    for (int32_t i = 0; i < mock_nb_plugin_creators; ++i) {
+        if (strcmp(mock_plugin_creators[i]->name, creator->name) == 0
+            && strcmp(mock_plugin_creators[i]->version, creator->version) == 0) {
+            mock_copy_error("Failed to register plugin creator", error_msg, error_msg_len);
+            return 3; // TRTX_ERROR_RUNTIME_ERROR
+        }
+    }
+    if (mock_nb_plugin_creators == MOCK_MAX_PLUGIN_CREATORS) {
+        mock_copy_error("Too many plugin creators", error_msg, error_msg_len);
+        return 3; // TRTX_ERROR_RUNTIME_ERROR
+    }
+    mock_plugin_creators[mock_nb_plugin_creators++] = creator;
+    return 0;
+}
+
+// ONNX Parser mock implementations
+typedef struct { uint32_t flags; } TrtxOnnxParser;
+
+int32_t trtx_onnx_parser_create(
+    void* network,
+    void* logger,
+    TrtxOnnxParser** out_parser,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_parser = calloc(1, sizeof(TrtxOnnxParser));
+    return 0;
+}
+
+void trtx_onnx_parser_destroy(TrtxOnnxParser* parser) {
+    free(parser);
+}
+
+int32_t trtx_onnx_parser_parse(
+    TrtxOnnxParser* parser,
+    const void* model_data,
+    size_t model_size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Mock: always succeeds
+    return 0;
+}
+
+// Mock: every model is one fully supported single-node subgraph
+static const int64_t mock_subgraph_nodes[] = {0};
+
+int32_t trtx_onnx_parser_supports_model(
+    TrtxOnnxParser* parser,
+    const void* model_data,
+    size_t model_size,
+    bool* out_supported,
+    int64_t* out_nb_subgraphs,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_supported = true;
+    *out_nb_subgraphs = 1;
+    return 0;
+}
+
+int32_t trtx_onnx_parser_get_subgraph(
+    TrtxOnnxParser* parser,
+    int64_t index,
+    bool* out_supported,
+    const int64_t** out_nodes,
+    int64_t* out_nb_nodes,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (index != 0) {
+        mock_copy_error("Subgraph index out of range", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_supported = true;
+    *out_nodes = mock_subgraph_nodes;
+    *out_nb_nodes = 1;
+    return 0;
+}
+
+int32_t trtx_onnx_parser_set_flag(
+    TrtxOnnxParser* parser,
+    int32_t flag,
+    bool enabled,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (flag < 0 || flag >= 32) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    if (enabled) {
+        parser->flags |= (1u << flag);
+    } else {
+        parser->flags &= ~(1u << flag);
+    }
+    return 0;
+}
+
+int32_t trtx_onnx_parser_get_flag(
+    TrtxOnnxParser* parser,
+    int32_t flag,
+    bool* out_enabled,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (flag < 0 || flag >= 32) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *out_enabled = (parser->flags & (1u << flag)) != 0;
+    return 0;
+}
+
+// CUDA Memory Management mock implementations
+int32_t trtx_cuda_malloc(
+    void** ptr,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    char* block = malloc(sizeof(MockAllocHeader) + MOCK_ALLOC_ALIGNMENT - 1 + size);
+    if (!block) {
+        return 2; // TRTX_ERROR_OUT_OF_MEMORY
+    }
+    uintptr_t start = (uintptr_t)(block + sizeof(MockAllocHeader));
+    void* aligned = (void*)((start + MOCK_ALLOC_ALIGNMENT - 1) & ~(uintptr_t)(MOCK_ALLOC_ALIGNMENT - 1));
+    mock_alloc_header(aligned)->block = block;
+    mock_alloc_header(aligned)->size = size;
+    *ptr = aligned;
+    return 0;
+}
+
+int32_t trtx_cuda_free(
+    void* ptr,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (ptr) {
+        free(mock_alloc_header(ptr)->block);
+    }
+    return 0;
+}
+
+int32_t trtx_cuda_memcpy_host_to_device(
+    void* dst,
+    const void* src,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    memcpy(dst, src, size);
+    return 0;
+}
+
+int32_t trtx_cuda_memcpy_device_to_host(
+    void* dst,
+    const void* src,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    memcpy(dst, src, size);
+    return 0;
+}
+
+int32_t trtx_cuda_memcpy_device_to_device(
+    void* dst,
+    const void* src,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    memmove(dst, src, size);
+    return 0;
+}
+
+int32_t trtx_cuda_memset(
+    void* ptr,
+    int32_t value,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    memset(ptr, value, size);
+    return 0;
+}
+
+int32_t trtx_cuda_synchronize(
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Mock: nothing to synchronize
+    return 0;
+}
+
+int32_t trtx_cuda_mem_get_info(
+    size_t* free_bytes,
+    size_t* total_bytes,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Mock: an 8 GiB device with 6 GiB free
+    *free_bytes = (size_t)6 << 30;
+    *total_bytes = (size_t)8 << 30;
+    return 0;
+}
+
+// Mock: a single device, id 0
+static int32_t mock_current_device = 0;
+
+// Mock: the CUDA runtime's per-thread last error, as a cudaError_t value
+static _Thread_local int32_t mock_last_cuda_error = 0;
+
+int32_t trtx_cuda_set_device(
+    int32_t device,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (device != 0) {
+        mock_last_cuda_error = 101; // cudaErrorInvalidDevice
+        mock_copy_error("invalid device ordinal", error_msg, error_msg_len);
+        return 4; // TRTX_ERROR_CUDA_ERROR
+    }
+    mock_current_device = device;
+    return 0;
+}
+
+int32_t trtx_cuda_ensure_context(
+    int32_t* out_cuda_error,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_cuda_error = 0;
+    return 0;
+}
+
+int32_t trtx_cuda_get_device(
+    int32_t* device,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!device) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *device = mock_current_device;
+    return 0;
+}
+
+// Mock: cudaGetErrorString texts of the errors callers most often match on
+static const char* mock_cuda_error_string(int32_t code) {
+    switch (code) {
+        case 0: return "no error";
+        case 1: return "invalid argument";
+        case 2: return "out of memory";
+        case 3: return "initialization error";
+        case 100: return "no CUDA-capable device is detected";
+        case 101: return "invalid device ordinal";
+        case 700: return "an illegal memory access was encountered";
+        case 719: return "unspecified launch failure";
+        default: return "unrecognized error code";
+    }
+}
+
+int32_t trtx_cuda_get_last_error(
+    bool peek,
+    int32_t* out_code,
+    const char** out_string,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_code = mock_last_cuda_error;
+    *out_string = mock_cuda_error_string(mock_last_cuda_error);
+    if (!peek) {
+        mock_last_cuda_error = 0;
+    }
+    return 0;
+}
+
+int32_t trtx_cuda_get_error_string(
+    int32_t code,
+    const char** out_string,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_string = mock_cuda_error_string(code);
+    return 0;
+}
+
+int32_t trtx_cuda_stream_create(
+    void** stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    MockStream* mock_stream = calloc(1, sizeof(MockStream));
+    if (!mock_stream) {
+        return 2; // TRTX_ERROR_OUT_OF_MEMORY
+    }
+    *stream = mock_stream;
+    return 0;
+}
+
+// Mock: all work runs synchronously, so blocking streams are plain streams
+int32_t trtx_cuda_stream_create_blocking(
+    void** stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    return trtx_cuda_stream_create(stream, error_msg, error_msg_len);
+}
+
+int32_t trtx_cuda_stream_create_with_priority(
+    void** stream,
+    int32_t priority,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    int32_t result = trtx_cuda_stream_create(stream, error_msg, error_msg_len);
+    if (result != 0) {
+        return result;
+    }
+    if (priority > MOCK_STREAM_PRIORITY_LEAST) priority = MOCK_STREAM_PRIORITY_LEAST;
+    if (priority < MOCK_STREAM_PRIORITY_GREATEST) priority = MOCK_STREAM_PRIORITY_GREATEST;
+    ((MockStream*)*stream)->priority = priority;
+    return 0;
+}
+
+int32_t trtx_cuda_stream_get_priority(
+    void* stream,
+    int32_t* out_priority,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_priority = stream ? ((MockStream*)stream)->priority : 0;
+    return 0;
+}
+
+int32_t trtx_cuda_stream_priority_range(
+    int32_t* out_least,
+    int32_t* out_greatest,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_least = MOCK_STREAM_PRIORITY_LEAST;
+    *out_greatest = MOCK_STREAM_PRIORITY_GREATEST;
+    return 0;
+}
+
+int32_t trtx_cuda_stream_destroy(
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    free(stream);
+    return 0;
+}
+
+int32_t trtx_cuda_stream_synchronize(
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Mock: work runs synchronously
+    return 0;
+}
+
+int32_t trtx_cuda_stream_launch_host_fn(
+    void* stream,
+    TrtxHostFnCallback callback,
+    void* user_data,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!callback) {
+        mock_copy_error("Invalid arguments", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    // Mock: work runs synchronously, so the host function runs right away
+    callback(user_data);
+    return 0;
+}
+
+int32_t trtx_cuda_stream_begin_capture(
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    MockStream* mock_stream = (MockStream*)stream;
+    if (!mock_stream || mock_stream->capturing) {
+        mock_copy_error("Stream cannot be captured", error_msg, error_msg_len);
+        return 4; // TRTX_ERROR_CUDA_ERROR
+    }
+    mock_stream->capturing = true;
+    mock_stream->nb_captured = 0;
+    return 0;
+}
+
+int32_t trtx_cuda_stream_end_capture(
+    void* stream,
+    void** graph_exec,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    MockStream* mock_stream = (MockStream*)stream;
+    if (!mock_stream || !mock_stream->capturing) {
+        mock_copy_error("Stream is not capturing", error_msg, error_msg_len);
+        return 4; // TRTX_ERROR_CUDA_ERROR
+    }
+    mock_stream->capturing = false;
+
+    MockGraphExec* exec = calloc(1, sizeof(MockGraphExec));
+    if (!exec) {
+        return 2; // TRTX_ERROR_OUT_OF_MEMORY
+    }
+    exec->nb_contexts = mock_stream->nb_captured;
+    memcpy(exec->contexts, mock_stream->captured, sizeof(exec->contexts));
+    *graph_exec = exec;
+    return 0;
+}
+
+int32_t trtx_cuda_graph_launch(
+    void* graph_exec,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    MockGraphExec* exec = (MockGraphExec*)graph_exec;
+    for (int32_t i = 0; i < exec->nb_contexts; ++i) {
+        mock_execute(exec->contexts[i]);
+    }
+    return 0;
+}
+
+int32_t trtx_cuda_graph_exec_destroy(
+    void* graph_exec,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    free(graph_exec);
+    return 0;
+}
+
+int32_t trtx_cuda_event_create(
+    void** event,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    MockEvent* mock_event = calloc(1, sizeof(MockEvent));
+    if (!mock_event) {
+        return 2; // TRTX_ERROR_OUT_OF_MEMORY
+    }
+    *event = mock_event;
+    return 0;
+}
+
+int32_t trtx_cuda_event_destroy(
+    void* event,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    free(event);
+    return 0;
+}
+
+int32_t trtx_cuda_event_record(
+    void* event,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    atomic_store(&((MockEvent*)event)->pending_queries, 1);
+    return 0;
+}
+
+int32_t trtx_cuda_event_query(
+    void* event,
+    bool* out_complete,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    MockEvent* mock_event = (MockEvent*)event;
+    int pending = atomic_load(&mock_event->pending_queries);
+    while (pending > 0 &&
+           !atomic_compare_exchange_weak(&mock_event->pending_queries, &pending, pending - 1)) {
+    }
+    *out_complete = pending <= 0;
+    return 0;
+}
+
+int32_t trtx_cuda_event_synchronize(
+    void* event,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    atomic_store(&((MockEvent*)event)->pending_queries, 0);
+    return 0;
+}
+
+// Mock: stream-ordered allocations complete immediately
+int32_t trtx_cuda_malloc_async(
+    void** ptr,
+    size_t size,
+    void* pool,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    return trtx_cuda_malloc(ptr, size, error_msg, error_msg_len);
+}
+
+int32_t trtx_cuda_free_async(
+    void* ptr,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    return trtx_cuda_free(ptr, error_msg, error_msg_len);
+}
+
+int32_t trtx_cuda_malloc_managed(
+    void** ptr,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Mock: device memory is host memory already
+    return trtx_cuda_malloc(ptr, size, error_msg, error_msg_len);
+}
+
+int32_t trtx_cuda_mem_prefetch_async(
+    const void* ptr,
+    size_t size,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!ptr) {
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    return 0;
+}
+
+typedef struct {
+    uint64_t release_threshold;
+} MockMemPool;
+
+int32_t trtx_cuda_mem_pool_create(
+    void** pool,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    MockMemPool* mock_pool = calloc(1, sizeof(MockMemPool));
+    if (!mock_pool) {
+        return 2; // TRTX_ERROR_OUT_OF_MEMORY
+    }
+    *pool = mock_pool;
+    return 0;
+}
+
+int32_t trtx_cuda_mem_pool_destroy(
+    void* pool,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    free(pool);
+    return 0;
+}
+
+int32_t trtx_cuda_mem_pool_set_release_threshold(
+    void* pool,
+    uint64_t threshold,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    ((MockMemPool*)pool)->release_threshold = threshold;
+    return 0;
+}
+
+int32_t trtx_cuda_mem_pool_get_release_threshold(
+    void* pool,
+    uint64_t* out_threshold,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    *out_threshold = ((MockMemPool*)pool)->release_threshold;
+    return 0;
+}
+
+// Mock device limits, indexed by cudaLimit value
+static size_t mock_device_limits[] = {
+    1024,            // cudaLimitStackSize
+    1024 * 1024,     // cudaLimitPrintfFifoSize
+    8 * 1024 * 1024, // cudaLimitMallocHeapSize
+    2,               // cudaLimitDevRuntimeSyncDepth
+    2048,            // cudaLimitDevRuntimePendingLaunchCount
+    64,              // cudaLimitMaxL2FetchGranularity
+    0,               // cudaLimitPersistingL2CacheSize
+};
+
+#define MOCK_NB_DEVICE_LIMITS (int32_t)(sizeof(mock_device_limits) / sizeof(mock_device_limits[0]))
+
+int32_t trtx_cuda_device_get_limit(
+    int32_t limit,
+    size_t* value,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!value || limit < 0 || limit >= MOCK_NB_DEVICE_LIMITS) {
+        mock_copy_error("Unsupported limit", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    *value = mock_device_limits[limit];
+    return 0;
+}
+
+int32_t trtx_cuda_device_set_limit(
+    int32_t limit,
+    size_t value,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (limit < 0 || limit >= MOCK_NB_DEVICE_LIMITS) {
+        mock_copy_error("Unsupported limit", error_msg, error_msg_len);
+        return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+    mock_device_limits[limit] = value;
+    return 0;
+}
+
+int32_t trtx_cuda_device_get_attribute(
+    int32_t attribute,
+    int32_t* value,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    // Mock: a 46-SM compute capability 8.9 GPU
+    switch (attribute) {
+        case 16: *value = 46; return 0; // cudaDevAttrMultiProcessorCount
+        case 31: *value = 1; return 0;  // cudaDevAttrConcurrentKernels
+        case 40: *value = 2; return 0;  // cudaDevAttrAsyncEngineCount
+        case 75: *value = 8; return 0;  // cudaDevAttrComputeCapabilityMajor
+        case 76: *value = 9; return 0;  // cudaDevAttrComputeCapabilityMinor
+        default:
+            mock_copy_error("Unsupported attribute", error_msg, error_msg_len);
+            return 1; // TRTX_ERROR_INVALID_ARGUMENT
+    }
+}
+
 void* trtx_cuda_get_default_stream() {
     return NULL;
 }
@@ -25,6 +25,26 @@
 // Include the generated bindings
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
+/// TensorRT-RTX version the bindings were built against, as `(major, minor, patch)`
+///
+/// Parsed from `NvInferVersion.h` by the build script; `(0, 0, 0)` in mock mode.
+pub const TRTX_VERSION: (u32, u32, u32) = (
+    parse_version_component(env!("TRTX_VERSION_MAJOR")),
+    parse_version_component(env!("TRTX_VERSION_MINOR")),
+    parse_version_component(env!("TRTX_VERSION_PATCH")),
+);
+
+const fn parse_version_component(s: &str) -> u32 {
+    let bytes = s.as_bytes();
+    let mut value = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        value = value * 10 + (bytes[i] - b'0') as u32;
+        i += 1;
+    }
+    value
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -35,4 +55,10 @@ mod tests {
         assert_eq!(TRTX_SUCCESS, 0);
         assert_ne!(TRTX_ERROR_INVALID_ARGUMENT, TRTX_SUCCESS);
     }
+
+    #[test]
+    fn test_parse_version_component() {
+        assert_eq!(parse_version_component("0"), 0);
+        assert_eq!(parse_version_component("12"), 12);
+    }
 }
@@ -2,8 +2,13 @@
 #include <NvInfer.h>
 #include <NvOnnxParser.h>
 #include <cstring>
+#include <atomic>
 #include <exception>
 #include <memory>
+#include <mutex>
+#include <string>
+#include <utility>
+#include <vector>
 
 // Helper to copy error messages
 static void copy_error(const char* msg, char* error_msg, size_t error_msg_len) {
@@ -27,6 +32,30 @@ static void copy_error(const char* msg, char* error_msg, size_t error_msg_len) {
         return TRTX_ERROR_UNKNOWN; \
     }
 
+// Helper to copy nvinfer1::Dims into a caller-provided array
+static int32_t copy_dims(
+    const nvinfer1::Dims& dims,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (dims.nbDims < 0) {
+        copy_error("Unknown tensor", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+    if (dims.nbDims > max_dims) {
+        copy_error("Tensor rank exceeds output capacity", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+    for (int32_t i = 0; i < dims.nbDims; ++i) {
+        out_dims[i] = dims.d[i];
+    }
+    *out_nb_dims = dims.nbDims;
+    return TRTX_SUCCESS;
+}
+
 // Logger wrapper that calls back into Rust
 class LoggerImpl : public nvinfer1::ILogger {
 public:
@@ -44,6 +73,433 @@ private:
     void* user_data_;
 };
 
+// Progress monitor wrapper that calls back into Rust
+class ProgressMonitorImpl : public nvinfer1::IProgressMonitor {
+public:
+    ProgressMonitorImpl(
+        TrtxPhaseStartCallback phase_start,
+        TrtxStepCompleteCallback step_complete,
+        TrtxPhaseFinishCallback phase_finish,
+        void* user_data)
+        : phase_start_(phase_start),
+          step_complete_(step_complete),
+          phase_finish_(phase_finish),
+          user_data_(user_data) {}
+
+    void phaseStart(const char* phase_name, const char* parent_phase, int32_t nb_steps) noexcept override {
+        phase_start_(user_data_, phase_name, parent_phase, nb_steps);
+    }
+
+    bool stepComplete(const char* phase_name, int32_t step) noexcept override {
+        return step_complete_(user_data_, phase_name, step);
+    }
+
+    void phaseFinish(const char* phase_name) noexcept override {
+        phase_finish_(user_data_, phase_name);
+    }
+
+private:
+    TrtxPhaseStartCallback phase_start_;
+    TrtxStepCompleteCallback step_complete_;
+    TrtxPhaseFinishCallback phase_finish_;
+    void* user_data_;
+};
+
+// Profiler wrapper that calls back into Rust
+class ProfilerImpl : public nvinfer1::IProfiler {
+public:
+    ProfilerImpl(TrtxProfilerCallback callback, void* user_data)
+        : callback_(callback), user_data_(user_data) {}
+
+    void reportLayerTime(const char* layer_name, float ms) noexcept override {
+        if (callback_) {
+            callback_(user_data_, layer_name, ms);
+        }
+    }
+
+private:
+    TrtxProfilerCallback callback_;
+    void* user_data_;
+};
+
+// Debug listener wrapper that calls back into Rust
+class DebugListenerImpl : public nvinfer1::IDebugListener {
+public:
+    DebugListenerImpl(TrtxDebugTensorCallback callback, void* user_data)
+        : callback_(callback), user_data_(user_data) {}
+
+    bool processDebugTensor(
+        const void* addr,
+        nvinfer1::TensorLocation location,
+        nvinfer1::DataType type,
+        const nvinfer1::Dims& shape,
+        const char* name,
+        cudaStream_t stream
+    ) noexcept override {
+        if (!callback_ || location != nvinfer1::TensorLocation::kDEVICE) {
+            return true;
+        }
+        // The tensor is only valid once the work enqueued before it is done
+        if (cudaStreamSynchronize(stream) != cudaSuccess) {
+            return false;
+        }
+        callback_(user_data_, name, addr, static_cast<int32_t>(type), shape.d, shape.nbDims);
+        return true;
+    }
+
+private:
+    TrtxDebugTensorCallback callback_;
+    void* user_data_;
+};
+
+// Output allocator wrapper that calls back into Rust
+class OutputAllocatorImpl : public nvinfer1::IOutputAllocator {
+public:
+    OutputAllocatorImpl(
+        TrtxReallocateOutputCallback reallocate,
+        TrtxNotifyShapeCallback notify_shape,
+        void* user_data)
+        : reallocate_(reallocate), notify_shape_(notify_shape), user_data_(user_data) {}
+
+    void* reallocateOutputAsync(
+        const char* tensor_name,
+        void* current_memory,
+        uint64_t size,
+        uint64_t alignment,
+        cudaStream_t /*stream*/) noexcept override {
+        return reallocate_(user_data_, tensor_name, current_memory, size, alignment);
+    }
+
+    void notifyShape(const char* tensor_name, const nvinfer1::Dims& dims) noexcept override {
+        notify_shape_(user_data_, tensor_name, dims.d, dims.nbDims);
+    }
+
+private:
+    TrtxReallocateOutputCallback reallocate_;
+    TrtxNotifyShapeCallback notify_shape_;
+    void* user_data_;
+};
+
+// Error recorder that accumulates errors until Rust drains them
+class ErrorRecorderImpl : public nvinfer1::IErrorRecorder {
+public:
+    int32_t getNbErrors() const noexcept override {
+        std::lock_guard<std::mutex> lock(mutex_);
+        return static_cast<int32_t>(errors_.size());
+    }
+
+    nvinfer1::ErrorCode getErrorCode(int32_t index) const noexcept override {
+        std::lock_guard<std::mutex> lock(mutex_);
+        if (index < 0 || static_cast<size_t>(index) >= errors_.size()) {
+            return nvinfer1::ErrorCode::kINVALID_ARGUMENT;
+        }
+        return errors_[index].first;
+    }
+
+    ErrorDesc getErrorDesc(int32_t index) const noexcept override {
+        std::lock_guard<std::mutex> lock(mutex_);
+        if (index < 0 || static_cast<size_t>(index) >= errors_.size()) {
+            return "Error index out of range";
+        }
+        return errors_[index].second.c_str();
+    }
+
+    // Copy an error out under the lock: the pointer returned by getErrorDesc
+    // dangles as soon as another thread clears the recorder
+    bool copyError(int32_t index, int32_t* out_code, char* desc_buf, size_t desc_buf_len) const noexcept {
+        std::lock_guard<std::mutex> lock(mutex_);
+        if (index < 0 || static_cast<size_t>(index) >= errors_.size()) {
+            return false;
+        }
+        *out_code = static_cast<int32_t>(errors_[index].first);
+        copy_error(errors_[index].second.c_str(), desc_buf, desc_buf_len);
+        return true;
+    }
+
+    bool hasOverflowed() const noexcept override {
+        return false;
+    }
+
+    void clear() noexcept override {
+        std::lock_guard<std::mutex> lock(mutex_);
+        errors_.clear();
+    }
+
+    bool reportError(nvinfer1::ErrorCode code, ErrorDesc desc) noexcept override {
+        try {
+            std::lock_guard<std::mutex> lock(mutex_);
+            errors_.emplace_back(code, desc ? desc : "");
+        } catch (...) {
+        }
+        // Not fatal: let TensorRT continue and fail the call normally
+        return false;
+    }
+
+    RefCount incRefCount() noexcept override {
+        return ++ref_count_;
+    }
+
+    RefCount decRefCount() noexcept override {
+        RefCount count = --ref_count_;
+        if (count == 0) {
+            delete this;
+        }
+        return count;
+    }
+
+private:
+    mutable std::mutex mutex_;
+    std::vector<std::pair<nvinfer1::ErrorCode, std::string>> errors_;
+    std::atomic<RefCount> ref_count_{1};
+};
+
+// Callbacks and identity shared by a plugin creator and its plugins
+struct PluginCallbacks {
+    std::string name;
+    std::string version;
+    TrtxPluginCreateCallback create;
+    TrtxPluginCloneCallback clone;
+    TrtxPluginEnqueueCallback enqueue;
+    TrtxPluginDestroyCallback destroy;
+    void* user_data;
+};
+
+// Owned copy of plugin fields, kept to serialize them into the engine
+class PluginFields {
+public:
+    PluginFields() = default;
+
+    explicit PluginFields(const nvinfer1::PluginFieldCollection* fc) {
+        if (!fc) {
+            return;
+        }
+        for (int32_t i = 0; i < fc->nbFields; ++i) {
+            const auto& field = fc->fields[i];
+            const char* data = static_cast<const char*>(field.data);
+            size_t size = data ? field_size(field) : 0;
+            names_.emplace_back(field.name ? field.name : "");
+            data_.emplace_back(data, data + size);
+            types_.push_back(field.type);
+            lengths_.push_back(field.length);
+        }
+    }
+
+    // Fields as passed to the Rust create callback
+    std::vector<TrtxPluginField> raw() const {
+        std::vector<TrtxPluginField> raw;
+        for (size_t i = 0; i < names_.size(); ++i) {
+            raw.push_back({names_[i].c_str(), data_[i].data(), data_[i].size()});
+        }
+        return raw;
+    }
+
+    const nvinfer1::PluginFieldCollection* collection() {
+        fields_.clear();
+        for (size_t i = 0; i < names_.size(); ++i) {
+            fields_.emplace_back(names_[i].c_str(), data_[i].data(), types_[i], lengths_[i]);
+        }
+        collection_.nbFields = static_cast<int32_t>(fields_.size());
+        collection_.fields = fields_.data();
+        return &collection_;
+    }
+
+private:
+    static size_t field_size(const nvinfer1::PluginField& field) {
+        size_t element = 1;
+        switch (field.type) {
+        case nvinfer1::PluginFieldType::kFLOAT16:
+        case nvinfer1::PluginFieldType::kINT16:
+        case nvinfer1::PluginFieldType::kBF16:
+            element = 2;
+            break;
+        case nvinfer1::PluginFieldType::kFLOAT32:
+        case nvinfer1::PluginFieldType::kINT32:
+            element = 4;
+            break;
+        case nvinfer1::PluginFieldType::kFLOAT64:
+        case nvinfer1::PluginFieldType::kINT64:
+            element = 8;
+            break;
+        case nvinfer1::PluginFieldType::kDIMS:
+            element = sizeof(nvinfer1::Dims);
+            break;
+        default:
+            break;
+        }
+        return element * static_cast<size_t>(field.length);
+    }
+
+    std::vector<std::string> names_;
+    std::vector<std::vector<char>> data_;
+    std::vector<nvinfer1::PluginFieldType> types_;
+    std::vector<int32_t> lengths_;
+    std::vector<nvinfer1::PluginField> fields_;
+    nvinfer1::PluginFieldCollection collection_{};
+};
+
+// Elementwise plugin forwarding enqueue to a Rust instance: one FP32 or FP16
+// input, one output of the same shape and type
+class RustPlugin : public nvinfer1::IPluginV3,
+                   public nvinfer1::IPluginV3OneCore,
+                   public nvinfer1::IPluginV3OneBuild,
+                   public nvinfer1::IPluginV3OneRuntime {
+public:
+    RustPlugin(std::shared_ptr<PluginCallbacks> callbacks, void* instance, PluginFields fields)
+        : callbacks_(std::move(callbacks)), instance_(instance), fields_(std::move(fields)) {}
+
+    ~RustPlugin() override {
+        callbacks_->destroy(instance_);
+    }
+
+    nvinfer1::IPluginCapability* getCapabilityInterface(
+        nvinfer1::PluginCapabilityType type
+    ) noexcept override {
+        switch (type) {
+        case nvinfer1::PluginCapabilityType::kCORE:
+            return static_cast<nvinfer1::IPluginV3OneCore*>(this);
+        case nvinfer1::PluginCapabilityType::kBUILD:
+            return static_cast<nvinfer1::IPluginV3OneBuild*>(this);
+        case nvinfer1::PluginCapabilityType::kRUNTIME:
+            return static_cast<nvinfer1::IPluginV3OneRuntime*>(this);
+        }
+        return nullptr;
+    }
+
+    nvinfer1::IPluginV3* clone() noexcept override {
+        void* instance = callbacks_->clone(instance_);
+        if (!instance) {
+            return nullptr;
+        }
+        try {
+            return new RustPlugin(callbacks_, instance, fields_);
+        } catch (...) {
+            callbacks_->destroy(instance);
+            return nullptr;
+        }
+    }
+
+    const char* getPluginName() const noexcept override { return callbacks_->name.c_str(); }
+    const char* getPluginVersion() const noexcept override { return callbacks_->version.c_str(); }
+    const char* getPluginNamespace() const noexcept override { return ""; }
+
+    int32_t getNbOutputs() const noexcept override { return 1; }
+
+    int32_t configurePlugin(
+        const nvinfer1::DynamicPluginTensorDesc* in, int32_t nb_inputs,
+        const nvinfer1::DynamicPluginTensorDesc* out, int32_t nb_outputs
+    ) noexcept override {
+        return nb_inputs == 1 && nb_outputs == 1 ? 0 : -1;
+    }
+
+    int32_t getOutputDataTypes(
+        nvinfer1::DataType* output_types, int32_t nb_outputs,
+        const nvinfer1::DataType* input_types, int32_t nb_inputs
+    ) const noexcept override {
+        if (nb_inputs != 1 || nb_outputs != 1) {
+            return -1;
+        }
+        output_types[0] = input_types[0];
+        return 0;
+    }
+
+    int32_t getOutputShapes(
+        const nvinfer1::DimsExprs* inputs, int32_t nb_inputs,
+        const nvinfer1::DimsExprs* shape_inputs, int32_t nb_shape_inputs,
+        nvinfer1::DimsExprs* outputs, int32_t nb_outputs,
+        nvinfer1::IExprBuilder& expr_builder
+    ) noexcept override {
+        if (nb_inputs != 1 || nb_outputs != 1) {
+            return -1;
+        }
+        outputs[0] = inputs[0];
+        return 0;
+    }
+
+    bool supportsFormatCombination(
+        int32_t pos, const nvinfer1::DynamicPluginTensorDesc* in_out,
+        int32_t nb_inputs, int32_t nb_outputs
+    ) noexcept override {
+        const auto& desc = in_out[pos].desc;
+        if (desc.format != nvinfer1::TensorFormat::kLINEAR) {
+            return false;
+        }
+        if (pos == 0) {
+            return desc.type == nvinfer1::DataType::kFLOAT || desc.type == nvinfer1::DataType::kHALF;
+        }
+        return desc.type == in_out[0].desc.type;
+    }
+
+    int32_t onShapeChange(
+        const nvinfer1::PluginTensorDesc* in, int32_t nb_inputs,
+        const nvinfer1::PluginTensorDesc* out, int32_t nb_outputs
+    ) noexcept override {
+        return 0;
+    }
+
+    int32_t enqueue(
+        const nvinfer1::PluginTensorDesc* input_desc,
+        const nvinfer1::PluginTensorDesc* output_desc,
+        const void* const* inputs, void* const* outputs,
+        void* workspace, cudaStream_t stream
+    ) noexcept override {
+        const auto& dims = input_desc[0].dims;
+        bool ok = callbacks_->enqueue(
+            instance_, inputs[0], outputs[0], static_cast<int32_t>(input_desc[0].type),
+            dims.d, dims.nbDims, stream);
+        return ok ? 0 : -1;
+    }
+
+    nvinfer1::IPluginV3* attachToContext(nvinfer1::IPluginResourceContext* context) noexcept override {
+        return clone();
+    }
+
+    const nvinfer1::PluginFieldCollection* getFieldsToSerialize() noexcept override {
+        return fields_.collection();
+    }
+
+private:
+    std::shared_ptr<PluginCallbacks> callbacks_;
+    void* instance_;
+    PluginFields fields_;
+};
+
+// Plugin creator that builds RustPlugins through the Rust create callback
+class RustPluginCreator : public nvinfer1::IPluginCreatorV3One {
+public:
+    explicit RustPluginCreator(std::shared_ptr<PluginCallbacks> callbacks)
+        : callbacks_(std::move(callbacks)) {}
+
+    nvinfer1::IPluginV3* createPlugin(
+        const char* name, const nvinfer1::PluginFieldCollection* fc, nvinfer1::TensorRTPhase phase
+    ) noexcept override {
+        try {
+            PluginFields fields(fc);
+            auto raw = fields.raw();
+            void* instance = callbacks_->create(
+                callbacks_->user_data, name ? name : "", raw.data(), static_cast<int32_t>(raw.size()));
+            if (!instance) {
+                return nullptr;
+            }
+            return new RustPlugin(callbacks_, instance, std::move(fields));
+        } catch (...) {
+            return nullptr;
+        }
+    }
+
+    const nvinfer1::PluginFieldCollection* getFieldNames() noexcept override {
+        // Fields are free-form; every field given is passed to Rust
+        return &no_fields_;
+    }
+
+    const char* getPluginName() const noexcept override { return callbacks_->name.c_str(); }
+    const char* getPluginVersion() const noexcept override { return callbacks_->version.c_str(); }
+    const char* getPluginNamespace() const noexcept override { return ""; }
+
+private:
+    std::shared_ptr<PluginCallbacks> callbacks_;
+    nvinfer1::PluginFieldCollection no_fields_{};
+};
+
 // Logger functions
 int32_t trtx_logger_create(
     TrtxLoggerCallback callback,
@@ -101,6 +557,60 @@ void trtx_builder_destroy(TrtxBuilder* builder) {
     }
 }
 
+int32_t trtx_builder_set_max_threads(
+    TrtxBuilder* builder,
+    int32_t nb_threads,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!builder) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* builder_impl = reinterpret_cast<nvinfer1::IBuilder*>(builder);
+        if (!builder_impl->setMaxThreads(nb_threads)) {
+            copy_error("Invalid thread count", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_builder_get_max_threads(
+    TrtxBuilder* builder,
+    int32_t* out_nb_threads,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!builder || !out_nb_threads) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        *out_nb_threads = reinterpret_cast<nvinfer1::IBuilder*>(builder)->getMaxThreads();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_builder_reset(
+    TrtxBuilder* builder,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!builder) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        reinterpret_cast<nvinfer1::IBuilder*>(builder)->reset();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
 int32_t trtx_builder_create_network(
     TrtxBuilder* builder,
     uint32_t flags,
@@ -125,6 +635,29 @@ int32_t trtx_builder_create_network(
     TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
+int32_t trtx_builder_create_optimization_profile(
+    TrtxBuilder* builder,
+    TrtxOptimizationProfile** out_profile,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!builder || !out_profile) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* builder_impl = reinterpret_cast<nvinfer1::IBuilder*>(builder);
+        auto* profile = builder_impl->createOptimizationProfile();
+        if (!profile) {
+            copy_error("Failed to create optimization profile", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        *out_profile = reinterpret_cast<TrtxOptimizationProfile*>(profile);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
 int32_t trtx_builder_create_builder_config(
     TrtxBuilder* builder,
     TrtxBuilderConfig** out_config,
@@ -191,383 +724,3489 @@ int32_t trtx_builder_build_serialized_network(
     TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-// BuilderConfig functions
-void trtx_builder_config_destroy(TrtxBuilderConfig* config) {
-    if (config) {
-        auto* impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
-        delete impl;
-    }
-}
-
-int32_t trtx_builder_config_set_memory_pool_limit(
-    TrtxBuilderConfig* config,
-    int32_t pool_type,
-    size_t pool_size,
+int32_t trtx_builder_set_error_recorder(
+    TrtxBuilder* builder,
+    TrtxErrorRecorder* recorder,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!config) {
+    if (!builder) {
         copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
 
     TRTX_TRY_CATCH_BEGIN
-        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
-        config_impl->setMemoryPoolLimit(
-            static_cast<nvinfer1::MemoryPoolType>(pool_type),
-            pool_size
-        );
+        auto* builder_impl = reinterpret_cast<nvinfer1::IBuilder*>(builder);
+        builder_impl->setErrorRecorder(reinterpret_cast<ErrorRecorderImpl*>(recorder));
         return TRTX_SUCCESS;
     TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-// NetworkDefinition functions
-void trtx_network_destroy(TrtxNetworkDefinition* network) {
-    if (network) {
-        auto* impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
-        delete impl;
-    }
-}
-
-// Runtime functions
-int32_t trtx_runtime_create(
-    TrtxLogger* logger,
-    TrtxRuntime** out_runtime,
+// ErrorRecorder functions
+int32_t trtx_error_recorder_create(
+    TrtxErrorRecorder** out_recorder,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!logger || !out_runtime) {
+    if (!out_recorder) {
         copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
 
     TRTX_TRY_CATCH_BEGIN
-        auto* logger_impl = reinterpret_cast<LoggerImpl*>(logger);
-        auto* runtime = nvinfer1::createInferRuntime(*logger_impl);
-        if (!runtime) {
-            copy_error("Failed to create runtime", error_msg, error_msg_len);
-            return TRTX_ERROR_RUNTIME_ERROR;
-        }
-        *out_runtime = reinterpret_cast<TrtxRuntime*>(runtime);
+        auto* recorder = new ErrorRecorderImpl();
+        *out_recorder = reinterpret_cast<TrtxErrorRecorder*>(recorder);
         return TRTX_SUCCESS;
     TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-void trtx_runtime_destroy(TrtxRuntime* runtime) {
-    if (runtime) {
-        auto* impl = reinterpret_cast<nvinfer1::IRuntime*>(runtime);
-        delete impl;
+void trtx_error_recorder_destroy(TrtxErrorRecorder* recorder) {
+    if (recorder) {
+        reinterpret_cast<ErrorRecorderImpl*>(recorder)->decRefCount();
     }
 }
 
-int32_t trtx_runtime_deserialize_cuda_engine(
-    TrtxRuntime* runtime,
-    const void* data,
-    size_t size,
-    TrtxCudaEngine** out_engine,
+int32_t trtx_error_recorder_get_nb_errors(TrtxErrorRecorder* recorder, int32_t* out_count) {
+    if (!recorder || !out_count) {
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    *out_count = reinterpret_cast<ErrorRecorderImpl*>(recorder)->getNbErrors();
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_error_recorder_get_error(
+    TrtxErrorRecorder* recorder,
+    int32_t index,
+    int32_t* out_code,
+    char* desc_buf,
+    size_t desc_buf_len,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!runtime || !data || !out_engine) {
+    if (!recorder || !out_code || !desc_buf) {
         copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
 
-    TRTX_TRY_CATCH_BEGIN
-        auto* runtime_impl = reinterpret_cast<nvinfer1::IRuntime*>(runtime);
-        auto* engine = runtime_impl->deserializeCudaEngine(data, size);
-        if (!engine) {
-            copy_error("Failed to deserialize engine", error_msg, error_msg_len);
-            return TRTX_ERROR_RUNTIME_ERROR;
-        }
-        *out_engine = reinterpret_cast<TrtxCudaEngine*>(engine);
-        return TRTX_SUCCESS;
-    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+    auto* recorder_impl = reinterpret_cast<ErrorRecorderImpl*>(recorder);
+    if (!recorder_impl->copyError(index, out_code, desc_buf, desc_buf_len)) {
+        copy_error("Error index out of range", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+    return TRTX_SUCCESS;
 }
 
-// CudaEngine functions
-void trtx_cuda_engine_destroy(TrtxCudaEngine* engine) {
-    if (engine) {
-        auto* impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+void trtx_error_recorder_clear(TrtxErrorRecorder* recorder) {
+    if (recorder) {
+        reinterpret_cast<ErrorRecorderImpl*>(recorder)->clear();
+    }
+}
+
+// BuilderConfig functions
+void trtx_builder_config_destroy(TrtxBuilderConfig* config) {
+    if (config) {
+        auto* impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
         delete impl;
     }
 }
 
-int32_t trtx_cuda_engine_create_execution_context(
-    TrtxCudaEngine* engine,
-    TrtxExecutionContext** out_context,
+int32_t trtx_builder_config_set_memory_pool_limit(
+    TrtxBuilderConfig* config,
+    int32_t pool_type,
+    size_t pool_size,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!engine || !out_context) {
+    if (!config) {
         copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
 
     TRTX_TRY_CATCH_BEGIN
-        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
-        auto* context = engine_impl->createExecutionContext();
-        if (!context) {
-            copy_error("Failed to create execution context", error_msg, error_msg_len);
-            return TRTX_ERROR_RUNTIME_ERROR;
-        }
-        *out_context = reinterpret_cast<TrtxExecutionContext*>(context);
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        config_impl->setMemoryPoolLimit(
+            static_cast<nvinfer1::MemoryPoolType>(pool_type),
+            pool_size
+        );
         return TRTX_SUCCESS;
     TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-int32_t trtx_cuda_engine_get_tensor_name(
-    TrtxCudaEngine* engine,
-    int32_t index,
-    const char** out_name,
+int32_t trtx_builder_config_set_flag(
+    TrtxBuilderConfig* config,
+    int32_t flag,
+    bool enabled,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!engine || !out_name) {
+    if (!config) {
         copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
 
     TRTX_TRY_CATCH_BEGIN
-        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
-        const char* name = engine_impl->getIOTensorName(index);
-        if (!name) {
-            copy_error("Invalid tensor index", error_msg, error_msg_len);
-            return TRTX_ERROR_INVALID_ARGUMENT;
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        auto builder_flag = static_cast<nvinfer1::BuilderFlag>(flag);
+        if (enabled) {
+            config_impl->setFlag(builder_flag);
+        } else {
+            config_impl->clearFlag(builder_flag);
         }
-        *out_name = name;
         return TRTX_SUCCESS;
     TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-int32_t trtx_cuda_engine_get_nb_io_tensors(
-    TrtxCudaEngine* engine,
-    int32_t* out_count
+int32_t trtx_builder_config_get_memory_pool_limit(
+    TrtxBuilderConfig* config,
+    int32_t pool_type,
+    size_t* out_pool_size,
+    char* error_msg,
+    size_t error_msg_len
 ) {
-    if (!engine || !out_count) {
+    if (!config || !out_pool_size) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
 
     TRTX_TRY_CATCH_BEGIN
-        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
-        *out_count = engine_impl->getNbIOTensors();
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        *out_pool_size = config_impl->getMemoryPoolLimit(
+            static_cast<nvinfer1::MemoryPoolType>(pool_type));
         return TRTX_SUCCESS;
-    TRTX_TRY_CATCH_END(nullptr, 0)
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-// ExecutionContext functions
-void trtx_execution_context_destroy(TrtxExecutionContext* context) {
-    if (context) {
-        auto* impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
-        delete impl;
+int32_t trtx_builder_config_get_flag(
+    TrtxBuilderConfig* config,
+    int32_t flag,
+    bool* out_enabled,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!config || !out_enabled) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
     }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        *out_enabled = config_impl->getFlag(static_cast<nvinfer1::BuilderFlag>(flag));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-int32_t trtx_execution_context_set_tensor_address(
-    TrtxExecutionContext* context,
-    const char* tensor_name,
-    void* data,
+int32_t trtx_builder_config_set_preview_feature(
+    TrtxBuilderConfig* config,
+    int32_t feature,
+    bool enabled,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!context || !tensor_name) {
+    if (!config || feature < 0) {
         copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
+    if (feature >= nvinfer1::EnumMax<nvinfer1::PreviewFeature>()) {
+        return TRTX_SUCCESS; // Unknown to this library version
+    }
 
     TRTX_TRY_CATCH_BEGIN
-        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
-        bool success = context_impl->setTensorAddress(tensor_name, data);
-        if (!success) {
-            copy_error("Failed to set tensor address", error_msg, error_msg_len);
-            return TRTX_ERROR_RUNTIME_ERROR;
-        }
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        config_impl->setPreviewFeature(static_cast<nvinfer1::PreviewFeature>(feature), enabled);
         return TRTX_SUCCESS;
     TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-int32_t trtx_execution_context_enqueue_v3(
-    TrtxExecutionContext* context,
-    void* cuda_stream,
+int32_t trtx_builder_config_get_preview_feature(
+    TrtxBuilderConfig* config,
+    int32_t feature,
+    bool* out_enabled,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!context) {
+    if (!config || !out_enabled || feature < 0) {
         copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
+    if (feature >= nvinfer1::EnumMax<nvinfer1::PreviewFeature>()) {
+        *out_enabled = false; // Unknown to this library version
+        return TRTX_SUCCESS;
+    }
 
     TRTX_TRY_CATCH_BEGIN
-        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
-        bool success = context_impl->enqueueV3(static_cast<cudaStream_t>(cuda_stream));
-        if (!success) {
-            copy_error("Failed to enqueue inference", error_msg, error_msg_len);
-            return TRTX_ERROR_RUNTIME_ERROR;
-        }
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        *out_enabled = config_impl->getPreviewFeature(static_cast<nvinfer1::PreviewFeature>(feature));
         return TRTX_SUCCESS;
     TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-// Utility functions
-void trtx_free_buffer(void* buffer) {
-    free(buffer);
-}
-
-// ONNX Parser functions
-int32_t trtx_onnx_parser_create(
-    TrtxNetworkDefinition* network,
-    TrtxLogger* logger,
-    TrtxOnnxParser** out_parser,
+int32_t trtx_builder_config_set_max_aux_streams(
+    TrtxBuilderConfig* config,
+    int32_t nb_streams,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!network || !logger || !out_parser) {
+    if (!config || nb_streams < -1) {
         copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
 
     TRTX_TRY_CATCH_BEGIN
-        auto* network_impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
-        auto* logger_impl = reinterpret_cast<LoggerImpl*>(logger);
-
-        auto* parser = nvonnxparser::createParser(*network_impl, *logger_impl);
-        if (!parser) {
-            copy_error("Failed to create ONNX parser", error_msg, error_msg_len);
-            return TRTX_ERROR_RUNTIME_ERROR;
-        }
-
-        *out_parser = reinterpret_cast<TrtxOnnxParser*>(parser);
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        config_impl->setMaxAuxStreams(nb_streams);
         return TRTX_SUCCESS;
     TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-void trtx_onnx_parser_destroy(TrtxOnnxParser* parser) {
-    if (parser) {
-        auto* impl = reinterpret_cast<nvonnxparser::IParser*>(parser);
-        delete impl;
+int32_t trtx_builder_config_get_max_aux_streams(
+    TrtxBuilderConfig* config,
+    int32_t* out_nb_streams,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!config || !out_nb_streams) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
     }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        *out_nb_streams = config_impl->getMaxAuxStreams();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-int32_t trtx_onnx_parser_parse(
-    TrtxOnnxParser* parser,
-    const void* model_data,
-    size_t model_size,
+int32_t trtx_builder_config_add_optimization_profile(
+    TrtxBuilderConfig* config,
+    TrtxOptimizationProfile* profile,
+    int32_t* out_index,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!parser || !model_data) {
+    if (!config || !profile || !out_index) {
         copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
 
     TRTX_TRY_CATCH_BEGIN
-        auto* parser_impl = reinterpret_cast<nvonnxparser::IParser*>(parser);
-
-        bool success = parser_impl->parse(model_data, model_size);
-        if (!success) {
-            // Get error from parser
-            int32_t num_errors = parser_impl->getNbErrors();
-            if (num_errors > 0) {
-                auto* first_error = parser_impl->getError(0);
-                copy_error(first_error->desc(), error_msg, error_msg_len);
-            } else {
-                copy_error("Failed to parse ONNX model", error_msg, error_msg_len);
-            }
-            return TRTX_ERROR_RUNTIME_ERROR;
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        int32_t index = config_impl->addOptimizationProfile(
+            reinterpret_cast<nvinfer1::IOptimizationProfile*>(profile));
+        if (index < 0) {
+            copy_error("Invalid optimization profile", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
         }
-
+        *out_index = index;
         return TRTX_SUCCESS;
     TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-// CUDA Memory Management functions
-#include <cuda_runtime.h>
-
-int32_t trtx_cuda_malloc(
-    void** ptr,
-    size_t size,
+int32_t trtx_builder_config_get_nb_optimization_profiles(
+    TrtxBuilderConfig* config,
+    int32_t* out_count,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!ptr) {
+    if (!config || !out_count) {
         copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
 
-    cudaError_t err = cudaMalloc(ptr, size);
-    if (err != cudaSuccess) {
-        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
-        return TRTX_ERROR_CUDA_ERROR;
-    }
-
-    return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_BEGIN
+        *out_count = reinterpret_cast<nvinfer1::IBuilderConfig*>(config)->getNbOptimizationProfiles();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-int32_t trtx_cuda_free(
-    void* ptr,
+int32_t trtx_builder_config_set_runtime_platform(
+    TrtxBuilderConfig* config,
+    int32_t platform,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!ptr) {
-        return TRTX_SUCCESS; // Freeing null is not an error
-    }
-
-    cudaError_t err = cudaFree(ptr);
-    if (err != cudaSuccess) {
-        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
-        return TRTX_ERROR_CUDA_ERROR;
+    if (!config) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
     }
 
-    return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_BEGIN
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        config_impl->setRuntimePlatform(static_cast<nvinfer1::RuntimePlatform>(platform));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-int32_t trtx_cuda_memcpy_host_to_device(
-    void* dst,
-    const void* src,
-    size_t size,
+int32_t trtx_builder_config_get_runtime_platform(
+    TrtxBuilderConfig* config,
+    int32_t* out_platform,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!dst || !src) {
+    if (!config || !out_platform) {
         copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
 
-    cudaError_t err = cudaMemcpy(dst, src, size, cudaMemcpyHostToDevice);
-    if (err != cudaSuccess) {
-        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
-        return TRTX_ERROR_CUDA_ERROR;
-    }
-
-    return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_BEGIN
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        *out_platform = static_cast<int32_t>(config_impl->getRuntimePlatform());
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-int32_t trtx_cuda_memcpy_device_to_host(
-    void* dst,
-    const void* src,
-    size_t size,
+int32_t trtx_builder_config_set_engine_capability(
+    TrtxBuilderConfig* config,
+    int32_t capability,
     char* error_msg,
     size_t error_msg_len
 ) {
-    if (!dst || !src) {
+    if (!config) {
         copy_error("Invalid arguments", error_msg, error_msg_len);
         return TRTX_ERROR_INVALID_ARGUMENT;
     }
 
-    cudaError_t err = cudaMemcpy(dst, src, size, cudaMemcpyDeviceToHost);
-    if (err != cudaSuccess) {
-        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
-        return TRTX_ERROR_CUDA_ERROR;
-    }
-
-    return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_BEGIN
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        config_impl->setEngineCapability(static_cast<nvinfer1::EngineCapability>(capability));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
 }
 
-int32_t trtx_cuda_synchronize(
+int32_t trtx_builder_config_get_engine_capability(
+    TrtxBuilderConfig* config,
+    int32_t* out_capability,
     char* error_msg,
     size_t error_msg_len
 ) {
-    cudaError_t err = cudaDeviceSynchronize();
+    if (!config || !out_capability) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        *out_capability = static_cast<int32_t>(config_impl->getEngineCapability());
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_builder_config_set_builder_optimization_level(
+    TrtxBuilderConfig* config,
+    int32_t level,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!config) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        config_impl->setBuilderOptimizationLevel(level);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_builder_config_get_builder_optimization_level(
+    TrtxBuilderConfig* config,
+    int32_t* out_level,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!config || !out_level) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        *out_level = config_impl->getBuilderOptimizationLevel();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_builder_config_set_profiling_verbosity(
+    TrtxBuilderConfig* config,
+    int32_t verbosity,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!config) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        config_impl->setProfilingVerbosity(static_cast<nvinfer1::ProfilingVerbosity>(verbosity));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// NetworkDefinition functions
+void trtx_network_destroy(TrtxNetworkDefinition* network) {
+    if (network) {
+        auto* impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
+        delete impl;
+    }
+}
+
+int32_t trtx_network_get_nb_inputs(
+    TrtxNetworkDefinition* network,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !out_count) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        *out_count = reinterpret_cast<nvinfer1::INetworkDefinition*>(network)->getNbInputs();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// Network input or layer output named name, or nullptr if there is none
+static nvinfer1::ITensor* find_network_tensor(TrtxNetworkDefinition* network, const char* name) {
+    auto* network_impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
+    for (int32_t i = 0; i < network_impl->getNbInputs(); ++i) {
+        auto* tensor = network_impl->getInput(i);
+        if (std::strcmp(tensor->getName(), name) == 0) {
+            return tensor;
+        }
+    }
+    for (int32_t i = 0; i < network_impl->getNbLayers(); ++i) {
+        auto* layer = network_impl->getLayer(i);
+        for (int32_t j = 0; j < layer->getNbOutputs(); ++j) {
+            auto* tensor = layer->getOutput(j);
+            if (tensor && std::strcmp(tensor->getName(), name) == 0) {
+                return tensor;
+            }
+        }
+    }
+    return nullptr;
+}
+
+int32_t trtx_network_mark_debug(
+    TrtxNetworkDefinition* network,
+    const char* name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* tensor = find_network_tensor(network, name);
+        if (!tensor) {
+            std::string msg = std::string("No tensor named ") + name;
+            copy_error(msg.c_str(), error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        if (!reinterpret_cast<nvinfer1::INetworkDefinition*>(network)->markDebug(*tensor)) {
+            copy_error("Failed to mark debug tensor", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_network_set_name(
+    TrtxNetworkDefinition* network,
+    const char* name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        reinterpret_cast<nvinfer1::INetworkDefinition*>(network)->setName(name);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_network_get_name(
+    TrtxNetworkDefinition* network,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !out_name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        const char* name = reinterpret_cast<nvinfer1::INetworkDefinition*>(network)->getName();
+        *out_name = name ? name : "";
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_network_get_flags(
+    TrtxNetworkDefinition* network,
+    uint32_t* out_flags,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !out_flags) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        *out_flags = reinterpret_cast<nvinfer1::INetworkDefinition*>(network)->getFlags();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// Layer producing the tensor named name, and the tensor's output index
+static nvinfer1::ILayer* find_producer(
+    TrtxNetworkDefinition* network, const char* name, int32_t* out_index) {
+    auto* network_impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
+    for (int32_t i = 0; i < network_impl->getNbLayers(); ++i) {
+        auto* layer = network_impl->getLayer(i);
+        for (int32_t j = 0; j < layer->getNbOutputs(); ++j) {
+            auto* tensor = layer->getOutput(j);
+            if (tensor && std::strcmp(tensor->getName(), name) == 0) {
+                *out_index = j;
+                return layer;
+            }
+        }
+    }
+    return nullptr;
+}
+
+// Layer named name, or nullptr if there is none
+static nvinfer1::ILayer* find_layer(TrtxNetworkDefinition* network, const char* name) {
+    auto* network_impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
+    for (int32_t i = 0; i < network_impl->getNbLayers(); ++i) {
+        auto* layer = network_impl->getLayer(i);
+        if (std::strcmp(layer->getName(), name) == 0) {
+            return layer;
+        }
+    }
+    return nullptr;
+}
+
+int32_t trtx_network_set_output_type(
+    TrtxNetworkDefinition* network,
+    const char* tensor_name,
+    int32_t data_type,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !tensor_name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        int32_t index = 0;
+        auto* layer = find_producer(network, tensor_name, &index);
+        if (!layer) {
+            std::string msg = std::string("No layer output named ") + tensor_name;
+            copy_error(msg.c_str(), error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        layer->setOutputType(index, static_cast<nvinfer1::DataType>(data_type));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_network_get_output_type(
+    TrtxNetworkDefinition* network,
+    const char* tensor_name,
+    int32_t* out_data_type,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !tensor_name || !out_data_type) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        int32_t index = 0;
+        auto* layer = find_producer(network, tensor_name, &index);
+        if (!layer) {
+            std::string msg = std::string("No layer output named ") + tensor_name;
+            copy_error(msg.c_str(), error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_data_type = static_cast<int32_t>(layer->getOutputType(index));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_network_set_layer_precision(
+    TrtxNetworkDefinition* network,
+    const char* layer_name,
+    int32_t data_type,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !layer_name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* layer = find_layer(network, layer_name);
+        if (!layer) {
+            std::string msg = std::string("No layer named ") + layer_name;
+            copy_error(msg.c_str(), error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        layer->setPrecision(static_cast<nvinfer1::DataType>(data_type));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_network_get_layer_precision(
+    TrtxNetworkDefinition* network,
+    const char* layer_name,
+    int32_t* out_data_type,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !layer_name || !out_data_type) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* layer = find_layer(network, layer_name);
+        if (!layer) {
+            std::string msg = std::string("No layer named ") + layer_name;
+            copy_error(msg.c_str(), error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_data_type = static_cast<int32_t>(layer->getPrecision());
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// Input tensor at index, or nullptr if out of range
+static nvinfer1::ITensor* network_input(TrtxNetworkDefinition* network, int32_t index) {
+    auto* network_impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
+    if (index < 0 || index >= network_impl->getNbInputs()) {
+        return nullptr;
+    }
+    return network_impl->getInput(index);
+}
+
+int32_t trtx_network_get_input_name(
+    TrtxNetworkDefinition* network,
+    int32_t index,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !out_name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* input = network_input(network, index);
+        if (!input) {
+            copy_error("Invalid input index", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_name = input->getName();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_network_get_input_shape(
+    TrtxNetworkDefinition* network,
+    int32_t index,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !out_dims || !out_nb_dims) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* input = network_input(network, index);
+        if (!input) {
+            copy_error("Invalid input index", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return copy_dims(input->getDimensions(), out_dims, max_dims, out_nb_dims,
+            error_msg, error_msg_len);
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// Helper to build nvinfer1::Dims from a caller-provided array
+static bool to_dims(const int64_t* dims, int32_t nb_dims, nvinfer1::Dims* out) {
+    if ((!dims && nb_dims > 0) || nb_dims < 0 || nb_dims > nvinfer1::Dims::MAX_DIMS) {
+        return false;
+    }
+    out->nbDims = nb_dims;
+    for (int32_t i = 0; i < nb_dims; ++i) {
+        out->d[i] = dims[i];
+    }
+    return true;
+}
+
+int32_t trtx_network_add_input(
+    TrtxNetworkDefinition* network,
+    const char* name,
+    int32_t data_type,
+    const int64_t* dims,
+    int32_t nb_dims,
+    TrtxTensor** out_tensor,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    nvinfer1::Dims shape{};
+    if (!network || !name || !out_tensor || !to_dims(dims, nb_dims, &shape)) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* network_impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
+        auto* tensor = network_impl->addInput(
+            name, static_cast<nvinfer1::DataType>(data_type), shape);
+        if (!tensor) {
+            std::string msg = std::string("Failed to add input ") + name;
+            copy_error(msg.c_str(), error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_tensor = reinterpret_cast<TrtxTensor*>(tensor);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_network_add_constant(
+    TrtxNetworkDefinition* network,
+    const int64_t* dims,
+    int32_t nb_dims,
+    int32_t data_type,
+    const void* values,
+    int64_t count,
+    TrtxLayer** out_layer,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    nvinfer1::Dims shape{};
+    if (!network || !out_layer || (!values && count > 0) || count < 0
+        || !to_dims(dims, nb_dims, &shape)) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* network_impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
+        nvinfer1::Weights weights{static_cast<nvinfer1::DataType>(data_type), values, count};
+        auto* layer = network_impl->addConstant(shape, weights);
+        if (!layer) {
+            copy_error("Failed to add constant layer", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_layer = reinterpret_cast<TrtxLayer*>(static_cast<nvinfer1::ILayer*>(layer));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_network_add_activation(
+    TrtxNetworkDefinition* network,
+    TrtxTensor* input,
+    int32_t activation,
+    TrtxLayer** out_layer,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !input || !out_layer) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* network_impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
+        auto* layer = network_impl->addActivation(
+            *reinterpret_cast<nvinfer1::ITensor*>(input),
+            static_cast<nvinfer1::ActivationType>(activation));
+        if (!layer) {
+            copy_error("Failed to add activation layer", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_layer = reinterpret_cast<TrtxLayer*>(static_cast<nvinfer1::ILayer*>(layer));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_network_add_elementwise(
+    TrtxNetworkDefinition* network,
+    TrtxTensor* a,
+    TrtxTensor* b,
+    int32_t op,
+    TrtxLayer** out_layer,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !a || !b || !out_layer) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* network_impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
+        auto* layer = network_impl->addElementWise(
+            *reinterpret_cast<nvinfer1::ITensor*>(a),
+            *reinterpret_cast<nvinfer1::ITensor*>(b),
+            static_cast<nvinfer1::ElementWiseOperation>(op));
+        if (!layer) {
+            copy_error("Failed to add elementwise layer", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_layer = reinterpret_cast<TrtxLayer*>(static_cast<nvinfer1::ILayer*>(layer));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_network_add_matrix_multiply(
+    TrtxNetworkDefinition* network,
+    TrtxTensor* a,
+    int32_t op_a,
+    TrtxTensor* b,
+    int32_t op_b,
+    TrtxLayer** out_layer,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !a || !b || !out_layer) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* network_impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
+        auto* layer = network_impl->addMatrixMultiply(
+            *reinterpret_cast<nvinfer1::ITensor*>(a),
+            static_cast<nvinfer1::MatrixOperation>(op_a),
+            *reinterpret_cast<nvinfer1::ITensor*>(b),
+            static_cast<nvinfer1::MatrixOperation>(op_b));
+        if (!layer) {
+            copy_error("Failed to add matrix multiply layer", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_layer = reinterpret_cast<TrtxLayer*>(static_cast<nvinfer1::ILayer*>(layer));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_network_mark_output(
+    TrtxNetworkDefinition* network,
+    TrtxTensor* tensor,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !tensor) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* network_impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
+        network_impl->markOutput(*reinterpret_cast<nvinfer1::ITensor*>(tensor));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// Tensor functions
+int32_t trtx_tensor_get_name(
+    TrtxTensor* tensor,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!tensor || !out_name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        *out_name = reinterpret_cast<nvinfer1::ITensor*>(tensor)->getName();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_tensor_get_dimensions(
+    TrtxTensor* tensor,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!tensor || !out_dims || !out_nb_dims) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        return copy_dims(reinterpret_cast<nvinfer1::ITensor*>(tensor)->getDimensions(),
+            out_dims, max_dims, out_nb_dims, error_msg, error_msg_len);
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_tensor_get_type(
+    TrtxTensor* tensor,
+    int32_t* out_data_type,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!tensor || !out_data_type) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        *out_data_type = static_cast<int32_t>(
+            reinterpret_cast<nvinfer1::ITensor*>(tensor)->getType());
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// Layer functions
+int32_t trtx_layer_get_name(
+    TrtxLayer* layer,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!layer || !out_name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        *out_name = reinterpret_cast<nvinfer1::ILayer*>(layer)->getName();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_layer_get_nb_outputs(
+    TrtxLayer* layer,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!layer || !out_count) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        *out_count = reinterpret_cast<nvinfer1::ILayer*>(layer)->getNbOutputs();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_layer_get_output(
+    TrtxLayer* layer,
+    int32_t index,
+    TrtxTensor** out_tensor,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!layer || !out_tensor) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* layer_impl = reinterpret_cast<nvinfer1::ILayer*>(layer);
+        if (index < 0 || index >= layer_impl->getNbOutputs()) {
+            copy_error("Invalid output index", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_tensor = reinterpret_cast<TrtxTensor*>(layer_impl->getOutput(index));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// OptimizationProfile functions
+int32_t trtx_optimization_profile_set_dimensions(
+    TrtxOptimizationProfile* profile,
+    const char* input_name,
+    int32_t select,
+    const int64_t* dims,
+    int32_t nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!profile || !input_name || (!dims && nb_dims > 0)
+        || nb_dims < 0 || nb_dims > nvinfer1::Dims::MAX_DIMS) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* profile_impl = reinterpret_cast<nvinfer1::IOptimizationProfile*>(profile);
+        nvinfer1::Dims shape{};
+        shape.nbDims = nb_dims;
+        for (int32_t i = 0; i < nb_dims; ++i) {
+            shape.d[i] = dims[i];
+        }
+        if (!profile_impl->setDimensions(
+                input_name, static_cast<nvinfer1::OptProfileSelector>(select), shape)) {
+            copy_error("Invalid profile dimensions", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_optimization_profile_get_dimensions(
+    TrtxOptimizationProfile* profile,
+    const char* input_name,
+    int32_t select,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!profile || !input_name || !out_dims || !out_nb_dims) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* profile_impl = reinterpret_cast<nvinfer1::IOptimizationProfile*>(profile);
+        nvinfer1::Dims dims = profile_impl->getDimensions(
+            input_name, static_cast<nvinfer1::OptProfileSelector>(select));
+        if (dims.nbDims < 0) {
+            copy_error("No dimensions set for input", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return copy_dims(dims, out_dims, max_dims, out_nb_dims, error_msg, error_msg_len);
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// Runtime functions
+int32_t trtx_runtime_create(
+    TrtxLogger* logger,
+    TrtxRuntime** out_runtime,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!logger || !out_runtime) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* logger_impl = reinterpret_cast<LoggerImpl*>(logger);
+        auto* runtime = nvinfer1::createInferRuntime(*logger_impl);
+        if (!runtime) {
+            copy_error("Failed to create runtime", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        *out_runtime = reinterpret_cast<TrtxRuntime*>(runtime);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+void trtx_runtime_destroy(TrtxRuntime* runtime) {
+    if (runtime) {
+        auto* impl = reinterpret_cast<nvinfer1::IRuntime*>(runtime);
+        delete impl;
+    }
+}
+
+int32_t trtx_runtime_set_max_threads(
+    TrtxRuntime* runtime,
+    int32_t nb_threads,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!runtime) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* runtime_impl = reinterpret_cast<nvinfer1::IRuntime*>(runtime);
+        if (!runtime_impl->setMaxThreads(nb_threads)) {
+            copy_error("Invalid thread count", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_runtime_get_max_threads(
+    TrtxRuntime* runtime,
+    int32_t* out_nb_threads,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!runtime || !out_nb_threads) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        *out_nb_threads = reinterpret_cast<nvinfer1::IRuntime*>(runtime)->getMaxThreads();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_runtime_get_engine_validity(
+    TrtxRuntime* runtime,
+    const void* data,
+    size_t size,
+    int32_t* out_validity,
+    uint64_t* out_diagnostics,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!runtime || !data || !out_validity || !out_diagnostics) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* runtime_impl = reinterpret_cast<nvinfer1::IRuntime*>(runtime);
+        uint64_t diagnostics = 0;
+        auto validity = runtime_impl->getEngineValidity(
+            data, static_cast<int64_t>(size), &diagnostics);
+        *out_validity = static_cast<int32_t>(validity);
+        *out_diagnostics = diagnostics;
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_runtime_load_runtime(
+    TrtxRuntime* runtime,
+    const char* path,
+    TrtxRuntime** out_runtime,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!runtime || !path || !out_runtime) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* runtime_impl = reinterpret_cast<nvinfer1::IRuntime*>(runtime);
+        auto* lean = runtime_impl->loadRuntime(path);
+        if (!lean) {
+            std::string msg = std::string("Failed to load lean runtime: ") + path;
+            copy_error(msg.c_str(), error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        *out_runtime = reinterpret_cast<TrtxRuntime*>(lean);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_runtime_deserialize_cuda_engine(
+    TrtxRuntime* runtime,
+    const void* data,
+    size_t size,
+    TrtxCudaEngine** out_engine,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!runtime || !data || !out_engine) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* runtime_impl = reinterpret_cast<nvinfer1::IRuntime*>(runtime);
+        auto* engine = runtime_impl->deserializeCudaEngine(data, size);
+        if (!engine) {
+            copy_error("Failed to deserialize engine", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        *out_engine = reinterpret_cast<TrtxCudaEngine*>(engine);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_runtime_set_engine_host_code_allowed(
+    TrtxRuntime* runtime,
+    bool allowed,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!runtime) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* runtime_impl = reinterpret_cast<nvinfer1::IRuntime*>(runtime);
+        runtime_impl->setEngineHostCodeAllowed(allowed);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_runtime_get_engine_host_code_allowed(
+    TrtxRuntime* runtime,
+    bool* out_allowed,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!runtime || !out_allowed) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* runtime_impl = reinterpret_cast<nvinfer1::IRuntime*>(runtime);
+        *out_allowed = runtime_impl->getEngineHostCodeAllowed();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// CudaEngine functions
+void trtx_cuda_engine_destroy(TrtxCudaEngine* engine) {
+    if (engine) {
+        auto* impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        delete impl;
+    }
+}
+
+int32_t trtx_cuda_engine_create_execution_context(
+    TrtxCudaEngine* engine,
+    TrtxExecutionContext** out_context,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !out_context) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        auto* context = engine_impl->createExecutionContext();
+        if (!context) {
+            copy_error("Failed to create execution context", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        *out_context = reinterpret_cast<TrtxExecutionContext*>(context);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_get_tensor_name(
+    TrtxCudaEngine* engine,
+    int32_t index,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !out_name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        const char* name = engine_impl->getIOTensorName(index);
+        if (!name) {
+            copy_error("Invalid tensor index", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_name = name;
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_get_nb_io_tensors(
+    TrtxCudaEngine* engine,
+    int32_t* out_count
+) {
+    if (!engine || !out_count) {
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        *out_count = engine_impl->getNbIOTensors();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(nullptr, 0)
+}
+
+int32_t trtx_cuda_engine_get_nb_layers(
+    TrtxCudaEngine* engine,
+    int32_t* out_count
+) {
+    if (!engine || !out_count) {
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        *out_count = engine_impl->getNbLayers();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(nullptr, 0)
+}
+
+int32_t trtx_cuda_engine_get_name(
+    TrtxCudaEngine* engine,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !out_name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        const char* name = reinterpret_cast<nvinfer1::ICudaEngine*>(engine)->getName();
+        *out_name = name ? name : "";
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_get_nb_optimization_profiles(
+    TrtxCudaEngine* engine,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !out_count) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        *out_count = engine_impl->getNbOptimizationProfiles();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_get_tensor_dtype(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    int32_t* out_dtype,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !tensor_name || !out_dtype) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        *out_dtype = static_cast<int32_t>(engine_impl->getTensorDataType(tensor_name));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_get_tensor_shape(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !tensor_name || !out_dims || !out_nb_dims) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        return copy_dims(engine_impl->getTensorShape(tensor_name),
+            out_dims, max_dims, out_nb_dims, error_msg, error_msg_len);
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_get_tensor_io_mode(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    int32_t* out_mode,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !tensor_name || !out_mode) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        *out_mode = static_cast<int32_t>(engine_impl->getTensorIOMode(tensor_name));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_is_shape_inference_io(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    bool* out_is_shape_io,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !tensor_name || !out_is_shape_io) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        *out_is_shape_io = engine_impl->isShapeInferenceIO(tensor_name);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_get_profile_shape(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    int32_t profile_index,
+    int32_t select,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !tensor_name || !out_dims || !out_nb_dims) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        nvinfer1::Dims dims = engine_impl->getProfileShape(
+            tensor_name, profile_index, static_cast<nvinfer1::OptProfileSelector>(select));
+        if (dims.nbDims < 0) {
+            copy_error("Not an input tensor, or invalid profile index", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return copy_dims(dims, out_dims, max_dims, out_nb_dims, error_msg, error_msg_len);
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_get_device_memory_size(
+    TrtxCudaEngine* engine,
+    int64_t* out_size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !out_size) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        *out_size = engine_impl->getDeviceMemorySizeV2();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_get_streamable_weights_size(
+    TrtxCudaEngine* engine,
+    int64_t* out_size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !out_size) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        *out_size = engine_impl->getStreamableWeightsSize();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_is_weight_streaming_enabled(
+    TrtxCudaEngine* engine,
+    bool* out_enabled,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !out_enabled) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        int64_t streamable = engine_impl->getStreamableWeightsSize();
+        *out_enabled = streamable > 0 && engine_impl->getWeightStreamingBudgetV2() < streamable;
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_get_minimum_weight_streaming_budget(
+    TrtxCudaEngine* engine,
+    int64_t* out_budget,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !out_budget) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    // setWeightStreamingBudgetV2 accepts any budget down to 0; the limit from
+    // getMinimumWeightStreamingBudget only applies to the deprecated V1 API
+    *out_budget = 0;
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_engine_set_weight_streaming_budget(
+    TrtxCudaEngine* engine,
+    int64_t budget,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        if (!engine_impl->setWeightStreamingBudgetV2(budget)) {
+            copy_error("Failed to set weight streaming budget", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_get_weight_streaming_budget(
+    TrtxCudaEngine* engine,
+    int64_t* out_budget,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !out_budget) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        *out_budget = engine_impl->getWeightStreamingBudgetV2();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_get_weight_streaming_automatic_budget(
+    TrtxCudaEngine* engine,
+    int64_t* out_budget,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !out_budget) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        *out_budget = engine_impl->getWeightStreamingAutomaticBudget();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_cuda_engine_create_engine_inspector(
+    TrtxCudaEngine* engine,
+    TrtxEngineInspector** out_inspector,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !out_inspector) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        auto* inspector = engine_impl->createEngineInspector();
+        if (!inspector) {
+            copy_error("Failed to create engine inspector", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        *out_inspector = reinterpret_cast<TrtxEngineInspector*>(inspector);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// EngineInspector functions
+int32_t trtx_cuda_engine_is_refittable(
+    TrtxCudaEngine* engine,
+    bool* out_refittable,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !out_refittable) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        *out_refittable = reinterpret_cast<nvinfer1::ICudaEngine*>(engine)->isRefittable();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_refitter_create(
+    TrtxCudaEngine* engine,
+    TrtxLogger* logger,
+    TrtxRefitter** out_refitter,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!engine || !logger || !out_refitter) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* engine_impl = reinterpret_cast<nvinfer1::ICudaEngine*>(engine);
+        auto* logger_impl = reinterpret_cast<LoggerImpl*>(logger);
+        auto* refitter = nvinfer1::createInferRefitter(*engine_impl, *logger_impl);
+        if (!refitter) {
+            copy_error("Failed to create refitter", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        *out_refitter = reinterpret_cast<TrtxRefitter*>(refitter);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+void trtx_refitter_destroy(TrtxRefitter* refitter) {
+    if (refitter) {
+        delete reinterpret_cast<nvinfer1::IRefitter*>(refitter);
+    }
+}
+
+int32_t trtx_refitter_get_all_weights(
+    TrtxRefitter* refitter,
+    const char** out_names,
+    int32_t max_names,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!refitter || !out_count || (!out_names && max_names > 0) || max_names < 0) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* refitter_impl = reinterpret_cast<nvinfer1::IRefitter*>(refitter);
+        int32_t count = refitter_impl->getAllWeights(0, nullptr);
+        if (max_names > 0) {
+            std::vector<const char*> names(count);
+            refitter_impl->getAllWeights(count, names.data());
+            for (int32_t i = 0; i < count && i < max_names; ++i) {
+                out_names[i] = names[i];
+            }
+        }
+        *out_count = count;
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_refitter_set_named_weights(
+    TrtxRefitter* refitter,
+    const char* name,
+    int32_t data_type,
+    const void* values,
+    int64_t count,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!refitter || !name || (!values && count > 0) || count < 0) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* refitter_impl = reinterpret_cast<nvinfer1::IRefitter*>(refitter);
+        nvinfer1::Weights weights{static_cast<nvinfer1::DataType>(data_type), values, count};
+        if (!refitter_impl->setNamedWeights(name, weights)) {
+            copy_error("Failed to set named weights", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_refitter_refit_cuda_engine(
+    TrtxRefitter* refitter,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!refitter) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        if (!reinterpret_cast<nvinfer1::IRefitter*>(refitter)->refitCudaEngine()) {
+            copy_error("Failed to refit engine; check that every required weight is set",
+                error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+void trtx_engine_inspector_destroy(TrtxEngineInspector* inspector) {
+    if (inspector) {
+        delete reinterpret_cast<nvinfer1::IEngineInspector*>(inspector);
+    }
+}
+
+int32_t trtx_engine_inspector_get_engine_information(
+    TrtxEngineInspector* inspector,
+    int32_t format,
+    const char** out_info,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!inspector || !out_info) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* inspector_impl = reinterpret_cast<nvinfer1::IEngineInspector*>(inspector);
+        const char* info = inspector_impl->getEngineInformation(
+            static_cast<nvinfer1::LayerInformationFormat>(format));
+        if (!info) {
+            copy_error("Failed to get engine information", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        *out_info = info;
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_engine_inspector_get_layer_information(
+    TrtxEngineInspector* inspector,
+    int32_t layer_index,
+    int32_t format,
+    const char** out_info,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!inspector || !out_info) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* inspector_impl = reinterpret_cast<nvinfer1::IEngineInspector*>(inspector);
+        const char* info = inspector_impl->getLayerInformation(
+            layer_index, static_cast<nvinfer1::LayerInformationFormat>(format));
+        if (!info) {
+            copy_error("Failed to get layer information", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_info = info;
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// Profiler functions
+int32_t trtx_profiler_create(
+    TrtxProfilerCallback callback,
+    void* user_data,
+    TrtxProfiler** out_profiler,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!callback || !out_profiler) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto profiler = new ProfilerImpl(callback, user_data);
+        *out_profiler = reinterpret_cast<TrtxProfiler*>(profiler);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+void trtx_profiler_destroy(TrtxProfiler* profiler) {
+    if (profiler) {
+        delete reinterpret_cast<ProfilerImpl*>(profiler);
+    }
+}
+
+// DebugListener functions
+int32_t trtx_debug_listener_create(
+    TrtxDebugTensorCallback callback,
+    void* user_data,
+    TrtxDebugListener** out_listener,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!callback || !out_listener) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto listener = new DebugListenerImpl(callback, user_data);
+        *out_listener = reinterpret_cast<TrtxDebugListener*>(listener);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+void trtx_debug_listener_destroy(TrtxDebugListener* listener) {
+    if (listener) {
+        delete reinterpret_cast<DebugListenerImpl*>(listener);
+    }
+}
+
+// OutputAllocator functions
+int32_t trtx_output_allocator_create(
+    TrtxReallocateOutputCallback reallocate,
+    TrtxNotifyShapeCallback notify_shape,
+    void* user_data,
+    TrtxOutputAllocator** out_allocator,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!reallocate || !notify_shape || !out_allocator) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto allocator = new OutputAllocatorImpl(reallocate, notify_shape, user_data);
+        *out_allocator = reinterpret_cast<TrtxOutputAllocator*>(allocator);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+void trtx_output_allocator_destroy(TrtxOutputAllocator* allocator) {
+    if (allocator) {
+        delete reinterpret_cast<OutputAllocatorImpl*>(allocator);
+    }
+}
+
+// ProgressMonitor functions
+int32_t trtx_progress_monitor_create(
+    TrtxPhaseStartCallback phase_start,
+    TrtxStepCompleteCallback step_complete,
+    TrtxPhaseFinishCallback phase_finish,
+    void* user_data,
+    TrtxProgressMonitor** out_monitor,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!phase_start || !step_complete || !phase_finish || !out_monitor) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto monitor = new ProgressMonitorImpl(phase_start, step_complete, phase_finish, user_data);
+        *out_monitor = reinterpret_cast<TrtxProgressMonitor*>(monitor);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+void trtx_progress_monitor_destroy(TrtxProgressMonitor* monitor) {
+    if (monitor) {
+        delete reinterpret_cast<ProgressMonitorImpl*>(monitor);
+    }
+}
+
+int32_t trtx_builder_config_set_progress_monitor(
+    TrtxBuilderConfig* config,
+    TrtxProgressMonitor* monitor,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!config) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        config_impl->setProgressMonitor(reinterpret_cast<ProgressMonitorImpl*>(monitor));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// TimingCache functions
+int32_t trtx_builder_config_create_timing_cache(
+    TrtxBuilderConfig* config,
+    const void* blob,
+    size_t size,
+    TrtxTimingCache** out_cache,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!config || (!blob && size > 0) || !out_cache) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        auto* cache = config_impl->createTimingCache(blob, size);
+        if (!cache) {
+            copy_error("Failed to create timing cache", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_cache = reinterpret_cast<TrtxTimingCache*>(cache);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_builder_config_set_timing_cache(
+    TrtxBuilderConfig* config,
+    TrtxTimingCache* cache,
+    bool ignore_mismatch,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!config || !cache) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* config_impl = reinterpret_cast<nvinfer1::IBuilderConfig*>(config);
+        auto* cache_impl = reinterpret_cast<nvinfer1::ITimingCache*>(cache);
+        if (!config_impl->setTimingCache(*cache_impl, ignore_mismatch)) {
+            copy_error("Timing cache was created for a different device", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+void trtx_timing_cache_destroy(TrtxTimingCache* cache) {
+    if (cache) {
+        delete reinterpret_cast<nvinfer1::ITimingCache*>(cache);
+    }
+}
+
+int32_t trtx_timing_cache_serialize(
+    TrtxTimingCache* cache,
+    void** out_data,
+    size_t* out_size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!cache || !out_data || !out_size) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* cache_impl = reinterpret_cast<nvinfer1::ITimingCache*>(cache);
+        auto* serialized = cache_impl->serialize();
+        if (!serialized) {
+            copy_error("Failed to serialize timing cache", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+
+        size_t size = serialized->size();
+        void* data = malloc(size);
+        if (!data) {
+            delete serialized;
+            copy_error("Failed to allocate memory", error_msg, error_msg_len);
+            return TRTX_ERROR_OUT_OF_MEMORY;
+        }
+
+        memcpy(data, serialized->data(), size);
+        delete serialized;
+
+        *out_data = data;
+        *out_size = size;
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_timing_cache_combine(
+    TrtxTimingCache* cache,
+    TrtxTimingCache* input_cache,
+    bool ignore_mismatch,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!cache || !input_cache) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* cache_impl = reinterpret_cast<nvinfer1::ITimingCache*>(cache);
+        auto* input_impl = reinterpret_cast<nvinfer1::ITimingCache*>(input_cache);
+        if (!cache_impl->combine(*input_impl, ignore_mismatch)) {
+            copy_error("Failed to combine timing caches", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// ExecutionContext functions
+void trtx_execution_context_destroy(TrtxExecutionContext* context) {
+    if (context) {
+        auto* impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        delete impl;
+    }
+}
+
+int32_t trtx_execution_context_set_profiler(
+    TrtxExecutionContext* context,
+    TrtxProfiler* profiler,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        context_impl->setProfiler(reinterpret_cast<ProfilerImpl*>(profiler));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_set_debug_listener(
+    TrtxExecutionContext* context,
+    TrtxDebugListener* listener,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        if (!context_impl->setDebugListener(reinterpret_cast<DebugListenerImpl*>(listener))
+            || !context_impl->setAllTensorsDebugState(listener != nullptr)) {
+            copy_error("Failed to set debug listener", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_set_name(
+    TrtxExecutionContext* context,
+    const char* name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context || !name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        reinterpret_cast<nvinfer1::IExecutionContext*>(context)->setName(name);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_get_name(
+    TrtxExecutionContext* context,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context || !out_name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        const char* name = reinterpret_cast<nvinfer1::IExecutionContext*>(context)->getName();
+        *out_name = name ? name : "";
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_set_tensor_address(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    void* data,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context || !tensor_name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        bool success = context_impl->setTensorAddress(tensor_name, data);
+        if (!success) {
+            copy_error("Failed to set tensor address", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_get_tensor_address(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    const void** out_data,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context || !tensor_name || !out_data) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        *out_data = context_impl->getTensorAddress(tensor_name);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_set_input_shape(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    const int64_t* dims,
+    int32_t nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context || !tensor_name || (!dims && nb_dims > 0)
+        || nb_dims < 0 || nb_dims > nvinfer1::Dims::MAX_DIMS) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        nvinfer1::Dims shape{};
+        shape.nbDims = nb_dims;
+        for (int32_t i = 0; i < nb_dims; ++i) {
+            shape.d[i] = dims[i];
+        }
+        if (!context_impl->setInputShape(tensor_name, shape)) {
+            copy_error("Failed to set input shape", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_get_optimization_profile(
+    TrtxExecutionContext* context,
+    int32_t* out_profile_index,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context || !out_profile_index) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        *out_profile_index = context_impl->getOptimizationProfile();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_set_optimization_profile(
+    TrtxExecutionContext* context,
+    int32_t profile_index,
+    void* cuda_stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        auto stream = static_cast<cudaStream_t>(cuda_stream);
+        if (!context_impl->setOptimizationProfileAsync(profile_index, stream)) {
+            copy_error("Failed to set optimization profile", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        cudaError_t err = cudaStreamSynchronize(stream);
+        if (err != cudaSuccess) {
+            copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+            return TRTX_ERROR_CUDA_ERROR;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_set_nvtx_verbosity(
+    TrtxExecutionContext* context,
+    int32_t verbosity,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        if (!context_impl->setNvtxVerbosity(static_cast<nvinfer1::ProfilingVerbosity>(verbosity))) {
+            copy_error("Failed to set NVTX verbosity", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_get_nvtx_verbosity(
+    TrtxExecutionContext* context,
+    int32_t* out_verbosity,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context || !out_verbosity) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        *out_verbosity = static_cast<int32_t>(context_impl->getNvtxVerbosity());
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_set_aux_streams(
+    TrtxExecutionContext* context,
+    void** streams,
+    int32_t nb_streams,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context || (!streams && nb_streams > 0) || nb_streams < 0) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        context_impl->setAuxStreams(reinterpret_cast<cudaStream_t*>(streams), nb_streams);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_get_tensor_shape(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context || !tensor_name || !out_dims || !out_nb_dims) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        return copy_dims(context_impl->getTensorShape(tensor_name),
+            out_dims, max_dims, out_nb_dims, error_msg, error_msg_len);
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_get_tensor_strides(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    int64_t* out_strides,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context || !tensor_name || !out_strides || !out_nb_dims) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        return copy_dims(context_impl->getTensorStrides(tensor_name),
+            out_strides, max_dims, out_nb_dims, error_msg, error_msg_len);
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_get_max_output_size(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    int64_t* out_size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context || !tensor_name || !out_size) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        int64_t size = context_impl->getMaxOutputSize(tensor_name);
+        if (size < 0) {
+            copy_error("Not an output, or input shapes are not set", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        *out_size = size;
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_set_output_allocator(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    TrtxOutputAllocator* allocator,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context || !tensor_name) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        bool success = context_impl->setOutputAllocator(
+            tensor_name, reinterpret_cast<OutputAllocatorImpl*>(allocator));
+        if (!success) {
+            copy_error("Failed to set output allocator", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_execution_context_enqueue_v3(
+    TrtxExecutionContext* context,
+    void* cuda_stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!context) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* context_impl = reinterpret_cast<nvinfer1::IExecutionContext*>(context);
+        bool success = context_impl->enqueueV3(static_cast<cudaStream_t>(cuda_stream));
+        if (!success) {
+            copy_error("Failed to enqueue inference", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// Utility functions
+void trtx_free_buffer(void* buffer) {
+    free(buffer);
+}
+
+// Plugin registry functions
+int32_t trtx_load_plugin_library(
+    const char* path,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!path) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* registry = getPluginRegistry();
+        if (!registry) {
+            copy_error("Plugin registry is unavailable", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        if (!registry->loadLibrary(path)) {
+            std::string msg = std::string("Failed to load plugin library: ") + path;
+            copy_error(msg.c_str(), error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// Plugin creator functions
+int32_t trtx_plugin_creator_create(
+    const char* name,
+    const char* version,
+    TrtxPluginCreateCallback create,
+    TrtxPluginCloneCallback clone,
+    TrtxPluginEnqueueCallback enqueue,
+    TrtxPluginDestroyCallback destroy,
+    void* user_data,
+    TrtxPluginCreator** out_creator,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!name || !version || !create || !clone || !enqueue || !destroy || !out_creator) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto callbacks = std::make_shared<PluginCallbacks>(
+            PluginCallbacks{name, version, create, clone, enqueue, destroy, user_data});
+        auto creator = new RustPluginCreator(std::move(callbacks));
+        *out_creator = reinterpret_cast<TrtxPluginCreator*>(creator);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+void trtx_plugin_creator_destroy(TrtxPluginCreator* creator) {
+    if (creator) {
+        delete reinterpret_cast<RustPluginCreator*>(creator);
+    }
+}
+
+int32_t trtx_runtime_register_plugin_creator(
+    TrtxRuntime* runtime,
+    TrtxPluginCreator* creator,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!runtime || !creator) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto& registry = reinterpret_cast<nvinfer1::IRuntime*>(runtime)->getPluginRegistry();
+        auto* creator_impl = reinterpret_cast<RustPluginCreator*>(creator);
+        if (!registry.registerCreator(*creator_impl, "")) {
+            std::string msg = std::string("Failed to register plugin creator ")
+                + creator_impl->getPluginName() + " version " + creator_impl->getPluginVersion();
+            copy_error(msg.c_str(), error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// ONNX Parser functions
+int32_t trtx_onnx_parser_create(
+    TrtxNetworkDefinition* network,
+    TrtxLogger* logger,
+    TrtxOnnxParser** out_parser,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!network || !logger || !out_parser) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* network_impl = reinterpret_cast<nvinfer1::INetworkDefinition*>(network);
+        auto* logger_impl = reinterpret_cast<LoggerImpl*>(logger);
+
+        auto* parser = nvonnxparser::createParser(*network_impl, *logger_impl);
+        if (!parser) {
+            copy_error("Failed to create ONNX parser", error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+
+        *out_parser = reinterpret_cast<TrtxOnnxParser*>(parser);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+void trtx_onnx_parser_destroy(TrtxOnnxParser* parser) {
+    if (parser) {
+        auto* impl = reinterpret_cast<nvonnxparser::IParser*>(parser);
+        delete impl;
+    }
+}
+
+int32_t trtx_onnx_parser_parse(
+    TrtxOnnxParser* parser,
+    const void* model_data,
+    size_t model_size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!parser || !model_data) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* parser_impl = reinterpret_cast<nvonnxparser::IParser*>(parser);
+
+        bool success = parser_impl->parse(model_data, model_size);
+        if (!success) {
+            // Report every parser error, with the node it came from
+            std::string msg;
+            for (int32_t i = 0; i < parser_impl->getNbErrors(); ++i) {
+                auto* error = parser_impl->getError(i);
+                if (!msg.empty()) {
+                    msg += "; ";
+                }
+                msg += error->desc();
+                const char* node_name = error->nodeName();
+                const char* node_op = error->nodeOperator();
+                if (node_name && *node_name) {
+                    msg += std::string(" (node ") + node_name;
+                    if (node_op && *node_op) {
+                        msg += std::string(", ") + node_op;
+                    }
+                    msg += ")";
+                }
+            }
+            copy_error(msg.empty() ? "Failed to parse ONNX model" : msg.c_str(), error_msg, error_msg_len);
+            return TRTX_ERROR_RUNTIME_ERROR;
+        }
+
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_onnx_parser_supports_model(
+    TrtxOnnxParser* parser,
+    const void* model_data,
+    size_t model_size,
+    bool* out_supported,
+    int64_t* out_nb_subgraphs,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!parser || !model_data || !out_supported || !out_nb_subgraphs) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* parser_impl = reinterpret_cast<nvonnxparser::IParser*>(parser);
+        *out_supported = parser_impl->supportsModelV2(model_data, model_size);
+        *out_nb_subgraphs = parser_impl->getNbSubgraphs();
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_onnx_parser_get_subgraph(
+    TrtxOnnxParser* parser,
+    int64_t index,
+    bool* out_supported,
+    const int64_t** out_nodes,
+    int64_t* out_nb_nodes,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!parser || !out_supported || !out_nodes || !out_nb_nodes) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* parser_impl = reinterpret_cast<nvonnxparser::IParser*>(parser);
+        if (index < 0 || index >= parser_impl->getNbSubgraphs()) {
+            copy_error("Subgraph index out of range", error_msg, error_msg_len);
+            return TRTX_ERROR_INVALID_ARGUMENT;
+        }
+        int64_t length = 0;
+        *out_nodes = parser_impl->getSubgraphNodes(index, length);
+        *out_nb_nodes = length;
+        *out_supported = parser_impl->isSubgraphSupported(index);
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_onnx_parser_set_flag(
+    TrtxOnnxParser* parser,
+    int32_t flag,
+    bool enabled,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!parser) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* parser_impl = reinterpret_cast<nvonnxparser::IParser*>(parser);
+        auto parser_flag = static_cast<nvonnxparser::OnnxParserFlag>(flag);
+        if (enabled) {
+            parser_impl->setFlag(parser_flag);
+        } else {
+            parser_impl->clearFlag(parser_flag);
+        }
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+int32_t trtx_onnx_parser_get_flag(
+    TrtxOnnxParser* parser,
+    int32_t flag,
+    bool* out_enabled,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!parser || !out_enabled) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    TRTX_TRY_CATCH_BEGIN
+        auto* parser_impl = reinterpret_cast<nvonnxparser::IParser*>(parser);
+        *out_enabled = parser_impl->getFlag(static_cast<nvonnxparser::OnnxParserFlag>(flag));
+        return TRTX_SUCCESS;
+    TRTX_TRY_CATCH_END(error_msg, error_msg_len)
+}
+
+// CUDA Memory Management functions
+#include <cuda_runtime.h>
+
+int32_t trtx_cuda_malloc(
+    void** ptr,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!ptr) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaError_t err = cudaMalloc(ptr, size);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_free(
+    void* ptr,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!ptr) {
+        return TRTX_SUCCESS; // Freeing null is not an error
+    }
+
+    cudaError_t err = cudaFree(ptr);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_memcpy_host_to_device(
+    void* dst,
+    const void* src,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!dst || !src) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaError_t err = cudaMemcpy(dst, src, size, cudaMemcpyHostToDevice);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_memcpy_device_to_host(
+    void* dst,
+    const void* src,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!dst || !src) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaError_t err = cudaMemcpy(dst, src, size, cudaMemcpyDeviceToHost);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_memcpy_device_to_device(
+    void* dst,
+    const void* src,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!dst || !src) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaError_t err = cudaMemcpy(dst, src, size, cudaMemcpyDeviceToDevice);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_memset(
+    void* ptr,
+    int32_t value,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!ptr) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaError_t err = cudaMemset(ptr, value, size);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_synchronize(
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaDeviceSynchronize();
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_mem_get_info(
+    size_t* free_bytes,
+    size_t* total_bytes,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!free_bytes || !total_bytes) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaError_t err = cudaMemGetInfo(free_bytes, total_bytes);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_set_device(
+    int32_t device,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaSetDevice(device);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_ensure_context(
+    int32_t* out_cuda_error,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!out_cuda_error) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    // Freeing null is a no-op that still initializes the context
+    cudaError_t err = cudaFree(nullptr);
+    *out_cuda_error = static_cast<int32_t>(err);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_get_device(
+    int32_t* device,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!device) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    int current = 0;
+    cudaError_t err = cudaGetDevice(&current);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    *device = current;
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_get_last_error(
+    bool peek,
+    int32_t* out_code,
+    const char** out_string,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!out_code || !out_string) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaError_t err = peek ? cudaPeekAtLastError() : cudaGetLastError();
+    *out_code = static_cast<int32_t>(err);
+    *out_string = cudaGetErrorString(err);
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_get_error_string(
+    int32_t code,
+    const char** out_string,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!out_string) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    *out_string = cudaGetErrorString(static_cast<cudaError_t>(code));
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_stream_create(
+    void** stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!stream) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaStream_t cuda_stream = nullptr;
+    cudaError_t err = cudaStreamCreateWithFlags(&cuda_stream, cudaStreamNonBlocking);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    *stream = cuda_stream;
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_stream_create_blocking(
+    void** stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!stream) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaStream_t cuda_stream = nullptr;
+    cudaError_t err = cudaStreamCreateWithFlags(&cuda_stream, cudaStreamDefault);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    *stream = cuda_stream;
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_stream_create_with_priority(
+    void** stream,
+    int32_t priority,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!stream) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaStream_t cuda_stream = nullptr;
+    cudaError_t err = cudaStreamCreateWithPriority(&cuda_stream, cudaStreamNonBlocking, priority);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    *stream = cuda_stream;
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_stream_get_priority(
+    void* stream,
+    int32_t* out_priority,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!out_priority) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    int priority = 0;
+    cudaError_t err = cudaStreamGetPriority(static_cast<cudaStream_t>(stream), &priority);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    *out_priority = priority;
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_stream_priority_range(
+    int32_t* out_least,
+    int32_t* out_greatest,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!out_least || !out_greatest) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    int least = 0;
+    int greatest = 0;
+    cudaError_t err = cudaDeviceGetStreamPriorityRange(&least, &greatest);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    *out_least = least;
+    *out_greatest = greatest;
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_stream_destroy(
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaStreamDestroy(static_cast<cudaStream_t>(stream));
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_stream_synchronize(
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaStreamSynchronize(static_cast<cudaStream_t>(stream));
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_stream_launch_host_fn(
+    void* stream,
+    TrtxHostFnCallback callback,
+    void* user_data,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!callback) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaError_t err = cudaLaunchHostFunc(
+        static_cast<cudaStream_t>(stream), callback, user_data);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_stream_begin_capture(
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaStreamBeginCapture(
+        static_cast<cudaStream_t>(stream), cudaStreamCaptureModeThreadLocal);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_stream_end_capture(
+    void* stream,
+    void** graph_exec,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!graph_exec) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaGraph_t graph = nullptr;
+    cudaError_t err = cudaStreamEndCapture(static_cast<cudaStream_t>(stream), &graph);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    cudaGraphExec_t exec = nullptr;
+    err = cudaGraphInstantiate(&exec, graph, 0);
+    cudaGraphDestroy(graph);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    *graph_exec = exec;
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_graph_launch(
+    void* graph_exec,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaGraphLaunch(
+        static_cast<cudaGraphExec_t>(graph_exec), static_cast<cudaStream_t>(stream));
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_graph_exec_destroy(
+    void* graph_exec,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaGraphExecDestroy(static_cast<cudaGraphExec_t>(graph_exec));
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_event_create(
+    void** event,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!event) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaEvent_t cuda_event = nullptr;
+    cudaError_t err = cudaEventCreateWithFlags(&cuda_event, cudaEventDisableTiming);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    *event = cuda_event;
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_event_destroy(
+    void* event,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaEventDestroy(static_cast<cudaEvent_t>(event));
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_event_record(
+    void* event,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaEventRecord(
+        static_cast<cudaEvent_t>(event), static_cast<cudaStream_t>(stream));
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_event_query(
+    void* event,
+    bool* out_complete,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!out_complete) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaError_t err = cudaEventQuery(static_cast<cudaEvent_t>(event));
+    if (err == cudaErrorNotReady) {
+        *out_complete = false;
+        return TRTX_SUCCESS;
+    }
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    *out_complete = true;
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_event_synchronize(
+    void* event,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaEventSynchronize(static_cast<cudaEvent_t>(event));
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_malloc_managed(
+    void** ptr,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!ptr) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaError_t err = cudaMallocManaged(ptr, size, cudaMemAttachGlobal);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return err == cudaErrorMemoryAllocation ? TRTX_ERROR_OUT_OF_MEMORY : TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_mem_prefetch_async(
+    const void* ptr,
+    size_t size,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!ptr) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    int device = 0;
+    cudaError_t err = cudaGetDevice(&device);
+    if (err == cudaSuccess) {
+#if CUDART_VERSION >= 13000
+        cudaMemLocation location = {};
+        location.type = cudaMemLocationTypeDevice;
+        location.id = device;
+        err = cudaMemPrefetchAsync(ptr, size, location, 0, static_cast<cudaStream_t>(stream));
+#else
+        err = cudaMemPrefetchAsync(ptr, size, device, static_cast<cudaStream_t>(stream));
+#endif
+    }
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_malloc_async(
+    void** ptr,
+    size_t size,
+    void* pool,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!ptr) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    auto cuda_stream = static_cast<cudaStream_t>(stream);
+    cudaError_t err = pool
+        ? cudaMallocFromPoolAsync(ptr, size, static_cast<cudaMemPool_t>(pool), cuda_stream)
+        : cudaMallocAsync(ptr, size, cuda_stream);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_free_async(
+    void* ptr,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (ptr) {
+        cudaError_t err = cudaFreeAsync(ptr, static_cast<cudaStream_t>(stream));
+        if (err != cudaSuccess) {
+            copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+            return TRTX_ERROR_CUDA_ERROR;
+        }
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_mem_pool_create(
+    void** pool,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!pool) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    int device = 0;
+    cudaError_t err = cudaGetDevice(&device);
+    if (err == cudaSuccess) {
+        cudaMemPoolProps props = {};
+        props.allocType = cudaMemAllocationTypePinned;
+        props.location.type = cudaMemLocationTypeDevice;
+        props.location.id = device;
+        cudaMemPool_t cuda_pool = nullptr;
+        err = cudaMemPoolCreate(&cuda_pool, &props);
+        *pool = cuda_pool;
+    }
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_mem_pool_destroy(
+    void* pool,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaMemPoolDestroy(static_cast<cudaMemPool_t>(pool));
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_mem_pool_set_release_threshold(
+    void* pool,
+    uint64_t threshold,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaMemPoolSetAttribute(
+        static_cast<cudaMemPool_t>(pool), cudaMemPoolAttrReleaseThreshold, &threshold);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_mem_pool_get_release_threshold(
+    void* pool,
+    uint64_t* out_threshold,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!out_threshold) {
+        copy_error("Invalid arguments", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaError_t err = cudaMemPoolGetAttribute(
+        static_cast<cudaMemPool_t>(pool), cudaMemPoolAttrReleaseThreshold, out_threshold);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_device_get_limit(
+    int32_t limit,
+    size_t* value,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!value) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    cudaError_t err = cudaDeviceGetLimit(value, static_cast<cudaLimit>(limit));
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_device_set_limit(
+    int32_t limit,
+    size_t value,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    cudaError_t err = cudaDeviceSetLimit(static_cast<cudaLimit>(limit), value);
+    if (err != cudaSuccess) {
+        copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
+        return TRTX_ERROR_CUDA_ERROR;
+    }
+
+    return TRTX_SUCCESS;
+}
+
+int32_t trtx_cuda_device_get_attribute(
+    int32_t attribute,
+    int32_t* value,
+    char* error_msg,
+    size_t error_msg_len
+) {
+    if (!value) {
+        copy_error("Invalid argument", error_msg, error_msg_len);
+        return TRTX_ERROR_INVALID_ARGUMENT;
+    }
+
+    int device = 0;
+    cudaError_t err = cudaGetDevice(&device);
+    if (err == cudaSuccess) {
+        err = cudaDeviceGetAttribute(value, static_cast<cudaDeviceAttr>(attribute), device);
+    }
     if (err != cudaSuccess) {
         copy_error(cudaGetErrorString(err), error_msg, error_msg_len);
         return TRTX_ERROR_CUDA_ERROR;
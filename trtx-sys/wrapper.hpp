@@ -16,6 +16,9 @@ extern "C" {
 #define TRTX_ERROR_CUDA_ERROR 4
 #define TRTX_ERROR_UNKNOWN 99
 
+// Maximum tensor rank (matching nvinfer1::Dims::MAX_DIMS)
+#define TRTX_MAX_DIMS 8
+
 // Logger severity levels (matching nvinfer1::ILogger::Severity)
 typedef enum {
     TRTX_SEVERITY_INTERNAL_ERROR = 0,
@@ -33,10 +36,70 @@ typedef struct TrtxNetworkDefinition TrtxNetworkDefinition;
 typedef struct TrtxRuntime TrtxRuntime;
 typedef struct TrtxCudaEngine TrtxCudaEngine;
 typedef struct TrtxExecutionContext TrtxExecutionContext;
+typedef struct TrtxEngineInspector TrtxEngineInspector;
+typedef struct TrtxErrorRecorder TrtxErrorRecorder;
+typedef struct TrtxProfiler TrtxProfiler;
+typedef struct TrtxDebugListener TrtxDebugListener;
+typedef struct TrtxProgressMonitor TrtxProgressMonitor;
+typedef struct TrtxOutputAllocator TrtxOutputAllocator;
+typedef struct TrtxTimingCache TrtxTimingCache;
+typedef struct TrtxOptimizationProfile TrtxOptimizationProfile;
+typedef struct TrtxPluginCreator TrtxPluginCreator;
+typedef struct TrtxRefitter TrtxRefitter;
+// Tensors and layers are owned by their network
+typedef struct TrtxTensor TrtxTensor;
+typedef struct TrtxLayer TrtxLayer;
 
 // Logger callback type
 typedef void (*TrtxLoggerCallback)(void* user_data, TrtxLoggerSeverity severity, const char* msg);
 
+// Profiler callback type, called once per layer with its execution time
+typedef void (*TrtxProfilerCallback)(void* user_data, const char* layer_name, float ms);
+
+// Debug listener callback type, called with each debug tensor once its data is
+// ready; addr is device memory and data_type an nvinfer1::DataType value
+typedef void (*TrtxDebugTensorCallback)(
+    void* user_data, const char* name, const void* addr, int32_t data_type,
+    const int64_t* dims, int32_t nb_dims);
+
+// Output allocator callbacks, mirroring nvinfer1::IOutputAllocator. reallocate
+// returns device memory of at least size bytes, or NULL on failure;
+// notify_shape reports the output's shape once execution has resolved it.
+typedef void* (*TrtxReallocateOutputCallback)(
+    void* user_data, const char* tensor_name, void* current_memory, uint64_t size, uint64_t alignment);
+typedef void (*TrtxNotifyShapeCallback)(
+    void* user_data, const char* tensor_name, const int64_t* dims, int32_t nb_dims);
+
+// Progress monitor callbacks, mirroring nvinfer1::IProgressMonitor. parent_phase
+// is NULL for top-level phases; returning false from step_complete cancels the build.
+typedef void (*TrtxPhaseStartCallback)(
+    void* user_data, const char* phase_name, const char* parent_phase, int32_t nb_steps);
+typedef bool (*TrtxStepCompleteCallback)(void* user_data, const char* phase_name, int32_t step);
+typedef void (*TrtxPhaseFinishCallback)(void* user_data, const char* phase_name);
+
+// Host function run by CUDA once the work queued on a stream before it is done
+typedef void (*TrtxHostFnCallback)(void* user_data);
+
+// Plugin field passed to a plugin creator; size is in bytes
+typedef struct {
+    const char* name;
+    const void* data;
+    size_t size;
+} TrtxPluginField;
+
+// Plugin callbacks for single-input, single-output elementwise plugins.
+// create returns a new instance, or NULL on failure; clone returns a copy of
+// an instance for another engine or context. enqueue runs on one input of
+// nb_dims dims, writing an output of the same shape and nvinfer1::DataType
+// data_type, and returns false on failure.
+typedef void* (*TrtxPluginCreateCallback)(
+    void* user_data, const char* name, const TrtxPluginField* fields, int32_t nb_fields);
+typedef void* (*TrtxPluginCloneCallback)(void* instance);
+typedef bool (*TrtxPluginEnqueueCallback)(
+    void* instance, const void* input, void* output, int32_t data_type,
+    const int64_t* dims, int32_t nb_dims, void* stream);
+typedef void (*TrtxPluginDestroyCallback)(void* instance);
+
 // Logger functions
 int32_t trtx_logger_create(
     TrtxLoggerCallback callback,
@@ -58,6 +121,28 @@ int32_t trtx_builder_create(
 
 void trtx_builder_destroy(TrtxBuilder* builder);
 
+// Returns TRTX_ERROR_INVALID_ARGUMENT if TensorRT rejects the thread count
+int32_t trtx_builder_set_max_threads(
+    TrtxBuilder* builder,
+    int32_t nb_threads,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_builder_get_max_threads(
+    TrtxBuilder* builder,
+    int32_t* out_nb_threads,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Networks and configs created before the reset must not be used afterwards
+int32_t trtx_builder_reset(
+    TrtxBuilder* builder,
+    char* error_msg,
+    size_t error_msg_len
+);
+
 int32_t trtx_builder_create_network(
     TrtxBuilder* builder,
     uint32_t flags,
@@ -66,6 +151,14 @@ int32_t trtx_builder_create_network(
     size_t error_msg_len
 );
 
+// The profile is owned by the builder and freed with it
+int32_t trtx_builder_create_optimization_profile(
+    TrtxBuilder* builder,
+    TrtxOptimizationProfile** out_profile,
+    char* error_msg,
+    size_t error_msg_len
+);
+
 int32_t trtx_builder_create_builder_config(
     TrtxBuilder* builder,
     TrtxBuilderConfig** out_config,
@@ -83,6 +176,37 @@ int32_t trtx_builder_build_serialized_network(
     size_t error_msg_len
 );
 
+int32_t trtx_builder_set_error_recorder(
+    TrtxBuilder* builder,
+    TrtxErrorRecorder* recorder,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// ErrorRecorder functions (reference counted; destroy releases the caller's reference)
+int32_t trtx_error_recorder_create(
+    TrtxErrorRecorder** out_recorder,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+void trtx_error_recorder_destroy(TrtxErrorRecorder* recorder);
+
+int32_t trtx_error_recorder_get_nb_errors(TrtxErrorRecorder* recorder, int32_t* out_count);
+
+// code is an nvinfer1::ErrorCode value; desc is copied into desc_buf (truncated if needed)
+int32_t trtx_error_recorder_get_error(
+    TrtxErrorRecorder* recorder,
+    int32_t index,
+    int32_t* out_code,
+    char* desc_buf,
+    size_t desc_buf_len,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+void trtx_error_recorder_clear(TrtxErrorRecorder* recorder);
+
 // BuilderConfig functions
 void trtx_builder_config_destroy(TrtxBuilderConfig* config);
 
@@ -94,124 +218,1299 @@ int32_t trtx_builder_config_set_memory_pool_limit(
     size_t error_msg_len
 );
 
-// NetworkDefinition functions
-void trtx_network_destroy(TrtxNetworkDefinition* network);
+int32_t trtx_builder_config_set_flag(
+    TrtxBuilderConfig* config,
+    int32_t flag,
+    bool enabled,
+    char* error_msg,
+    size_t error_msg_len
+);
 
-// Runtime functions
-int32_t trtx_runtime_create(
-    TrtxLogger* logger,
-    TrtxRuntime** out_runtime,
+int32_t trtx_builder_config_get_memory_pool_limit(
+    TrtxBuilderConfig* config,
+    int32_t pool_type,
+    size_t* out_pool_size,
     char* error_msg,
     size_t error_msg_len
 );
 
-void trtx_runtime_destroy(TrtxRuntime* runtime);
+int32_t trtx_builder_config_get_flag(
+    TrtxBuilderConfig* config,
+    int32_t flag,
+    bool* out_enabled,
+    char* error_msg,
+    size_t error_msg_len
+);
 
-int32_t trtx_runtime_deserialize_cuda_engine(
-    TrtxRuntime* runtime,
-    const void* data,
-    size_t size,
-    TrtxCudaEngine** out_engine,
+// feature is an nvinfer1::PreviewFeature value. Features unknown to the
+// linked library are ignored by the setter and reported disabled by the getter.
+int32_t trtx_builder_config_set_preview_feature(
+    TrtxBuilderConfig* config,
+    int32_t feature,
+    bool enabled,
     char* error_msg,
     size_t error_msg_len
 );
 
-// CudaEngine functions
-void trtx_cuda_engine_destroy(TrtxCudaEngine* engine);
+int32_t trtx_builder_config_get_preview_feature(
+    TrtxBuilderConfig* config,
+    int32_t feature,
+    bool* out_enabled,
+    char* error_msg,
+    size_t error_msg_len
+);
 
-int32_t trtx_cuda_engine_create_execution_context(
-    TrtxCudaEngine* engine,
-    TrtxExecutionContext** out_context,
+// -1 lets TensorRT choose how many auxiliary streams to use
+int32_t trtx_builder_config_set_max_aux_streams(
+    TrtxBuilderConfig* config,
+    int32_t nb_streams,
     char* error_msg,
     size_t error_msg_len
 );
 
-int32_t trtx_cuda_engine_get_tensor_name(
-    TrtxCudaEngine* engine,
+int32_t trtx_builder_config_get_max_aux_streams(
+    TrtxBuilderConfig* config,
+    int32_t* out_nb_streams,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Fails if the profile is missing dimensions or its min/opt/max are inconsistent
+int32_t trtx_builder_config_add_optimization_profile(
+    TrtxBuilderConfig* config,
+    TrtxOptimizationProfile* profile,
+    int32_t* out_index,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_builder_config_get_nb_optimization_profiles(
+    TrtxBuilderConfig* config,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_builder_config_set_runtime_platform(
+    TrtxBuilderConfig* config,
+    int32_t platform,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_builder_config_get_runtime_platform(
+    TrtxBuilderConfig* config,
+    int32_t* out_platform,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// capability is an nvinfer1::EngineCapability value
+int32_t trtx_builder_config_set_engine_capability(
+    TrtxBuilderConfig* config,
+    int32_t capability,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_builder_config_get_engine_capability(
+    TrtxBuilderConfig* config,
+    int32_t* out_capability,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_builder_config_set_builder_optimization_level(
+    TrtxBuilderConfig* config,
+    int32_t level,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_builder_config_get_builder_optimization_level(
+    TrtxBuilderConfig* config,
+    int32_t* out_level,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// verbosity is an nvinfer1::ProfilingVerbosity value
+int32_t trtx_builder_config_set_profiling_verbosity(
+    TrtxBuilderConfig* config,
+    int32_t verbosity,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// NetworkDefinition functions
+void trtx_network_destroy(TrtxNetworkDefinition* network);
+
+int32_t trtx_network_get_nb_inputs(
+    TrtxNetworkDefinition* network,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// The name is owned by the network
+int32_t trtx_network_get_input_name(
+    TrtxNetworkDefinition* network,
     int32_t index,
     const char** out_name,
     char* error_msg,
     size_t error_msg_len
 );
 
-int32_t trtx_cuda_engine_get_nb_io_tensors(
-    TrtxCudaEngine* engine,
-    int32_t* out_count
+// Dynamic dimensions are reported as -1
+int32_t trtx_network_get_input_shape(
+    TrtxNetworkDefinition* network,
+    int32_t index,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
 );
 
-// ExecutionContext functions
-void trtx_execution_context_destroy(TrtxExecutionContext* context);
+// Marks the tensor named name (a network input or layer output) as a debug
+// tensor, reported to the execution context's debug listener
+int32_t trtx_network_mark_debug(
+    TrtxNetworkDefinition* network,
+    const char* name,
+    char* error_msg,
+    size_t error_msg_len
+);
 
-int32_t trtx_execution_context_set_tensor_address(
-    TrtxExecutionContext* context,
+// Sets the type of the layer output named tensor_name (data_type is an
+// nvinfer1::DataType value); fails for network inputs
+int32_t trtx_network_set_output_type(
+    TrtxNetworkDefinition* network,
     const char* tensor_name,
-    void* data,
+    int32_t data_type,
     char* error_msg,
     size_t error_msg_len
 );
 
-int32_t trtx_execution_context_enqueue_v3(
-    TrtxExecutionContext* context,
-    void* cuda_stream,
+int32_t trtx_network_get_output_type(
+    TrtxNetworkDefinition* network,
+    const char* tensor_name,
+    int32_t* out_data_type,
     char* error_msg,
     size_t error_msg_len
 );
 
-// Utility functions
-void trtx_free_buffer(void* buffer);
+// Sets the compute precision of the layer named layer_name
+int32_t trtx_network_set_layer_precision(
+    TrtxNetworkDefinition* network,
+    const char* layer_name,
+    int32_t data_type,
+    char* error_msg,
+    size_t error_msg_len
+);
 
-// ONNX Parser functions
-typedef struct TrtxOnnxParser TrtxOnnxParser;
+int32_t trtx_network_get_layer_precision(
+    TrtxNetworkDefinition* network,
+    const char* layer_name,
+    int32_t* out_data_type,
+    char* error_msg,
+    size_t error_msg_len
+);
 
-int32_t trtx_onnx_parser_create(
+// TensorRT copies the name; built engines report it from getName
+int32_t trtx_network_set_name(
     TrtxNetworkDefinition* network,
-    TrtxLogger* logger,
-    TrtxOnnxParser** out_parser,
+    const char* name,
     char* error_msg,
     size_t error_msg_len
 );
 
-void trtx_onnx_parser_destroy(TrtxOnnxParser* parser);
+int32_t trtx_network_get_name(
+    TrtxNetworkDefinition* network,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+);
 
-int32_t trtx_onnx_parser_parse(
-    TrtxOnnxParser* parser,
-    const void* model_data,
-    size_t model_size,
+// Bitmask of the nvinfer1::NetworkDefinitionCreationFlag values the network
+// was created with
+int32_t trtx_network_get_flags(
+    TrtxNetworkDefinition* network,
+    uint32_t* out_flags,
     char* error_msg,
     size_t error_msg_len
 );
 
-// CUDA Memory Management functions
-int32_t trtx_cuda_malloc(
-    void** ptr,
-    size_t size,
+// Adds a network input; data_type is an nvinfer1::DataType value
+int32_t trtx_network_add_input(
+    TrtxNetworkDefinition* network,
+    const char* name,
+    int32_t data_type,
+    const int64_t* dims,
+    int32_t nb_dims,
+    TrtxTensor** out_tensor,
     char* error_msg,
     size_t error_msg_len
 );
 
-int32_t trtx_cuda_free(
-    void* ptr,
+// Adds a constant layer holding count elements of data_type at values. The
+// values are not copied and must stay valid until the network is built.
+int32_t trtx_network_add_constant(
+    TrtxNetworkDefinition* network,
+    const int64_t* dims,
+    int32_t nb_dims,
+    int32_t data_type,
+    const void* values,
+    int64_t count,
+    TrtxLayer** out_layer,
     char* error_msg,
     size_t error_msg_len
 );
 
-int32_t trtx_cuda_memcpy_host_to_device(
-    void* dst,
-    const void* src,
-    size_t size,
+// Adds an activation layer; activation is an nvinfer1::ActivationType value
+int32_t trtx_network_add_activation(
+    TrtxNetworkDefinition* network,
+    TrtxTensor* input,
+    int32_t activation,
+    TrtxLayer** out_layer,
     char* error_msg,
     size_t error_msg_len
 );
 
-int32_t trtx_cuda_memcpy_device_to_host(
-    void* dst,
-    const void* src,
-    size_t size,
+// Adds an elementwise layer; op is an nvinfer1::ElementWiseOperation value
+int32_t trtx_network_add_elementwise(
+    TrtxNetworkDefinition* network,
+    TrtxTensor* a,
+    TrtxTensor* b,
+    int32_t op,
+    TrtxLayer** out_layer,
     char* error_msg,
     size_t error_msg_len
 );
 
-int32_t trtx_cuda_synchronize(
+// Adds a matrix multiply layer; op_a and op_b are nvinfer1::MatrixOperation values
+int32_t trtx_network_add_matrix_multiply(
+    TrtxNetworkDefinition* network,
+    TrtxTensor* a,
+    int32_t op_a,
+    TrtxTensor* b,
+    int32_t op_b,
+    TrtxLayer** out_layer,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Marks tensor as a network output
+int32_t trtx_network_mark_output(
+    TrtxNetworkDefinition* network,
+    TrtxTensor* tensor,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Tensor functions (names are owned by the tensor)
+int32_t trtx_tensor_get_name(
+    TrtxTensor* tensor,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_tensor_get_dimensions(
+    TrtxTensor* tensor,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_tensor_get_type(
+    TrtxTensor* tensor,
+    int32_t* out_data_type,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Layer functions (names are owned by the layer)
+int32_t trtx_layer_get_name(
+    TrtxLayer* layer,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_layer_get_nb_outputs(
+    TrtxLayer* layer,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_layer_get_output(
+    TrtxLayer* layer,
+    int32_t index,
+    TrtxTensor** out_tensor,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// OptimizationProfile functions (select is an nvinfer1::OptProfileSelector value)
+int32_t trtx_optimization_profile_set_dimensions(
+    TrtxOptimizationProfile* profile,
+    const char* input_name,
+    int32_t select,
+    const int64_t* dims,
+    int32_t nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_optimization_profile_get_dimensions(
+    TrtxOptimizationProfile* profile,
+    const char* input_name,
+    int32_t select,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Runtime functions
+int32_t trtx_runtime_create(
+    TrtxLogger* logger,
+    TrtxRuntime** out_runtime,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+void trtx_runtime_destroy(TrtxRuntime* runtime);
+
+int32_t trtx_runtime_deserialize_cuda_engine(
+    TrtxRuntime* runtime,
+    const void* data,
+    size_t size,
+    TrtxCudaEngine** out_engine,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_runtime_set_engine_host_code_allowed(
+    TrtxRuntime* runtime,
+    bool allowed,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_runtime_get_engine_host_code_allowed(
+    TrtxRuntime* runtime,
+    bool* out_allowed,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Returns TRTX_ERROR_INVALID_ARGUMENT if TensorRT rejects the thread count
+int32_t trtx_runtime_set_max_threads(
+    TrtxRuntime* runtime,
+    int32_t nb_threads,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_runtime_get_max_threads(
+    TrtxRuntime* runtime,
+    int32_t* out_nb_threads,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Validity is an nvinfer1::EngineValidity value and diagnostics a mask of
+// nvinfer1::EngineInvalidityDiagnostics bits; the plan is not deserialized
+int32_t trtx_runtime_get_engine_validity(
+    TrtxRuntime* runtime,
+    const void* data,
+    size_t size,
+    int32_t* out_validity,
+    uint64_t* out_diagnostics,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// The loaded runtime must be destroyed before the runtime that loaded it
+int32_t trtx_runtime_load_runtime(
+    TrtxRuntime* runtime,
+    const char* path,
+    TrtxRuntime** out_runtime,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// CudaEngine functions
+void trtx_cuda_engine_destroy(TrtxCudaEngine* engine);
+
+int32_t trtx_cuda_engine_create_execution_context(
+    TrtxCudaEngine* engine,
+    TrtxExecutionContext** out_context,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_engine_get_tensor_name(
+    TrtxCudaEngine* engine,
+    int32_t index,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_engine_get_nb_io_tensors(
+    TrtxCudaEngine* engine,
+    int32_t* out_count
+);
+
+int32_t trtx_cuda_engine_get_nb_layers(
+    TrtxCudaEngine* engine,
+    int32_t* out_count
+);
+
+// Name of the network the engine was built from; empty if none was set
+int32_t trtx_cuda_engine_get_name(
+    TrtxCudaEngine* engine,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_engine_get_nb_optimization_profiles(
+    TrtxCudaEngine* engine,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Returns the tensor data type as an nvinfer1::DataType value
+int32_t trtx_cuda_engine_get_tensor_dtype(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    int32_t* out_dtype,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Writes up to max_dims dimensions; dynamic dimensions are reported as -1
+int32_t trtx_cuda_engine_get_tensor_shape(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Returns an nvinfer1::TensorIOMode value
+int32_t trtx_cuda_engine_get_tensor_io_mode(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    int32_t* out_mode,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Shape tensors (and their outputs' shape-computing inputs) live in host memory
+int32_t trtx_cuda_engine_is_shape_inference_io(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    bool* out_is_shape_io,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// select is an nvinfer1::OptProfileSelector value; fails for tensors that are
+// not inputs and for out-of-range profile indices
+int32_t trtx_cuda_engine_get_profile_shape(
+    TrtxCudaEngine* engine,
+    const char* tensor_name,
+    int32_t profile_index,
+    int32_t select,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Device memory required by an execution context, in bytes
+int32_t trtx_cuda_engine_get_device_memory_size(
+    TrtxCudaEngine* engine,
+    int64_t* out_size,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Total size of weights that can be streamed (0 if weight streaming is disabled)
+int32_t trtx_cuda_engine_get_streamable_weights_size(
+    TrtxCudaEngine* engine,
+    int64_t* out_size,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Whether the engine streams weights: it has streamable weights and its
+// budget keeps only part of them resident
+int32_t trtx_cuda_engine_is_weight_streaming_enabled(
+    TrtxCudaEngine* engine,
+    bool* out_enabled,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Smallest budget accepted by trtx_cuda_engine_set_weight_streaming_budget
+int32_t trtx_cuda_engine_get_minimum_weight_streaming_budget(
+    TrtxCudaEngine* engine,
+    int64_t* out_budget,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_engine_set_weight_streaming_budget(
+    TrtxCudaEngine* engine,
+    int64_t budget,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_engine_get_weight_streaming_budget(
+    TrtxCudaEngine* engine,
+    int64_t* out_budget,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Budget TensorRT would pick given current free device memory
+int32_t trtx_cuda_engine_get_weight_streaming_automatic_budget(
+    TrtxCudaEngine* engine,
+    int64_t* out_budget,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_engine_create_engine_inspector(
+    TrtxCudaEngine* engine,
+    TrtxEngineInspector** out_inspector,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// True if the engine was built with BuilderFlag::kREFIT
+int32_t trtx_cuda_engine_is_refittable(
+    TrtxCudaEngine* engine,
+    bool* out_refittable,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// EngineInspector functions
+void trtx_engine_inspector_destroy(TrtxEngineInspector* inspector);
+
+// format is an nvinfer1::LayerInformationFormat value. The returned string is
+// owned by the inspector and valid until the next call on it.
+int32_t trtx_engine_inspector_get_engine_information(
+    TrtxEngineInspector* inspector,
+    int32_t format,
+    const char** out_info,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Same ownership as trtx_engine_inspector_get_engine_information
+int32_t trtx_engine_inspector_get_layer_information(
+    TrtxEngineInspector* inspector,
+    int32_t layer_index,
+    int32_t format,
+    const char** out_info,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Refitter functions
+int32_t trtx_refitter_create(
+    TrtxCudaEngine* engine,
+    TrtxLogger* logger,
+    TrtxRefitter** out_refitter,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+void trtx_refitter_destroy(TrtxRefitter* refitter);
+
+// Writes up to max_names names of refittable weights and the total count.
+// Names are owned by the engine.
+int32_t trtx_refitter_get_all_weights(
+    TrtxRefitter* refitter,
+    const char** out_names,
+    int32_t max_names,
+    int32_t* out_count,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// data_type is an nvinfer1::DataType value; values must stay valid until refit
+int32_t trtx_refitter_set_named_weights(
+    TrtxRefitter* refitter,
+    const char* name,
+    int32_t data_type,
+    const void* values,
+    int64_t count,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_refitter_refit_cuda_engine(
+    TrtxRefitter* refitter,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Profiler functions
+int32_t trtx_profiler_create(
+    TrtxProfilerCallback callback,
+    void* user_data,
+    TrtxProfiler** out_profiler,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+void trtx_profiler_destroy(TrtxProfiler* profiler);
+
+// DebugListener functions
+int32_t trtx_debug_listener_create(
+    TrtxDebugTensorCallback callback,
+    void* user_data,
+    TrtxDebugListener** out_listener,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+void trtx_debug_listener_destroy(TrtxDebugListener* listener);
+
+// OutputAllocator functions
+int32_t trtx_output_allocator_create(
+    TrtxReallocateOutputCallback reallocate,
+    TrtxNotifyShapeCallback notify_shape,
+    void* user_data,
+    TrtxOutputAllocator** out_allocator,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+void trtx_output_allocator_destroy(TrtxOutputAllocator* allocator);
+
+// ProgressMonitor functions
+int32_t trtx_progress_monitor_create(
+    TrtxPhaseStartCallback phase_start,
+    TrtxStepCompleteCallback step_complete,
+    TrtxPhaseFinishCallback phase_finish,
+    void* user_data,
+    TrtxProgressMonitor** out_monitor,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+void trtx_progress_monitor_destroy(TrtxProgressMonitor* monitor);
+
+// Pass NULL to remove the monitor
+int32_t trtx_builder_config_set_progress_monitor(
+    TrtxBuilderConfig* config,
+    TrtxProgressMonitor* monitor,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// TimingCache functions
+// An empty blob (size 0) creates an empty cache
+int32_t trtx_builder_config_create_timing_cache(
+    TrtxBuilderConfig* config,
+    const void* blob,
+    size_t size,
+    TrtxTimingCache** out_cache,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// The cache must outlive every build using this config
+int32_t trtx_builder_config_set_timing_cache(
+    TrtxBuilderConfig* config,
+    TrtxTimingCache* cache,
+    bool ignore_mismatch,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+void trtx_timing_cache_destroy(TrtxTimingCache* cache);
+
+// Free out_data with trtx_free_buffer
+int32_t trtx_timing_cache_serialize(
+    TrtxTimingCache* cache,
+    void** out_data,
+    size_t* out_size,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_timing_cache_combine(
+    TrtxTimingCache* cache,
+    TrtxTimingCache* input_cache,
+    bool ignore_mismatch,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// ExecutionContext functions
+void trtx_execution_context_destroy(TrtxExecutionContext* context);
+
+// Pass NULL to remove the profiler
+int32_t trtx_execution_context_set_profiler(
+    TrtxExecutionContext* context,
+    TrtxProfiler* profiler,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Pass NULL to remove the listener; setting one enables every debug tensor
+int32_t trtx_execution_context_set_debug_listener(
+    TrtxExecutionContext* context,
+    TrtxDebugListener* listener,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// TensorRT copies the name; it appears in its log messages for the context
+int32_t trtx_execution_context_set_name(
+    TrtxExecutionContext* context,
+    const char* name,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_execution_context_get_name(
+    TrtxExecutionContext* context,
+    const char** out_name,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_execution_context_set_tensor_address(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    void* data,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// NULL if no address is set
+int32_t trtx_execution_context_get_tensor_address(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    const void** out_data,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_execution_context_set_input_shape(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    const int64_t* dims,
+    int32_t nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_execution_context_get_optimization_profile(
+    TrtxExecutionContext* context,
+    int32_t* out_profile_index,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Switches profile on cuda_stream and waits for the switch to complete
+int32_t trtx_execution_context_set_optimization_profile(
+    TrtxExecutionContext* context,
+    int32_t profile_index,
+    void* cuda_stream,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// verbosity is an nvinfer1::ProfilingVerbosity value; it cannot exceed the
+// verbosity the engine was built with
+int32_t trtx_execution_context_set_nvtx_verbosity(
+    TrtxExecutionContext* context,
+    int32_t verbosity,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_execution_context_get_nvtx_verbosity(
+    TrtxExecutionContext* context,
+    int32_t* out_verbosity,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// streams are cudaStream_t handles, used by the next enqueue calls
+int32_t trtx_execution_context_set_aux_streams(
+    TrtxExecutionContext* context,
+    void** streams,
+    int32_t nb_streams,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Shape with dynamic dimensions resolved from the input shapes set so far
+int32_t trtx_execution_context_get_tensor_shape(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    int64_t* out_dims,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Strides in elements of a tensor, resolved from the input shapes set so far
+int32_t trtx_execution_context_get_tensor_strides(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    int64_t* out_strides,
+    int32_t max_dims,
+    int32_t* out_nb_dims,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Upper bound in bytes on an output's size for the current profile and input shapes
+int32_t trtx_execution_context_get_max_output_size(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    int64_t* out_size,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Pass NULL to remove the allocator
+int32_t trtx_execution_context_set_output_allocator(
+    TrtxExecutionContext* context,
+    const char* tensor_name,
+    TrtxOutputAllocator* allocator,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_execution_context_enqueue_v3(
+    TrtxExecutionContext* context,
+    void* cuda_stream,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Utility functions
+void trtx_free_buffer(void* buffer);
+
+// Plugin registry functions
+// Loads a shared library of plugins into the global plugin registry; the
+// library stays loaded for the life of the process
+int32_t trtx_load_plugin_library(
+    const char* path,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Plugin creator backed by callbacks; registering it hands it to the
+// process-wide registry, which keeps it for the life of the process
+int32_t trtx_plugin_creator_create(
+    const char* name,
+    const char* version,
+    TrtxPluginCreateCallback create,
+    TrtxPluginCloneCallback clone,
+    TrtxPluginEnqueueCallback enqueue,
+    TrtxPluginDestroyCallback destroy,
+    void* user_data,
+    TrtxPluginCreator** out_creator,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Only for creators that were never registered
+void trtx_plugin_creator_destroy(TrtxPluginCreator* creator);
+
+// Fails if a creator with the same name and version is already registered
+int32_t trtx_runtime_register_plugin_creator(
+    TrtxRuntime* runtime,
+    TrtxPluginCreator* creator,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// ONNX Parser functions
+typedef struct TrtxOnnxParser TrtxOnnxParser;
+
+int32_t trtx_onnx_parser_create(
+    TrtxNetworkDefinition* network,
+    TrtxLogger* logger,
+    TrtxOnnxParser** out_parser,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+void trtx_onnx_parser_destroy(TrtxOnnxParser* parser);
+
+int32_t trtx_onnx_parser_parse(
+    TrtxOnnxParser* parser,
+    const void* model_data,
+    size_t model_size,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Partitions the model into subgraphs (supportsModelV2) without building it
+int32_t trtx_onnx_parser_supports_model(
+    TrtxOnnxParser* parser,
+    const void* model_data,
+    size_t model_size,
+    bool* out_supported,
+    int64_t* out_nb_subgraphs,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// out_nodes holds node indices of the ONNX graph, owned by the parser
+int32_t trtx_onnx_parser_get_subgraph(
+    TrtxOnnxParser* parser,
+    int64_t index,
+    bool* out_supported,
+    const int64_t** out_nodes,
+    int64_t* out_nb_nodes,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// flag is an nvonnxparser::OnnxParserFlag value
+int32_t trtx_onnx_parser_set_flag(
+    TrtxOnnxParser* parser,
+    int32_t flag,
+    bool enabled,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_onnx_parser_get_flag(
+    TrtxOnnxParser* parser,
+    int32_t flag,
+    bool* out_enabled,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// CUDA Memory Management functions
+int32_t trtx_cuda_malloc(
+    void** ptr,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_free(
+    void* ptr,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Unified memory accessible from host and device; freed with trtx_cuda_free
+int32_t trtx_cuda_malloc_managed(
+    void** ptr,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Migrate managed memory to the current device in stream order
+int32_t trtx_cuda_mem_prefetch_async(
+    const void* ptr,
+    size_t size,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Stream-ordered allocation from pool, or the device's default pool if NULL
+int32_t trtx_cuda_malloc_async(
+    void** ptr,
+    size_t size,
+    void* pool,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Stream-ordered free; the memory may be reused by work queued after it
+int32_t trtx_cuda_free_async(
+    void* ptr,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_memcpy_host_to_device(
+    void* dst,
+    const void* src,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_memcpy_device_to_host(
+    void* dst,
+    const void* src,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_memcpy_device_to_device(
+    void* dst,
+    const void* src,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Sets size bytes at ptr to value
+int32_t trtx_cuda_memset(
+    void* ptr,
+    int32_t value,
+    size_t size,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_synchronize(
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_mem_get_info(
+    size_t* free_bytes,
+    size_t* total_bytes,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_set_device(
+    int32_t device,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Creates the current device's context if the thread has none yet. On
+// failure out_cuda_error holds the cudaError_t returned by CUDA
+int32_t trtx_cuda_ensure_context(
+    int32_t* out_cuda_error,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_get_device(
+    int32_t* device,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Last CUDA runtime error of the calling thread as a cudaError_t value (0 if
+// none) and its cudaGetErrorString text; resets it unless peek is set
+int32_t trtx_cuda_get_last_error(
+    bool peek,
+    int32_t* out_code,
+    const char** out_string,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// cudaGetErrorString text of a cudaError_t value; the string is static
+int32_t trtx_cuda_get_error_string(
+    int32_t code,
+    const char** out_string,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// CUDA streams and graphs (streams and graph execs are passed as void*)
+int32_t trtx_cuda_stream_create(
+    void** stream,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Unlike trtx_cuda_stream_create, the stream synchronizes with the legacy
+// default stream that trtx_cuda_memcpy_* and trtx_cuda_memset run on
+int32_t trtx_cuda_stream_create_blocking(
+    void** stream,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Lower numbers are higher priorities; CUDA clamps priority to the device's range
+int32_t trtx_cuda_stream_create_with_priority(
+    void** stream,
+    int32_t priority,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_stream_get_priority(
+    void* stream,
+    int32_t* out_priority,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// out_greatest is the highest priority, numerically at most out_least
+int32_t trtx_cuda_stream_priority_range(
+    int32_t* out_least,
+    int32_t* out_greatest,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_stream_destroy(
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_stream_synchronize(
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Queues callback on stream with cudaLaunchHostFunc. It runs on a CUDA
+// driver thread and must not make CUDA calls.
+int32_t trtx_cuda_stream_launch_host_fn(
+    void* stream,
+    TrtxHostFnCallback callback,
+    void* user_data,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_stream_begin_capture(
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Ends capture on stream and instantiates the captured graph
+int32_t trtx_cuda_stream_end_capture(
+    void* stream,
+    void** graph_exec,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_graph_launch(
+    void* graph_exec,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_graph_exec_destroy(
+    void* graph_exec,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// CUDA events (passed as void*), created without timing
+int32_t trtx_cuda_event_create(
+    void** event,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_event_destroy(
+    void* event,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_event_record(
+    void* event,
+    void* stream,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Sets out_complete to false while work recorded before the event is pending
+int32_t trtx_cuda_event_query(
+    void* event,
+    bool* out_complete,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_event_synchronize(
+    void* event,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// Memory pool functions; pools allocate on the current device
+int32_t trtx_cuda_mem_pool_create(
+    void** pool,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_mem_pool_destroy(
+    void* pool,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_mem_pool_set_release_threshold(
+    void* pool,
+    uint64_t threshold,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_mem_pool_get_release_threshold(
+    void* pool,
+    uint64_t* out_threshold,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// limit is a cudaLimit value
+int32_t trtx_cuda_device_get_limit(
+    int32_t limit,
+    size_t* value,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+int32_t trtx_cuda_device_set_limit(
+    int32_t limit,
+    size_t value,
+    char* error_msg,
+    size_t error_msg_len
+);
+
+// attribute is a cudaDeviceAttr value, queried on the current device
+int32_t trtx_cuda_device_get_attribute(
+    int32_t attribute,
+    int32_t* value,
     char* error_msg,
     size_t error_msg_len
 );
@@ -0,0 +1,123 @@
+//! End-to-end latency and throughput of `Executor::run`
+//!
+//! Runs a fixed engine at several batch sizes and reports p50/p99 latency
+//! per call next to Criterion's own estimates. Configured by environment:
+//!
+//! - `TRTX_BENCH_ENGINE`: serialized engine to run (required without the
+//!   `mock` feature; with it, defaults to the mock identity engine)
+//! - `TRTX_BENCH_BATCH`: comma-separated batch sizes (default `1,8,32`),
+//!   substituted for dynamic dimensions of the inputs
+//! - `TRTX_BENCH_WARMUP`: untimed runs per batch size before measuring
+//!   (default 10)
+//!
+//! Usage: `cargo bench --features mock --bench executor`, or append
+//! `-- --test` to run each case once as a smoke test.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::time::{Duration, Instant};
+use trtx::{DataType, Executor, Logger, Runtime, TensorData, TensorInput, TensorIoMode};
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+fn engine_data() -> Vec<u8> {
+    match std::env::var("TRTX_BENCH_ENGINE") {
+        Ok(path) => std::fs::read(&path).unwrap_or_else(|e| panic!("Cannot read {}: {}", path, e)),
+        // The mock runtime deserializes any other bytes as an identity engine
+        Err(_) if cfg!(feature = "mock") => vec![0u8; 16],
+        Err(_) => panic!("Set TRTX_BENCH_ENGINE to a serialized engine"),
+    }
+}
+
+/// Zero-filled inputs for every engine input, with dynamic dimensions set to `batch`
+fn batch_inputs(executor: &Executor, batch: i64) -> Vec<TensorInput> {
+    executor
+        .engine()
+        .tensors()
+        .map(|tensor| tensor.unwrap())
+        .filter(|tensor| tensor.io_mode == TensorIoMode::Input)
+        .map(|tensor| {
+            let shape: trtx::Dims = tensor
+                .shape
+                .iter()
+                .map(|&d| if d < 0 { batch } else { d })
+                .collect();
+            let len = shape.product().unwrap();
+            let data = match tensor.dtype {
+                DataType::Float => TensorData::F32(vec![0.0; len]),
+                DataType::Int32 => TensorData::I32(vec![0; len]),
+                DataType::Int64 => TensorData::I64(vec![0; len]),
+                DataType::Int8 => TensorData::I8(vec![0; len]),
+                DataType::Uint8 | DataType::Bool => TensorData::U8(vec![0; len]),
+                dtype => panic!("Unsupported input type {:?} for {}", dtype, tensor.name),
+            };
+            TensorInput {
+                name: tensor.name,
+                shape,
+                data,
+            }
+        })
+        .collect()
+}
+
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    let index = ((sorted.len() - 1) as f64 * p).round() as usize;
+    sorted[index]
+}
+
+fn bench_executor_run(c: &mut Criterion) {
+    let batches: Vec<i64> = std::env::var("TRTX_BENCH_BATCH")
+        .unwrap_or_else(|_| "1,8,32".to_string())
+        .split(',')
+        .map(|batch| batch.trim().parse().expect("TRTX_BENCH_BATCH"))
+        .collect();
+    let warmup: usize = env_or("TRTX_BENCH_WARMUP", 10);
+
+    let logger = Logger::null().unwrap();
+    let runtime = Runtime::new(&logger).unwrap();
+    let engine = runtime.deserialize_cuda_engine(&engine_data()).unwrap();
+    let mut executor = Executor::new(&engine).unwrap();
+
+    let mut group = c.benchmark_group("executor_run");
+    for batch in batches {
+        let inputs = batch_inputs(&executor, batch);
+        for _ in 0..warmup {
+            executor.run(&inputs).unwrap();
+        }
+
+        let mut latencies = Vec::new();
+        group.throughput(Throughput::Elements(batch as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(batch), &inputs, |b, inputs| {
+            b.iter_custom(|iters| {
+                let mut total = Duration::ZERO;
+                for _ in 0..iters {
+                    let start = Instant::now();
+                    executor.run(inputs).unwrap();
+                    let elapsed = start.elapsed();
+                    latencies.push(elapsed);
+                    total += elapsed;
+                }
+                total
+            })
+        });
+
+        latencies.sort();
+        if !latencies.is_empty() {
+            println!(
+                "executor_run/{}: p50 {:?}, p99 {:?} over {} runs",
+                batch,
+                percentile(&latencies, 0.50),
+                percentile(&latencies, 0.99),
+                latencies.len()
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_executor_run);
+criterion_main!(benches);
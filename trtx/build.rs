@@ -0,0 +1,29 @@
+//! Exposes the linked TensorRT-RTX version as cfg flags
+//!
+//! For a library at version `X.Y`, `trtx_rtx_at_least = "X.0"` through
+//! `trtx_rtx_at_least = "X.Y"` are set, so APIs introduced in a given release
+//! can be gated with `#[cfg(trtx_rtx_at_least = "1.1")]`. Nothing is set in
+//! mock mode, where the version is `0.0.0`.
+
+use std::env;
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(trtx_rtx_at_least, values(any()))");
+
+    let component = |key: &str| -> u32 {
+        env::var(format!("DEP_TENSORRT_RTX_VERSION_{}", key))
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0)
+    };
+    let major = component("MAJOR");
+    let minor = component("MINOR");
+
+    if major == 0 {
+        return;
+    }
+
+    for m in 0..=minor {
+        println!("cargo:rustc-cfg=trtx_rtx_at_least=\"{}.{}\"", major, m);
+    }
+}
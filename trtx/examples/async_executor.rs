@@ -0,0 +1,53 @@
+//! Example running inference from async code without blocking a worker thread
+//!
+//! `Executor::enqueue_async` copies the inputs to the device, enqueues
+//! execution and returns a future. While the GPU works, the tokio runtime is
+//! free to run other tasks, shown here by a heartbeat running alongside.
+//!
+//! Run with: cargo run --features mock,async --example async_executor [model.engine]
+//!
+//! Without an engine path, the mock identity engine is used.
+
+use std::error::Error;
+use std::time::Duration;
+use trtx::executor::{Executor, TensorInput};
+use trtx::{Logger, Runtime};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let engine_data = match std::env::args().nth(1) {
+        Some(path) => std::fs::read(path)?,
+        None => vec![0u8; 16],
+    };
+
+    let logger = Logger::stderr()?;
+    let runtime = Runtime::new(&logger)?;
+    let engine = runtime.deserialize_cuda_engine(&engine_data)?;
+    let mut executor = Executor::new(&engine)?;
+
+    let inputs = vec![TensorInput {
+        name: "input".to_string(),
+        shape: vec![2, 4].into(),
+        data: vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0].into(),
+    }];
+
+    let heartbeat = async {
+        for i in 0..3 {
+            println!("heartbeat {}", i);
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    };
+
+    // The executor stays borrowed by the future, so it is awaited in this
+    // task rather than spawned
+    let (outputs, ()) = tokio::join!(executor.enqueue_async(&inputs), heartbeat);
+
+    for output in outputs? {
+        println!("{}: shape {}", output.name, output.shape);
+        if let Some(values) = output.data.as_f32() {
+            println!("  values: {:?}", values);
+        }
+    }
+
+    Ok(())
+}
@@ -2,18 +2,20 @@
 //!
 //! This example demonstrates:
 //! 1. Creating a logger
-//! 2. Building an engine
+//! 2. Building an engine for a small hand-built MLP
 //! 3. Serializing to disk
 //! 4. Deserializing and running inference
 //!
 //! Note: This is a skeleton example. Real usage requires:
-//! - Adding layers to the network
 //! - Allocating CUDA memory for tensors
 //! - Copying data to/from GPU
 
 use std::error::Error;
 use trtx::builder::{network_flags, MemoryPoolType};
-use trtx::{Builder, Logger, Runtime};
+use trtx::tensor::DataType;
+use trtx::{
+    ActivationType, Builder, ElementWiseOperation, Logger, MatrixOperation, Runtime, Weights,
+};
 
 fn main() -> Result<(), Box<dyn Error>> {
     println!("TensorRT-RTX Basic Workflow Example");
@@ -42,18 +44,27 @@ fn main() -> Result<(), Box<dyn Error>> {
     config.set_memory_pool_limit(MemoryPoolType::Workspace, 1 << 30)?;
     println!("   ✓ Workspace limit set to 1GB");
 
-    // Note: In a real application, you would add layers to the network here
-    // For example:
-    // - network.add_input(...)
-    // - network.add_convolution(...)
-    // - network.add_activation(...)
-    // - etc.
-
-    println!("\n   Note: This example uses an empty network.");
-    println!("   In production, you would:");
-    println!("   - Parse an ONNX model");
-    println!("   - Or programmatically add layers");
-    println!("   - Define input/output tensors\n");
+    // Add layers: output = relu(features x kernel + bias). Networks are
+    // usually parsed from ONNX instead (see OnnxParser).
+    let kernel = vec![0.01f32; 16 * 8];
+    let bias = vec![0.5f32; 8];
+    let features = network.add_input("features", DataType::Float, &[1, 16])?;
+    let kernel = network.add_constant(&[16, 8], &Weights::from_f32(&kernel))?;
+    let bias = network.add_constant(&[1, 8], &Weights::from_f32(&bias))?;
+    let matmul = network.add_matrix_multiply(
+        features,
+        MatrixOperation::None,
+        kernel.get_output(0)?,
+        MatrixOperation::None,
+    )?;
+    let biased = network.add_elementwise(
+        matmul.get_output(0)?,
+        bias.get_output(0)?,
+        ElementWiseOperation::Sum,
+    )?;
+    let relu = network.add_activation(biased.get_output(0)?, ActivationType::Relu)?;
+    network.mark_output(relu.get_output(0)?)?;
+    println!("   ✓ Layers added: matmul + bias + relu\n");
 
     // Build serialized network
     println!("   Building serialized engine...");
@@ -76,12 +87,17 @@ fn main() -> Result<(), Box<dyn Error>> {
             println!("   ✓ Engine deserialized");
 
             // Query engine information
-            let num_tensors = engine.get_nb_io_tensors()?;
-            println!("   ✓ Engine has {} I/O tensors", num_tensors);
-
-            for i in 0..num_tensors {
-                let name = engine.get_tensor_name(i)?;
-                println!("      - Tensor {}: {}", i, name);
+            println!(
+                "   ✓ Engine has {} I/O tensors",
+                engine.get_nb_io_tensors()?
+            );
+
+            for tensor in engine.tensors() {
+                let tensor = tensor?;
+                println!(
+                    "      - {:?} {}: {:?} {:?}",
+                    tensor.io_mode, tensor.name, tensor.dtype, tensor.shape
+                );
             }
 
             // Create execution context
@@ -97,8 +113,6 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
         Err(e) => {
             eprintln!("   ✗ Failed to build engine: {}", e);
-            eprintln!("\n   This is expected for an empty network.");
-            eprintln!("   In production, add layers before building.");
             return Err(e.into());
         }
     }
@@ -0,0 +1,56 @@
+//! Fluent engine building example
+//!
+//! The basic workflow with [`EngineBuilder`] instead of wiring the builder,
+//! network, parser and config by hand:
+//! 1. Building an engine from an ONNX model
+//! 2. Serializing to disk
+//! 3. Deserializing it for inference
+//!
+//! Usage: `cargo run --features mock --example engine_builder [model.onnx]`
+
+use std::error::Error;
+use trtx::{EngineBuilder, Logger, Runtime};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let model_path = std::env::args().nth(1).unwrap_or_else(|| {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/super-resolution-10.onnx"
+        )
+        .to_string()
+    });
+
+    println!("TensorRT-RTX Fluent Engine Building Example");
+    println!("===========================================\n");
+
+    let logger = Logger::stderr()?;
+
+    // Step 1: Build, reusing our logger
+    println!("1. Building engine from {}...", model_path);
+    let onnx = std::fs::read(&model_path)?;
+    let engine_data = EngineBuilder::from_onnx(&onnx)
+        .logger(&logger)
+        .fp16()
+        .workspace(1 << 30)
+        .build()?;
+    println!("   ✓ Engine built ({} bytes)\n", engine_data.len());
+
+    // Step 2: Save to disk
+    let engine_path = "/tmp/example.engine";
+    std::fs::write(engine_path, &engine_data)?;
+    println!("2. Engine saved to {}\n", engine_path);
+
+    // Step 3: Load for inference
+    println!("3. Loading engine for inference...");
+    let runtime = Runtime::new(&logger)?;
+    let engine = runtime.deserialize_cuda_engine(&engine_data)?;
+    for (i, name) in engine.io_tensor_names()?.iter().enumerate() {
+        println!("      - Tensor {}: {}", i, name);
+    }
+    let _context = engine.create_execution_context()?;
+    println!("   ✓ Execution context created");
+
+    println!("\n✓ Example completed successfully!");
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//! Engine inspection example
+//!
+//! Builds an engine from an ONNX model and prints what TensorRT-RTX made of
+//! each layer: fusions, chosen tactics and precisions.
+//!
+//! Usage: cargo run --example inspect_engine -- model.onnx
+
+use std::error::Error;
+use trtx::builder::{network_flags, MemoryPoolType};
+use trtx::{Builder, LayerInformationFormat, Logger, OnnxParser, Runtime};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let model_path = std::env::args()
+        .nth(1)
+        .ok_or("usage: inspect_engine <model.onnx>")?;
+    let model = std::fs::read(&model_path)?;
+
+    let logger = Logger::stderr()?;
+
+    // Build
+    let builder = Builder::new(&logger)?;
+    let network = builder.create_network(network_flags::EXPLICIT_BATCH)?;
+    let parser = OnnxParser::new(&network, &logger)?;
+    parser.parse(&model)?;
+
+    let mut config = builder.create_config()?;
+    config.set_memory_pool_limit(MemoryPoolType::Workspace, 1 << 30)?;
+    let engine_data = builder.build_serialized_network(&network, &config)?;
+
+    // Inspect
+    let runtime = Runtime::new(&logger)?;
+    let engine = runtime.deserialize_cuda_engine(&engine_data)?;
+    let inspector = engine.create_inspector()?;
+
+    let nb_layers = engine.get_nb_layers()?;
+    println!(
+        "{} has {} layers after optimization:",
+        model_path, nb_layers
+    );
+    for index in 0..nb_layers {
+        let info = inspector.get_layer_information(index, LayerInformationFormat::Json)?;
+        println!("{}", info);
+    }
+
+    Ok(())
+}
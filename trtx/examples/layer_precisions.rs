@@ -0,0 +1,50 @@
+//! Layer precision example
+//!
+//! Builds an engine from an ONNX model with every reduced precision the
+//! device supports, then prints how many layers ran at each precision. Handy
+//! for checking whether FP16/INT8 actually took effect.
+//!
+//! Usage: cargo run --example layer_precisions -- model.onnx
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use trtx::builder::{network_flags, MemoryPoolType, ProfilingVerbosity};
+use trtx::{Builder, Logger, OnnxParser, PrecisionConfig, Runtime};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let model_path = std::env::args()
+        .nth(1)
+        .ok_or("usage: layer_precisions <model.onnx>")?;
+    let model = std::fs::read(&model_path)?;
+
+    let logger = Logger::stderr()?;
+
+    // Build with reduced precisions, keeping layer details for inspection
+    let builder = Builder::new(&logger)?;
+    let network = builder.create_network(network_flags::EXPLICIT_BATCH)?;
+    let parser = OnnxParser::new(&network, &logger)?;
+    parser.parse(&model)?;
+
+    let mut config = builder.create_config()?;
+    config.set_memory_pool_limit(MemoryPoolType::Workspace, 1 << 30)?;
+    config.apply_precision(&builder, &PrecisionConfig::best())?;
+    config.set_profiling_verbosity(ProfilingVerbosity::Detailed)?;
+    let engine_data = builder.build_serialized_network(&network, &config)?;
+
+    let runtime = Runtime::new(&logger)?;
+    let engine = runtime.deserialize_cuda_engine(&engine_data)?;
+
+    // Histogram of layer precisions
+    let precisions = engine.get_layer_precisions()?;
+    let mut histogram = BTreeMap::new();
+    for (_, precision) in &precisions {
+        *histogram.entry(format!("{:?}", precision)).or_insert(0) += 1;
+    }
+
+    println!("{}: {} layers", model_path, precisions.len());
+    for (precision, count) in histogram {
+        println!("  {:<6} {:>5}", precision, count);
+    }
+
+    Ok(())
+}
@@ -9,6 +9,7 @@
 
 use std::error::Error;
 use trtx::executor::{run_onnx_with_tensorrt, run_onnx_zeroed, TensorInput};
+use trtx::Dims;
 
 fn main() -> Result<(), Box<dyn Error>> {
     println!("TensorRT-RTX Executor for rustnn");
@@ -19,7 +20,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let dummy_onnx = create_dummy_onnx_model();
 
-    let input_descriptors = vec![("input".to_string(), vec![1, 3, 224, 224])];
+    let input_descriptors = vec![("input".to_string(), Dims::from([1, 3, 224, 224]))];
 
     match run_onnx_zeroed(&dummy_onnx, &input_descriptors) {
         Ok(outputs) => {
@@ -27,7 +28,7 @@ fn main() -> Result<(), Box<dyn Error>> {
             println!("   Outputs:");
             for output in outputs {
                 println!(
-                    "      - {}: shape {:?}, {} values",
+                    "      - {}: shape {}, {} values",
                     output.name,
                     output.shape,
                     output.data.len()
@@ -45,19 +46,21 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let inputs = vec![TensorInput {
         name: "input".to_string(),
-        shape: vec![1, 3, 224, 224],
-        data: create_sample_input(3 * 224 * 224),
+        shape: vec![1, 3, 224, 224].into(),
+        data: create_sample_input(3 * 224 * 224).into(),
     }];
 
     match run_onnx_with_tensorrt(&dummy_onnx, &inputs) {
         Ok(outputs) => {
             println!("   ✓ Execution succeeded");
             for output in outputs {
-                println!("      - {}: shape {:?}", output.name, output.shape);
-                println!(
-                    "        First 5 values: {:?}",
-                    &output.data[..output.data.len().min(5)]
-                );
+                println!("      - {}: shape {}", output.name, output.shape);
+                if let Some(values) = output.data.as_f32() {
+                    println!(
+                        "        First 5 values: {:?}",
+                        &values[..values.len().min(5)]
+                    );
+                }
             }
         }
         Err(e) => {
@@ -1,15 +1,47 @@
 //! Builder for creating TensorRT engines
 
+use crate::cuda::{get_attribute, DeviceAttribute};
 use crate::error::{Error, Result};
-use crate::logger::Logger;
+use crate::error_recorder::ErrorRecorder;
+use crate::logger::{Logger, Severity};
+use crate::network::{
+    ActivationType, ElementWiseOperation, LayerRef, MatrixOperation, TensorRef, Weights,
+};
+use crate::progress::{Cancellation, Monitor, PhaseTimer, ProgressMonitor};
+use crate::shapes::Dims;
+use crate::tensor::{DataType, OptProfileSelector};
+use crate::timing_cache::TimingCache;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use trtx_sys::*;
 
 /// Network definition builder flags
 pub mod network_flags {
     /// Explicit batch sizes
     pub const EXPLICIT_BATCH: u32 = 1 << 0;
+    /// Take tensor types from the network instead of letting the builder
+    /// choose them
+    ///
+    /// Every layer runs in the type of its inputs as given by the model, e.g.
+    /// the FP16 and INT8 tensors of a pre-quantized ONNX export. Precision
+    /// flags on the [`BuilderConfig`](super::BuilderConfig) such as
+    /// [`BuilderFlag::Fp16`](super::BuilderFlag::Fp16) and
+    /// [`PrecisionConfig`](super::PrecisionConfig) are ignored, and so are
+    /// layer precisions and output types set on the network.
+    pub const STRONGLY_TYPED: u32 = 1 << 1;
 }
 
+/// SMs per auxiliary stream assumed by [`Builder::recommended_aux_streams`]
+const SMS_PER_AUX_STREAM: i32 = 16;
+
+/// Upper bound on [`Builder::recommended_aux_streams`]
+const MAX_RECOMMENDED_AUX_STREAMS: i32 = 4;
+
 /// Memory pool types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(i32)]
@@ -24,45 +56,340 @@ pub enum MemoryPoolType {
     DlaGlobalDram = 3,
 }
 
-/// Network definition for building TensorRT engines
-pub struct NetworkDefinition {
-    inner: *mut TrtxNetworkDefinition,
+/// Builder flags (mirrors `nvinfer1::BuilderFlag`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum BuilderFlag {
+    /// Enable FP16 layer selection
+    Fp16 = 0,
+    /// Enable INT8 layer selection
+    Int8 = 1,
+    /// Allow the engine's weights to be refitted after build
+    Refit = 4,
+    /// Time every tactic instead of reusing timings from the timing cache,
+    /// so repeated builds do not depend on earlier ones
+    DisableTimingCache = 5,
+    /// Use sparse kernels for weights with 2:4 structured sparsity
+    ///
+    /// Needs Ampere (compute capability 8.0) or newer; on older GPUs the flag
+    /// is accepted but dense kernels are used.
+    SparseWeights = 7,
+    /// Require layers to run in the precision set on them, failing the build
+    /// if no implementation exists
+    ObeyPrecisionConstraints = 9,
+    /// Require network I/O tensors to be read and written in place, without
+    /// reformatting layers; the build fails if that is not possible
+    DirectIo = 11,
+    /// Build an engine that can be deserialized by later TensorRT minor versions.
+    ///
+    /// The lean runtime is embedded in the plan, so engines are larger, and the
+    /// runtime must opt in with [`Runtime::set_engine_host_code_allowed`] before
+    /// deserializing them.
+    ///
+    /// [`Runtime::set_engine_host_code_allowed`]: crate::Runtime::set_engine_host_code_allowed
+    VersionCompatible = 13,
+    /// Do not embed the lean runtime in a version-compatible engine; it must
+    /// then be supplied separately at deserialization time with
+    /// [`Runtime::load_runtime`]
+    ///
+    /// [`Runtime::load_runtime`]: crate::Runtime::load_runtime
+    ExcludeLeanRuntime = 14,
+    /// Enable FP8 layer selection (Ada and newer GPUs)
+    Fp8 = 15,
+    /// Allow the engine's weights to stay in host memory and be streamed to
+    /// the GPU; the budget is set with [`CudaEngine::set_weight_streaming_budget`]
+    ///
+    /// [`CudaEngine::set_weight_streaming_budget`]: crate::CudaEngine::set_weight_streaming_budget
+    WeightStreaming = 21,
 }
 
-impl NetworkDefinition {
-    /// Get the raw pointer (for internal use)
-    pub(crate) fn as_ptr(&self) -> *mut TrtxNetworkDefinition {
-        self.inner
+/// Opt-in TensorRT behaviors not yet enabled by default (mirrors `nvinfer1::PreviewFeature`)
+///
+/// Features are named after the release that introduced them. Libraries
+/// older than a feature do not know it: enabling it is then a no-op and
+/// [`BuilderConfig::get_preview_feature`] reports it disabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum PreviewFeature {
+    /// Share tactic profiling data between execution contexts (TensorRT 8.6)
+    ProfileSharing0806 = 0,
+    /// Allow plugins to alias their input and output buffers (TensorRT 10.3)
+    AliasedPluginIo1003 = 1,
+    /// Allow resizing activation memory at runtime for dynamic shapes (TensorRT 10.10)
+    RuntimeActivationResize1010 = 2,
+}
+
+/// Platform an engine is built to run on (mirrors `nvinfer1::RuntimePlatform`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum RuntimePlatform {
+    /// The platform the engine is built on
+    SameAsBuild = 0,
+    /// Windows on x86-64, cross-compiled from a Linux x86-64 build host
+    WindowsAmd64 = 1,
+}
+
+impl RuntimePlatform {
+    fn from_raw(value: i32) -> Result<Self> {
+        match value {
+            0 => Ok(RuntimePlatform::SameAsBuild),
+            1 => Ok(RuntimePlatform::WindowsAmd64),
+            _ => Err(Error::Unknown(format!(
+                "Unknown runtime platform: {}",
+                value
+            ))),
+        }
     }
 }
 
-impl Drop for NetworkDefinition {
-    fn drop(&mut self) {
-        if !self.inner.is_null() {
-            unsafe {
-                trtx_network_destroy(self.inner);
-            }
+/// What an engine may be used for (mirrors `nvinfer1::EngineCapability`)
+///
+/// Only [`Standard`](Self::Standard) engines can be run with this crate's
+/// [`Runtime`](crate::Runtime) and [`ExecutionContext`](crate::ExecutionContext):
+///
+/// - [`Safety`](Self::Safety) engines restrict the network to the safety
+///   certified layer subset with static shapes, and must be deserialized by
+///   the separate TensorRT safety runtime. Dynamic shapes, optimization
+///   profiles, refitting, plugins outside the safety registry and
+///   data-dependent outputs are unavailable.
+/// - [`DlaStandalone`](Self::DlaStandalone) engines are DLA loadables for
+///   cuDLA, built entirely for DLA with no GPU fallback; they are not
+///   TensorRT engines and cannot be deserialized here at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(i32)]
+pub enum EngineCapability {
+    /// Full TensorRT feature set (the default)
+    #[default]
+    Standard = 0,
+    /// Safety-certified subset, run with the safety runtime
+    Safety = 1,
+    /// Standalone DLA loadable, run with cuDLA
+    DlaStandalone = 2,
+}
+
+impl EngineCapability {
+    fn from_raw(value: i32) -> Result<Self> {
+        match value {
+            0 => Ok(EngineCapability::Standard),
+            1 => Ok(EngineCapability::Safety),
+            2 => Ok(EngineCapability::DlaStandalone),
+            _ => Err(Error::Unknown(format!(
+                "Unknown engine capability: {}",
+                value
+            ))),
         }
     }
 }
 
-unsafe impl Send for NetworkDefinition {}
+/// How much layer detail an engine keeps for inspection (mirrors
+/// `nvinfer1::ProfilingVerbosity`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum ProfilingVerbosity {
+    /// Layer names only (the default)
+    LayerNamesOnly = 0,
+    /// No layer information
+    None = 1,
+    /// Layer types, tactics, formats and precisions
+    Detailed = 2,
+}
 
-/// Builder configuration
-pub struct BuilderConfig {
-    inner: *mut TrtxBuilderConfig,
+impl ProfilingVerbosity {
+    pub(crate) fn from_raw(value: i32) -> Result<Self> {
+        match value {
+            0 => Ok(ProfilingVerbosity::LayerNamesOnly),
+            1 => Ok(ProfilingVerbosity::None),
+            2 => Ok(ProfilingVerbosity::Detailed),
+            _ => Err(Error::Unknown(format!(
+                "Unknown profiling verbosity: {}",
+                value
+            ))),
+        }
+    }
 }
 
-impl BuilderConfig {
-    /// Set memory pool limit
-    pub fn set_memory_pool_limit(&mut self, pool: MemoryPoolType, size: usize) -> Result<()> {
+/// Runtime platforms the linked library can target from this build host
+///
+/// TensorRT has no query for this, so it follows the documented support
+/// matrix: cross-platform engines can only be built on Linux x86-64.
+fn supported_runtime_platforms() -> Vec<RuntimePlatform> {
+    let mut platforms = vec![RuntimePlatform::SameAsBuild];
+    if cfg!(all(target_os = "linux", target_arch = "x86_64")) {
+        platforms.push(RuntimePlatform::WindowsAmd64);
+    }
+    platforms
+}
+
+/// Compute capability of the current device as `(major, minor)`
+fn compute_capability() -> Result<(i32, i32)> {
+    Ok((
+        get_attribute(DeviceAttribute::ComputeCapabilityMajor)?,
+        get_attribute(DeviceAttribute::ComputeCapabilityMinor)?,
+    ))
+}
+
+/// Whether a device of `capability` has fast kernels for a precision flag
+///
+/// Follows TensorRT's own checks: FP16 on 5.3, 6.0, 6.2 and 7.0 or newer,
+/// INT8 on 6.1 or newer and FP8 on 8.9 or newer. Other flags are always
+/// supported.
+fn has_fast_precision(flag: BuilderFlag, capability: (i32, i32)) -> bool {
+    match flag {
+        BuilderFlag::Fp16 => matches!(capability, (5, 3) | (6, 0) | (6, 2)) || capability >= (7, 0),
+        BuilderFlag::Int8 => capability >= (6, 1),
+        BuilderFlag::Fp8 => capability >= (8, 9),
+        _ => true,
+    }
+}
+
+/// Reduced precisions to enable in one call, applied with
+/// [`BuilderConfig::apply_precision`]
+///
+/// ```no_run
+/// # use trtx::{Builder, Logger, PrecisionConfig};
+/// # let logger = Logger::stderr()?;
+/// # let builder = Builder::new(&logger)?;
+/// let mut config = builder.create_config()?;
+/// config.apply_precision(&builder, &PrecisionConfig::new().fp16().obey_constraints())?;
+/// # Ok::<(), trtx::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrecisionConfig {
+    flags: Vec<BuilderFlag>,
+    // Skip unsupported precisions without a warning
+    supported_only: bool,
+}
+
+impl PrecisionConfig {
+    /// Start from full FP32 precision
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable every reduced precision the device supports
+    pub fn best() -> Self {
+        PrecisionConfig {
+            flags: vec![BuilderFlag::Fp16, BuilderFlag::Int8, BuilderFlag::Fp8],
+            supported_only: true,
+        }
+    }
+
+    /// Allow FP16 kernels
+    pub fn fp16(self) -> Self {
+        self.with(BuilderFlag::Fp16)
+    }
+
+    /// Allow INT8 kernels
+    ///
+    /// TensorRT-RTX has no calibration API; INT8 layers come from explicit
+    /// quantization (Q/DQ nodes) in the model.
+    pub fn int8(self) -> Self {
+        self.with(BuilderFlag::Int8)
+    }
+
+    /// Allow FP8 kernels
+    pub fn fp8(self) -> Self {
+        self.with(BuilderFlag::Fp8)
+    }
+
+    /// Fail the build rather than ignore precisions set on individual layers
+    ///
+    /// Required for [`NetworkDefinition::set_output_type`] and
+    /// [`NetworkDefinition::set_layer_precision`] to take effect.
+    pub fn obey_constraints(self) -> Self {
+        self.with(BuilderFlag::ObeyPrecisionConstraints)
+    }
+
+    /// Flags this configuration sets
+    pub fn flags(&self) -> &[BuilderFlag] {
+        &self.flags
+    }
+
+    fn with(mut self, flag: BuilderFlag) -> Self {
+        if !self.flags.contains(&flag) {
+            self.flags.push(flag);
+        }
+        self
+    }
+}
+
+/// Min, opt and max shapes of one dynamic input in an optimization profile
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputProfile {
+    /// Input tensor name
+    pub name: String,
+    /// Smallest shape the engine accepts
+    pub min: Vec<i64>,
+    /// Shape the engine is tuned for
+    pub opt: Vec<i64>,
+    /// Largest shape the engine accepts
+    pub max: Vec<i64>,
+}
+
+/// Every builder setting of a build, applied with [`BuilderConfig::apply`]
+///
+/// Two builds of the same network with equal `ModelConfig`s use the same
+/// settings, so a config stored next to an engine (with the `serde` feature)
+/// tells how it was built. Its `Hash` gives a key for caching engines within
+/// one process only: `std`'s hashers are not stable across Rust versions or
+/// runs, so key an on-disk cache on the serialized config (or a digest of
+/// it) instead. Settings left at `None` or empty keep the builder's defaults.
+///
+/// ```no_run
+/// # use trtx::{Builder, Logger, ModelConfig, PrecisionConfig};
+/// # let logger = Logger::stderr()?;
+/// # let builder = Builder::new(&logger)?;
+/// let model = ModelConfig {
+///     workspace: Some(1 << 30),
+///     precision: PrecisionConfig::new().fp16(),
+///     ..Default::default()
+/// };
+/// let mut config = builder.create_config()?;
+/// config.apply(&builder, &model)?;
+/// # Ok::<(), trtx::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModelConfig {
+    /// Workspace memory pool limit in bytes
+    pub workspace: Option<usize>,
+    /// Builder flags to set, besides the precision flags
+    pub flags: Vec<BuilderFlag>,
+    /// Builder optimization level (see
+    /// [`BuilderConfig::set_builder_optimization_level`])
+    pub optimization_level: Option<i32>,
+    /// Optimization profiles, each with the shapes of every dynamic input
+    pub profiles: Vec<Vec<InputProfile>>,
+    /// Reduced precisions to enable
+    pub precision: PrecisionConfig,
+}
+
+/// Network definition for building TensorRT engines
+pub struct NetworkDefinition {
+    inner: *mut TrtxNetworkDefinition,
+    // Copies of constant weights, which TensorRT reads until the build
+    weights: RefCell<Vec<Box<[u8]>>>,
+}
+
+impl NetworkDefinition {
+    /// Mark the tensor `name` (an input or layer output) as a debug tensor
+    ///
+    /// Debug tensors are kept observable in the built engine and reported to
+    /// the listener set with [`ExecutionContext::set_debug_listener`], without
+    /// adding network outputs.
+    ///
+    /// [`ExecutionContext::set_debug_listener`]: crate::ExecutionContext::set_debug_listener
+    pub fn mark_debug(&mut self, name: &str) -> Result<()> {
+        let name_cstr = std::ffi::CString::new(name)?;
         let mut error_msg = [0i8; 1024];
 
         let result = unsafe {
-            trtx_builder_config_set_memory_pool_limit(
+            trtx_network_mark_debug(
                 self.inner,
-                pool as i32,
-                size,
+                name_cstr.as_ptr(),
                 error_msg.as_mut_ptr(),
                 error_msg.len(),
             )
@@ -75,40 +402,90 @@ impl BuilderConfig {
         Ok(())
     }
 
-    /// Get the raw pointer (for internal use)
-    pub(crate) fn as_ptr(&self) -> *mut TrtxBuilderConfig {
-        self.inner
+    /// Name the network; engines built from it report the name through
+    /// [`CudaEngine::get_name`]
+    ///
+    /// [`CudaEngine::get_name`]: crate::CudaEngine::get_name
+    pub fn set_name(&mut self, name: &str) -> Result<()> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_network_set_name(
+                self.inner,
+                name_cstr.as_ptr(),
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
     }
-}
 
-impl Drop for BuilderConfig {
-    fn drop(&mut self) {
-        if !self.inner.is_null() {
-            unsafe {
-                trtx_builder_config_destroy(self.inner);
-            }
+    /// Get the network's name
+    pub fn get_name(&self) -> Result<String> {
+        let mut name_ptr: *const i8 = std::ptr::null();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_network_get_name(
+                self.inner,
+                &mut name_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
         }
+
+        let name = unsafe { CStr::from_ptr(name_ptr) }.to_str()?.to_string();
+
+        Ok(name)
     }
-}
 
-unsafe impl Send for BuilderConfig {}
+    /// Get the [`network_flags`] the network was created with
+    pub fn get_flags(&self) -> Result<u32> {
+        let mut flags: u32 = 0;
+        let mut error_msg = [0i8; 1024];
 
-/// Builder for creating optimized TensorRT engines
-pub struct Builder<'a> {
-    inner: *mut TrtxBuilder,
-    _logger: &'a Logger,
-}
+        let result = unsafe {
+            trtx_network_get_flags(
+                self.inner,
+                &mut flags,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
 
-impl<'a> Builder<'a> {
-    /// Create a new builder
-    pub fn new(logger: &'a Logger) -> Result<Self> {
-        let mut builder_ptr: *mut TrtxBuilder = std::ptr::null_mut();
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(flags)
+    }
+
+    /// Pin the type of tensor `name`, produced by a layer of the network
+    ///
+    /// Use it to keep accuracy-sensitive tensors, such as softmax or
+    /// normalization outputs, in [`DataType::Float`] while the rest of the
+    /// network runs in reduced precision. TensorRT only honors the type when
+    /// the config has [`BuilderFlag::ObeyPrecisionConstraints`], set by
+    /// [`PrecisionConfig::obey_constraints`]. Fails for network inputs.
+    pub fn set_output_type(&mut self, name: &str, dtype: DataType) -> Result<()> {
+        let name_cstr = std::ffi::CString::new(name)?;
         let mut error_msg = [0i8; 1024];
 
         let result = unsafe {
-            trtx_builder_create(
-                logger.as_ptr(),
-                &mut builder_ptr,
+            trtx_network_set_output_type(
+                self.inner,
+                name_cstr.as_ptr(),
+                dtype as i32,
                 error_msg.as_mut_ptr(),
                 error_msg.len(),
             )
@@ -118,22 +495,20 @@ impl<'a> Builder<'a> {
             return Err(Error::from_ffi(result, &error_msg));
         }
 
-        Ok(Builder {
-            inner: builder_ptr,
-            _logger: logger,
-        })
+        Ok(())
     }
 
-    /// Create a network definition
-    pub fn create_network(&self, flags: u32) -> Result<NetworkDefinition> {
-        let mut network_ptr: *mut TrtxNetworkDefinition = std::ptr::null_mut();
+    /// Get the type pinned on tensor `name` with [`set_output_type`](Self::set_output_type)
+    pub fn get_output_type(&self, name: &str) -> Result<DataType> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut dtype: i32 = 0;
         let mut error_msg = [0i8; 1024];
 
         let result = unsafe {
-            trtx_builder_create_network(
+            trtx_network_get_output_type(
                 self.inner,
-                flags,
-                &mut network_ptr,
+                name_cstr.as_ptr(),
+                &mut dtype,
                 error_msg.as_mut_ptr(),
                 error_msg.len(),
             )
@@ -143,18 +518,23 @@ impl<'a> Builder<'a> {
             return Err(Error::from_ffi(result, &error_msg));
         }
 
-        Ok(NetworkDefinition { inner: network_ptr })
+        DataType::from_raw(dtype)
     }
 
-    /// Create a builder configuration
-    pub fn create_config(&self) -> Result<BuilderConfig> {
-        let mut config_ptr: *mut TrtxBuilderConfig = std::ptr::null_mut();
+    /// Pin the precision layer `layer_name` computes in
+    ///
+    /// Like [`set_output_type`](Self::set_output_type), only honored with
+    /// [`BuilderFlag::ObeyPrecisionConstraints`]. A reduced precision must
+    /// also be enabled on the config, e.g. with [`PrecisionConfig::fp16`].
+    pub fn set_layer_precision(&mut self, layer_name: &str, dtype: DataType) -> Result<()> {
+        let name_cstr = std::ffi::CString::new(layer_name)?;
         let mut error_msg = [0i8; 1024];
 
         let result = unsafe {
-            trtx_builder_create_builder_config(
+            trtx_network_set_layer_precision(
                 self.inner,
-                &mut config_ptr,
+                name_cstr.as_ptr(),
+                dtype as i32,
                 error_msg.as_mut_ptr(),
                 error_msg.len(),
             )
@@ -164,26 +544,20 @@ impl<'a> Builder<'a> {
             return Err(Error::from_ffi(result, &error_msg));
         }
 
-        Ok(BuilderConfig { inner: config_ptr })
+        Ok(())
     }
 
-    /// Build a serialized network (engine)
-    pub fn build_serialized_network(
-        &self,
-        network: &NetworkDefinition,
-        config: &BuilderConfig,
-    ) -> Result<Vec<u8>> {
-        let mut data_ptr: *mut std::ffi::c_void = std::ptr::null_mut();
-        let mut size: usize = 0;
+    /// Get the precision pinned on layer `layer_name`
+    pub fn get_layer_precision(&self, layer_name: &str) -> Result<DataType> {
+        let name_cstr = std::ffi::CString::new(layer_name)?;
+        let mut dtype: i32 = 0;
         let mut error_msg = [0i8; 1024];
 
         let result = unsafe {
-            trtx_builder_build_serialized_network(
+            trtx_network_get_layer_precision(
                 self.inner,
-                network.as_ptr(),
-                config.as_ptr(),
-                &mut data_ptr,
-                &mut size,
+                name_cstr.as_ptr(),
+                &mut dtype,
                 error_msg.as_mut_ptr(),
                 error_msg.len(),
             )
@@ -193,26 +567,2401 @@ impl<'a> Builder<'a> {
             return Err(Error::from_ffi(result, &error_msg));
         }
 
-        // Copy data to Vec and free C buffer
-        let data = unsafe {
-            let slice = std::slice::from_raw_parts(data_ptr as *const u8, size);
-            let vec = slice.to_vec();
-            trtx_free_buffer(data_ptr);
-            vec
+        DataType::from_raw(dtype)
+    }
+
+    /// Get the number of network inputs
+    pub fn get_nb_inputs(&self) -> Result<i32> {
+        let mut count: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_network_get_nb_inputs(
+                self.inner,
+                &mut count,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
         };
 
-        Ok(data)
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(count)
     }
-}
 
-impl Drop for Builder<'_> {
-    fn drop(&mut self) {
-        if !self.inner.is_null() {
-            unsafe {
-                trtx_builder_destroy(self.inner);
-            }
+    /// Get the name of the input at `index`
+    pub fn get_input_name(&self, index: i32) -> Result<String> {
+        let mut name_ptr: *const i8 = std::ptr::null();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_network_get_input_name(
+                self.inner,
+                index,
+                &mut name_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
         }
+
+        let name = unsafe { CStr::from_ptr(name_ptr) }.to_str()?.to_string();
+
+        Ok(name)
     }
-}
 
-unsafe impl Send for Builder<'_> {}
+    /// Get the shape of the input at `index` (`-1` marks a dynamic dimension)
+    pub fn get_input_shape(&self, index: i32) -> Result<Dims> {
+        let mut dims = [0i64; TRTX_MAX_DIMS as usize];
+        let mut nb_dims: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_network_get_input_shape(
+                self.inner,
+                index,
+                dims.as_mut_ptr(),
+                dims.len() as i32,
+                &mut nb_dims,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(Dims::from(&dims[..nb_dims as usize]))
+    }
+
+    /// Add a network input of element type `dtype` (`-1` marks a dynamic dimension)
+    pub fn add_input(&self, name: &str, dtype: DataType, dims: &[i64]) -> Result<TensorRef<'_>> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut tensor_ptr: *mut TrtxTensor = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_network_add_input(
+                self.inner,
+                name_cstr.as_ptr(),
+                dtype as i32,
+                dims.as_ptr(),
+                dims.len() as i32,
+                &mut tensor_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(TensorRef::from_ptr(tensor_ptr))
+    }
+
+    /// Add a constant layer of shape `dims` holding `weights`
+    ///
+    /// The weights are copied into the network, which keeps them until it is
+    /// dropped. Fails with [`Error::InvalidArgument`] if the number of
+    /// weights does not match `dims`.
+    pub fn add_constant(&self, dims: &[i64], weights: &Weights) -> Result<LayerRef<'_>> {
+        let volume: i64 = dims.iter().product();
+        if volume != weights.count() as i64 {
+            return Err(Error::InvalidArgument(format!(
+                "Constant of shape {:?} needs {} weights, got {}",
+                dims,
+                volume,
+                weights.count()
+            )));
+        }
+
+        let data: Box<[u8]> = weights.as_bytes().into();
+        let mut layer_ptr: *mut TrtxLayer = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_network_add_constant(
+                self.inner,
+                dims.as_ptr(),
+                dims.len() as i32,
+                weights.dtype() as i32,
+                data.as_ptr() as *const std::ffi::c_void,
+                weights.count() as i64,
+                &mut layer_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        // Moving the box keeps the heap allocation TensorRT points into
+        self.weights.borrow_mut().push(data);
+
+        Ok(LayerRef::from_ptr(layer_ptr))
+    }
+
+    /// Add an activation layer applying `kind` to `input`
+    pub fn add_activation(
+        &self,
+        input: TensorRef<'_>,
+        kind: ActivationType,
+    ) -> Result<LayerRef<'_>> {
+        let mut layer_ptr: *mut TrtxLayer = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_network_add_activation(
+                self.inner,
+                input.as_ptr(),
+                kind as i32,
+                &mut layer_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(LayerRef::from_ptr(layer_ptr))
+    }
+
+    /// Add an elementwise layer computing `op` of `a` and `b`
+    pub fn add_elementwise(
+        &self,
+        a: TensorRef<'_>,
+        b: TensorRef<'_>,
+        op: ElementWiseOperation,
+    ) -> Result<LayerRef<'_>> {
+        let mut layer_ptr: *mut TrtxLayer = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_network_add_elementwise(
+                self.inner,
+                a.as_ptr(),
+                b.as_ptr(),
+                op as i32,
+                &mut layer_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(LayerRef::from_ptr(layer_ptr))
+    }
+
+    /// Add a matrix multiply layer computing `op_a(a) x op_b(b)`
+    pub fn add_matrix_multiply(
+        &self,
+        a: TensorRef<'_>,
+        op_a: MatrixOperation,
+        b: TensorRef<'_>,
+        op_b: MatrixOperation,
+    ) -> Result<LayerRef<'_>> {
+        let mut layer_ptr: *mut TrtxLayer = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_network_add_matrix_multiply(
+                self.inner,
+                a.as_ptr(),
+                op_a as i32,
+                b.as_ptr(),
+                op_b as i32,
+                &mut layer_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(LayerRef::from_ptr(layer_ptr))
+    }
+
+    /// Mark `tensor` as a network output
+    ///
+    /// Networks built layer by layer need at least one output; the ONNX
+    /// parser marks outputs itself.
+    pub fn mark_output(&self, tensor: TensorRef<'_>) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_network_mark_output(
+                self.inner,
+                tensor.as_ptr(),
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get the raw pointer (for internal use)
+    pub(crate) fn as_ptr(&self) -> *mut TrtxNetworkDefinition {
+        self.inner
+    }
+}
+
+impl Drop for NetworkDefinition {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe {
+                trtx_network_destroy(self.inner);
+            }
+        }
+    }
+}
+
+unsafe impl Send for NetworkDefinition {}
+
+/// Min, opt and max shapes of dynamic inputs (mirrors `nvinfer1::IOptimizationProfile`)
+///
+/// Created by [`Builder::create_optimization_profile`], which owns it.
+pub struct OptimizationProfile<'b> {
+    inner: *mut TrtxOptimizationProfile,
+    _builder: PhantomData<&'b ()>,
+}
+
+impl OptimizationProfile<'_> {
+    /// Set one of the shapes of input `name`
+    pub fn set_dimensions(
+        &mut self,
+        name: &str,
+        selector: OptProfileSelector,
+        dims: &[i64],
+    ) -> Result<()> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_optimization_profile_set_dimensions(
+                self.inner,
+                name_cstr.as_ptr(),
+                selector as i32,
+                dims.as_ptr(),
+                dims.len() as i32,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get one of the shapes of input `name`
+    pub fn get_dimensions(&self, name: &str, selector: OptProfileSelector) -> Result<Dims> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut dims = [0i64; TRTX_MAX_DIMS as usize];
+        let mut nb_dims: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_optimization_profile_get_dimensions(
+                self.inner,
+                name_cstr.as_ptr(),
+                selector as i32,
+                dims.as_mut_ptr(),
+                dims.len() as i32,
+                &mut nb_dims,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(Dims::from(&dims[..nb_dims as usize]))
+    }
+
+    /// Set the min, opt and max shapes of input `name` at once
+    pub fn set_shape(&mut self, name: &str, min: &[i64], opt: &[i64], max: &[i64]) -> Result<()> {
+        self.set_dimensions(name, OptProfileSelector::Min, min)?;
+        self.set_dimensions(name, OptProfileSelector::Opt, opt)?;
+        self.set_dimensions(name, OptProfileSelector::Max, max)
+    }
+}
+
+unsafe impl Send for OptimizationProfile<'_> {}
+
+/// Builder configuration
+pub struct BuilderConfig {
+    inner: *mut TrtxBuilderConfig,
+    progress_monitor: Option<Monitor>,
+    timing_cache: Option<TimingCache>,
+}
+
+impl BuilderConfig {
+    /// Set memory pool limit
+    pub fn set_memory_pool_limit(&mut self, pool: MemoryPoolType, size: usize) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_set_memory_pool_limit(
+                self.inner,
+                pool as i32,
+                size,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get the memory pool limit in bytes
+    pub fn get_memory_pool_limit(&self, pool: MemoryPoolType) -> Result<usize> {
+        let mut size: usize = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_get_memory_pool_limit(
+                self.inner,
+                pool as i32,
+                &mut size,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(size)
+    }
+
+    /// Enable a builder flag
+    pub fn set_flag(&mut self, flag: BuilderFlag) -> Result<()> {
+        self.update_flag(flag, true)
+    }
+
+    /// Disable a builder flag
+    pub fn clear_flag(&mut self, flag: BuilderFlag) -> Result<()> {
+        self.update_flag(flag, false)
+    }
+
+    /// Check whether a builder flag is enabled
+    pub fn get_flag(&self, flag: BuilderFlag) -> Result<bool> {
+        let mut enabled = false;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_get_flag(
+                self.inner,
+                flag as i32,
+                &mut enabled,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(enabled)
+    }
+
+    /// Enable or disable a preview feature
+    ///
+    /// Features the linked library does not know are ignored.
+    pub fn set_preview_feature(&mut self, feature: PreviewFeature, enabled: bool) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_set_preview_feature(
+                self.inner,
+                feature as i32,
+                enabled,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Check whether a preview feature is enabled
+    pub fn get_preview_feature(&self, feature: PreviewFeature) -> Result<bool> {
+        let mut enabled = false;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_get_preview_feature(
+                self.inner,
+                feature as i32,
+                &mut enabled,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(enabled)
+    }
+
+    /// Report build phases to `monitor`, replacing any previous monitor
+    pub fn set_progress_monitor(&mut self, monitor: Arc<dyn ProgressMonitor>) -> Result<()> {
+        let monitor = Monitor::new(monitor)?;
+        self.update_progress_monitor(monitor.as_ptr())?;
+        self.progress_monitor = Some(monitor);
+        Ok(())
+    }
+
+    /// Stop reporting build phases
+    pub fn clear_progress_monitor(&mut self) -> Result<()> {
+        self.update_progress_monitor(std::ptr::null_mut())?;
+        self.progress_monitor = None;
+        Ok(())
+    }
+
+    fn update_progress_monitor(&mut self, monitor: *mut TrtxProgressMonitor) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_set_progress_monitor(
+                self.inner,
+                monitor,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Create a timing cache from serialized data, or an empty one if `data` is empty
+    pub fn create_timing_cache(&self, data: &[u8]) -> Result<TimingCache> {
+        let mut cache_ptr: *mut TrtxTimingCache = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_create_timing_cache(
+                self.inner,
+                data.as_ptr() as *const std::ffi::c_void,
+                data.len(),
+                &mut cache_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(TimingCache::from_raw(cache_ptr))
+    }
+
+    /// Use `cache` for builds with this config, replacing any previous cache
+    ///
+    /// Builds read timings from the cache and add the ones they measure.
+    /// Fails if the cache was recorded on a different device, unless
+    /// `ignore_mismatch` is set.
+    pub fn set_timing_cache(&mut self, cache: TimingCache, ignore_mismatch: bool) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_set_timing_cache(
+                self.inner,
+                cache.as_ptr(),
+                ignore_mismatch,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        self.timing_cache = Some(cache);
+        Ok(())
+    }
+
+    /// Get the timing cache set with [`set_timing_cache`](Self::set_timing_cache)
+    pub fn get_timing_cache(&self) -> Option<&TimingCache> {
+        self.timing_cache.as_ref()
+    }
+
+    /// Limit how many auxiliary streams inference may use
+    ///
+    /// Auxiliary streams let independent branches of the network run
+    /// concurrently, at the cost of extra activation memory. `-1` (the
+    /// default) lets TensorRT decide; see [`Builder::recommended_aux_streams`]
+    /// for a value suited to the current device.
+    pub fn set_max_aux_streams(&mut self, nb_streams: i32) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_set_max_aux_streams(
+                self.inner,
+                nb_streams,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get the maximum number of auxiliary streams (`-1` if TensorRT decides)
+    pub fn get_max_aux_streams(&self) -> Result<i32> {
+        let mut nb_streams: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_get_max_aux_streams(
+                self.inner,
+                &mut nb_streams,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(nb_streams)
+    }
+
+    /// Set the platform the engine should run on
+    ///
+    /// Returns [`Error::InvalidArgument`] if the platform cannot be targeted from
+    /// this build host (see [`Builder::supported_runtime_platforms`]).
+    pub fn set_runtime_platform(&mut self, platform: RuntimePlatform) -> Result<()> {
+        let supported = supported_runtime_platforms();
+        if !supported.contains(&platform) {
+            return Err(Error::InvalidArgument(format!(
+                "Runtime platform {:?} is not supported on this build host (supported: {:?})",
+                platform, supported
+            )));
+        }
+
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_set_runtime_platform(
+                self.inner,
+                platform as i32,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get the platform the engine will run on
+    pub fn get_runtime_platform(&self) -> Result<RuntimePlatform> {
+        let mut platform: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_get_runtime_platform(
+                self.inner,
+                &mut platform,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        RuntimePlatform::from_raw(platform)
+    }
+
+    /// Set what the engine may be used for
+    ///
+    /// Anything but [`EngineCapability::Standard`] produces an engine the
+    /// standard [`Runtime`](crate::Runtime) cannot run; see
+    /// [`EngineCapability`] for the restrictions each capability brings.
+    pub fn set_engine_capability(&mut self, capability: EngineCapability) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_set_engine_capability(
+                self.inner,
+                capability as i32,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get what the engine may be used for
+    pub fn get_engine_capability(&self) -> Result<EngineCapability> {
+        let mut capability: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_get_engine_capability(
+                self.inner,
+                &mut capability,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        EngineCapability::from_raw(capability)
+    }
+
+    /// Set how long the builder searches for fast kernels
+    ///
+    /// Levels go from 0 (fastest build) to 5 (slowest build, fastest engine
+    /// in most cases); the default is 3. Fails with
+    /// [`Error::InvalidArgument`] for levels outside that range.
+    pub fn set_builder_optimization_level(&mut self, level: i32) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_set_builder_optimization_level(
+                self.inner,
+                level,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get the builder optimization level
+    pub fn get_builder_optimization_level(&self) -> Result<i32> {
+        let mut level: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_get_builder_optimization_level(
+                self.inner,
+                &mut level,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(level)
+    }
+
+    /// Set how much layer detail the engine keeps for the
+    /// [`EngineInspector`](crate::EngineInspector)
+    ///
+    /// Per-layer tactics and precisions are only reported for engines built
+    /// with [`ProfilingVerbosity::Detailed`].
+    pub fn set_profiling_verbosity(&mut self, verbosity: ProfilingVerbosity) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_set_profiling_verbosity(
+                self.inner,
+                verbosity as i32,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Add an optimization profile, returning its index
+    ///
+    /// Fails with [`Error::InvalidArgument`] if an input lacks a shape or its
+    /// shapes do not satisfy min <= opt <= max.
+    pub fn add_optimization_profile(&mut self, profile: &OptimizationProfile) -> Result<i32> {
+        let mut index: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_add_optimization_profile(
+                self.inner,
+                profile.inner,
+                &mut index,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(index)
+    }
+
+    /// Get the number of optimization profiles added to this configuration
+    pub fn get_nb_optimization_profiles(&self) -> Result<i32> {
+        let mut count: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_get_nb_optimization_profiles(
+                self.inner,
+                &mut count,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(count)
+    }
+
+    /// Configure the build for LLM inference on RTX GPUs
+    ///
+    /// Enables FP16, FP8 on compute capability 8.9 and newer, and weight
+    /// streaming, then adds an optimization profile covering every dynamic
+    /// input of `network`: the first dimension is the batch (1 to
+    /// `max_batch`) and the other dynamic dimensions are the sequence (1 to
+    /// `max_seq_len`, tuned for half of it). Returns the profile's index.
+    ///
+    /// Profiles are created by the builder, hence the `builder` argument. The
+    /// weight streaming budget is set after deserialization with
+    /// [`CudaEngine::set_weight_streaming_budget`].
+    ///
+    /// [`CudaEngine::set_weight_streaming_budget`]: crate::CudaEngine::set_weight_streaming_budget
+    pub fn llm_preset(
+        &mut self,
+        builder: &Builder,
+        network: &NetworkDefinition,
+        max_seq_len: i64,
+        max_batch: i64,
+    ) -> Result<i32> {
+        if max_seq_len < 1 || max_batch < 1 {
+            return Err(Error::InvalidArgument(format!(
+                "max_seq_len and max_batch must be at least 1 (got {} and {})",
+                max_seq_len, max_batch
+            )));
+        }
+
+        self.set_flag(BuilderFlag::Fp16)?;
+        if has_fast_precision(BuilderFlag::Fp8, compute_capability()?) {
+            self.set_flag(BuilderFlag::Fp8)?;
+        }
+        self.set_flag(BuilderFlag::WeightStreaming)?;
+
+        let mut profile = builder.create_optimization_profile()?;
+        for index in 0..network.get_nb_inputs()? {
+            let shape = network.get_input_shape(index)?;
+            if !shape.contains(&-1) {
+                continue;
+            }
+
+            let (mut min, mut opt, mut max) = (shape.clone(), shape.clone(), shape);
+            for dim in 0..max.len() {
+                if max[dim] != -1 {
+                    continue;
+                }
+                let (lo, mid, hi) = if dim == 0 {
+                    (1, max_batch, max_batch)
+                } else {
+                    (1, (max_seq_len + 1) / 2, max_seq_len)
+                };
+                min[dim] = lo;
+                opt[dim] = mid;
+                max[dim] = hi;
+            }
+            profile.set_shape(&network.get_input_name(index)?, &min, &opt, &max)?;
+        }
+
+        self.add_optimization_profile(&profile)
+    }
+
+    /// Apply every setting of a [`ModelConfig`]
+    ///
+    /// Precisions go through [`apply_precision`](Self::apply_precision), and
+    /// each profile is added as a new optimization profile, hence the
+    /// `builder` argument.
+    pub fn apply(&mut self, builder: &Builder, model: &ModelConfig) -> Result<()> {
+        if let Some(workspace) = model.workspace {
+            self.set_memory_pool_limit(MemoryPoolType::Workspace, workspace)?;
+        }
+        if let Some(level) = model.optimization_level {
+            self.set_builder_optimization_level(level)?;
+        }
+        if !model.precision.flags().is_empty() {
+            self.apply_precision(builder, &model.precision)?;
+        }
+        for &flag in &model.flags {
+            self.set_flag(flag)?;
+        }
+        for shapes in &model.profiles {
+            let mut profile = builder.create_optimization_profile()?;
+            for input in shapes {
+                profile.set_shape(&input.name, &input.min, &input.opt, &input.max)?;
+            }
+            self.add_optimization_profile(&profile)?;
+        }
+        Ok(())
+    }
+
+    /// Set the flags of a [`PrecisionConfig`]
+    ///
+    /// Precisions the device has no fast kernels for are skipped, with a
+    /// warning through the builder's logger unless requested by
+    /// [`PrecisionConfig::best`].
+    pub fn apply_precision(
+        &mut self,
+        builder: &Builder,
+        precision: &PrecisionConfig,
+    ) -> Result<()> {
+        self.apply_precision_for(builder.logger, precision, compute_capability()?)
+    }
+
+    fn apply_precision_for(
+        &mut self,
+        logger: &Logger,
+        precision: &PrecisionConfig,
+        capability: (i32, i32),
+    ) -> Result<()> {
+        for &flag in &precision.flags {
+            if has_fast_precision(flag, capability) {
+                self.set_flag(flag)?;
+            } else if !precision.supported_only {
+                logger.log(
+                    Severity::Warning,
+                    &format!(
+                        "{:?} requested but not supported on compute capability {}.{}; skipping",
+                        flag, capability.0, capability.1
+                    ),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn update_flag(&mut self, flag: BuilderFlag, enabled: bool) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_config_set_flag(
+                self.inner,
+                flag as i32,
+                enabled,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get the raw pointer (for internal use)
+    pub(crate) fn as_ptr(&self) -> *mut TrtxBuilderConfig {
+        self.inner
+    }
+}
+
+impl Drop for BuilderConfig {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe {
+                trtx_builder_config_destroy(self.inner);
+            }
+        }
+    }
+}
+
+unsafe impl Send for BuilderConfig {}
+
+/// Builder for creating optimized TensorRT engines
+pub struct Builder<'a> {
+    inner: *mut TrtxBuilder,
+    logger: &'a Logger,
+    error_recorder: Option<ErrorRecorder>,
+    // Device made current before building, if created with new_on_device
+    device: Option<i32>,
+}
+
+impl<'a> Builder<'a> {
+    /// Create a new builder
+    ///
+    /// Fails with [`Error::CudaNotInitialized`] if no CUDA context can be
+    /// created (see [`ensure_context`](crate::cuda::ensure_context)).
+    pub fn new(logger: &'a Logger) -> Result<Self> {
+        crate::cuda::ensure_context()?;
+        let mut builder_ptr: *mut TrtxBuilder = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_create(
+                logger.as_ptr(),
+                &mut builder_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(Builder {
+            inner: builder_ptr,
+            logger,
+            error_recorder: None,
+            device: None,
+        })
+    }
+
+    /// Create a new builder on a chosen CUDA device
+    ///
+    /// Makes `device` current before creating the builder and again before
+    /// each build, so the builder cannot end up on a different device than
+    /// the [`Runtime`](crate::Runtime) that loads its engines. Buffer
+    /// allocations and enqueues for the built engine must also happen with
+    /// `device` current; see [`set_device`](crate::cuda::set_device).
+    pub fn new_on_device(logger: &'a Logger, device: i32) -> Result<Self> {
+        crate::cuda::set_device(device)?;
+        let mut builder = Self::new(logger)?;
+        builder.device = Some(device);
+        Ok(builder)
+    }
+
+    /// Get the device this builder was created on with
+    /// [`new_on_device`](Self::new_on_device)
+    pub fn device(&self) -> Option<i32> {
+        self.device
+    }
+
+    /// Reset the builder to its default state so it can build another network
+    ///
+    /// Creating a builder is expensive; tools building many models can reuse
+    /// one by resetting it between builds. The error recorder set with
+    /// [`set_error_recorder`](Self::set_error_recorder) stays attached.
+    ///
+    /// # Safety
+    ///
+    /// [`NetworkDefinition`]s and [`BuilderConfig`]s created before the reset
+    /// become invalid, so all of them must be dropped before calling this.
+    pub unsafe fn reset(&mut self) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result =
+            unsafe { trtx_builder_reset(self.inner, error_msg.as_mut_ptr(), error_msg.len()) };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Limit how many threads building may use
+    ///
+    /// Defaults to 1 (the calling thread only). Fails with
+    /// [`Error::InvalidArgument`] if `nb_threads` is less than 1.
+    pub fn set_max_threads(&mut self, nb_threads: i32) -> Result<()> {
+        if nb_threads < 1 {
+            return Err(Error::InvalidArgument(format!(
+                "Thread count must be at least 1, got {}",
+                nb_threads
+            )));
+        }
+
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_set_max_threads(
+                self.inner,
+                nb_threads,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get the maximum number of threads building may use
+    pub fn get_max_threads(&self) -> Result<i32> {
+        let mut nb_threads: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_get_max_threads(
+                self.inner,
+                &mut nb_threads,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(nb_threads)
+    }
+
+    /// Create a network definition
+    pub fn create_network(&self, flags: u32) -> Result<NetworkDefinition> {
+        let mut network_ptr: *mut TrtxNetworkDefinition = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_create_network(
+                self.inner,
+                flags,
+                &mut network_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(NetworkDefinition {
+            inner: network_ptr,
+            weights: RefCell::new(Vec::new()),
+        })
+    }
+
+    /// Create an optimization profile to fill in and add to a [`BuilderConfig`]
+    pub fn create_optimization_profile(&self) -> Result<OptimizationProfile<'_>> {
+        let mut profile_ptr: *mut TrtxOptimizationProfile = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_create_optimization_profile(
+                self.inner,
+                &mut profile_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(OptimizationProfile {
+            inner: profile_ptr,
+            _builder: PhantomData,
+        })
+    }
+
+    /// Create a builder configuration
+    pub fn create_config(&self) -> Result<BuilderConfig> {
+        let mut config_ptr: *mut TrtxBuilderConfig = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_create_builder_config(
+                self.inner,
+                &mut config_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(BuilderConfig {
+            inner: config_ptr,
+            progress_monitor: None,
+            timing_cache: None,
+        })
+    }
+
+    /// Route TensorRT errors from this builder to `recorder`
+    ///
+    /// When a build fails, the recorded errors are drained into
+    /// [`Error::Recorded`] instead of only going to the logger.
+    pub fn set_error_recorder(&mut self, recorder: &ErrorRecorder) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_set_error_recorder(
+                self.inner,
+                recorder.as_ptr(),
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        self.error_recorder = Some(recorder.clone());
+        Ok(())
+    }
+
+    /// Suggest a [`BuilderConfig::set_max_aux_streams`] value for the current device
+    ///
+    /// A heuristic: one auxiliary stream per 16 SMs beyond the first 16, at
+    /// most one more than the device's async engine count and at most 4. GPUs
+    /// that cannot run kernels concurrently get 0.
+    pub fn recommended_aux_streams(&self) -> Result<i32> {
+        if get_attribute(DeviceAttribute::ConcurrentKernels)? == 0 {
+            return Ok(0);
+        }
+
+        let sm_count = get_attribute(DeviceAttribute::MultiProcessorCount)?;
+        let async_engines = get_attribute(DeviceAttribute::AsyncEngineCount)?;
+        let streams = (sm_count / SMS_PER_AUX_STREAM - 1).min(async_engines + 1);
+        Ok(streams.clamp(0, MAX_RECOMMENDED_AUX_STREAMS))
+    }
+
+    /// List the runtime platforms engines can be built for from this host
+    pub fn supported_runtime_platforms(&self) -> Result<Vec<RuntimePlatform>> {
+        Ok(supported_runtime_platforms())
+    }
+
+    /// Build a serialized network (engine)
+    pub fn build_serialized_network(
+        &self,
+        network: &NetworkDefinition,
+        config: &BuilderConfig,
+    ) -> Result<Vec<u8>> {
+        if let Some(device) = self.device {
+            crate::cuda::set_device(device)?;
+        }
+
+        let mut data_ptr: *mut std::ffi::c_void = std::ptr::null_mut();
+        let mut size: usize = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_builder_build_serialized_network(
+                self.inner,
+                network.as_ptr(),
+                config.as_ptr(),
+                &mut data_ptr,
+                &mut size,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            let error = Error::from_ffi(result, &error_msg);
+            return Err(match &self.error_recorder {
+                Some(recorder) => error.with_recorded(recorder.drain()?),
+                None => error,
+            });
+        }
+
+        // Copy data to Vec and free C buffer
+        let data = unsafe {
+            let slice = std::slice::from_raw_parts(data_ptr as *const u8, size);
+            let vec = slice.to_vec();
+            trtx_free_buffer(data_ptr);
+            vec
+        };
+
+        Ok(data)
+    }
+
+    /// Build a serialized network and report its [`BuildStats`]
+    pub fn build_serialized_network_with_stats(
+        &self,
+        network: &NetworkDefinition,
+        config: &BuilderConfig,
+    ) -> Result<(Vec<u8>, BuildStats)> {
+        let workspace_limit = config.get_memory_pool_limit(MemoryPoolType::Workspace)?;
+        let start = Instant::now();
+        let engine = self.build_serialized_network(network, config)?;
+        let stats = BuildStats {
+            duration: start.elapsed(),
+            engine_size: engine.len(),
+            workspace_limit,
+        };
+        Ok((engine, stats))
+    }
+
+    /// Build a serialized network and report how long each build phase took
+    ///
+    /// Phases are keyed by the names TensorRT reports, nested phases included.
+    /// Any progress monitor on `config` is suspended for the build and
+    /// restored afterwards.
+    pub fn build_serialized_network_timed(
+        &self,
+        network: &NetworkDefinition,
+        config: &mut BuilderConfig,
+    ) -> Result<(Vec<u8>, HashMap<String, Duration>)> {
+        let previous = config.progress_monitor.as_ref().map(Monitor::handler);
+        let timer = Arc::new(PhaseTimer::new());
+        config.set_progress_monitor(timer.clone())?;
+
+        let result = self.build_serialized_network(network, config);
+
+        match previous {
+            Some(monitor) => config.set_progress_monitor(monitor)?,
+            None => config.clear_progress_monitor()?,
+        }
+
+        Ok((result?, timer.timings()))
+    }
+
+    /// Build a serialized network that can be aborted from another thread
+    ///
+    /// Once `cancel` is set, the build stops at the next progress step and
+    /// fails with [`Error::Cancelled`]. Any progress monitor on `config` keeps
+    /// receiving updates during the build. A build that completes before it
+    /// sees the flag still returns the engine.
+    pub fn build_serialized_network_cancellable(
+        &self,
+        network: &NetworkDefinition,
+        config: &mut BuilderConfig,
+        cancel: Arc<AtomicBool>,
+    ) -> Result<Vec<u8>> {
+        let previous = config.progress_monitor.as_ref().map(Monitor::handler);
+        config.set_progress_monitor(Arc::new(Cancellation::new(
+            cancel.clone(),
+            previous.clone(),
+        )))?;
+
+        let result = self.build_serialized_network(network, config);
+
+        match previous {
+            Some(monitor) => config.set_progress_monitor(monitor)?,
+            None => config.clear_progress_monitor()?,
+        }
+
+        match result {
+            Err(_) if cancel.load(Ordering::Relaxed) => Err(Error::Cancelled),
+            result => result,
+        }
+    }
+
+    /// Build a serialized network, halving the workspace on out-of-memory
+    ///
+    /// Starts from the workspace limit set on `config`. Each time the build
+    /// runs out of device memory, a warning is logged and the build is retried
+    /// with half the workspace, until it would drop below `min_workspace`; the
+    /// last error is then returned. On success `config` keeps the workspace
+    /// that worked.
+    pub fn build_with_workspace_fallback(
+        &self,
+        network: &NetworkDefinition,
+        config: &mut BuilderConfig,
+        min_workspace: usize,
+    ) -> Result<Vec<u8>> {
+        let mut workspace = config.get_memory_pool_limit(MemoryPoolType::Workspace)?;
+        loop {
+            match self.build_serialized_network(network, config) {
+                Err(error) if is_out_of_memory(&error) && workspace / 2 >= min_workspace.max(1) => {
+                    workspace /= 2;
+                    self.logger.log(
+                        Severity::Warning,
+                        &format!(
+                            "Out of memory building engine ({}); retrying with a {} byte workspace",
+                            error, workspace
+                        ),
+                    );
+                    config.set_memory_pool_limit(MemoryPoolType::Workspace, workspace)?;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Whether a build failed for lack of device memory
+fn is_out_of_memory(error: &Error) -> bool {
+    match error {
+        Error::OutOfMemory(_) => true,
+        Error::Recorded { errors, .. } => errors
+            .iter()
+            .any(|e| e.code == crate::ErrorCode::FailedAllocation),
+        _ => false,
+    }
+}
+
+impl Drop for Builder<'_> {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe {
+                trtx_builder_destroy(self.inner);
+            }
+        }
+    }
+}
+
+unsafe impl Send for Builder<'_> {}
+
+/// Input shapes of an [`EngineBuilder`] profile: name, min, opt and max
+type ProfileShape = (String, Vec<i64>, Vec<i64>, Vec<i64>);
+
+/// Fluent builder turning an ONNX model into a serialized engine
+///
+/// Runs the builder, network, parser, config and build steps in one go:
+///
+/// ```no_run
+/// # use trtx::EngineBuilder;
+/// # let onnx = std::fs::read("model.onnx")?;
+/// let engine_data = EngineBuilder::from_onnx(&onnx)
+///     .fp16()
+///     .workspace(1 << 30)
+///     .optimization_profile("input", &[1, 3, 224, 224], &[8, 3, 224, 224], &[32, 3, 224, 224])
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Use [`Builder`] directly for anything not covered here.
+pub struct EngineBuilder<'a> {
+    onnx: &'a [u8],
+    logger: Option<&'a Logger>,
+    precision: PrecisionConfig,
+    flags: Vec<BuilderFlag>,
+    workspace: Option<usize>,
+    shapes: Vec<ProfileShape>,
+    pinned: Vec<(String, Dims)>,
+}
+
+impl<'a> EngineBuilder<'a> {
+    /// Start building an engine from ONNX model bytes
+    pub fn from_onnx(onnx: &'a [u8]) -> Self {
+        EngineBuilder {
+            onnx,
+            logger: None,
+            precision: PrecisionConfig::new(),
+            flags: Vec::new(),
+            workspace: None,
+            shapes: Vec::new(),
+            pinned: Vec::new(),
+        }
+    }
+
+    /// Log through `logger` instead of a [`Logger::stderr`] created for the build
+    pub fn logger(mut self, logger: &'a Logger) -> Self {
+        self.logger = Some(logger);
+        self
+    }
+
+    /// Allow FP16 kernels (see [`PrecisionConfig::fp16`])
+    pub fn fp16(mut self) -> Self {
+        self.precision = self.precision.fp16();
+        self
+    }
+
+    /// Replace the precisions to enable
+    pub fn precision(mut self, precision: PrecisionConfig) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Enable a builder flag
+    pub fn flag(mut self, flag: BuilderFlag) -> Self {
+        if !self.flags.contains(&flag) {
+            self.flags.push(flag);
+        }
+        self
+    }
+
+    /// Limit the build workspace to `bytes`
+    pub fn workspace(mut self, bytes: usize) -> Self {
+        self.workspace = Some(bytes);
+        self
+    }
+
+    /// Set the min, opt and max shapes of dynamic input `input`
+    ///
+    /// Call once per dynamic input; all shapes go into a single
+    /// optimization profile.
+    pub fn optimization_profile(
+        mut self,
+        input: &str,
+        min: &[i64],
+        opt: &[i64],
+        max: &[i64],
+    ) -> Self {
+        self.shapes
+            .push((input.to_string(), min.to_vec(), opt.to_vec(), max.to_vec()));
+        self
+    }
+
+    /// Fix the dynamic dimensions of input `input` to `dims`
+    ///
+    /// For models exported with free dimensions (e.g. batch or height and
+    /// width) when only one size is needed: the input gets `dims` as its min,
+    /// opt and max shape in the optimization profile, without editing the
+    /// model. `dims` must keep the model's rank and its static dimensions;
+    /// [`build`](Self::build) fails with [`Error::InvalidArgument`] otherwise,
+    /// or if `input` also has an [`optimization_profile`](Self::optimization_profile).
+    pub fn with_input_shape(mut self, input: &str, dims: impl Into<Dims>) -> Self {
+        self.pinned.push((input.to_string(), dims.into()));
+        self
+    }
+
+    /// Build the engine and return its serialized form
+    ///
+    /// Fails with [`Error::InvalidArgument`] if the bytes are not an ONNX
+    /// model.
+    pub fn build(self) -> Result<Vec<u8>> {
+        Ok(self.build_with_precisions(false)?.engine)
+    }
+
+    /// Build the engine, falling back to FP32 if the requested precision fails
+    ///
+    /// Builds with the requested [`precision`](Self::precision) first. If
+    /// that build fails, e.g. because an op has no FP16 implementation, the
+    /// precision flags are cleared and the build retried once in full FP32,
+    /// logging the downgrade as a warning. Errors before the build itself,
+    /// such as an invalid model, are returned without retrying, and so is
+    /// running out of device memory: an FP32 build needs more memory, not
+    /// less. Use [`Builder::build_with_workspace_fallback`] to retry with a
+    /// smaller workspace instead.
+    pub fn build_with_precision_fallback(self) -> Result<FallbackBuild> {
+        self.build_with_precisions(true)
+    }
+
+    fn build_with_precisions(self, fallback: bool) -> Result<FallbackBuild> {
+        crate::executor::check_onnx(self.onnx)?;
+
+        let stderr_logger;
+        let logger = match self.logger {
+            Some(logger) => logger,
+            None => {
+                stderr_logger = Logger::stderr()?;
+                &stderr_logger
+            }
+        };
+
+        let builder = Builder::new(logger)?;
+        let network = builder.create_network(network_flags::EXPLICIT_BATCH)?;
+        let parser = crate::OnnxParser::new(&network, logger)?;
+        parser.parse(self.onnx)?;
+
+        let error = match self.build_network(&builder, &network, &self.precision) {
+            Ok(engine) => {
+                return Ok(FallbackBuild {
+                    engine,
+                    precision: self.precision,
+                    fell_back: false,
+                })
+            }
+            // Configuration mistakes and cancellation fail at any precision,
+            // and FP32 would only need more memory
+            Err(error @ (Error::InvalidArgument(_) | Error::Cancelled)) => return Err(error),
+            Err(error) if is_out_of_memory(&error) => return Err(error),
+            Err(error) if !fallback || self.precision.flags().is_empty() => return Err(error),
+            Err(error) => error,
+        };
+
+        logger.log(
+            Severity::Warning,
+            &format!(
+                "Building with {:?} failed ({}); retrying in FP32",
+                self.precision.flags(),
+                error
+            ),
+        );
+        let precision = PrecisionConfig::new();
+        let engine = self.build_network(&builder, &network, &precision)?;
+        Ok(FallbackBuild {
+            engine,
+            precision,
+            fell_back: true,
+        })
+    }
+
+    /// Configure and run one build of the parsed `network` at `precision`
+    fn build_network(
+        &self,
+        builder: &Builder,
+        network: &NetworkDefinition,
+        precision: &PrecisionConfig,
+    ) -> Result<Vec<u8>> {
+        let mut config = builder.create_config()?;
+        if let Some(workspace) = self.workspace {
+            config.set_memory_pool_limit(MemoryPoolType::Workspace, workspace)?;
+        }
+        if !precision.flags().is_empty() {
+            config.apply_precision(builder, precision)?;
+        }
+        for &flag in &self.flags {
+            config.set_flag(flag)?;
+        }
+        if !self.shapes.is_empty() || !self.pinned.is_empty() {
+            let mut profile = builder.create_optimization_profile()?;
+            for (input, min, opt, max) in &self.shapes {
+                profile.set_shape(input, min, opt, max)?;
+            }
+            for (input, dims) in &self.pinned {
+                if self.shapes.iter().any(|(name, ..)| name == input) {
+                    return Err(Error::InvalidArgument(format!(
+                        "Input '{}' has both a pinned shape and an optimization profile",
+                        input
+                    )));
+                }
+                check_pinned_shape(network, input, dims)?;
+                profile.set_shape(input, dims, dims, dims)?;
+            }
+            config.add_optimization_profile(&profile)?;
+        }
+
+        builder.build_serialized_network(network, &config)
+    }
+}
+
+/// Engine built by [`EngineBuilder::build_with_precision_fallback`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FallbackBuild {
+    /// The serialized engine
+    pub engine: Vec<u8>,
+    /// Precision the engine was built with: the requested one, or full FP32
+    /// after a fallback
+    pub precision: PrecisionConfig,
+    /// Whether the requested precision failed and the engine is FP32
+    pub fell_back: bool,
+}
+
+/// Statistics of a build by [`Builder::build_serialized_network_with_stats`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildStats {
+    /// Wall-clock time of the build
+    pub duration: Duration,
+    /// Size of the serialized engine in bytes
+    pub engine_size: usize,
+    /// Workspace limit the build ran with, in bytes
+    ///
+    /// An upper bound on the workspace the builder used: TensorRT does not
+    /// report the peak it actually reached.
+    pub workspace_limit: usize,
+}
+
+/// Check that `dims` only fills in dynamic dimensions of network input `input`
+fn check_pinned_shape(network: &NetworkDefinition, input: &str, dims: &Dims) -> Result<()> {
+    for index in 0..network.get_nb_inputs()? {
+        if network.get_input_name(index)? != input {
+            continue;
+        }
+        let declared = network.get_input_shape(index)?;
+        let fits = !dims.is_dynamic()
+            && declared.len() == dims.len()
+            && declared
+                .iter()
+                .zip(dims.iter())
+                .all(|(&model, &pinned)| model < 0 || model == pinned);
+        if !fits {
+            return Err(Error::InvalidArgument(format!(
+                "Cannot pin input '{}' of shape {} to {}",
+                input, declared, dims
+            )));
+        }
+        return Ok(());
+    }
+
+    Err(Error::InvalidArgument(format!(
+        "Model has no input '{}'",
+        input
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_flags() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let mut config = builder.create_config().unwrap();
+
+        assert!(!config.get_flag(BuilderFlag::VersionCompatible).unwrap());
+        config.set_flag(BuilderFlag::VersionCompatible).unwrap();
+        assert!(config.get_flag(BuilderFlag::VersionCompatible).unwrap());
+        config.clear_flag(BuilderFlag::VersionCompatible).unwrap();
+        assert!(!config.get_flag(BuilderFlag::VersionCompatible).unwrap());
+
+        for flag in [
+            BuilderFlag::DisableTimingCache,
+            BuilderFlag::SparseWeights,
+            BuilderFlag::DirectIo,
+        ] {
+            assert!(!config.get_flag(flag).unwrap(), "{:?}", flag);
+            config.set_flag(flag).unwrap();
+            assert!(config.get_flag(flag).unwrap(), "{:?}", flag);
+            config.clear_flag(flag).unwrap();
+            assert!(!config.get_flag(flag).unwrap(), "{:?}", flag);
+        }
+    }
+
+    #[test]
+    fn test_optimization_profile() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let mut config = builder.create_config().unwrap();
+        let mut profile = builder.create_optimization_profile().unwrap();
+
+        profile
+            .set_shape("input", &[1, 4], &[8, 4], &[16, 4])
+            .unwrap();
+        assert_eq!(
+            profile
+                .get_dimensions("input", OptProfileSelector::Opt)
+                .unwrap(),
+            vec![8, 4]
+        );
+        assert_eq!(config.add_optimization_profile(&profile).unwrap(), 0);
+
+        let mut inverted = builder.create_optimization_profile().unwrap();
+        inverted
+            .set_shape("input", &[16, 4], &[8, 4], &[1, 4])
+            .unwrap();
+        assert!(config.add_optimization_profile(&inverted).is_err());
+    }
+
+    #[test]
+    fn test_mark_debug() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let mut network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+
+        #[cfg(feature = "mock")]
+        network.mark_debug("input").unwrap();
+        assert!(network.mark_debug("no_such_tensor").is_err());
+    }
+
+    #[test]
+    fn test_set_output_type() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let mut network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+
+        #[cfg(feature = "mock")]
+        {
+            network.set_output_type("output", DataType::Half).unwrap();
+            assert_eq!(network.get_output_type("output").unwrap(), DataType::Half);
+            network
+                .set_layer_precision("mock_layer", DataType::Float)
+                .unwrap();
+            assert_eq!(
+                network.get_layer_precision("mock_layer").unwrap(),
+                DataType::Float
+            );
+        }
+        // Network inputs are not produced by a layer
+        assert!(network.set_output_type("input", DataType::Float).is_err());
+        assert!(network
+            .set_layer_precision("no_such_layer", DataType::Float)
+            .is_err());
+    }
+
+    #[test]
+    fn test_strongly_typed_network() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH | network_flags::STRONGLY_TYPED)
+            .unwrap();
+        assert_ne!(
+            network.get_flags().unwrap() & network_flags::STRONGLY_TYPED,
+            0
+        );
+
+        let weakly_typed = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+        assert_eq!(
+            weakly_typed.get_flags().unwrap() & network_flags::STRONGLY_TYPED,
+            0
+        );
+
+        // Precision flags are ignored, so constraints that cannot be met in
+        // FP16 do not fail the build
+        #[cfg(feature = "mock")]
+        {
+            let mut config = builder.create_config().unwrap();
+            config.set_flag(BuilderFlag::Fp16).unwrap();
+            config
+                .set_flag(BuilderFlag::ObeyPrecisionConstraints)
+                .unwrap();
+            assert!(builder.build_serialized_network(&network, &config).is_ok());
+            assert!(builder
+                .build_serialized_network(&weakly_typed, &config)
+                .is_err());
+            assert!(builder.create_network(1 << 5).is_err());
+        }
+    }
+
+    #[test]
+    fn test_engine_name_round_trip() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+
+        let mut named = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+        named.set_name("resnet50-v2").unwrap();
+        assert_eq!(named.get_name().unwrap(), "resnet50-v2");
+
+        #[cfg(feature = "mock")]
+        {
+            let config = builder.create_config().unwrap();
+            let runtime = crate::Runtime::new(&logger).unwrap();
+            let plan = builder.build_serialized_network(&named, &config).unwrap();
+            let engine = runtime.deserialize_cuda_engine(&plan).unwrap();
+            assert_eq!(engine.get_name().unwrap(), "resnet50-v2");
+        }
+
+        // Unnamed networks build engines with an empty name
+        #[cfg(feature = "mock")]
+        {
+            let unnamed = builder
+                .create_network(network_flags::EXPLICIT_BATCH)
+                .unwrap();
+            let config = builder.create_config().unwrap();
+            let runtime = crate::Runtime::new(&logger).unwrap();
+            let plan = builder.build_serialized_network(&unnamed, &config).unwrap();
+            let engine = runtime.deserialize_cuda_engine(&plan).unwrap();
+            assert_eq!(engine.get_name().unwrap(), "");
+        }
+    }
+
+    #[test]
+    fn test_build_on_device() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new_on_device(&logger, 0).unwrap();
+        let runtime = crate::Runtime::new_on_device(&logger, 0).unwrap();
+        assert_eq!(builder.device(), Some(0));
+        assert_eq!(runtime.device(), Some(0));
+        assert_eq!(Builder::new(&logger).unwrap().device(), None);
+
+        #[cfg(feature = "mock")]
+        {
+            let network = builder
+                .create_network(network_flags::EXPLICIT_BATCH)
+                .unwrap();
+            let config = builder.create_config().unwrap();
+            let plan = builder.build_serialized_network(&network, &config).unwrap();
+            assert!(runtime.deserialize_cuda_engine(&plan).is_ok());
+            assert_eq!(crate::cuda::current_device().unwrap(), 0);
+
+            // The mock has a single device
+            assert!(Builder::new_on_device(&logger, 1).is_err());
+        }
+    }
+
+    #[test]
+    fn test_engine_builder() {
+        let onnx = std::fs::read(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/super-resolution-10.onnx"
+        ))
+        .unwrap();
+        let logger = Logger::stderr().unwrap();
+
+        let result = EngineBuilder::from_onnx(&onnx)
+            .logger(&logger)
+            .fp16()
+            .flag(BuilderFlag::SparseWeights)
+            .workspace(1 << 28)
+            .optimization_profile(
+                "input",
+                &[1, 1, 224, 224],
+                &[1, 1, 224, 224],
+                &[4, 1, 224, 224],
+            )
+            .build();
+        #[cfg(feature = "mock")]
+        {
+            let engine_data = result.as_ref().unwrap();
+            let runtime = crate::Runtime::new(&logger).unwrap();
+            assert!(runtime.deserialize_cuda_engine(engine_data).is_ok());
+        }
+        drop(result);
+
+        assert!(matches!(
+            EngineBuilder::from_onnx(b"not onnx").build(),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_engine_builder_pinned_shape() {
+        let onnx = std::fs::read(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/super-resolution-10.onnx"
+        ))
+        .unwrap();
+        let logger = Logger::stderr().unwrap();
+        let build = |input: &str, dims: &[i64]| {
+            EngineBuilder::from_onnx(&onnx)
+                .logger(&logger)
+                .with_input_shape(input, dims)
+                .build()
+        };
+
+        // Mock parsed networks have an `input` of shape [-1, 4]
+        assert!(build("input", &[8, 4]).is_ok());
+        for (input, dims) in [
+            ("input", &[8, 5][..]),
+            ("input", &[8]),
+            ("input", &[-1, 4]),
+            ("missing", &[8, 4]),
+        ] {
+            assert!(
+                matches!(build(input, dims), Err(Error::InvalidArgument(_))),
+                "{} {:?} should be rejected",
+                input,
+                dims
+            );
+        }
+
+        let both = EngineBuilder::from_onnx(&onnx)
+            .logger(&logger)
+            .optimization_profile("input", &[1, 4], &[4, 4], &[8, 4])
+            .with_input_shape("input", [8, 4])
+            .build();
+        assert!(matches!(both, Err(Error::InvalidArgument(_))));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_build_with_precision_fallback() {
+        let onnx = std::fs::read(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/super-resolution-10.onnx"
+        ))
+        .unwrap();
+        let logger = Logger::stderr().unwrap();
+
+        // Mock builds cannot obey FP16 constraints, so the first attempt fails
+        let strict_fp16 = PrecisionConfig::new().fp16().obey_constraints();
+        assert!(EngineBuilder::from_onnx(&onnx)
+            .logger(&logger)
+            .precision(strict_fp16.clone())
+            .build()
+            .is_err());
+
+        let build = EngineBuilder::from_onnx(&onnx)
+            .logger(&logger)
+            .precision(strict_fp16)
+            .build_with_precision_fallback()
+            .unwrap();
+        assert!(build.fell_back);
+        assert_eq!(build.precision, PrecisionConfig::new());
+        assert!(!build.engine.is_empty());
+
+        let build = EngineBuilder::from_onnx(&onnx)
+            .logger(&logger)
+            .fp16()
+            .build_with_precision_fallback()
+            .unwrap();
+        assert!(!build.fell_back);
+        assert_eq!(build.precision.flags(), &[BuilderFlag::Fp16]);
+
+        // Invalid models fail without a retry
+        assert!(matches!(
+            EngineBuilder::from_onnx(&[0u8; 16])
+                .fp16()
+                .build_with_precision_fallback(),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        // So do builds that run out of memory (the mock device has 6 GiB free)
+        assert!(matches!(
+            EngineBuilder::from_onnx(&onnx)
+                .logger(&logger)
+                .fp16()
+                .workspace(8 << 30)
+                .build_with_precision_fallback(),
+            Err(Error::OutOfMemory(_))
+        ));
+    }
+
+    #[test]
+    fn test_llm_preset() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+        let mut config = builder.create_config().unwrap();
+
+        assert!(matches!(
+            config.llm_preset(&builder, &network, 0, 8),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        let index = config.llm_preset(&builder, &network, 2048, 8).unwrap();
+        assert_eq!(index, 0);
+        assert!(config.get_flag(BuilderFlag::Fp16).unwrap());
+        assert!(config.get_flag(BuilderFlag::WeightStreaming).unwrap());
+        // Mock GPU: compute capability 8.9
+        #[cfg(feature = "mock")]
+        assert!(config.get_flag(BuilderFlag::Fp8).unwrap());
+        assert_eq!(config.get_nb_optimization_profiles().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_precision_presets() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+
+        let presets = [
+            (PrecisionConfig::new().fp16(), vec![BuilderFlag::Fp16]),
+            (PrecisionConfig::new().int8(), vec![BuilderFlag::Int8]),
+            (
+                PrecisionConfig::new().fp16().obey_constraints(),
+                vec![BuilderFlag::Fp16, BuilderFlag::ObeyPrecisionConstraints],
+            ),
+            // Mock GPU: compute capability 8.9 supports every precision
+            (
+                PrecisionConfig::best(),
+                vec![BuilderFlag::Fp16, BuilderFlag::Int8, BuilderFlag::Fp8],
+            ),
+        ];
+        for (precision, expected) in presets {
+            let mut config = builder.create_config().unwrap();
+            config.apply_precision(&builder, &precision).unwrap();
+            #[cfg(feature = "mock")]
+            for flag in [
+                BuilderFlag::Fp16,
+                BuilderFlag::Int8,
+                BuilderFlag::Fp8,
+                BuilderFlag::ObeyPrecisionConstraints,
+            ] {
+                assert_eq!(config.get_flag(flag).unwrap(), expected.contains(&flag));
+            }
+            assert_eq!(precision.flags(), expected.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_precision_unsupported_warns() {
+        let warnings = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = warnings.clone();
+        let logger = Logger::from_fn(move |severity, message| {
+            if severity == Severity::Warning {
+                sink.lock().unwrap().push(message.to_string());
+            }
+        })
+        .unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let mut config = builder.create_config().unwrap();
+
+        // Pascal consumer GPU: INT8 but no fast FP16 or FP8
+        config
+            .apply_precision_for(&logger, &PrecisionConfig::new().fp16().int8(), (6, 1))
+            .unwrap();
+        assert!(!config.get_flag(BuilderFlag::Fp16).unwrap());
+        assert!(config.get_flag(BuilderFlag::Int8).unwrap());
+        assert_eq!(warnings.lock().unwrap().len(), 1);
+        assert!(warnings.lock().unwrap()[0].contains("Fp16"));
+
+        config
+            .apply_precision_for(&logger, &PrecisionConfig::best(), (6, 1))
+            .unwrap();
+        assert!(!config.get_flag(BuilderFlag::Fp8).unwrap());
+        assert_eq!(warnings.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_builder_max_threads() {
+        let logger = Logger::stderr().unwrap();
+        let mut builder = Builder::new(&logger).unwrap();
+
+        #[cfg(feature = "mock")]
+        assert_eq!(builder.get_max_threads().unwrap(), 1);
+        builder.set_max_threads(8).unwrap();
+        #[cfg(feature = "mock")]
+        assert_eq!(builder.get_max_threads().unwrap(), 8);
+        assert!(matches!(
+            builder.set_max_threads(-1),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_preview_features() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let mut config = builder.create_config().unwrap();
+
+        let feature = PreviewFeature::AliasedPluginIo1003;
+        config.set_preview_feature(feature, true).unwrap();
+        assert!(config.get_preview_feature(feature).unwrap());
+        config.set_preview_feature(feature, false).unwrap();
+        assert!(!config.get_preview_feature(feature).unwrap());
+
+        // Unknown to the mock library: ignored rather than an error
+        let newer = PreviewFeature::RuntimeActivationResize1010;
+        config.set_preview_feature(newer, true).unwrap();
+        #[cfg(feature = "mock")]
+        assert!(!config.get_preview_feature(newer).unwrap());
+    }
+
+    #[test]
+    fn test_reset_builds_two_networks() {
+        let logger = Logger::stderr().unwrap();
+        let mut builder = Builder::new(&logger).unwrap();
+
+        for _ in 0..2 {
+            let network = builder
+                .create_network(network_flags::EXPLICIT_BATCH)
+                .unwrap();
+            let config = builder.create_config().unwrap();
+            let result = builder.build_serialized_network(&network, &config);
+            #[cfg(feature = "mock")]
+            assert!(!result.unwrap().is_empty());
+            #[cfg(not(feature = "mock"))]
+            assert!(result.is_err());
+
+            drop((network, config));
+            unsafe { builder.reset().unwrap() };
+        }
+    }
+
+    #[test]
+    fn test_recommended_aux_streams() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let mut config = builder.create_config().unwrap();
+        assert_eq!(config.get_max_aux_streams().unwrap(), -1);
+
+        let streams = builder.recommended_aux_streams().unwrap();
+        assert!((0..=MAX_RECOMMENDED_AUX_STREAMS).contains(&streams));
+        // Mock GPU: 46 SMs and 2 async engines
+        #[cfg(feature = "mock")]
+        assert_eq!(streams, 1);
+
+        config.set_max_aux_streams(streams).unwrap();
+        assert_eq!(config.get_max_aux_streams().unwrap(), streams);
+    }
+
+    #[test]
+    fn test_build_timed() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+        let mut config = builder.create_config().unwrap();
+
+        let result = builder.build_serialized_network_timed(&network, &mut config);
+        #[cfg(feature = "mock")]
+        {
+            let (engine, timings) = result.unwrap();
+            assert!(!engine.is_empty());
+            for phase in ["Building engine", "Timing tactics", "Serializing engine"] {
+                assert!(timings.contains_key(phase), "missing phase {}", phase);
+            }
+            assert!(timings["Building engine"] >= timings["Timing tactics"]);
+        }
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_build_with_stats() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+        let mut config = builder.create_config().unwrap();
+        config
+            .set_memory_pool_limit(MemoryPoolType::Workspace, 1 << 20)
+            .unwrap();
+
+        let (engine, stats) = builder
+            .build_serialized_network_with_stats(&network, &config)
+            .unwrap();
+        assert!(stats.duration > Duration::ZERO);
+        assert!(stats.engine_size > 0);
+        assert_eq!(stats.engine_size, engine.len());
+        assert_eq!(stats.workspace_limit, 1 << 20);
+    }
+
+    #[test]
+    fn test_build_cancellable() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+        let mut config = builder.create_config().unwrap();
+
+        let cancel = Arc::new(AtomicBool::new(false));
+        let engine = builder.build_serialized_network_cancellable(&network, &mut config, cancel);
+        #[cfg(feature = "mock")]
+        assert!(!engine.unwrap().is_empty());
+        // TensorRT rejects the empty network before any phase starts
+        #[cfg(not(feature = "mock"))]
+        let _ = engine;
+
+        #[cfg(feature = "mock")]
+        {
+            // Cancels the build as soon as it starts
+            struct CancelOnStart(Arc<AtomicBool>);
+            impl ProgressMonitor for CancelOnStart {
+                fn phase_start(&self, _phase: &str, _parent: Option<&str>, _nb_steps: i32) {
+                    self.0.store(true, Ordering::Relaxed);
+                }
+            }
+
+            let cancel = Arc::new(AtomicBool::new(false));
+            config
+                .set_progress_monitor(Arc::new(CancelOnStart(cancel.clone())))
+                .unwrap();
+            let result =
+                builder.build_serialized_network_cancellable(&network, &mut config, cancel.clone());
+            assert!(cancel.load(Ordering::Relaxed));
+            assert!(matches!(result, Err(Error::Cancelled)));
+            // The caller's monitor is restored
+            assert!(config.progress_monitor.is_some());
+        }
+    }
+
+    #[test]
+    fn test_engine_capability() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let mut config = builder.create_config().unwrap();
+
+        assert_eq!(
+            config.get_engine_capability().unwrap(),
+            EngineCapability::Standard
+        );
+        for capability in [
+            EngineCapability::Safety,
+            EngineCapability::DlaStandalone,
+            EngineCapability::Standard,
+        ] {
+            config.set_engine_capability(capability).unwrap();
+            assert_eq!(config.get_engine_capability().unwrap(), capability);
+        }
+    }
+
+    #[test]
+    fn test_builder_optimization_level() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let mut config = builder.create_config().unwrap();
+
+        config.set_builder_optimization_level(5).unwrap();
+        assert_eq!(config.get_builder_optimization_level().unwrap(), 5);
+        #[cfg(feature = "mock")]
+        assert!(matches!(
+            config.set_builder_optimization_level(6),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_model_config() {
+        let model = ModelConfig {
+            workspace: Some(1 << 24),
+            flags: vec![BuilderFlag::Refit],
+            optimization_level: Some(1),
+            profiles: vec![vec![InputProfile {
+                name: "input".to_string(),
+                min: vec![1, 4],
+                opt: vec![4, 4],
+                max: vec![8, 4],
+            }]],
+            precision: PrecisionConfig::new().obey_constraints(),
+        };
+        #[cfg(all(feature = "serde", feature = "json"))]
+        let model: ModelConfig =
+            serde_json::from_str(&serde_json::to_string(&model).unwrap()).unwrap();
+
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let mut config = builder.create_config().unwrap();
+        config.apply(&builder, &model).unwrap();
+
+        assert_eq!(
+            config
+                .get_memory_pool_limit(MemoryPoolType::Workspace)
+                .unwrap(),
+            1 << 24
+        );
+        assert!(config.get_flag(BuilderFlag::Refit).unwrap());
+        assert!(config
+            .get_flag(BuilderFlag::ObeyPrecisionConstraints)
+            .unwrap());
+        assert_eq!(config.get_builder_optimization_level().unwrap(), 1);
+        assert_eq!(config.get_nb_optimization_profiles().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_runtime_platforms() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let platforms = builder.supported_runtime_platforms().unwrap();
+        assert!(platforms.contains(&RuntimePlatform::SameAsBuild));
+
+        let mut config = builder.create_config().unwrap();
+        config
+            .set_runtime_platform(RuntimePlatform::SameAsBuild)
+            .unwrap();
+        assert_eq!(
+            config.get_runtime_platform().unwrap(),
+            RuntimePlatform::SameAsBuild
+        );
+
+        if !platforms.contains(&RuntimePlatform::WindowsAmd64) {
+            assert!(matches!(
+                config.set_runtime_platform(RuntimePlatform::WindowsAmd64),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_build_failure_drains_error_recorder() {
+        let logger = Logger::stderr().unwrap();
+        let mut builder = Builder::new(&logger).unwrap();
+        let recorder = ErrorRecorder::new().unwrap();
+        builder.set_error_recorder(&recorder).unwrap();
+
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+        let mut config = builder.create_config().unwrap();
+        config
+            .set_memory_pool_limit(MemoryPoolType::Workspace, 0)
+            .unwrap();
+
+        let result = builder.build_serialized_network(&network, &config);
+        #[cfg(feature = "mock")]
+        {
+            match result {
+                Err(Error::Recorded { errors, .. }) => {
+                    assert_eq!(errors.len(), 1);
+                    assert_eq!(errors[0].code, crate::ErrorCode::InvalidConfig);
+                }
+                other => panic!("Expected recorded errors, got {:?}", other.err()),
+            }
+            // Drained into the error
+            assert!(recorder.errors().unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_build_with_workspace_fallback() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+        let mut config = builder.create_config().unwrap();
+        config
+            .set_memory_pool_limit(MemoryPoolType::Workspace, 8 << 30)
+            .unwrap();
+
+        let result = builder.build_with_workspace_fallback(&network, &mut config, 1 << 30);
+        #[cfg(feature = "mock")]
+        {
+            // The mock device has 6 GiB free: 8 GiB fails, 4 GiB succeeds
+            assert!(!result.unwrap().is_empty());
+            assert_eq!(
+                config
+                    .get_memory_pool_limit(MemoryPoolType::Workspace)
+                    .unwrap(),
+                4 << 30
+            );
+
+            // Nothing to fall back to above the floor
+            config
+                .set_memory_pool_limit(MemoryPoolType::Workspace, 8 << 30)
+                .unwrap();
+            assert!(matches!(
+                builder.build_with_workspace_fallback(&network, &mut config, 6 << 30),
+                Err(Error::OutOfMemory(_))
+            ));
+        }
+    }
+}
@@ -1,17 +1,33 @@
 //! CUDA memory management utilities
 
 use crate::error::{Error, Result};
+use std::ffi::CStr;
+use std::sync::Arc;
 use trtx_sys::*;
 
+/// Alignment in bytes TensorRT requires of tensor addresses
+///
+/// This is the crate-wide guarantee for CUDA allocations: `cudaMalloc`,
+/// `cudaMallocAsync` and `cudaMallocManaged` return at least this alignment,
+/// so [`DeviceBuffer`], [`ManagedBuffer`] and [`MemPool`] allocations always
+/// satisfy it; offsets into a buffer may not.
+pub const TENSOR_ALIGNMENT: usize = 256;
+
 /// RAII wrapper for CUDA device memory
 pub struct DeviceBuffer {
     ptr: *mut std::ffi::c_void,
     size: usize,
+    // Stream the buffer was allocated on by new_async, freed on in order
+    stream: Option<Arc<StreamHandle>>,
 }
 
 impl DeviceBuffer {
     /// Allocate CUDA device memory
+    ///
+    /// Fails with [`Error::CudaNotInitialized`] if no CUDA context can be
+    /// created (see [`ensure_context`]).
     pub fn new(size: usize) -> Result<Self> {
+        ensure_context()?;
         let mut ptr: *mut std::ffi::c_void = std::ptr::null_mut();
         let mut error_msg = [0i8; 1024];
 
@@ -22,7 +38,73 @@ impl DeviceBuffer {
             return Err(Error::from_ffi(result, &error_msg));
         }
 
-        Ok(DeviceBuffer { ptr, size })
+        Ok(DeviceBuffer {
+            ptr,
+            size,
+            stream: None,
+        })
+    }
+
+    /// Allocate device memory with every byte set to zero
+    pub fn new_zeroed(size: usize) -> Result<Self> {
+        let buffer = Self::new(size)?;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_memset(buffer.ptr, 0, size, error_msg.as_mut_ptr(), error_msg.len())
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(buffer)
+    }
+
+    /// Allocate device memory holding a copy of `data`
+    pub fn from_host_slice(data: &[u8]) -> Result<Self> {
+        let mut buffer = Self::new(data.len())?;
+        buffer.copy_from_host(data)?;
+        Ok(buffer)
+    }
+
+    /// Allocate device memory in stream order on `stream`
+    ///
+    /// Memory comes from `pool`, or from the device's default pool if `None`,
+    /// and may only be used by work queued on `stream` after this call (or
+    /// once the stream is synchronized).
+    ///
+    /// Dropping the buffer frees it in stream order too: the free is queued
+    /// on `stream` behind any work already there, and the pool may hand the
+    /// memory to the next allocation on that stream without a device sync.
+    /// Work on other streams must not use the buffer after it is dropped
+    /// unless those streams were synchronized first, since nothing orders
+    /// their work against the free. The buffer keeps the stream alive until
+    /// then.
+    pub fn new_async(size: usize, stream: &CudaStream, pool: Option<&MemPool>) -> Result<Self> {
+        let mut ptr: *mut std::ffi::c_void = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_malloc_async(
+                &mut ptr,
+                size,
+                pool.map_or(std::ptr::null_mut(), MemPool::as_ptr),
+                stream.as_ptr(),
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(DeviceBuffer {
+            ptr,
+            size,
+            stream: Some(stream.inner.clone()),
+        })
     }
 
     /// Get the raw device pointer
@@ -35,6 +117,41 @@ impl DeviceBuffer {
         self.size
     }
 
+    /// Get the device pointer as a `*mut T`, for your own CUDA kernels
+    ///
+    /// The pointer is [`TENSOR_ALIGNMENT`]-aligned, which satisfies every
+    /// [`DeviceElement`], and points at [`len_as::<T>()`](Self::len_as)
+    /// elements; the bytes after the last whole element are out of bounds.
+    /// It is a device address: dereferencing it on the host is undefined
+    /// behavior, and it dangles once the buffer is dropped. Kernels reading
+    /// or writing it must be ordered with TensorRT work on the same memory,
+    /// e.g. by launching them on the stream passed to
+    /// [`ExecutionContext::run`](crate::ExecutionContext::run).
+    ///
+    /// ```no_run
+    /// # use trtx::{CudaStream, DeviceBuffer};
+    /// // Stand-in for a kernel launcher compiled with nvcc and linked in
+    /// unsafe fn launch_scale(data: *mut f32, len: usize, factor: f32, stream: *mut std::ffi::c_void) {}
+    ///
+    /// let stream = CudaStream::new()?;
+    /// let buffer = DeviceBuffer::new_zeroed(1024)?;
+    /// // SAFETY: the kernel stays within len_as::<f32>() elements and
+    /// // `buffer` outlives it, as the stream is synchronized below
+    /// unsafe {
+    ///     launch_scale(buffer.as_device_ptr_typed::<f32>(), buffer.len_as::<f32>(), 0.5, stream.as_ptr());
+    /// }
+    /// stream.synchronize()?;
+    /// # Ok::<(), trtx::Error>(())
+    /// ```
+    pub fn as_device_ptr_typed<T: DeviceElement>(&self) -> *mut T {
+        self.ptr as *mut T
+    }
+
+    /// Number of whole `T` elements the buffer holds
+    pub fn len_as<T: DeviceElement>(&self) -> usize {
+        self.size / std::mem::size_of::<T>()
+    }
+
     /// Copy data from host to device
     pub fn copy_from_host(&mut self, data: &[u8]) -> Result<()> {
         if data.len() > self.size {
@@ -88,6 +205,63 @@ impl DeviceBuffer {
 
         Ok(())
     }
+
+    /// Copy all of `src` to the start of this buffer without a host round trip
+    ///
+    /// Lets one engine's output buffer feed another engine's input. Fails
+    /// with [`Error::InvalidArgument`] if `src` is larger than this buffer.
+    pub fn copy_from_device(&mut self, src: &DeviceBuffer) -> Result<()> {
+        if src.size > self.size {
+            return Err(Error::InvalidArgument(format!(
+                "Source buffer of {} bytes does not fit in {} bytes",
+                src.size, self.size
+            )));
+        }
+
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_memcpy_device_to_device(
+                self.ptr,
+                src.ptr,
+                src.size,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Copy the contents of a file to the device via a memory map
+    ///
+    /// Avoids reading large constant inputs into a host `Vec` first. Fails if
+    /// the file is larger than the buffer.
+    #[cfg(feature = "memmap2")]
+    pub fn copy_from_mmap(&mut self, path: &std::path::Path) -> Result<()> {
+        let file = std::fs::File::open(path)?;
+        let len = file.metadata()?.len() as usize;
+        if len > self.size {
+            return Err(Error::InvalidArgument(format!(
+                "File {} is {} bytes but the buffer holds {}",
+                path.display(),
+                len,
+                self.size
+            )));
+        }
+        if len == 0 {
+            return Ok(());
+        }
+
+        // SAFETY: the mapping is read-only and only lives for this copy; the
+        // caller must not truncate the file concurrently.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        self.copy_from_host(&mmap)
+    }
 }
 
 impl Drop for DeviceBuffer {
@@ -95,7 +269,15 @@ impl Drop for DeviceBuffer {
         if !self.ptr.is_null() {
             let mut error_msg = [0i8; 1024];
             unsafe {
-                let _ = trtx_cuda_free(self.ptr, error_msg.as_mut_ptr(), error_msg.len());
+                let _ = match &self.stream {
+                    Some(stream) => trtx_cuda_free_async(
+                        self.ptr,
+                        stream.0,
+                        error_msg.as_mut_ptr(),
+                        error_msg.len(),
+                    ),
+                    None => trtx_cuda_free(self.ptr, error_msg.as_mut_ptr(), error_msg.len()),
+                };
             }
         }
     }
@@ -103,7 +285,201 @@ impl Drop for DeviceBuffer {
 
 unsafe impl Send for DeviceBuffer {}
 
+/// RAII wrapper for CUDA unified (managed) memory
+///
+/// The same allocation is addressable from host and device, so a buffer can
+/// be bound as a tensor address and its results read on the host without
+/// [`DeviceBuffer::copy_to_host`]. The driver migrates pages on demand: the
+/// first device access after host writes (and vice versa) takes page faults,
+/// which is usually slower than an explicit copy. Call
+/// [`prefetch_to_device`](Self::prefetch_to_device) before inference to
+/// migrate the data up front. Prefer [`DeviceBuffer`] when throughput
+/// matters.
+///
+/// The host views are `unsafe`: device work that touches the buffer must
+/// have completed before they are taken, e.g. by synchronizing its stream.
+pub struct ManagedBuffer {
+    ptr: *mut std::ffi::c_void,
+    size: usize,
+}
+
+impl ManagedBuffer {
+    /// Allocate managed memory
+    pub fn new(size: usize) -> Result<Self> {
+        let mut ptr: *mut std::ffi::c_void = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_malloc_managed(&mut ptr, size, error_msg.as_mut_ptr(), error_msg.len())
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(ManagedBuffer { ptr, size })
+    }
+
+    /// Get the raw pointer, valid on both host and device
+    pub fn as_ptr(&self) -> *mut std::ffi::c_void {
+        self.ptr
+    }
+
+    /// Get the size in bytes
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// View the contents from the host
+    ///
+    /// # Safety
+    ///
+    /// No device work that writes the buffer may be in flight, or be queued
+    /// while the slice is alive. Synchronize the stream (or the device) that
+    /// last used the buffer first.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        if self.size == 0 {
+            return &[];
+        }
+        std::slice::from_raw_parts(self.ptr as *const u8, self.size)
+    }
+
+    /// Mutable view of the contents from the host
+    ///
+    /// # Safety
+    ///
+    /// No device work that reads or writes the buffer may be in flight, or
+    /// be queued while the slice is alive. Synchronize the stream (or the
+    /// device) that last used the buffer first.
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+        if self.size == 0 {
+            return &mut [];
+        }
+        std::slice::from_raw_parts_mut(self.ptr as *mut u8, self.size)
+    }
+
+    /// Migrate the contents to the current device, in order on `stream`
+    ///
+    /// Avoids page faults when work later queued on `stream` reads the buffer.
+    pub fn prefetch_to_device(&self, stream: &CudaStream) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_mem_prefetch_async(
+                self.ptr,
+                self.size,
+                stream.as_ptr(),
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for ManagedBuffer {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            let mut error_msg = [0i8; 1024];
+            unsafe {
+                let _ = trtx_cuda_free(self.ptr, error_msg.as_mut_ptr(), error_msg.len());
+            }
+        }
+    }
+}
+
+unsafe impl Send for ManagedBuffer {}
+
+/// Element types that can be stored in a [`TypedDeviceBuffer`]
+///
+/// Implemented for plain-old-data numeric types whose in-memory layout matches
+/// the corresponding TensorRT data type.
+pub trait DeviceElement: Copy + Default + 'static {}
+
+impl DeviceElement for f32 {}
+impl DeviceElement for f64 {}
+impl DeviceElement for i8 {}
+impl DeviceElement for u8 {}
+impl DeviceElement for i32 {}
+impl DeviceElement for i64 {}
+#[cfg(feature = "half")]
+impl DeviceElement for half::f16 {}
+
+/// Device buffer holding a fixed number of elements of type `T`
+pub struct TypedDeviceBuffer<T: DeviceElement> {
+    buffer: DeviceBuffer,
+    len: usize,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T: DeviceElement> TypedDeviceBuffer<T> {
+    /// Allocate device memory for `len` elements
+    pub fn new(len: usize) -> Result<Self> {
+        Ok(TypedDeviceBuffer {
+            buffer: DeviceBuffer::new(len * std::mem::size_of::<T>())?,
+            len,
+            _marker: std::marker::PhantomData,
+        })
+    }
+
+    /// Allocate device memory and upload `data` into it
+    pub fn from_slice(data: &[T]) -> Result<Self> {
+        let mut buffer = Self::new(data.len())?;
+        buffer.copy_from_host(data)?;
+        Ok(buffer)
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the buffer holds no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the raw device pointer
+    pub fn as_ptr(&self) -> *mut std::ffi::c_void {
+        self.buffer.as_ptr()
+    }
+
+    /// Copy elements from host to device
+    pub fn copy_from_host(&mut self, data: &[T]) -> Result<()> {
+        let bytes = unsafe {
+            std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data))
+        };
+        self.buffer.copy_from_host(bytes)
+    }
+
+    /// Copy elements from device to host
+    pub fn copy_to_host(&self, data: &mut [T]) -> Result<()> {
+        let bytes = unsafe {
+            std::slice::from_raw_parts_mut(
+                data.as_mut_ptr() as *mut u8,
+                std::mem::size_of_val(data),
+            )
+        };
+        self.buffer.copy_to_host(bytes)
+    }
+
+    /// Copy the whole buffer back into a new `Vec`
+    pub fn to_vec(&self) -> Result<Vec<T>> {
+        let mut out = vec![T::default(); self.len];
+        self.copy_to_host(&mut out)?;
+        Ok(out)
+    }
+}
+
 /// Synchronize CUDA device
+///
+/// Waits for all work on every stream of the device. To wait for a single
+/// stream, prefer [`CudaStream::synchronize`] or [`synchronize_stream`].
 pub fn synchronize() -> Result<()> {
     let mut error_msg = [0i8; 1024];
 
@@ -116,39 +492,1140 @@ pub fn synchronize() -> Result<()> {
     Ok(())
 }
 
-/// Get the default CUDA stream
-pub fn get_default_stream() -> *mut std::ffi::c_void {
-    unsafe { trtx_cuda_get_default_stream() }
+/// Wait for all work queued on a raw stream handle, such as
+/// [`get_default_stream`]
+///
+/// # Safety
+///
+/// `stream` must be a valid CUDA stream handle, or null for the default
+/// stream.
+pub unsafe fn synchronize_stream(stream: *mut std::ffi::c_void) -> Result<()> {
+    let mut error_msg = [0i8; 1024];
+
+    let result =
+        unsafe { trtx_cuda_stream_synchronize(stream, error_msg.as_mut_ptr(), error_msg.len()) };
+
+    if result != TRTX_SUCCESS as i32 {
+        return Err(Error::from_ffi(result, &error_msg));
+    }
+
+    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Range of stream priorities of the current device, as `(least, greatest)`
+///
+/// Lower numbers are higher priorities, so `greatest <= least`; current GPUs
+/// report `(0, -5)`, but the range varies by device and driver. Streams from
+/// [`CudaStream::new`] use `least`.
+pub fn stream_priority_range() -> Result<(i32, i32)> {
+    let mut least: i32 = 0;
+    let mut greatest: i32 = 0;
+    let mut error_msg = [0i8; 1024];
 
-    #[test]
-    fn test_device_buffer_allocation() {
-        let buffer = DeviceBuffer::new(1024);
-        assert!(buffer.is_ok());
+    let result = unsafe {
+        trtx_cuda_stream_priority_range(
+            &mut least,
+            &mut greatest,
+            error_msg.as_mut_ptr(),
+            error_msg.len(),
+        )
+    };
 
-        let buffer = buffer.unwrap();
-        assert_eq!(buffer.size(), 1024);
+    if result != TRTX_SUCCESS as i32 {
+        return Err(Error::from_ffi(result, &error_msg));
     }
 
-    #[test]
-    fn test_device_buffer_copy() {
-        let mut buffer = DeviceBuffer::new(256).unwrap();
+    Ok((least, greatest))
+}
 
-        let host_data = vec![42u8; 256];
-        assert!(buffer.copy_from_host(&host_data).is_ok());
+/// RAII wrapper for a CUDA stream
+///
+/// Streams from [`new`](Self::new) and [`with_priority`](Self::with_priority)
+/// are non-blocking: their work is not ordered with the legacy default
+/// stream, which [`DeviceBuffer::copy_from_host`] and
+/// [`DeviceBuffer::new_zeroed`] use. Synchronize before enqueueing work that
+/// reads such data, or use [`new_blocking`](Self::new_blocking).
+pub struct CudaStream {
+    // Shared with buffers from DeviceBuffer::new_async, which free on it
+    inner: Arc<StreamHandle>,
+}
 
-        let mut output = vec![0u8; 256];
-        assert!(buffer.copy_to_host(&mut output).is_ok());
+/// Owned stream handle, destroyed once the stream and its buffers are gone
+struct StreamHandle(*mut std::ffi::c_void);
 
-        assert_eq!(host_data, output);
+impl Drop for StreamHandle {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            let mut error_msg = [0i8; 1024];
+            unsafe {
+                let _ = trtx_cuda_stream_destroy(self.0, error_msg.as_mut_ptr(), error_msg.len());
+            }
+        }
     }
+}
 
-    #[test]
-    fn test_synchronize() {
-        assert!(synchronize().is_ok());
+unsafe impl Send for StreamHandle {}
+unsafe impl Sync for StreamHandle {}
+
+impl CudaStream {
+    /// Create a new stream
+    pub fn new() -> Result<Self> {
+        let mut stream: *mut std::ffi::c_void = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_stream_create(&mut stream, error_msg.as_mut_ptr(), error_msg.len())
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(CudaStream {
+            inner: Arc::new(StreamHandle(stream)),
+        })
+    }
+
+    /// Create a new stream that is ordered with the legacy default stream
+    ///
+    /// Work queued on it waits for earlier work on the default stream, such
+    /// as the copies of [`DeviceBuffer::copy_from_host`], and later default
+    /// stream work waits for it.
+    pub fn new_blocking() -> Result<Self> {
+        let mut stream: *mut std::ffi::c_void = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_stream_create_blocking(&mut stream, error_msg.as_mut_ptr(), error_msg.len())
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(CudaStream {
+            inner: Arc::new(StreamHandle(stream)),
+        })
+    }
+
+    /// The legacy default stream, as returned by [`get_default_stream`]
+    ///
+    /// Synchronous copies and memsets such as
+    /// [`DeviceBuffer::copy_from_host`] run on this stream, so work queued
+    /// on it needs no extra synchronization with them. It is owned by CUDA:
+    /// dropping the wrapper does not destroy it.
+    pub fn default_stream() -> Self {
+        CudaStream {
+            inner: Arc::new(StreamHandle(get_default_stream())),
+        }
+    }
+
+    /// Create a new stream with a scheduling priority
+    ///
+    /// Lower numbers are higher priorities: work on a higher-priority stream
+    /// is scheduled ahead of pending work on lower-priority ones, so
+    /// latency-sensitive requests can overtake batch work. The valid range
+    /// depends on the device (see [`stream_priority_range`]); priorities
+    /// outside it are clamped.
+    pub fn with_priority(priority: i32) -> Result<Self> {
+        let mut stream: *mut std::ffi::c_void = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_stream_create_with_priority(
+                &mut stream,
+                priority,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(CudaStream {
+            inner: Arc::new(StreamHandle(stream)),
+        })
+    }
+
+    /// Get the stream's priority, after clamping to the device's range
+    pub fn priority(&self) -> Result<i32> {
+        let mut priority: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_stream_get_priority(
+                self.inner.0,
+                &mut priority,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(priority)
+    }
+
+    /// Get the raw stream handle, e.g. for [`ExecutionContext::enqueue_v3`]
+    ///
+    /// [`ExecutionContext::enqueue_v3`]: crate::ExecutionContext::enqueue_v3
+    pub fn as_ptr(&self) -> *mut std::ffi::c_void {
+        self.inner.0
+    }
+
+    /// Wait for all work queued on this stream
+    pub fn synchronize(&self) -> Result<()> {
+        unsafe { synchronize_stream(self.inner.0) }
+    }
+
+    /// Queue `f` to run once the work queued so far on this stream is done
+    ///
+    /// `f` runs on a CUDA driver thread, which it holds up along with later
+    /// work on the stream, and must not call CUDA.
+    #[cfg(feature = "async")]
+    pub(crate) fn launch_host_fn<F: FnOnce() + Send + 'static>(&self, f: F) -> Result<()> {
+        let user_data = Box::into_raw(Box::new(f));
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_stream_launch_host_fn(
+                self.inner.0,
+                Some(Self::host_fn_callback::<F>),
+                user_data.cast(),
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            // Never queued, so never called
+            drop(unsafe { Box::from_raw(user_data) });
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// C callback running a closure queued by [`launch_host_fn`](Self::launch_host_fn)
+    #[cfg(feature = "async")]
+    extern "C" fn host_fn_callback<F: FnOnce()>(user_data: *mut std::ffi::c_void) {
+        let f = unsafe { Box::from_raw(user_data.cast::<F>()) };
+        f();
+    }
+
+    /// Start recording work queued on this stream into a graph
+    ///
+    /// Work enqueued until [`end_capture`](Self::end_capture) is recorded,
+    /// not executed.
+    pub fn begin_capture(&self) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_stream_begin_capture(self.inner.0, error_msg.as_mut_ptr(), error_msg.len())
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Stop recording and instantiate the captured work as a graph
+    pub fn end_capture(&self) -> Result<CudaGraph> {
+        let mut graph_exec: *mut std::ffi::c_void = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_stream_end_capture(
+                self.inner.0,
+                &mut graph_exec,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(CudaGraph { inner: graph_exec })
+    }
+}
+
+/// Instantiated CUDA graph, replaying captured stream work in one launch
+pub struct CudaGraph {
+    inner: *mut std::ffi::c_void,
+}
+
+impl CudaGraph {
+    /// Launch the graph on `stream`
+    pub fn launch(&self, stream: &CudaStream) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_graph_launch(
+                self.inner,
+                stream.as_ptr(),
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for CudaGraph {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            let mut error_msg = [0i8; 1024];
+            unsafe {
+                let _ = trtx_cuda_graph_exec_destroy(
+                    self.inner,
+                    error_msg.as_mut_ptr(),
+                    error_msg.len(),
+                );
+            }
+        }
+    }
+}
+
+unsafe impl Send for CudaGraph {}
+unsafe impl Sync for CudaGraph {}
+
+/// RAII wrapper for a CUDA event, marking a point in a stream's work
+pub struct CudaEvent {
+    inner: *mut std::ffi::c_void,
+}
+
+impl CudaEvent {
+    /// Create a new event
+    pub fn new() -> Result<Self> {
+        let mut event: *mut std::ffi::c_void = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result =
+            unsafe { trtx_cuda_event_create(&mut event, error_msg.as_mut_ptr(), error_msg.len()) };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(CudaEvent { inner: event })
+    }
+
+    /// Mark the point after all work queued so far on `stream`
+    pub fn record(&self, stream: &CudaStream) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_event_record(
+                self.inner,
+                stream.as_ptr(),
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Check without blocking whether the recorded work has completed
+    pub fn query(&self) -> Result<bool> {
+        let mut complete = false;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_event_query(
+                self.inner,
+                &mut complete,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(complete)
+    }
+
+    /// Wait for the recorded work to complete
+    pub fn synchronize(&self) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_event_synchronize(self.inner, error_msg.as_mut_ptr(), error_msg.len())
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for CudaEvent {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            let mut error_msg = [0i8; 1024];
+            unsafe {
+                let _ =
+                    trtx_cuda_event_destroy(self.inner, error_msg.as_mut_ptr(), error_msg.len());
+            }
+        }
+    }
+}
+
+unsafe impl Send for CudaEvent {}
+unsafe impl Sync for CudaEvent {}
+
+/// RAII wrapper for a CUDA memory pool on the current device
+///
+/// Pools back [`DeviceBuffer::new_async`]. Freed memory stays reserved in
+/// the pool up to its release threshold, so long-running servers can reuse
+/// it instead of fragmenting device memory.
+pub struct MemPool {
+    inner: *mut std::ffi::c_void,
+}
+
+impl MemPool {
+    /// Create a pool with a release threshold of 0
+    pub fn new() -> Result<Self> {
+        let mut pool: *mut std::ffi::c_void = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_mem_pool_create(&mut pool, error_msg.as_mut_ptr(), error_msg.len())
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(MemPool { inner: pool })
+    }
+
+    /// Keep up to `bytes` of freed memory reserved at each synchronization
+    /// instead of returning it to the device
+    pub fn set_release_threshold(&self, bytes: u64) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_mem_pool_set_release_threshold(
+                self.inner,
+                bytes,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get the release threshold in bytes
+    pub fn get_release_threshold(&self) -> Result<u64> {
+        let mut bytes: u64 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_mem_pool_get_release_threshold(
+                self.inner,
+                &mut bytes,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(bytes)
+    }
+
+    /// Get the raw `cudaMemPool_t` handle
+    pub fn as_ptr(&self) -> *mut std::ffi::c_void {
+        self.inner
+    }
+}
+
+impl Drop for MemPool {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            let mut error_msg = [0i8; 1024];
+            unsafe {
+                let _ =
+                    trtx_cuda_mem_pool_destroy(self.inner, error_msg.as_mut_ptr(), error_msg.len());
+            }
+        }
+    }
+}
+
+unsafe impl Send for MemPool {}
+unsafe impl Sync for MemPool {}
+
+/// Per-device resource limit (mirrors `cudaLimit`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum DeviceLimit {
+    /// Stack size in bytes of each GPU thread
+    StackSize = 0,
+    /// Size in bytes of the FIFO used by device-side `printf`
+    PrintfFifoSize = 1,
+    /// Size in bytes of the heap used by device-side `malloc`
+    MallocHeapSize = 2,
+    /// Maximum nesting depth of device-side synchronization
+    DevRuntimeSyncDepth = 3,
+    /// Maximum number of outstanding device-side launches
+    DevRuntimePendingLaunchCount = 4,
+    /// L2 cache fetch granularity in bytes
+    MaxL2FetchGranularity = 5,
+    /// Size in bytes of L2 cache reserved for persisting accesses
+    PersistingL2CacheSize = 6,
+}
+
+/// Get a resource limit of the current device
+pub fn get_limit(limit: DeviceLimit) -> Result<usize> {
+    let mut value: usize = 0;
+    let mut error_msg = [0i8; 1024];
+
+    let result = unsafe {
+        trtx_cuda_device_get_limit(
+            limit as i32,
+            &mut value,
+            error_msg.as_mut_ptr(),
+            error_msg.len(),
+        )
+    };
+
+    if result != TRTX_SUCCESS as i32 {
+        return Err(Error::from_ffi(result, &error_msg));
+    }
+
+    Ok(value)
+}
+
+/// Set a resource limit of the current device
+///
+/// CUDA may round the value; read it back with [`get_limit`] to see what
+/// was applied.
+pub fn set_limit(limit: DeviceLimit, value: usize) -> Result<()> {
+    let mut error_msg = [0i8; 1024];
+
+    let result = unsafe {
+        trtx_cuda_device_set_limit(limit as i32, value, error_msg.as_mut_ptr(), error_msg.len())
+    };
+
+    if result != TRTX_SUCCESS as i32 {
+        return Err(Error::from_ffi(result, &error_msg));
+    }
+
+    Ok(())
+}
+
+/// Property of a device (subset of `cudaDeviceAttr`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum DeviceAttribute {
+    /// Number of streaming multiprocessors
+    MultiProcessorCount = 16,
+    /// Whether the device can run multiple kernels concurrently (0 or 1)
+    ConcurrentKernels = 31,
+    /// Number of engines that can copy memory while kernels run
+    AsyncEngineCount = 40,
+    /// Major compute capability version
+    ComputeCapabilityMajor = 75,
+    /// Minor compute capability version
+    ComputeCapabilityMinor = 76,
+}
+
+/// Get a property of the current device
+pub fn get_attribute(attribute: DeviceAttribute) -> Result<i32> {
+    let mut value: i32 = 0;
+    let mut error_msg = [0i8; 1024];
+
+    let result = unsafe {
+        trtx_cuda_device_get_attribute(
+            attribute as i32,
+            &mut value,
+            error_msg.as_mut_ptr(),
+            error_msg.len(),
+        )
+    };
+
+    if result != TRTX_SUCCESS as i32 {
+        return Err(Error::from_ffi(result, &error_msg));
+    }
+
+    Ok(value)
+}
+
+/// Get the `(free, total)` device memory of the current device, in bytes
+pub fn memory_info() -> Result<(usize, usize)> {
+    let mut free: usize = 0;
+    let mut total: usize = 0;
+    let mut error_msg = [0i8; 1024];
+
+    let result = unsafe {
+        trtx_cuda_mem_get_info(
+            &mut free,
+            &mut total,
+            error_msg.as_mut_ptr(),
+            error_msg.len(),
+        )
+    };
+
+    if result != TRTX_SUCCESS as i32 {
+        return Err(Error::from_ffi(result, &error_msg));
+    }
+
+    Ok((free, total))
+}
+
+/// Make `device` the current CUDA device of the calling thread
+///
+/// Allocations, streams and enqueues use the current device, so everything
+/// touching one engine must happen with the same device current.
+pub fn set_device(device: i32) -> Result<()> {
+    let mut error_msg = [0i8; 1024];
+
+    let result = unsafe { trtx_cuda_set_device(device, error_msg.as_mut_ptr(), error_msg.len()) };
+
+    if result != TRTX_SUCCESS as i32 {
+        return Err(Error::from_ffi(result, &error_msg));
+    }
+
+    Ok(())
+}
+
+/// Create the CUDA context of the current device if there is none yet
+///
+/// CUDA creates contexts lazily, so without this the first call needing one
+/// (usually an allocation) fails with whatever error CUDA reports, far from
+/// the cause. Called by [`DeviceBuffer::new`] and
+/// [`Builder::new`](crate::Builder::new); cheap once the context exists.
+/// Fails with [`Error::CudaNotInitialized`] when CUDA cannot start, e.g.
+/// without a GPU or driver. Other CUDA errors, such as sticky errors left by
+/// an earlier kernel fault, are returned as [`Error::Cuda`].
+pub fn ensure_context() -> Result<()> {
+    let mut cuda_error: i32 = 0;
+    let mut error_msg = [0i8; 1024];
+
+    let result = unsafe {
+        trtx_cuda_ensure_context(&mut cuda_error, error_msg.as_mut_ptr(), error_msg.len())
+    };
+
+    if result != TRTX_SUCCESS as i32 {
+        return Err(match Error::from_ffi(result, &error_msg) {
+            Error::Cuda { message, .. } if CudaError::is_initialization_code(cuda_error) => {
+                Error::CudaNotInitialized {
+                    message,
+                    code: CudaError::from_code(cuda_error).ok(),
+                }
+            }
+            Error::Cuda { message, .. } => Error::Cuda {
+                message,
+                code: CudaError::from_code(cuda_error).ok(),
+            },
+            other => other,
+        });
+    }
+
+    Ok(())
+}
+
+/// Get the current CUDA device of the calling thread
+pub fn current_device() -> Result<i32> {
+    let mut device: i32 = 0;
+    let mut error_msg = [0i8; 1024];
+
+    let result =
+        unsafe { trtx_cuda_get_device(&mut device, error_msg.as_mut_ptr(), error_msg.len()) };
+
+    if result != TRTX_SUCCESS as i32 {
+        return Err(Error::from_ffi(result, &error_msg));
+    }
+
+    Ok(device)
+}
+
+/// A CUDA runtime error (`cudaError_t`) with its description
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CudaError {
+    /// The `cudaError_t` value
+    pub code: i32,
+    /// Description from `cudaGetErrorString`
+    pub message: String,
+}
+
+impl CudaError {
+    /// `cudaErrorInvalidValue`
+    pub const INVALID_VALUE: i32 = 1;
+    /// `cudaErrorMemoryAllocation`
+    pub const MEMORY_ALLOCATION: i32 = 2;
+    /// `cudaErrorInitializationError`
+    pub const INITIALIZATION_ERROR: i32 = 3;
+    /// `cudaErrorStubLibrary`
+    pub const STUB_LIBRARY: i32 = 34;
+    /// `cudaErrorInsufficientDriver`
+    pub const INSUFFICIENT_DRIVER: i32 = 35;
+    /// `cudaErrorDevicesUnavailable`
+    pub const DEVICES_UNAVAILABLE: i32 = 46;
+    /// `cudaErrorNoDevice`
+    pub const NO_DEVICE: i32 = 100;
+    /// `cudaErrorInvalidDevice`
+    pub const INVALID_DEVICE: i32 = 101;
+    /// `cudaErrorIllegalAddress`
+    pub const ILLEGAL_ADDRESS: i32 = 700;
+    /// `cudaErrorLaunchFailure`
+    pub const LAUNCH_FAILURE: i32 = 719;
+    /// `cudaErrorSystemNotReady`
+    pub const SYSTEM_NOT_READY: i32 = 802;
+    /// `cudaErrorSystemDriverMismatch`
+    pub const SYSTEM_DRIVER_MISMATCH: i32 = 803;
+
+    // Codes meaning CUDA could not start at all, as opposed to a failure of
+    // a working context
+    fn is_initialization_code(code: i32) -> bool {
+        matches!(
+            code,
+            Self::INITIALIZATION_ERROR
+                | Self::STUB_LIBRARY
+                | Self::INSUFFICIENT_DRIVER
+                | Self::DEVICES_UNAVAILABLE
+                | Self::NO_DEVICE
+                | Self::INVALID_DEVICE
+                | Self::SYSTEM_NOT_READY
+                | Self::SYSTEM_DRIVER_MISMATCH
+        )
+    }
+
+    /// Describe a `cudaError_t` value
+    pub fn from_code(code: i32) -> Result<Self> {
+        let mut string_ptr: *const i8 = std::ptr::null();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_get_error_string(
+                code,
+                &mut string_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(CudaError {
+            code,
+            message: unsafe { CStr::from_ptr(string_ptr) }
+                .to_string_lossy()
+                .into_owned(),
+        })
+    }
+}
+
+impl std::fmt::Display for CudaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (cudaError_t {})", self.message, self.code)
+    }
+}
+
+fn get_last_error(peek: bool) -> Option<CudaError> {
+    let mut code: i32 = 0;
+    let mut string_ptr: *const i8 = std::ptr::null();
+    let mut error_msg = [0i8; 1024];
+
+    let result = unsafe {
+        trtx_cuda_get_last_error(
+            peek,
+            &mut code,
+            &mut string_ptr,
+            error_msg.as_mut_ptr(),
+            error_msg.len(),
+        )
+    };
+
+    if result != TRTX_SUCCESS as i32 || code == 0 {
+        return None;
+    }
+
+    Some(CudaError {
+        code,
+        message: unsafe { CStr::from_ptr(string_ptr) }
+            .to_string_lossy()
+            .into_owned(),
+    })
+}
+
+/// Take the last CUDA runtime error of the calling thread, if any
+///
+/// Resets it, like `cudaGetLastError`, so the next call returns `None`
+/// unless another error occurs. [`Error::Cuda`] already takes the error of
+/// the failing call; this is for errors from CUDA work outside this crate.
+pub fn last_error() -> Option<CudaError> {
+    get_last_error(false)
+}
+
+/// Get the last CUDA runtime error of the calling thread without resetting it
+///
+/// Like `cudaPeekAtLastError`.
+pub fn peek_last_error() -> Option<CudaError> {
+    get_last_error(true)
+}
+
+/// Get the default CUDA stream
+pub fn get_default_stream() -> *mut std::ffi::c_void {
+    unsafe { trtx_cuda_get_default_stream() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_device_buffer_allocation() {
+        let buffer = DeviceBuffer::new(1024);
+        assert!(buffer.is_ok());
+
+        let buffer = buffer.unwrap();
+        assert_eq!(buffer.size(), 1024);
+    }
+
+    #[test]
+    fn test_device_buffer_typed_view() {
+        let buffer = DeviceBuffer::new(30).unwrap();
+        assert_eq!(buffer.len_as::<f32>(), 7);
+        assert_eq!(buffer.len_as::<u8>(), 30);
+        assert_eq!(buffer.len_as::<i64>(), 3);
+        assert_eq!(
+            buffer.as_device_ptr_typed::<f32>() as usize,
+            buffer.as_ptr() as usize
+        );
+    }
+
+    #[test]
+    fn test_device_buffer_copy() {
+        let mut buffer = DeviceBuffer::new(256).unwrap();
+
+        let host_data = vec![42u8; 256];
+        assert!(buffer.copy_from_host(&host_data).is_ok());
+
+        let mut output = vec![0u8; 256];
+        assert!(buffer.copy_to_host(&mut output).is_ok());
+
+        assert_eq!(host_data, output);
+    }
+
+    #[test]
+    fn test_device_buffer_copy_from_device() {
+        let data: Vec<u8> = (0..64).collect();
+        let mut src = DeviceBuffer::new(64).unwrap();
+        src.copy_from_host(&data).unwrap();
+
+        let mut dst = DeviceBuffer::new(128).unwrap();
+        dst.copy_from_device(&src).unwrap();
+        let mut output = vec![0u8; 64];
+        dst.copy_to_host(&mut output).unwrap();
+        assert_eq!(output, data);
+
+        assert!(matches!(
+            src.copy_from_device(&dst),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_device_buffer_async_free() {
+        let stream = CudaStream::new().unwrap();
+        for _ in 0..4 {
+            let mut buffer = DeviceBuffer::new_async(256, &stream, None).unwrap();
+            // The synchronous copy is not ordered after the stream's allocation
+            stream.synchronize().unwrap();
+            buffer.copy_from_host(&[3u8; 256]).unwrap();
+        }
+
+        // The buffer keeps the stream alive until it is freed
+        let buffer = DeviceBuffer::new_async(64, &stream, None).unwrap();
+        drop(stream);
+        drop(buffer);
+    }
+
+    #[test]
+    fn test_device_buffer_constructors() {
+        // Dirty the allocator first so zeroing is not a coincidence
+        let mut dirty = DeviceBuffer::new(64).unwrap();
+        dirty.copy_from_host(&[0xab; 64]).unwrap();
+        drop(dirty);
+
+        let zeroed = DeviceBuffer::new_zeroed(64).unwrap();
+        let mut readback = vec![0xffu8; 64];
+        zeroed.copy_to_host(&mut readback).unwrap();
+        assert!(readback.iter().all(|&byte| byte == 0));
+
+        let data: Vec<u8> = (0..48).collect();
+        let buffer = DeviceBuffer::from_host_slice(&data).unwrap();
+        assert_eq!(buffer.size(), data.len());
+        let mut readback = vec![0u8; data.len()];
+        buffer.copy_to_host(&mut readback).unwrap();
+        assert_eq!(readback, data);
+    }
+
+    #[test]
+    fn test_mem_pool_allocation() {
+        let pool = MemPool::new().unwrap();
+        pool.set_release_threshold(64 << 20).unwrap();
+        assert_eq!(pool.get_release_threshold().unwrap(), 64 << 20);
+
+        let stream = CudaStream::new().unwrap();
+        let data: Vec<u8> = (0..64).collect();
+        for pool in [Some(&pool), None] {
+            let mut buffer = DeviceBuffer::new_async(64, &stream, pool).unwrap();
+            stream.synchronize().unwrap();
+            assert_eq!(buffer.size(), 64);
+            assert!((buffer.as_ptr() as usize).is_multiple_of(TENSOR_ALIGNMENT));
+
+            buffer.copy_from_host(&data).unwrap();
+            let mut output = vec![0u8; 64];
+            buffer.copy_to_host(&mut output).unwrap();
+            assert_eq!(output, data);
+        }
+    }
+
+    #[cfg(feature = "memmap2")]
+    #[test]
+    fn test_device_buffer_copy_from_mmap() {
+        let path = std::env::temp_dir().join(format!("trtx_mmap_{}.bin", std::process::id()));
+        let data: Vec<u8> = (0..200).map(|i| i as u8).collect();
+        std::fs::write(&path, &data).unwrap();
+
+        let mut buffer = DeviceBuffer::new(256).unwrap();
+        buffer.copy_from_mmap(&path).unwrap();
+        let mut output = vec![0u8; 200];
+        buffer.copy_to_host(&mut output).unwrap();
+        assert_eq!(output, data);
+
+        let mut small = DeviceBuffer::new(100).unwrap();
+        let result = small.copy_from_mmap(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_managed_buffer_inference() {
+        use crate::{Logger, Runtime};
+
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+
+        let mut input = ManagedBuffer::new(16).unwrap();
+        let mut output = ManagedBuffer::new(16).unwrap();
+        assert_eq!(input.size(), 16);
+        let values = [1.0f32, -2.0, 3.5, 4.0];
+        // No device work has been queued yet
+        unsafe {
+            for (chunk, value) in input.as_mut_slice().chunks_exact_mut(4).zip(values) {
+                chunk.copy_from_slice(&value.to_ne_bytes());
+            }
+            output.as_mut_slice().fill(0);
+        }
+
+        let stream = CudaStream::new().unwrap();
+        input.prefetch_to_device(&stream).unwrap();
+        context.set_input_shape("input", &[1, 4]).unwrap();
+        unsafe {
+            context.set_tensor_address("input", input.as_ptr()).unwrap();
+            context
+                .set_tensor_address("output", output.as_ptr())
+                .unwrap();
+            context.enqueue_v3(stream.as_ptr()).unwrap();
+        }
+        stream.synchronize().unwrap();
+
+        // The identity engine's result is readable without a copy
+        let result: Vec<f32> = unsafe { output.as_slice() }
+            .chunks_exact(4)
+            .map(|b| f32::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+        assert_eq!(result, values);
+    }
+
+    #[test]
+    fn test_typed_device_buffer() {
+        let data = [1.5f32, -2.0, 3.25];
+        let buffer = TypedDeviceBuffer::from_slice(&data).unwrap();
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.to_vec().unwrap(), data);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn test_typed_device_buffer_f16() {
+        let data: Vec<half::f16> = [0.5f32, 1.0, -4.0]
+            .iter()
+            .map(|&v| half::f16::from_f32(v))
+            .collect();
+        let buffer = TypedDeviceBuffer::from_slice(&data).unwrap();
+        assert_eq!(buffer.to_vec().unwrap(), data);
+    }
+
+    #[test]
+    fn test_synchronize() {
+        assert!(synchronize().is_ok());
+    }
+
+    #[test]
+    fn test_stream_priority() {
+        let (least, greatest) = stream_priority_range().unwrap();
+        assert!(greatest <= least);
+
+        let urgent = CudaStream::with_priority(greatest).unwrap();
+        assert_eq!(urgent.priority().unwrap(), greatest);
+        let clamped = CudaStream::with_priority(greatest - 100).unwrap();
+        assert_eq!(clamped.priority().unwrap(), greatest);
+        assert_eq!(CudaStream::new().unwrap().priority().unwrap(), least);
+    }
+
+    #[test]
+    fn test_synchronize_stream() {
+        let stream = CudaStream::new().unwrap();
+        let mut buffer = DeviceBuffer::new(16).unwrap();
+        buffer.copy_from_host(&[7u8; 16]).unwrap();
+        assert!(stream.synchronize().is_ok());
+        assert!(unsafe { synchronize_stream(get_default_stream()) }.is_ok());
+
+        let blocking = CudaStream::new_blocking().unwrap();
+        assert!(blocking.synchronize().is_ok());
+        let default = CudaStream::default_stream();
+        assert_eq!(default.as_ptr(), get_default_stream());
+        assert!(default.synchronize().is_ok());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_stream_host_fn() {
+        let stream = CudaStream::new().unwrap();
+        let (done, ran) = std::sync::mpsc::channel();
+        stream
+            .launch_host_fn(move || done.send(()).unwrap())
+            .unwrap();
+        stream.synchronize().unwrap();
+        ran.recv().unwrap();
+    }
+
+    #[test]
+    fn test_event() {
+        let stream = CudaStream::new().unwrap();
+        let event = CudaEvent::new().unwrap();
+        event.record(&stream).unwrap();
+        event.synchronize().unwrap();
+        assert!(event.query().unwrap());
+    }
+
+    #[test]
+    fn test_cuda_error_string() {
+        let error = CudaError::from_code(CudaError::MEMORY_ALLOCATION).unwrap();
+        assert_eq!(error.message, "out of memory");
+        assert_eq!(error.to_string(), "out of memory (cudaError_t 2)");
+        assert_eq!(
+            CudaError::from_code(CudaError::INVALID_DEVICE)
+                .unwrap()
+                .message,
+            "invalid device ordinal"
+        );
+    }
+
+    #[test]
+    fn test_initialization_codes() {
+        assert!(CudaError::is_initialization_code(CudaError::NO_DEVICE));
+        assert!(CudaError::is_initialization_code(
+            CudaError::INSUFFICIENT_DRIVER
+        ));
+        // Sticky errors from an earlier fault are not initialization failures
+        assert!(!CudaError::is_initialization_code(
+            CudaError::ILLEGAL_ADDRESS
+        ));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_last_error() {
+        // Mock has a single device, so selecting another one fails
+        match set_device(1) {
+            Err(Error::Cuda {
+                code: Some(error), ..
+            }) => assert_eq!(error.code, CudaError::INVALID_DEVICE),
+            other => panic!("expected a structured CUDA error, got {:?}", other),
+        }
+        // Still pending after the error above, until taken
+        assert_eq!(
+            peek_last_error().map(|error| error.code),
+            Some(CudaError::INVALID_DEVICE)
+        );
+        assert_eq!(
+            last_error().map(|error| error.code),
+            Some(CudaError::INVALID_DEVICE)
+        );
+        assert_eq!(peek_last_error(), None);
+    }
+
+    #[test]
+    #[ignore] // Requires a GPU - run with: cargo test --ignored test_ensure_context
+    fn test_ensure_context() {
+        ensure_context().unwrap();
+        // Idempotent once the context exists
+        ensure_context().unwrap();
+    }
+
+    #[test]
+    #[ignore] // Requires a GPU - run with: cargo test --ignored test_memory_info
+    fn test_memory_info() {
+        let (free, total) = memory_info().unwrap();
+        assert!(free > 0);
+        assert!(total >= free);
+    }
+
+    #[test]
+    fn test_device_attributes() {
+        assert!(get_attribute(DeviceAttribute::MultiProcessorCount).unwrap() > 0);
+        assert!(get_attribute(DeviceAttribute::ComputeCapabilityMajor).unwrap() > 0);
+    }
+
+    #[test]
+    fn test_device_limits() {
+        let heap = get_limit(DeviceLimit::MallocHeapSize).unwrap();
+        assert!(heap > 0);
+
+        set_limit(DeviceLimit::PrintfFifoSize, 2 * 1024 * 1024).unwrap();
+        #[cfg(feature = "mock")]
+        assert_eq!(
+            get_limit(DeviceLimit::PrintfFifoSize).unwrap(),
+            2 * 1024 * 1024
+        );
     }
 }
@@ -0,0 +1,252 @@
+//! Inspecting intermediate tensors during inference
+
+use crate::error::{Error, Result};
+use crate::tensor::DataType;
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr};
+use std::os::raw::c_char;
+use std::sync::{Arc, Mutex};
+use trtx_sys::*;
+
+/// Trait for receiving debug tensors from TensorRT
+///
+/// Mark tensors with [`NetworkDefinition::mark_debug`] before building, then
+/// attach a listener with [`ExecutionContext::set_debug_listener`]. Each
+/// marked tensor is reported once per inference, after the work producing it
+/// has completed.
+///
+/// [`NetworkDefinition::mark_debug`]: crate::NetworkDefinition::mark_debug
+/// [`ExecutionContext::set_debug_listener`]: crate::ExecutionContext::set_debug_listener
+pub trait DebugListener: Send + Sync {
+    /// Called with a debug tensor; `data` is device memory, only valid
+    /// for the duration of the call
+    fn process_debug_tensor(&self, name: &str, data: *const c_void, dtype: DataType, shape: &[i64]);
+}
+
+/// Listener that copies float32 debug tensors to the host and records their range
+///
+/// Ranges are kept per tensor and read back with [`ranges`](Self::ranges),
+/// so NaNs and overflows can be traced to the first layer producing them.
+/// Tensors of other data types are ignored.
+#[derive(Debug, Default)]
+pub struct MinMaxListener {
+    ranges: Mutex<HashMap<String, (f32, f32)>>,
+    failures: Mutex<HashMap<String, String>>,
+}
+
+impl MinMaxListener {
+    /// Create a listener with no recorded ranges
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Min and max of the last value seen for each tensor
+    ///
+    /// A tensor containing NaN reports NaN for both.
+    pub fn ranges(&self) -> HashMap<String, (f32, f32)> {
+        self.ranges.lock().unwrap().clone()
+    }
+
+    /// Tensors whose last value could not be copied to the host, with the error
+    pub fn failures(&self) -> HashMap<String, String> {
+        self.failures.lock().unwrap().clone()
+    }
+
+    fn copy_to_host(data: *const c_void, len: usize) -> Result<Vec<f32>> {
+        let mut host = vec![0f32; len];
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_memcpy_device_to_host(
+                host.as_mut_ptr() as *mut c_void,
+                data,
+                std::mem::size_of_val(host.as_slice()),
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(host)
+    }
+}
+
+impl DebugListener for MinMaxListener {
+    fn process_debug_tensor(
+        &self,
+        name: &str,
+        data: *const c_void,
+        dtype: DataType,
+        shape: &[i64],
+    ) {
+        if dtype != DataType::Float || shape.iter().any(|&dim| dim < 0) {
+            return;
+        }
+
+        let len = shape.iter().product::<i64>() as usize;
+        let values = match Self::copy_to_host(data, len) {
+            Ok(values) => values,
+            Err(e) => {
+                self.failures
+                    .lock()
+                    .unwrap()
+                    .insert(name.to_string(), e.to_string());
+                return;
+            }
+        };
+        self.failures.lock().unwrap().remove(name);
+
+        let range = if values.iter().any(|v| v.is_nan()) {
+            (f32::NAN, f32::NAN)
+        } else {
+            values
+                .iter()
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &v| {
+                    (min.min(v), max.max(v))
+                })
+        };
+        self.ranges.lock().unwrap().insert(name.to_string(), range);
+    }
+}
+
+/// Debug listener registered with TensorRT, forwarding to a [`DebugListener`]
+pub(crate) struct Listener {
+    inner: *mut TrtxDebugListener,
+    // Boxed so the address passed to TensorRT as user data stays stable
+    _handler: Box<Arc<dyn DebugListener>>,
+}
+
+impl Listener {
+    pub(crate) fn new(handler: Arc<dyn DebugListener>) -> Result<Self> {
+        let handler = Box::new(handler);
+        let user_data = &*handler as *const Arc<dyn DebugListener> as *mut c_void;
+
+        let mut listener_ptr: *mut TrtxDebugListener = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_debug_listener_create(
+                Some(Self::debug_tensor_callback),
+                user_data,
+                &mut listener_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(Listener {
+            inner: listener_ptr,
+            _handler: handler,
+        })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut TrtxDebugListener {
+        self.inner
+    }
+
+    /// C callback function that bridges to Rust trait
+    extern "C" fn debug_tensor_callback(
+        user_data: *mut c_void,
+        name: *const c_char,
+        addr: *const c_void,
+        data_type: i32,
+        dims: *const i64,
+        nb_dims: i32,
+    ) {
+        if user_data.is_null() || name.is_null() || (dims.is_null() && nb_dims > 0) {
+            return;
+        }
+
+        unsafe {
+            let handler = &*(user_data as *const Arc<dyn DebugListener>);
+            let shape = if nb_dims > 0 {
+                std::slice::from_raw_parts(dims, nb_dims as usize)
+            } else {
+                &[]
+            };
+            if let (Ok(name), Ok(dtype)) =
+                (CStr::from_ptr(name).to_str(), DataType::from_raw(data_type))
+            {
+                handler.process_debug_tensor(name, addr, dtype, shape);
+            }
+        }
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe {
+                trtx_debug_listener_destroy(self.inner);
+            }
+        }
+    }
+}
+
+unsafe impl Send for Listener {}
+unsafe impl Sync for Listener {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DeviceBuffer, Logger, Runtime};
+
+    #[test]
+    fn test_min_max_listener() {
+        let mut buffer = DeviceBuffer::new(16).unwrap();
+        let values = [1.5f32, -2.0, 0.25, 3.0];
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_ne_bytes()).collect();
+        buffer.copy_from_host(&bytes).unwrap();
+
+        let listener = MinMaxListener::new();
+        listener.process_debug_tensor("conv1", buffer.as_ptr(), DataType::Float, &[1, 4]);
+        listener.process_debug_tensor("ids", buffer.as_ptr(), DataType::Int32, &[1, 4]);
+
+        let ranges = listener.ranges();
+        assert_eq!(ranges["conv1"], (-2.0, 3.0));
+        assert!(!ranges.contains_key("ids"));
+        assert!(listener.failures().is_empty());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_context_debug_listener() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+
+        let listener = Arc::new(MinMaxListener::new());
+        context.set_debug_listener(listener.clone()).unwrap();
+
+        let mut input = DeviceBuffer::new(16).unwrap();
+        let bytes: Vec<u8> = [0.5f32, f32::NAN, 1.0, 2.0]
+            .iter()
+            .flat_map(|v| v.to_ne_bytes())
+            .collect();
+        input.copy_from_host(&bytes).unwrap();
+        let output = DeviceBuffer::new(16).unwrap();
+        context.set_input_shape("input", &[1, 4]).unwrap();
+        unsafe {
+            context.set_tensor_address("input", input.as_ptr()).unwrap();
+            context
+                .set_tensor_address("output", output.as_ptr())
+                .unwrap();
+            context
+                .enqueue_v3(crate::cuda::get_default_stream())
+                .unwrap();
+        }
+        crate::synchronize().unwrap();
+
+        // Mock engines report their output as a debug tensor
+        assert!(listener.ranges()["output"].0.is_nan());
+        context.clear_debug_listener().unwrap();
+    }
+}
@@ -0,0 +1,110 @@
+//! Lightweight summaries of serialized engines
+
+use crate::error::Result;
+use crate::logger::Logger;
+use crate::runtime::Runtime;
+use crate::shapes::Dims;
+use crate::tensor::{DataType, TensorIoMode};
+
+/// An I/O tensor as declared by an engine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TensorInfo {
+    /// Tensor name
+    pub name: String,
+    /// Whether the tensor is an input or an output
+    pub io_mode: TensorIoMode,
+    /// Element type
+    pub dtype: DataType,
+    /// Shape, with `-1` for dynamic dimensions
+    pub shape: Dims,
+}
+
+/// I/O metadata of a serialized engine, for cataloging engines without keeping them loaded
+///
+/// TensorRT has no API to read a plan's metadata without deserializing it,
+/// so [`from_bytes`](Self::from_bytes) deserializes the engine with
+/// [`Runtime::deserialize_with_version_check`], records its I/O tensors and
+/// frees it immediately.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EngineHeader {
+    /// TensorRT-RTX version the engine loads with, as `(major, minor, patch)`
+    ///
+    /// A plan only loads in the version that built it (see
+    /// [`version`](crate::version)), unless it was built as version-compatible.
+    pub version: (u32, u32, u32),
+    /// I/O tensors in engine order
+    pub tensors: Vec<TensorInfo>,
+}
+
+impl EngineHeader {
+    /// Read the version and I/O tensors of a serialized engine
+    ///
+    /// Plans built by another TensorRT-RTX version, and truncated or corrupted
+    /// plans, fail with [`Error::InvalidArgument`](crate::Error::InvalidArgument)
+    /// saying so, before anything is deserialized.
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let logger = Logger::stderr()?;
+        let runtime = Runtime::new(&logger)?;
+        let engine = runtime.deserialize_with_version_check(data)?;
+
+        let tensors = engine.tensors().collect::<Result<_>>()?;
+
+        Ok(EngineHeader {
+            version: crate::version(),
+            tensors,
+        })
+    }
+
+    /// Input tensors, in engine order
+    pub fn inputs(&self) -> impl Iterator<Item = &TensorInfo> {
+        self.tensors
+            .iter()
+            .filter(|tensor| tensor.io_mode == TensorIoMode::Input)
+    }
+
+    /// Output tensors, in engine order
+    pub fn outputs(&self) -> impl Iterator<Item = &TensorInfo> {
+        self.tensors
+            .iter()
+            .filter(|tensor| tensor.io_mode == TensorIoMode::Output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_engine_header() {
+        let header = EngineHeader::from_bytes(b"TRTXMOCK\x01\x02").unwrap();
+
+        let inputs: Vec<_> = header.inputs().map(|t| t.name.as_str()).collect();
+        let outputs: Vec<_> = header.outputs().map(|t| t.name.as_str()).collect();
+        assert_eq!(inputs, vec!["input"]);
+        assert_eq!(outputs, vec!["output", "output_1"]);
+        assert_eq!(header.tensors[0].dtype, DataType::Float);
+        assert_eq!(header.tensors[0].shape, vec![-1, 4]);
+        assert_eq!(header.version, crate::version());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_engine_header_version_mismatch() {
+        // Mock plan built by another TensorRT-RTX version
+        let err = EngineHeader::from_bytes(b"TRTXOLDV\x01\x01").unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+        assert!(err.to_string().contains("another TensorRT-RTX version"));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_engine_header_rejects_unloadable_engine() {
+        // Mock engine header with more inputs than the mock supports
+        let err = EngineHeader::from_bytes(b"TRTXMOCK\x05\x01").unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+        // It passes the version check and fails to deserialize
+        assert!(!err.to_string().contains("another TensorRT-RTX version"));
+    }
+}
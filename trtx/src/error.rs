@@ -1,5 +1,7 @@
 //! Error types for TensorRT-RTX operations
 
+use crate::cuda::CudaError;
+use crate::error_recorder::RecordedError;
 use std::ffi::NulError;
 use thiserror::Error;
 
@@ -22,8 +24,25 @@ pub enum Error {
     Runtime(String),
 
     /// CUDA error
-    #[error("CUDA error: {0}")]
-    Cuda(String),
+    #[error("CUDA error: {message}")]
+    Cuda {
+        /// Message from the failing call
+        message: String,
+        /// The CUDA runtime error behind it, if the call left one
+        code: Option<CudaError>,
+    },
+
+    /// No CUDA context could be created, e.g. no GPU, driver or visible device
+    #[error(
+        "CUDA could not be initialized: {message} (check that an NVIDIA driver is installed \
+         and the device is visible, e.g. through CUDA_VISIBLE_DEVICES)"
+    )]
+    CudaNotInitialized {
+        /// Message from the failing call
+        message: String,
+        /// The CUDA runtime error behind it, if the call left one
+        code: Option<CudaError>,
+    },
 
     /// Unknown error
     #[error("Unknown error: {0}")]
@@ -40,10 +59,31 @@ pub enum Error {
     /// IO error
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
+
+    /// Operation stopped because its cancellation flag was set
+    #[error("Cancelled")]
+    Cancelled,
+
+    /// Failure with the details TensorRT reported to an [`ErrorRecorder`](crate::ErrorRecorder)
+    #[error("{message}: {}", join_recorded(.errors))]
+    Recorded {
+        /// Message from the failing call
+        message: String,
+        /// Errors drained from the recorder
+        errors: Vec<RecordedError>,
+    },
+
+    /// JSON parsing error (e.g. engine inspector output)
+    #[cfg(feature = "json")]
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
 }
 
 impl Error {
     /// Create error from FFI error code and message buffer
+    ///
+    /// CUDA errors peek at the thread's last CUDA error without resetting it,
+    /// so callers can still inspect it with [`crate::cuda::last_error`].
     pub(crate) fn from_ffi(code: i32, error_msg: &[i8]) -> Self {
         let msg = Self::parse_error_msg(error_msg);
 
@@ -53,11 +93,26 @@ impl Error {
             }
             code if code == trtx_sys::TRTX_ERROR_OUT_OF_MEMORY as i32 => Error::OutOfMemory(msg),
             code if code == trtx_sys::TRTX_ERROR_RUNTIME_ERROR as i32 => Error::Runtime(msg),
-            code if code == trtx_sys::TRTX_ERROR_CUDA_ERROR as i32 => Error::Cuda(msg),
+            code if code == trtx_sys::TRTX_ERROR_CUDA_ERROR as i32 => Error::Cuda {
+                message: msg,
+                code: crate::cuda::peek_last_error(),
+            },
             _ => Error::Unknown(msg),
         }
     }
 
+    /// Attach recorded errors to an error, or return it unchanged if there are none
+    pub(crate) fn with_recorded(self, errors: Vec<RecordedError>) -> Self {
+        if errors.is_empty() {
+            return self;
+        }
+
+        Error::Recorded {
+            message: self.to_string(),
+            errors,
+        }
+    }
+
     /// Parse error message from C string buffer
     fn parse_error_msg(buffer: &[i8]) -> String {
         // Find null terminator
@@ -70,6 +125,14 @@ impl Error {
     }
 }
 
+fn join_recorded(errors: &[RecordedError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,196 @@
+//! Error recorder for collecting detailed TensorRT errors
+
+use crate::error::{Error, Result};
+use std::fmt;
+use std::sync::Arc;
+use trtx_sys::*;
+
+/// TensorRT error category (mirrors `nvinfer1::ErrorCode`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum ErrorCode {
+    /// Not an error
+    Success = 0,
+    /// Unclassified error
+    UnspecifiedError = 1,
+    /// Internal TensorRT error
+    InternalError = 2,
+    /// Invalid argument passed to an API
+    InvalidArgument = 3,
+    /// Invalid builder or runtime configuration
+    InvalidConfig = 4,
+    /// Memory allocation failed
+    FailedAllocation = 5,
+    /// Initialization of a plugin or resource failed
+    FailedInitialization = 6,
+    /// Execution failed
+    FailedExecution = 7,
+    /// Computation produced an error
+    FailedComputation = 8,
+    /// Object was in an invalid state for the call
+    InvalidState = 9,
+    /// Requested feature is unsupported
+    UnsupportedState = 10,
+}
+
+impl ErrorCode {
+    /// Convert a raw `nvinfer1::ErrorCode` value returned over FFI
+    pub(crate) fn from_raw(value: i32) -> Self {
+        match value {
+            0 => ErrorCode::Success,
+            2 => ErrorCode::InternalError,
+            3 => ErrorCode::InvalidArgument,
+            4 => ErrorCode::InvalidConfig,
+            5 => ErrorCode::FailedAllocation,
+            6 => ErrorCode::FailedInitialization,
+            7 => ErrorCode::FailedExecution,
+            8 => ErrorCode::FailedComputation,
+            9 => ErrorCode::InvalidState,
+            10 => ErrorCode::UnsupportedState,
+            _ => ErrorCode::UnspecifiedError,
+        }
+    }
+}
+
+/// An error reported to an [`ErrorRecorder`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedError {
+    /// Error category
+    pub code: ErrorCode,
+    /// Description from TensorRT
+    pub description: String,
+}
+
+impl fmt::Display for RecordedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:?}] {}", self.code, self.description)
+    }
+}
+
+struct RecorderHandle(*mut TrtxErrorRecorder);
+
+impl Drop for RecorderHandle {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe {
+                trtx_error_recorder_destroy(self.0);
+            }
+        }
+    }
+}
+
+unsafe impl Send for RecorderHandle {}
+unsafe impl Sync for RecorderHandle {}
+
+/// Collects errors reported by TensorRT (wraps `nvinfer1::IErrorRecorder`)
+///
+/// Without a recorder, TensorRT only sends failure details to the logger.
+/// Attach one with [`Builder::set_error_recorder`] to have build failures
+/// return them in [`Error::Recorded`]. Clones share the same recorder.
+///
+/// [`Builder::set_error_recorder`]: crate::Builder::set_error_recorder
+#[derive(Clone)]
+pub struct ErrorRecorder {
+    handle: Arc<RecorderHandle>,
+}
+
+impl ErrorRecorder {
+    /// Create an empty error recorder
+    pub fn new() -> Result<Self> {
+        let mut recorder_ptr: *mut TrtxErrorRecorder = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_error_recorder_create(&mut recorder_ptr, error_msg.as_mut_ptr(), error_msg.len())
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(ErrorRecorder {
+            handle: Arc::new(RecorderHandle(recorder_ptr)),
+        })
+    }
+
+    /// Get the errors recorded so far
+    pub fn errors(&self) -> Result<Vec<RecordedError>> {
+        let mut count: i32 = 0;
+
+        let result = unsafe { trtx_error_recorder_get_nb_errors(self.as_ptr(), &mut count) };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &[]));
+        }
+
+        (0..count)
+            .map(|index| {
+                let mut code: i32 = 0;
+                let mut description = [0i8; 1024];
+                let mut error_msg = [0i8; 1024];
+
+                let result = unsafe {
+                    trtx_error_recorder_get_error(
+                        self.as_ptr(),
+                        index,
+                        &mut code,
+                        description.as_mut_ptr(),
+                        description.len(),
+                        error_msg.as_mut_ptr(),
+                        error_msg.len(),
+                    )
+                };
+
+                if result != TRTX_SUCCESS as i32 {
+                    return Err(Error::from_ffi(result, &error_msg));
+                }
+
+                let description = unsafe { std::ffi::CStr::from_ptr(description.as_ptr()) }
+                    .to_string_lossy()
+                    .into_owned();
+
+                Ok(RecordedError {
+                    code: ErrorCode::from_raw(code),
+                    description,
+                })
+            })
+            .collect()
+    }
+
+    /// Remove all recorded errors
+    pub fn clear(&self) {
+        unsafe {
+            trtx_error_recorder_clear(self.as_ptr());
+        }
+    }
+
+    /// Return the recorded errors and clear the recorder
+    pub fn drain(&self) -> Result<Vec<RecordedError>> {
+        let errors = self.errors()?;
+        self.clear();
+        Ok(errors)
+    }
+
+    /// Get the raw pointer (for internal use)
+    pub(crate) fn as_ptr(&self) -> *mut TrtxErrorRecorder {
+        self.handle.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_recorder_empty() {
+        let recorder = ErrorRecorder::new().unwrap();
+        assert!(recorder.errors().unwrap().is_empty());
+        assert!(recorder.drain().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_error_code_from_raw() {
+        assert_eq!(ErrorCode::from_raw(4), ErrorCode::InvalidConfig);
+        assert_eq!(ErrorCode::from_raw(42), ErrorCode::UnspecifiedError);
+    }
+}
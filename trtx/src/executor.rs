@@ -3,25 +3,1264 @@
 //! This module provides a simplified API for executing ONNX models with TensorRT,
 //! designed to integrate easily with rustnn's executor pattern.
 
-use crate::builder::network_flags;
-use crate::cuda::DeviceBuffer;
-use crate::error::Result;
-use crate::{Builder, Logger, OnnxParser, Runtime};
+use crate::builder::{
+    network_flags, BuilderConfig, EngineBuilder, MemoryPoolType, PrecisionConfig,
+};
+use crate::cuda::{CudaStream, DeviceBuffer};
+use crate::engine_header::TensorInfo;
+use crate::error::{Error, Result};
+use crate::logger::Severity;
+use crate::onnx_parser::OnnxParserFlag;
+use crate::output_allocator::DeviceOutputAllocator;
+use crate::shapes::Dims;
+use crate::tensor::{DataType, TensorIoMode};
+use crate::{Builder, CudaEngine, ExecutionContext, Logger, OnnxParser, Runtime};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+#[cfg(feature = "async")]
+use crate::cuda::CudaEvent;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::sync::Mutex;
+#[cfg(feature = "async")]
+use std::task::{Context as TaskContext, Poll, Waker};
+
+/// Host-side tensor data tagged with its element type
+///
+/// Some variants only exist with a crate feature, so matches need a
+/// wildcard arm to keep compiling when another crate enables it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TensorData {
+    /// 32-bit floats (`DataType::Float`)
+    F32(Vec<f32>),
+    /// 64-bit floats (host only, requires dtype coercion)
+    F64(Vec<f64>),
+    /// 32-bit integers (`DataType::Int32`)
+    I32(Vec<i32>),
+    /// 64-bit integers (`DataType::Int64`)
+    I64(Vec<i64>),
+    /// Signed bytes (`DataType::Int8`)
+    I8(Vec<i8>),
+    /// Unsigned bytes (`DataType::Uint8` and `DataType::Bool`)
+    U8(Vec<u8>),
+    /// 16-bit floats (`DataType::Half`), uploaded as-is
+    #[cfg(feature = "half")]
+    F16(Vec<half::f16>),
+    /// Raw tensor bytes in a memory-mapped file, see [`TensorInput::from_mmap`]
+    ///
+    /// Not serializable.
+    #[cfg(feature = "memmap2")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Mapped(MappedTensor),
+}
+
+/// A memory-mapped file read as the raw bytes of a tensor
+///
+/// The file is uploaded straight from the mapping, so its contents are never
+/// copied into host memory. Clones share the mapping.
+#[cfg(feature = "memmap2")]
+#[derive(Clone)]
+pub struct MappedTensor {
+    kind: HostKind,
+    map: Arc<memmap2::Mmap>,
+}
+
+#[cfg(feature = "memmap2")]
+impl std::fmt::Debug for MappedTensor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MappedTensor")
+            .field("kind", &self.kind)
+            .field("bytes", &self.map.len())
+            .finish()
+    }
+}
+
+#[cfg(feature = "memmap2")]
+impl PartialEq for MappedTensor {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.map[..] == other.map[..]
+    }
+}
+
+/// Element type of a [`TensorData`] value
+///
+/// Used to drive conversions, and to choose the host type of an output with
+/// [`SessionOptions::output_types`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HostKind {
+    /// `f32`, [`TensorData::F32`]
+    F32,
+    /// `f64`, [`TensorData::F64`]
+    F64,
+    /// `i32`, [`TensorData::I32`]
+    I32,
+    /// `i64`, [`TensorData::I64`]
+    I64,
+    /// `i8`, [`TensorData::I8`]
+    I8,
+    /// `u8`, [`TensorData::U8`]
+    U8,
+    /// `half::f16`, `TensorData::F16`
+    #[cfg(feature = "half")]
+    F16,
+}
+
+impl HostKind {
+    /// Size of one element in bytes
+    #[cfg(feature = "memmap2")]
+    fn size(self) -> usize {
+        match self {
+            HostKind::F64 | HostKind::I64 => 8,
+            HostKind::F32 | HostKind::I32 => 4,
+            HostKind::I8 | HostKind::U8 => 1,
+            #[cfg(feature = "half")]
+            HostKind::F16 => 2,
+        }
+    }
+
+    /// Name of the host element type, as reported by [`TensorData::type_name`]
+    pub fn name(self) -> &'static str {
+        match self {
+            HostKind::F32 => "f32",
+            HostKind::F64 => "f64",
+            HostKind::I32 => "i32",
+            HostKind::I64 => "i64",
+            HostKind::I8 => "i8",
+            HostKind::U8 => "u8",
+            #[cfg(feature = "half")]
+            HostKind::F16 => "f16",
+        }
+    }
+
+    /// Kind named by [`TensorData::type_name`]
+    #[cfg(feature = "json")]
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "f32" => Some(HostKind::F32),
+            "f64" => Some(HostKind::F64),
+            "i32" => Some(HostKind::I32),
+            "i64" => Some(HostKind::I64),
+            "i8" => Some(HostKind::I8),
+            "u8" => Some(HostKind::U8),
+            #[cfg(feature = "half")]
+            "f16" => Some(HostKind::F16),
+            _ => None,
+        }
+    }
+
+    /// Host representation used for an engine tensor of the given type
+    fn for_dtype(dtype: DataType) -> Result<Self> {
+        match dtype {
+            DataType::Float => Ok(HostKind::F32),
+            DataType::Int32 => Ok(HostKind::I32),
+            DataType::Int64 => Ok(HostKind::I64),
+            DataType::Int8 => Ok(HostKind::I8),
+            DataType::Uint8 | DataType::Bool => Ok(HostKind::U8),
+            #[cfg(feature = "half")]
+            DataType::Half => Ok(HostKind::F16),
+            #[cfg(not(feature = "half"))]
+            DataType::Half => Err(Error::InvalidArgument(
+                "FP16 tensors require the `half` feature".to_string(),
+            )),
+            other => Err(Error::InvalidArgument(format!(
+                "Tensors of type {:?} are not supported by the executor",
+                other
+            ))),
+        }
+    }
+
+    /// Build data of this kind from `f64` values, counting values that changed
+    fn convert(self, values: &[f64]) -> (TensorData, usize) {
+        fn cast<T: Copy>(
+            values: &[f64],
+            to: impl Fn(f64) -> T,
+            back: impl Fn(T) -> f64,
+        ) -> (Vec<T>, usize) {
+            let mut lossy = 0;
+            let out = values
+                .iter()
+                .map(|&v| {
+                    let c = to(v);
+                    let r = back(c);
+                    if r != v && !(r.is_nan() && v.is_nan()) {
+                        lossy += 1;
+                    }
+                    c
+                })
+                .collect();
+            (out, lossy)
+        }
+
+        match self {
+            HostKind::F32 => {
+                let (v, n) = cast(values, |v| v as f32, f64::from);
+                (TensorData::F32(v), n)
+            }
+            HostKind::F64 => (TensorData::F64(values.to_vec()), 0),
+            HostKind::I32 => {
+                let (v, n) = cast(values, |v| v as i32, f64::from);
+                (TensorData::I32(v), n)
+            }
+            HostKind::I64 => {
+                let (v, n) = cast(values, |v| v as i64, |v| v as f64);
+                (TensorData::I64(v), n)
+            }
+            HostKind::I8 => {
+                let (v, n) = cast(values, |v| v as i8, f64::from);
+                (TensorData::I8(v), n)
+            }
+            HostKind::U8 => {
+                let (v, n) = cast(values, |v| v as u8, f64::from);
+                (TensorData::U8(v), n)
+            }
+            #[cfg(feature = "half")]
+            HostKind::F16 => {
+                let (v, n) = cast(values, half::f16::from_f64, half::f16::to_f64);
+                (TensorData::F16(v), n)
+            }
+        }
+    }
+}
+
+impl TensorData {
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        match self {
+            TensorData::F32(v) => v.len(),
+            TensorData::F64(v) => v.len(),
+            TensorData::I32(v) => v.len(),
+            TensorData::I64(v) => v.len(),
+            TensorData::I8(v) => v.len(),
+            TensorData::U8(v) => v.len(),
+            #[cfg(feature = "half")]
+            TensorData::F16(v) => v.len(),
+            #[cfg(feature = "memmap2")]
+            TensorData::Mapped(m) => m.map.len() / m.kind.size(),
+        }
+    }
+
+    /// Whether the tensor holds no elements
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Borrow the values as `f32`, if this is `F32` data
+    pub fn as_f32(&self) -> Option<&[f32]> {
+        match self {
+            TensorData::F32(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Borrow the values as `f16`, if this is `F16` data
+    #[cfg(feature = "half")]
+    pub fn as_f16(&self) -> Option<&[half::f16]> {
+        match self {
+            TensorData::F16(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Name of the host element type, for diagnostics
+    pub fn type_name(&self) -> &'static str {
+        self.kind().name()
+    }
+
+    /// View the raw bytes of the data for uploading to the device
+    pub fn as_bytes(&self) -> &[u8] {
+        fn bytes<T>(v: &[T]) -> &[u8] {
+            unsafe { std::slice::from_raw_parts(v.as_ptr() as *const u8, std::mem::size_of_val(v)) }
+        }
+
+        match self {
+            TensorData::F32(v) => bytes(v),
+            TensorData::F64(v) => bytes(v),
+            TensorData::I32(v) => bytes(v),
+            TensorData::I64(v) => bytes(v),
+            TensorData::I8(v) => bytes(v),
+            TensorData::U8(v) => bytes(v),
+            #[cfg(feature = "half")]
+            TensorData::F16(v) => bytes(v),
+            #[cfg(feature = "memmap2")]
+            TensorData::Mapped(m) => &m.map,
+        }
+    }
+
+    fn kind(&self) -> HostKind {
+        match self {
+            TensorData::F32(_) => HostKind::F32,
+            TensorData::F64(_) => HostKind::F64,
+            TensorData::I32(_) => HostKind::I32,
+            TensorData::I64(_) => HostKind::I64,
+            TensorData::I8(_) => HostKind::I8,
+            TensorData::U8(_) => HostKind::U8,
+            #[cfg(feature = "half")]
+            TensorData::F16(_) => HostKind::F16,
+            #[cfg(feature = "memmap2")]
+            TensorData::Mapped(m) => m.kind,
+        }
+    }
+
+    fn to_f64(&self) -> Vec<f64> {
+        match self {
+            TensorData::F32(v) => v.iter().map(|&x| f64::from(x)).collect(),
+            TensorData::F64(v) => v.clone(),
+            TensorData::I32(v) => v.iter().map(|&x| f64::from(x)).collect(),
+            TensorData::I64(v) => v.iter().map(|&x| x as f64).collect(),
+            TensorData::I8(v) => v.iter().map(|&x| f64::from(x)).collect(),
+            TensorData::U8(v) => v.iter().map(|&x| f64::from(x)).collect(),
+            #[cfg(feature = "half")]
+            TensorData::F16(v) => v.iter().map(|x| x.to_f64()).collect(),
+            #[cfg(feature = "memmap2")]
+            TensorData::Mapped(m) => Self::from_bytes(m.kind, &m.map).to_f64(),
+        }
+    }
+
+    /// Rebuild data from its [`type_name`](Self::type_name) and raw bytes
+    #[cfg(feature = "json")]
+    pub(crate) fn from_type_name(type_name: &str, bytes: &[u8]) -> Option<TensorData> {
+        Some(Self::from_bytes(HostKind::from_name(type_name)?, bytes))
+    }
+
+    /// Reinterpret bytes copied back from the device as data of the given kind
+    fn from_bytes(kind: HostKind, bytes: &[u8]) -> TensorData {
+        fn read<T: Copy + Default>(bytes: &[u8]) -> Vec<T> {
+            let mut out = vec![T::default(); bytes.len() / std::mem::size_of::<T>()];
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    bytes.as_ptr(),
+                    out.as_mut_ptr() as *mut u8,
+                    std::mem::size_of_val(out.as_slice()),
+                );
+            }
+            out
+        }
+
+        match kind {
+            HostKind::F32 => TensorData::F32(read(bytes)),
+            HostKind::F64 => TensorData::F64(read(bytes)),
+            HostKind::I32 => TensorData::I32(read(bytes)),
+            HostKind::I64 => TensorData::I64(read(bytes)),
+            HostKind::I8 => TensorData::I8(read(bytes)),
+            HostKind::U8 => TensorData::U8(read(bytes)),
+            #[cfg(feature = "half")]
+            HostKind::F16 => TensorData::F16(read(bytes)),
+        }
+    }
+}
+
+impl From<Vec<f32>> for TensorData {
+    fn from(v: Vec<f32>) -> Self {
+        TensorData::F32(v)
+    }
+}
+
+impl From<Vec<f64>> for TensorData {
+    fn from(v: Vec<f64>) -> Self {
+        TensorData::F64(v)
+    }
+}
+
+impl From<Vec<i32>> for TensorData {
+    fn from(v: Vec<i32>) -> Self {
+        TensorData::I32(v)
+    }
+}
+
+impl From<Vec<i64>> for TensorData {
+    fn from(v: Vec<i64>) -> Self {
+        TensorData::I64(v)
+    }
+}
+
+impl From<Vec<i8>> for TensorData {
+    fn from(v: Vec<i8>) -> Self {
+        TensorData::I8(v)
+    }
+}
+
+impl From<Vec<u8>> for TensorData {
+    fn from(v: Vec<u8>) -> Self {
+        TensorData::U8(v)
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<Vec<half::f16>> for TensorData {
+    fn from(v: Vec<half::f16>) -> Self {
+        TensorData::F16(v)
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<&[half::f16]> for TensorData {
+    fn from(v: &[half::f16]) -> Self {
+        TensorData::F16(v.to_vec())
+    }
+}
 
 /// Input descriptor for TensorRT execution
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TensorInput {
     pub name: String,
-    pub shape: Vec<usize>,
-    pub data: Vec<f32>,
+    pub shape: Dims,
+    pub data: TensorData,
+}
+
+impl TensorInput {
+    /// Input whose data is the raw contents of the file at `path`, mapped
+    /// into memory instead of read
+    ///
+    /// For gigabyte-sized inputs this avoids holding a second copy in host
+    /// memory: the mapping is uploaded to the device directly. The file must
+    /// hold exactly `shape.product() * dtype.size()` bytes in native byte
+    /// order, and must not be modified while the input is alive.
+    #[cfg(feature = "memmap2")]
+    pub fn from_mmap(
+        name: impl Into<String>,
+        shape: impl Into<Dims>,
+        dtype: DataType,
+        path: &std::path::Path,
+    ) -> Result<Self> {
+        let name = name.into();
+        let shape = shape.into();
+        let kind = HostKind::for_dtype(dtype)?;
+        let elements = shape.product().ok_or_else(|| {
+            Error::InvalidArgument(format!("Input '{}' has dynamic shape {}", name, shape))
+        })?;
+        let expected = elements * dtype.size();
+
+        let file = std::fs::File::open(path)?;
+        let len = file.metadata()?.len();
+        if len != expected as u64 {
+            return Err(Error::InvalidArgument(format!(
+                "Input '{}' of shape {} and type {:?} needs {} bytes but {:?} holds {}",
+                name, shape, dtype, expected, path, len
+            )));
+        }
+
+        // SAFETY: the mapping is read-only; the caller must not modify or
+        // truncate the file while the input is alive.
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+
+        Ok(TensorInput {
+            name,
+            shape,
+            data: TensorData::Mapped(MappedTensor {
+                kind,
+                map: Arc::new(map),
+            }),
+        })
+    }
 }
 
 /// Output descriptor from TensorRT execution
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TensorOutput {
     pub name: String,
-    pub shape: Vec<usize>,
-    pub data: Vec<f32>,
+    pub shape: Dims,
+    pub data: TensorData,
+}
+
+/// Summary statistics over an output tensor's values
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutputStats {
+    /// Smallest non-NaN value (NaN if there is none)
+    pub min: f64,
+    /// Largest non-NaN value (NaN if there is none)
+    pub max: f64,
+    /// Mean of the non-NaN values (NaN if there is none)
+    pub mean: f64,
+    /// Whether any value is NaN
+    pub has_nan: bool,
+    /// Whether any value is infinite
+    pub has_inf: bool,
+}
+
+impl TensorOutput {
+    /// Compute min/max/mean and NaN/infinity flags over the output values
+    pub fn stats(&self) -> OutputStats {
+        let mut stats = OutputStats {
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            mean: 0.0,
+            has_nan: false,
+            has_inf: false,
+        };
+        let mut count = 0usize;
+
+        for value in self.data.to_f64() {
+            if value.is_nan() {
+                stats.has_nan = true;
+                continue;
+            }
+            stats.has_inf |= value.is_infinite();
+            stats.min = stats.min.min(value);
+            stats.max = stats.max.max(value);
+            stats.mean += value;
+            count += 1;
+        }
+
+        if count == 0 {
+            stats.min = f64::NAN;
+            stats.max = f64::NAN;
+            stats.mean = f64::NAN;
+        } else {
+            stats.mean /= count as f64;
+        }
+
+        stats
+    }
+
+    /// Softmax of the values over the last axis
+    ///
+    /// For a `[batch, classes]` output each row sums to 1. Outputs without a
+    /// shape are treated as a single row.
+    pub fn softmax(&self) -> Vec<f32> {
+        let values = self.data.to_f64();
+        let row = self
+            .shape
+            .last()
+            .and_then(|&n| usize::try_from(n).ok())
+            .filter(|&n| n > 0 && values.len().is_multiple_of(n))
+            .unwrap_or(values.len());
+        softmax_strided(&values, row, 1)
+    }
+
+    /// Softmax of the values along `axis`, in the output's row-major layout
+    ///
+    /// Fails with [`Error::InvalidArgument`] if `axis` is out of range or the
+    /// shape does not match the number of values.
+    pub fn softmax_axis(&self, axis: usize) -> Result<Vec<f32>> {
+        let values = self.data.to_f64();
+        if axis >= self.shape.len() || self.shape.product() != Some(values.len()) {
+            return Err(Error::InvalidArgument(format!(
+                "Cannot take softmax along axis {} of output '{}' with shape {} and {} values",
+                axis,
+                self.name,
+                self.shape,
+                values.len()
+            )));
+        }
+        let inner = self.shape[axis + 1..].iter().product::<i64>() as usize;
+        Ok(softmax_strided(&values, self.shape[axis] as usize, inner))
+    }
+
+    /// The `k` largest values and their indices in the flattened data,
+    /// largest first
+    ///
+    /// For a `[1, classes]` logits output the indices are class ids. Returns
+    /// fewer than `k` entries if the output is smaller; NaNs sort last.
+    pub fn top_k(&self, k: usize) -> Vec<(usize, f32)> {
+        let mut ranked: Vec<(usize, f32)> = self
+            .data
+            .to_f64()
+            .into_iter()
+            .map(|v| v as f32)
+            .enumerate()
+            .collect();
+        ranked.sort_by(|a, b| match (a.1.is_nan(), b.1.is_nan()) {
+            (false, false) => b.1.total_cmp(&a.1),
+            (nan_a, nan_b) => nan_a.cmp(&nan_b),
+        });
+        ranked.truncate(k);
+        ranked
+    }
+
+    /// Dequantize the values as `(q - zero_point) * scale`
+    ///
+    /// For outputs that stay quantized, such as an INT8 tensor after a final
+    /// QuantizeLinear node. Engines do not expose tensor scales, so pass the
+    /// scale and zero point from the model's quantization parameters. Outputs
+    /// of other types are transformed the same way.
+    pub fn dequantize(&self, scale: f32, zero_point: i32) -> Vec<f32> {
+        let (scale, zero_point) = (f64::from(scale), f64::from(zero_point));
+        self.data
+            .to_f64()
+            .into_iter()
+            .map(|q| ((q - zero_point) * scale) as f32)
+            .collect()
+    }
+}
+
+/// Softmax over groups of `len` values spaced `inner` apart (row-major axis)
+fn softmax_strided(values: &[f64], len: usize, inner: usize) -> Vec<f32> {
+    let mut out = vec![0f32; values.len()];
+    if len == 0 || inner == 0 {
+        return out;
+    }
+    let block = len * inner;
+    for start in (0..values.len()).step_by(block) {
+        for offset in 0..inner {
+            let indices = (0..len).map(|i| start + offset + i * inner);
+            let max = indices
+                .clone()
+                .map(|i| values[i])
+                .fold(f64::NEG_INFINITY, f64::max);
+            let sum: f64 = indices.clone().map(|i| (values[i] - max).exp()).sum();
+            for i in indices {
+                out[i] = ((values[i] - max).exp() / sum) as f32;
+            }
+        }
+    }
+    out
+}
+
+/// Options controlling how a [`Session`] executes inference
+#[derive(Debug, Clone, Default)]
+pub struct SessionOptions {
+    /// Convert inputs whose host type differs from the engine's tensor type
+    /// instead of failing (e.g. `f64` host data for an `f32` engine). Outputs
+    /// are not affected; see [`output_types`](Self::output_types). Conversions
+    /// that lose precision are reported as warnings through the logger.
+    ///
+    /// Off by default so that mismatched data is a hard error. In particular,
+    /// `f32` data for an FP16 tensor is rejected unless this is enabled; the
+    /// conversion then costs an extra pass over the host data on every call,
+    /// so prefer supplying `TensorData::F16` directly (requires the `half`
+    /// feature).
+    pub coerce_dtypes: bool,
+
+    /// Host type to return each named output as
+    ///
+    /// Outputs not listed come back in their engine type's host
+    /// representation (e.g. [`HostKind::F32`] for an `f32` tensor), whatever
+    /// the host types of the inputs. Conversions that lose precision are
+    /// reported as warnings through the logger.
+    pub output_types: HashMap<String, HostKind>,
+
+    /// Upper bound on device memory used by the session, in bytes
+    ///
+    /// When passed to [`Session::from_onnx`], the build workspace is capped to
+    /// this budget. Before each inference the engine's activation memory plus
+    /// the I/O buffers are checked against it (failing with
+    /// [`Error::OutOfMemory`] if they cannot fit). For engines built with
+    /// weight streaming, the remaining budget is given to resident weights.
+    /// Non-streamable weights are not accounted for.
+    pub memory_budget: Option<usize>,
+
+    /// Log [`OutputStats`] for every output after each inference
+    ///
+    /// Outputs containing NaN or infinite values are logged as warnings, which
+    /// helps spot overflowing activations in FP16 engines.
+    pub log_output_stats: bool,
+}
+
+/// An inference session over a serialized engine
+///
+/// The engine is deserialized on the first [`infer`](Session::infer) call,
+/// and it and its execution context are reused by later calls.
+pub struct Session {
+    // Declared before the logger: the engine is dropped first
+    state: Option<SessionState>,
+    logger: Logger,
+    engine_data: Vec<u8>,
+    options: SessionOptions,
+    #[cfg(feature = "json")]
+    record_path: Option<std::path::PathBuf>,
+}
+
+/// Deserialized engine of a [`Session`] and its execution context
+struct SessionState {
+    // Borrows `engine`, so it is declared, and dropped, first
+    context: Option<ExecutionContext<'static>>,
+    // Boxed so that the context's reference survives moves of the state
+    engine: Box<CudaEngine>,
+    // Weight streaming budget set for the memory budget, if any
+    streaming_budget: Option<i64>,
+}
+
+impl SessionState {
+    fn new(logger: &Logger, engine_data: &[u8]) -> Result<Self> {
+        let runtime = Runtime::new(logger)?;
+        Ok(SessionState {
+            context: None,
+            engine: Box::new(runtime.deserialize_cuda_engine(engine_data)?),
+            streaming_budget: None,
+        })
+    }
+
+    /// Set the engine's weight streaming budget, recreating the context if it changes
+    fn set_streaming_budget(&mut self, budget: i64) -> Result<()> {
+        if self.streaming_budget != Some(budget) {
+            // The budget can only change while the engine has no contexts
+            self.context = None;
+            self.engine.set_weight_streaming_budget(budget)?;
+            self.streaming_budget = Some(budget);
+        }
+        Ok(())
+    }
+
+    /// The cached execution context, created on first use
+    fn context(&mut self) -> Result<&mut ExecutionContext<'static>> {
+        if self.context.is_none() {
+            let context = self.engine.create_execution_context()?;
+            // SAFETY: the engine is boxed and never moved out of the state,
+            // the context is dropped before it, and the engine is only
+            // borrowed mutably after the context is dropped
+            let context: ExecutionContext<'static> = unsafe { std::mem::transmute(context) };
+            self.context = Some(context);
+        }
+        Ok(self.context.as_mut().unwrap())
+    }
+}
+
+impl Session {
+    /// Build an engine from an ONNX model and create a session for it
+    pub fn from_onnx(onnx_model_bytes: &[u8], options: SessionOptions) -> Result<Self> {
+        let logger = Logger::stderr()?;
+        let workspace = options
+            .memory_budget
+            .map_or(DEFAULT_WORKSPACE_SIZE, |budget| {
+                budget.min(DEFAULT_WORKSPACE_SIZE)
+            });
+        let engine_data = build_engine_from_onnx(&logger, onnx_model_bytes, workspace)?;
+
+        Ok(Session {
+            state: None,
+            logger,
+            engine_data,
+            options,
+            #[cfg(feature = "json")]
+            record_path: None,
+        })
+    }
+
+    /// Create a session from an already serialized engine
+    pub fn from_engine(engine_data: Vec<u8>, options: SessionOptions) -> Result<Self> {
+        Ok(Session {
+            state: None,
+            logger: Logger::stderr()?,
+            engine_data,
+            options,
+            #[cfg(feature = "json")]
+            record_path: None,
+        })
+    }
+
+    /// Limit the device memory used by this session's inferences
+    ///
+    /// The engine is already built at this point, so unlike setting
+    /// [`SessionOptions::memory_budget`] before [`from_onnx`](Self::from_onnx),
+    /// this does not cap the build workspace. Only the inference checks and
+    /// the weight streaming budget apply.
+    pub fn with_inference_memory_budget(mut self, bytes: usize) -> Self {
+        self.options.memory_budget = Some(bytes);
+        self
+    }
+
+    /// Options this session was created with
+    pub fn options(&self) -> &SessionOptions {
+        &self.options
+    }
+
+    /// The serialized engine this session runs
+    pub fn engine_data(&self) -> &[u8] {
+        &self.engine_data
+    }
+
+    /// Save the inputs of the next [`infer`](Self::infer) call to `path`
+    ///
+    /// The recording holds the inputs and a hash of the engine, and is
+    /// written before inference runs so that it survives a crash. Reproduce
+    /// the call with [`replay_inference`](crate::replay_inference).
+    #[cfg(feature = "json")]
+    pub fn record_next_inference(&mut self, path: impl AsRef<std::path::Path>) {
+        self.record_path = Some(path.as_ref().to_path_buf());
+    }
+
+    /// Run inference with the given inputs
+    ///
+    /// Every engine input must be provided. Engines without inputs (e.g.
+    /// constant generators) accept an empty slice.
+    pub fn infer(&mut self, inputs: &[TensorInput]) -> Result<Vec<TensorOutput>> {
+        #[cfg(feature = "json")]
+        if let Some(path) = self.record_path.take() {
+            crate::replay::record(&path, &self.engine_data, inputs, &self.options)?;
+        }
+
+        if self.state.is_none() {
+            self.state = Some(SessionState::new(&self.logger, &self.engine_data)?);
+        }
+        let state = self.state.as_mut().unwrap();
+        let outputs = execute_engine(&self.logger, state, inputs, &self.options)?;
+
+        if self.options.log_output_stats {
+            for output in &outputs {
+                let stats = output.stats();
+                let severity = if stats.has_nan || stats.has_inf {
+                    Severity::Warning
+                } else {
+                    Severity::Info
+                };
+                self.logger.log(
+                    severity,
+                    &format!(
+                        "Output '{}': min={} max={} mean={} nan={} inf={}",
+                        output.name, stats.min, stats.max, stats.mean, stats.has_nan, stats.has_inf
+                    ),
+                );
+            }
+        }
+
+        Ok(outputs)
+    }
+
+    /// Run inference on a single-output engine and return that output
+    ///
+    /// Fails with [`Error::InvalidArgument`] if the engine has more than one
+    /// output; use [`infer`](Self::infer) for those.
+    pub fn infer_single(&mut self, inputs: &[TensorInput]) -> Result<TensorOutput> {
+        let mut outputs = self.infer(inputs)?;
+        if outputs.len() != 1 {
+            return Err(Error::InvalidArgument(format!(
+                "infer_single requires a single-output engine, this one has {} outputs",
+                outputs.len()
+            )));
+        }
+        Ok(outputs.remove(0))
+    }
+}
+
+/// Shape of each engine output, `None` until execution reports it
+type OutputShapes = Vec<Option<Dims>>;
+
+/// Reusable inference executor over a deserialized engine
+///
+/// Unlike [`Session`], which allocates fresh device buffers on every call,
+/// an executor keeps its device buffers alive across calls, growing them
+/// only when inputs get larger. Input data must match the engine's tensor
+/// types exactly.
+///
+/// Outputs whose shapes depend on the input data (e.g. NonZero or NMS) are
+/// allocated by TensorRT during execution instead of being preallocated.
+pub struct Executor<'e> {
+    engine: &'e CudaEngine,
+    context: ExecutionContext<'e>,
+    inputs: Vec<(String, DataType)>,
+    outputs: Vec<(String, DataType, HostKind)>,
+    buffers: HashMap<String, DeviceBuffer>,
+    // Memory for data-dependent outputs, registered on first use
+    output_allocator: Arc<DeviceOutputAllocator>,
+    allocated_outputs: HashSet<String>,
+    // Created on first use, so only the executor's own work is waited on.
+    // Blocking, so enqueues wait for the input copies on the default stream
+    stream: Option<CudaStream>,
+}
+
+impl<'e> Executor<'e> {
+    /// Create an executor with its own execution context on `engine`
+    pub fn new(engine: &'e CudaEngine) -> Result<Self> {
+        let mut inputs = Vec::new();
+        let mut outputs = Vec::new();
+
+        for tensor in engine.tensors() {
+            let TensorInfo {
+                name,
+                dtype,
+                io_mode,
+                ..
+            } = tensor?;
+            match io_mode {
+                TensorIoMode::Input => inputs.push((name, dtype)),
+                TensorIoMode::Output => outputs.push((name, dtype, HostKind::for_dtype(dtype)?)),
+                TensorIoMode::None => {}
+            }
+        }
+
+        Ok(Executor {
+            engine,
+            context: engine.create_execution_context()?,
+            inputs,
+            outputs,
+            buffers: HashMap::new(),
+            output_allocator: Arc::new(DeviceOutputAllocator::new()),
+            allocated_outputs: HashSet::new(),
+            stream: None,
+        })
+    }
+
+    /// Engine this executor runs
+    pub fn engine(&self) -> &'e CudaEngine {
+        self.engine
+    }
+
+    /// Names of the engine's input tensors
+    pub fn input_names(&self) -> impl Iterator<Item = &str> {
+        self.inputs.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Set the batch size of every input with a dynamic batch dimension
+    ///
+    /// For engines whose only dynamic dimension is the leading (batch) one,
+    /// as in most classification and detection models: each such input gets
+    /// its leading dimension set to `n`, so output shapes resolve before any
+    /// data is available. Inputs without dynamic dimensions are left alone.
+    /// [`run`](Self::run) still sets shapes from the inputs it is given.
+    ///
+    /// Fails with [`Error::InvalidArgument`] if `n` is not positive, or if an
+    /// input has a dynamic dimension other than the leading one.
+    pub fn set_batch_size(&mut self, n: i32) -> Result<()> {
+        if n <= 0 {
+            return Err(Error::InvalidArgument(format!(
+                "Batch size must be positive, got {}",
+                n
+            )));
+        }
+
+        let mut shapes = Vec::new();
+        for (name, _) in &self.inputs {
+            let mut shape = self.engine.get_tensor_shape(name)?;
+            if !shape.is_dynamic() {
+                continue;
+            }
+            if shape.iter().skip(1).any(|&d| d < 0) {
+                return Err(Error::InvalidArgument(format!(
+                    "Input '{}' has shape {}; set_batch_size needs the batch to be its only \
+                     dynamic dimension",
+                    name, shape
+                )));
+            }
+            shape[0] = i64::from(n);
+            shapes.push((name, shape));
+        }
+
+        for (name, shape) in shapes {
+            self.context.set_input_shape(name, &shape)?;
+        }
+        Ok(())
+    }
+
+    /// Run inference and wait for the outputs
+    ///
+    /// Fails with [`Error::InvalidArgument`] if an engine input is missing,
+    /// an input name is unknown, or an input's type or size is wrong.
+    pub fn run(&mut self, inputs: &[TensorInput]) -> Result<Vec<TensorOutput>> {
+        let output_shapes = self.prepare(inputs)?;
+        if self.stream.is_none() {
+            self.stream = Some(CudaStream::new_blocking()?);
+        }
+        let stream = self.stream.as_ref().unwrap();
+        unsafe {
+            self.context.enqueue_v3(stream.as_ptr())?;
+        }
+        stream.synchronize()?;
+        self.collect_outputs(output_shapes)
+    }
+
+    /// Run inference without blocking the calling thread
+    ///
+    /// Inputs are checked and copied to the device right away, then execution
+    /// is enqueued on a stream owned by the executor. The returned future
+    /// resolves to the outputs once execution completes; errors, including
+    /// the input errors [`run`](Self::run) reports, surface when it is awaited.
+    ///
+    /// The future does not depend on a particular async runtime: a CUDA host
+    /// function queued on the stream after the inference wakes the awaiting
+    /// task once it completes, so nothing polls or sleeps. That costs one
+    /// host function launch per inference, and the wake-up runs on a CUDA
+    /// driver thread, briefly holding up the stream's next work; wakers that
+    /// do more than schedule the task, or call CUDA, should not be used.
+    /// Dropping the future early blocks until the enqueued work finishes,
+    /// since that work still uses the executor's buffers.
+    #[cfg(feature = "async")]
+    pub fn enqueue_async(&mut self, inputs: &[TensorInput]) -> InferenceFuture<'_, 'e> {
+        match self.enqueue_on_stream(inputs) {
+            Ok((output_shapes, event, completion)) => InferenceFuture {
+                pending: Some(PendingInference {
+                    executor: self,
+                    output_shapes,
+                    event,
+                    completion,
+                }),
+                error: None,
+            },
+            Err(error) => InferenceFuture {
+                pending: None,
+                error: Some(error),
+            },
+        }
+    }
+
+    #[cfg(feature = "async")]
+    fn enqueue_on_stream(
+        &mut self,
+        inputs: &[TensorInput],
+    ) -> Result<(OutputShapes, Arc<CudaEvent>, Arc<Mutex<Completion>>)> {
+        let output_shapes = self.prepare(inputs)?;
+        if self.stream.is_none() {
+            self.stream = Some(CudaStream::new_blocking()?);
+        }
+        let stream = self.stream.as_ref().unwrap();
+        unsafe {
+            self.context.enqueue_v3(stream.as_ptr())?;
+        }
+        let event = CudaEvent::new()?;
+        event.record(stream)?;
+
+        let completion = Arc::new(Mutex::new(Completion::default()));
+        let signal = completion.clone();
+        stream.launch_host_fn(move || {
+            let mut completion = signal.lock().unwrap();
+            completion.done = true;
+            if let Some(waker) = completion.waker.take() {
+                waker.wake();
+            }
+        })?;
+        Ok((output_shapes, Arc::new(event), completion))
+    }
+
+    /// Check and upload `inputs` and bind the outputs
+    ///
+    /// Returns each output's shape, or `None` for outputs whose shape is only
+    /// known after execution.
+    fn prepare(&mut self, inputs: &[TensorInput]) -> Result<OutputShapes> {
+        if let Some(unknown) = inputs
+            .iter()
+            .find(|input| !self.inputs.iter().any(|(name, _)| *name == input.name))
+        {
+            return Err(Error::InvalidArgument(format!(
+                "Unknown input '{}' (engine inputs: {:?})",
+                unknown.name,
+                self.input_names().collect::<Vec<_>>()
+            )));
+        }
+
+        for (name, dtype) in &self.inputs {
+            let input = inputs
+                .iter()
+                .find(|input| input.name == *name)
+                .ok_or_else(|| Error::InvalidArgument(format!("Missing input '{}'", name)))?;
+            if input.data.kind() != HostKind::for_dtype(*dtype)? {
+                return Err(Error::InvalidArgument(format!(
+                    "Input '{}' has host type {} but the engine expects {:?}",
+                    name,
+                    input.data.type_name(),
+                    dtype
+                )));
+            }
+            if input.shape.product() != Some(input.data.len()) {
+                return Err(Error::InvalidArgument(format!(
+                    "Input '{}' has {} elements but shape {}",
+                    name,
+                    input.data.len(),
+                    input.shape
+                )));
+            }
+
+            self.context.set_input_shape(name, &input.shape)?;
+
+            let bytes = input.data.as_bytes();
+            let buffer = Self::bind(&mut self.context, &mut self.buffers, name, bytes.len())?;
+            buffer.copy_from_host(bytes)?;
+        }
+
+        // Outputs still unresolved have data-dependent shapes: TensorRT
+        // allocates them during execution and reports their shapes afterwards
+        let mut output_shapes = Vec::with_capacity(self.outputs.len());
+        for (name, dtype, _) in &self.outputs {
+            let shape = self.context.get_tensor_shape(name)?;
+            let Some(elements) = shape.product() else {
+                if !self.allocated_outputs.contains(name) {
+                    self.context
+                        .set_output_allocator(name, self.output_allocator.clone())?;
+                    self.allocated_outputs.insert(name.clone());
+                }
+                output_shapes.push(None);
+                continue;
+            };
+            Self::bind(
+                &mut self.context,
+                &mut self.buffers,
+                name,
+                elements * dtype.size(),
+            )?;
+            output_shapes.push(Some(shape));
+        }
+
+        Ok(output_shapes)
+    }
+
+    /// Copy the outputs of a completed execution to the host
+    fn collect_outputs(&self, output_shapes: OutputShapes) -> Result<Vec<TensorOutput>> {
+        let mut outputs = Vec::with_capacity(self.outputs.len());
+        for ((name, dtype, kind), shape) in self.outputs.iter().zip(output_shapes) {
+            let allocated = shape.is_none();
+            let shape = match shape {
+                Some(shape) => shape,
+                None => self.output_allocator.shape(name).ok_or_else(|| {
+                    Error::Runtime(format!("Output '{}' shape was not reported", name))
+                })?,
+            };
+            let mut host_data = vec![0u8; shape.product().unwrap_or(0) * dtype.size()];
+            if allocated {
+                self.output_allocator.copy_to_host(name, &mut host_data)?;
+            } else {
+                self.buffers[name].copy_to_host(&mut host_data)?;
+            }
+            outputs.push(TensorOutput {
+                name: name.clone(),
+                shape,
+                data: TensorData::from_bytes(*kind, &host_data),
+            });
+        }
+
+        Ok(outputs)
+    }
+
+    /// Run inference on `f32` arrays, returning outputs reshaped to their true shapes
+    ///
+    /// All engine inputs and outputs must be `f32` tensors.
+    #[cfg(feature = "ndarray")]
+    pub fn infer(
+        &mut self,
+        inputs: HashMap<String, ndarray::ArrayViewD<f32>>,
+    ) -> Result<HashMap<String, ndarray::ArrayD<f32>>> {
+        let inputs: Vec<TensorInput> = inputs
+            .into_iter()
+            .map(|(name, array)| TensorInput {
+                name,
+                shape: array.shape().iter().map(|&d| d as i64).collect(),
+                data: TensorData::F32(array.iter().copied().collect()),
+            })
+            .collect();
+
+        self.run(&inputs)?
+            .into_iter()
+            .map(|output| {
+                let data = match output.data {
+                    TensorData::F32(data) => data,
+                    other => {
+                        return Err(Error::InvalidArgument(format!(
+                            "Output '{}' has host type {}, expected f32",
+                            output.name,
+                            other.type_name()
+                        )))
+                    }
+                };
+                let shape = output.shape.to_usize().unwrap_or_default();
+                let array = ndarray::ArrayD::from_shape_vec(shape, data)
+                    .map_err(|e| Error::Runtime(e.to_string()))?;
+                Ok((output.name, array))
+            })
+            .collect()
+    }
+
+    /// Get a device buffer of at least `size` bytes for `name`, rebinding it if reallocated
+    fn bind<'b>(
+        context: &mut ExecutionContext<'e>,
+        buffers: &'b mut HashMap<String, DeviceBuffer>,
+        name: &str,
+        size: usize,
+    ) -> Result<&'b mut DeviceBuffer> {
+        let reallocate = buffers.get(name).is_none_or(|buffer| buffer.size() < size);
+        if reallocate {
+            let (free, _) = crate::cuda::memory_info()?;
+            if size > free {
+                return Err(Error::OutOfMemory(format!(
+                    "Tensor '{}' needs {} bytes but only {} bytes of device memory are free",
+                    name, size, free
+                )));
+            }
+            let buffer = DeviceBuffer::new(size)?;
+            unsafe {
+                context.set_tensor_address(name, buffer.as_ptr())?;
+            }
+            buffers.insert(name.to_string(), buffer);
+        }
+        Ok(buffers.get_mut(name).unwrap())
+    }
+}
+
+/// Future returned by [`Executor::enqueue_async`], resolving to the outputs
+///
+/// Holds the executor borrowed until it resolves or is dropped.
+#[cfg(feature = "async")]
+#[must_use = "the outputs are only copied back when the future is awaited"]
+pub struct InferenceFuture<'a, 'e> {
+    pending: Option<PendingInference<'a, 'e>>,
+    // Set when the inference could not be enqueued
+    error: Option<Error>,
+}
+
+#[cfg(feature = "async")]
+struct PendingInference<'a, 'e> {
+    executor: &'a mut Executor<'e>,
+    output_shapes: OutputShapes,
+    event: Arc<CudaEvent>,
+    completion: Arc<Mutex<Completion>>,
+}
+
+/// Shared between a pending inference and the host function signalling its end
+#[cfg(feature = "async")]
+#[derive(Default)]
+struct Completion {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+#[cfg(feature = "async")]
+impl PendingInference<'_, '_> {
+    /// Arrange for `waker` to be woken once the inference completes
+    fn wake_on_completion(&self, waker: &Waker) {
+        let mut completion = self.completion.lock().unwrap();
+        if completion.done {
+            // Completed after the caller's query but before this registration
+            waker.wake_by_ref();
+            return;
+        }
+        completion.waker = Some(waker.clone());
+    }
+}
+
+#[cfg(feature = "async")]
+impl Drop for PendingInference<'_, '_> {
+    fn drop(&mut self) {
+        // The executor's buffers must outlive the enqueued work; returns
+        // immediately once the event has fired
+        let _ = self.event.synchronize();
+    }
+}
+
+#[cfg(feature = "async")]
+impl Future for InferenceFuture<'_, '_> {
+    type Output = Result<Vec<TensorOutput>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        if let Some(error) = this.error.take() {
+            return Poll::Ready(Err(error));
+        }
+        let pending = this
+            .pending
+            .as_mut()
+            .expect("InferenceFuture polled after completion");
+
+        match pending.event.query() {
+            Ok(false) => {
+                pending.wake_on_completion(cx.waker());
+                Poll::Pending
+            }
+            Ok(true) => {
+                let mut pending = this.pending.take().unwrap();
+                let output_shapes = std::mem::take(&mut pending.output_shapes);
+                Poll::Ready(pending.executor.collect_outputs(output_shapes))
+            }
+            Err(error) => {
+                this.pending = None;
+                Poll::Ready(Err(error))
+            }
+        }
+    }
 }
 
 /// Execute an ONNX model with TensorRT using provided inputs
@@ -44,119 +1283,236 @@ pub fn run_onnx_with_tensorrt(
     onnx_model_bytes: &[u8],
     inputs: &[TensorInput],
 ) -> Result<Vec<TensorOutput>> {
-    // Create logger
-    let logger = Logger::stderr()?;
+    Session::from_onnx(onnx_model_bytes, SessionOptions::default())?.infer(inputs)
+}
 
-    // Build engine from ONNX
-    let engine_data = build_engine_from_onnx(&logger, onnx_model_bytes)?;
+/// Default build workspace size (1GB)
+const DEFAULT_WORKSPACE_SIZE: usize = 1 << 30;
 
-    // Execute inference
-    execute_engine(&logger, &engine_data, inputs)
+/// Build TensorRT engine from ONNX model
+fn build_engine_from_onnx(logger: &Logger, onnx_bytes: &[u8], workspace: usize) -> Result<Vec<u8>> {
+    EngineBuilder::from_onnx(onnx_bytes)
+        .logger(logger)
+        .workspace(workspace)
+        .build()
 }
 
-/// Build TensorRT engine from ONNX model
-fn build_engine_from_onnx(logger: &Logger, onnx_bytes: &[u8]) -> Result<Vec<u8>> {
-    // Create builder
-    let builder = Builder::new(logger)?;
+/// Fail with [`Error::InvalidArgument`] unless `bytes` look like an ONNX model
+///
+/// Every entry point building from ONNX bytes calls this first, so that bytes
+/// which are not a model fail clearly instead of obscurely in the parser.
+pub(crate) fn check_onnx(bytes: &[u8]) -> Result<()> {
+    if !looks_like_onnx(bytes) {
+        return Err(Error::InvalidArgument("not a valid ONNX model".to_string()));
+    }
+    Ok(())
+}
 
-    // Create network with explicit batch
-    let network = builder.create_network(network_flags::EXPLICIT_BATCH)?;
+/// Check that `bytes` is a well-formed protobuf message with a graph, as an
+/// ONNX `ModelProto` is
+///
+/// Only the top-level fields are walked, so a corrupt graph still reaches the
+/// parser and gets its detailed errors.
+pub(crate) fn looks_like_onnx(bytes: &[u8]) -> bool {
+    // ModelProto.graph
+    const GRAPH_FIELD: u64 = 7;
+
+    fn read_varint(bytes: &[u8], pos: &mut usize) -> Option<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *bytes.get(*pos)?;
+            *pos += 1;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    let mut pos = 0;
+    let mut has_graph = false;
+    while pos < bytes.len() {
+        let Some(key) = read_varint(bytes, &mut pos) else {
+            return false;
+        };
+        let field = key >> 3;
+        if field == 0 {
+            return false;
+        }
+        let skip = match key & 7 {
+            0 => read_varint(bytes, &mut pos).map(|_| 0),
+            1 => Some(8),
+            2 => read_varint(bytes, &mut pos),
+            5 => Some(4),
+            _ => None,
+        };
+        match skip.and_then(|skip| pos.checked_add(usize::try_from(skip).ok()?)) {
+            Some(end) if end <= bytes.len() => pos = end,
+            _ => return false,
+        }
+        has_graph |= field == GRAPH_FIELD && key & 7 == 2;
+    }
+    has_graph
+}
 
-    // Parse ONNX model
-    let parser = OnnxParser::new(&network, logger)?;
-    parser.parse(onnx_bytes)?;
+/// Build a serialized engine from a PyTorch-exported ONNX model with known-good defaults
+///
+/// Uses explicit batch, a 1GB workspace, FP16 kernels if the device has fast
+/// FP16 (see [`BuilderConfig::apply_precision`]; FP32 otherwise) and the
+/// native InstanceNormalization layer that PyTorch exports expect. Fails with
+/// [`Error::InvalidArgument`] if the bytes are not an ONNX model.
+pub fn build_from_pytorch_onnx(onnx_model_bytes: &[u8]) -> Result<Vec<u8>> {
+    check_onnx(onnx_model_bytes)?;
 
-    // Configure builder
+    let logger = Logger::stderr()?;
+    let builder = Builder::new(&logger)?;
+    let network = builder.create_network(network_flags::EXPLICIT_BATCH)?;
+    let mut parser = OnnxParser::new(&network, &logger)?;
     let mut config = builder.create_config()?;
 
-    // Set workspace memory (1GB)
-    config.set_memory_pool_limit(crate::builder::MemoryPoolType::Workspace, 1 << 30)?;
+    apply_pytorch_defaults(&builder, &mut parser, &mut config)?;
+    parser.parse(onnx_model_bytes)?;
 
-    // Build serialized engine
     builder.build_serialized_network(&network, &config)
 }
 
-/// Execute TensorRT engine with inputs
+/// Parser and builder settings used by [`build_from_pytorch_onnx`]
+fn apply_pytorch_defaults(
+    builder: &Builder,
+    parser: &mut OnnxParser,
+    config: &mut BuilderConfig,
+) -> Result<()> {
+    parser.set_flag(OnnxParserFlag::NativeInstanceNorm)?;
+    config.set_memory_pool_limit(MemoryPoolType::Workspace, DEFAULT_WORKSPACE_SIZE)?;
+    config.apply_precision(builder, &PrecisionConfig::new().fp16())
+}
+
+/// Execute a session's engine with inputs
 fn execute_engine(
     logger: &Logger,
-    engine_data: &[u8],
+    state: &mut SessionState,
     inputs: &[TensorInput],
+    options: &SessionOptions,
 ) -> Result<Vec<TensorOutput>> {
-    // Create runtime and deserialize engine
-    let runtime = Runtime::new(logger)?;
-    let engine = runtime.deserialize_cuda_engine(engine_data)?;
-    let mut context = engine.create_execution_context()?;
+    let engine = &*state.engine;
 
-    // Get tensor information
-    let num_tensors = engine.get_nb_io_tensors()?;
-
-    // Prepare CUDA buffers for inputs and outputs
-    let mut device_buffers: Vec<(String, DeviceBuffer)> = Vec::new();
-    let mut output_info: Vec<(String, Vec<usize>)> = Vec::new();
+    // Host data and shape for each input, and element type for each output
+    let mut input_data: Vec<(String, &Dims, Cow<TensorData>)> = Vec::new();
+    let mut output_info: Vec<(String, DataType, HostKind)> = Vec::new();
 
     // Process each tensor
-    for i in 0..num_tensors {
-        let name = engine.get_tensor_name(i)?;
-
-        // Check if this is an input or output
-        if let Some(input) = inputs.iter().find(|inp| inp.name == name) {
-            // Input tensor - allocate and copy data
-            let size_bytes = input.data.len() * std::mem::size_of::<f32>();
-            let mut buffer = DeviceBuffer::new(size_bytes)?;
+    for name in engine.io_tensor_names()? {
+        let dtype = engine.get_tensor_dtype(&name)?;
+        let kind = HostKind::for_dtype(dtype)?;
 
-            // Copy input data to device
-            let input_bytes =
-                unsafe { std::slice::from_raw_parts(input.data.as_ptr() as *const u8, size_bytes) };
-            buffer.copy_from_host(input_bytes)?;
+        match engine.get_tensor_io_mode(&name)? {
+            TensorIoMode::Input => {
+                let input = inputs
+                    .iter()
+                    .find(|inp| inp.name == name)
+                    .ok_or_else(|| Error::InvalidArgument(format!("Missing input '{}'", name)))?;
+                if input.shape.product() != Some(input.data.len()) {
+                    return Err(Error::InvalidArgument(format!(
+                        "Input '{}' has {} elements but shape {}",
+                        name,
+                        input.data.len(),
+                        input.shape
+                    )));
+                }
+                let data = coerce_input(logger, input, dtype, kind, options)?;
+                input_data.push((name, &input.shape, data));
+            }
+            TensorIoMode::Output => output_info.push((name, dtype, kind)),
+            TensorIoMode::None => {}
+        }
+    }
 
-            // Bind tensor address
-            unsafe {
-                context.set_tensor_address(&name, buffer.as_ptr())?;
+    if let Some(budget) = options.memory_budget {
+        // Outputs with dynamic dimensions are only sized once the context has
+        // the input shapes, so only static outputs are counted here
+        let mut io_bytes: usize = input_data
+            .iter()
+            .map(|(_, _, data)| data.as_bytes().len())
+            .sum();
+        for (name, dtype, _) in &output_info {
+            if let Some(elements) = engine.get_tensor_shape(name)?.product() {
+                io_bytes += elements * dtype.size();
             }
+        }
+        if let Some(streaming_budget) = memory_budget_streaming(engine, budget, io_bytes)? {
+            state.set_streaming_budget(streaming_budget)?;
+        }
+    }
 
-            device_buffers.push((name.clone(), buffer));
-        } else {
-            // Output tensor - allocate buffer
-            // Note: In a real implementation, we would query the tensor shape
-            // For now, we'll use a reasonable default size
-            let estimated_size = 1000 * std::mem::size_of::<f32>();
-            let buffer = DeviceBuffer::new(estimated_size)?;
+    let context = state.context()?;
 
-            unsafe {
-                context.set_tensor_address(&name, buffer.as_ptr())?;
-            }
+    // Prepare CUDA buffers for inputs and outputs
+    let mut device_buffers: Vec<(String, DeviceBuffer)> = Vec::new();
 
-            output_info.push((name.clone(), vec![1, 1000])); // Dummy shape
-            device_buffers.push((name.clone(), buffer));
-        }
+    for (name, shape, data) in &input_data {
+        context.set_input_shape(name, shape)?;
+
+        // Input tensor - copy data to the device
+        let buffer = DeviceBuffer::from_host_slice(data.as_bytes())?;
+        device_buffers.push((name.clone(), buffer));
     }
 
-    // Execute inference
-    unsafe {
-        context.enqueue_v3(crate::cuda::get_default_stream())?;
+    // Output shapes are fully resolved now that all input shapes are set
+    let mut output_shapes: Vec<Dims> = Vec::new();
+
+    for (name, dtype, _) in &output_info {
+        let shape = context.get_tensor_shape(name)?;
+        let elements = shape.product().ok_or_else(|| {
+            Error::Runtime(format!("Output '{}' has unresolved shape {}", name, shape))
+        })?;
+
+        // Output tensor - zeroed, so layers that skip elements never expose garbage
+        let buffer = DeviceBuffer::new_zeroed(elements * dtype.size())?;
+        device_buffers.push((name.clone(), buffer));
+        output_shapes.push(shape);
     }
 
-    // Synchronize to ensure completion
-    crate::cuda::synchronize()?;
+    // Bind every tensor, execute inference and wait for it
+    let bindings: Vec<(&str, &DeviceBuffer)> = device_buffers
+        .iter()
+        .map(|(name, buffer)| (name.as_str(), buffer))
+        .collect();
+    // The default stream is ordered after the uploads and memsets above
+    context.run(&bindings, &CudaStream::default_stream())?;
 
     // Copy outputs back to host
     let mut outputs = Vec::new();
 
-    for (name, shape) in output_info {
+    for ((name, dtype, kind), shape) in output_info.into_iter().zip(output_shapes) {
         if let Some((_, buffer)) = device_buffers.iter().find(|(n, _)| n == &name) {
-            let size_bytes = shape.iter().product::<usize>() * std::mem::size_of::<f32>();
+            let size_bytes = shape.product().unwrap_or(0) * dtype.size();
             let mut host_data = vec![0u8; size_bytes];
 
             buffer.copy_to_host(&mut host_data)?;
 
-            // Convert bytes to f32
-            let data: Vec<f32> = unsafe {
-                std::slice::from_raw_parts(
-                    host_data.as_ptr() as *const f32,
-                    size_bytes / std::mem::size_of::<f32>(),
-                )
+            let mut data = TensorData::from_bytes(kind, &host_data);
+
+            // Convert to the host type requested for this output, if any
+            if let Some(&host_kind) = options.output_types.get(&name) {
+                if host_kind != kind {
+                    let (converted, lossy) = host_kind.convert(&data.to_f64());
+                    if lossy > 0 {
+                        logger.log(
+                            Severity::Warning,
+                            &format!(
+                                "Converting output '{}' from {:?} to {} lost precision in {} of {} values",
+                                name,
+                                dtype,
+                                host_kind.name(),
+                                lossy,
+                                data.len()
+                            ),
+                        );
+                    }
+                    data = converted;
+                }
             }
-            .to_vec();
 
             outputs.push(TensorOutput { name, shape, data });
         }
@@ -165,23 +1521,97 @@ fn execute_engine(
     Ok(outputs)
 }
 
+/// Check that the engine fits into `budget` bytes of device memory, returning
+/// the weight streaming budget that gives any headroom to streamable weights
+fn memory_budget_streaming(
+    engine: &CudaEngine,
+    budget: usize,
+    io_bytes: usize,
+) -> Result<Option<i64>> {
+    let activation_bytes = engine.get_device_memory_size()?.max(0) as usize;
+    let required = activation_bytes + io_bytes;
+
+    if required > budget {
+        return Err(Error::OutOfMemory(format!(
+            "Session needs {} bytes for activations and I/O buffers, exceeding the memory budget of {} bytes",
+            required, budget
+        )));
+    }
+
+    let streamable = engine.get_streamable_weights_size()?;
+    if streamable > 0 {
+        let headroom = (budget - required) as i64;
+        let minimum = engine.get_minimum_weight_streaming_budget()?;
+        if headroom < minimum {
+            return Err(Error::OutOfMemory(format!(
+                "Engine needs {} bytes of resident weights but the memory budget leaves {} bytes",
+                minimum, headroom
+            )));
+        }
+        return Ok(Some(headroom.min(streamable)));
+    }
+
+    Ok(None)
+}
+
+/// Convert an input to the engine's tensor type if allowed by the session options
+fn coerce_input<'i>(
+    logger: &Logger,
+    input: &'i TensorInput,
+    dtype: DataType,
+    kind: HostKind,
+    options: &SessionOptions,
+) -> Result<Cow<'i, TensorData>> {
+    if input.data.kind() == kind {
+        return Ok(Cow::Borrowed(&input.data));
+    }
+
+    if !options.coerce_dtypes {
+        return Err(Error::InvalidArgument(format!(
+            "Input '{}' has host type {} but the engine expects {:?} (enable coerce_dtypes to convert)",
+            input.name,
+            input.data.type_name(),
+            dtype
+        )));
+    }
+
+    let (data, lossy) = kind.convert(&input.data.to_f64());
+    if lossy > 0 {
+        logger.log(
+            Severity::Warning,
+            &format!(
+                "Coercing input '{}' from {} to {:?} lost precision in {} of {} values",
+                input.name,
+                input.data.type_name(),
+                dtype,
+                lossy,
+                input.data.len()
+            ),
+        );
+    }
+
+    Ok(Cow::Owned(data))
+}
+
 /// Simpler version: Execute with zero-filled inputs (useful for testing/validation)
 pub fn run_onnx_zeroed(
     onnx_model_bytes: &[u8],
-    input_descriptors: &[(String, Vec<usize>)],
+    input_descriptors: &[(String, Dims)],
 ) -> Result<Vec<TensorOutput>> {
     // Create zero-filled inputs
     let inputs: Vec<TensorInput> = input_descriptors
         .iter()
         .map(|(name, shape)| {
-            let size: usize = shape.iter().product();
-            TensorInput {
+            let size = shape.product().ok_or_else(|| {
+                Error::InvalidArgument(format!("Input '{}' has dynamic shape {}", name, shape))
+            })?;
+            Ok(TensorInput {
                 name: name.clone(),
                 shape: shape.clone(),
-                data: vec![0.0; size],
-            }
+                data: vec![0.0f32; size].into(),
+            })
         })
-        .collect();
+        .collect::<Result<_>>()?;
 
     run_onnx_with_tensorrt(onnx_model_bytes, &inputs)
 }
@@ -194,8 +1624,8 @@ mod tests {
     fn test_tensor_input_creation() {
         let input = TensorInput {
             name: "input".to_string(),
-            shape: vec![1, 3, 224, 224],
-            data: vec![0.0; 3 * 224 * 224],
+            shape: vec![1, 3, 224, 224].into(),
+            data: vec![0.0f32; 3 * 224 * 224].into(),
         };
 
         assert_eq!(input.name, "input");
@@ -204,14 +1634,578 @@ mod tests {
     }
 
     #[test]
-    #[ignore] // Requires valid ONNX model
-    fn test_executor_basic() {
-        let dummy_onnx = vec![0u8; 100];
-        let inputs = vec![("input".to_string(), vec![1, 3, 224, 224])];
+    fn test_tensor_data_bytes() {
+        let data = TensorData::from(vec![1.0f32, 2.0]);
+        assert_eq!(data.as_bytes().len(), 8);
+        assert_eq!(TensorData::from_bytes(HostKind::F32, data.as_bytes()), data);
+    }
+
+    #[test]
+    fn test_dtype_mismatch_without_coercion() {
+        let mut session = Session::from_engine(vec![0u8; 16], SessionOptions::default()).unwrap();
+        let inputs = vec![TensorInput {
+            name: "input".to_string(),
+            shape: vec![1, 4].into(),
+            data: vec![0.5f64; 4].into(),
+        }];
+
+        let result = session.infer(&inputs);
+        #[cfg(feature = "mock")]
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_coerce_f64_input_to_f32_engine() {
+        let options = SessionOptions {
+            coerce_dtypes: true,
+            ..Default::default()
+        };
+        let mut session = Session::from_engine(vec![0u8; 16], options).unwrap();
+        let inputs = vec![TensorInput {
+            name: "input".to_string(),
+            shape: vec![1, 4].into(),
+            // 0.1 is not representable in f32, so this conversion is lossy
+            data: vec![0.1f64; 4].into(),
+        }];
+
+        let result = session.infer(&inputs);
+        #[cfg(feature = "mock")]
+        {
+            let outputs = result.unwrap();
+            assert_eq!(outputs.len(), 1);
+            // Outputs keep the engine's type unless asked otherwise
+            assert!(matches!(outputs[0].data, TensorData::F32(_)));
+        }
+    }
+
+    #[test]
+    fn test_output_types_ignore_input_host_types() {
+        let options = SessionOptions {
+            coerce_dtypes: true,
+            output_types: HashMap::from([("output".to_string(), HostKind::I64)]),
+            ..Default::default()
+        };
+        let mut session = Session::from_engine(vec![0u8; 16], options).unwrap();
+        // The f64 input is coerced, but the output's type is its own
+        let inputs = vec![TensorInput {
+            name: "input".to_string(),
+            shape: vec![1, 4].into(),
+            data: vec![1.0f64, -2.0, 3.0, 4.0].into(),
+        }];
+
+        let result = session.infer(&inputs);
+        #[cfg(feature = "mock")]
+        assert_eq!(result.unwrap()[0].data, TensorData::I64(vec![1, -2, 3, 4]));
+    }
+
+    #[test]
+    fn test_output_type_without_matching_input() {
+        // Mock engine header: no inputs, one f32 output
+        let options = SessionOptions {
+            output_types: HashMap::from([("output".to_string(), HostKind::F64)]),
+            ..Default::default()
+        };
+        let mut session = Session::from_engine(b"TRTXMOCK\x00\x01".to_vec(), options).unwrap();
+
+        let result = session.infer(&[]);
+        #[cfg(feature = "mock")]
+        assert_eq!(result.unwrap()[0].data, TensorData::F64(vec![1.0; 4]));
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn test_f16_data() {
+        let values = [half::f16::from_f32(1.0), half::f16::from_f32(-0.5)];
+        let data = TensorData::from(&values[..]);
+        assert_eq!(data.as_bytes().len(), 4);
+        assert_eq!(data.as_f16().unwrap(), &values);
+
+        // f32 values are only converted to f16 when explicitly requested
+        let (converted, lossy) = HostKind::F16.convert(&[1.0, -0.5]);
+        assert_eq!(converted, data);
+        assert_eq!(lossy, 0);
+    }
+
+    #[cfg(all(feature = "serde", feature = "json"))]
+    #[test]
+    fn test_tensor_serde_round_trip() {
+        let input = TensorInput {
+            name: "input".to_string(),
+            shape: vec![2, 2].into(),
+            data: vec![1.0f32, -2.5, 0.0, 4.0].into(),
+        };
+        let json = serde_json::to_string(&input).unwrap();
+        let decoded: TensorInput = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.name, input.name);
+        assert_eq!(decoded.shape, input.shape);
+        assert_eq!(decoded.data, input.data);
+
+        let output = TensorOutput {
+            name: "output".to_string(),
+            shape: vec![3].into(),
+            data: vec![1i64, 2, 3].into(),
+        };
+        let decoded: TensorOutput =
+            serde_json::from_str(&serde_json::to_string(&output).unwrap()).unwrap();
+        assert_eq!(decoded.shape, vec![3]);
+        assert_eq!(decoded.data, TensorData::I64(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_session_memory_budget() {
+        let inputs = vec![TensorInput {
+            name: "input".to_string(),
+            shape: vec![1, 4].into(),
+            data: vec![0.0f32; 4].into(),
+        }];
 
-        let result = run_onnx_zeroed(&dummy_onnx, &inputs);
-        // In mock mode, this should succeed
+        let mut session = Session::from_engine(vec![0u8; 16], SessionOptions::default())
+            .unwrap()
+            .with_inference_memory_budget(1 << 30);
+        assert_eq!(session.options().memory_budget, Some(1 << 30));
+        let result = session.infer(&inputs);
         #[cfg(feature = "mock")]
         assert!(result.is_ok());
+
+        let mut session = Session::from_engine(vec![0u8; 16], SessionOptions::default())
+            .unwrap()
+            .with_inference_memory_budget(1024);
+        let result = session.infer(&inputs);
+        #[cfg(feature = "mock")]
+        assert!(matches!(result, Err(Error::OutOfMemory(_))));
+
+        // The headroom goes to the streamable weights
+        let mut session =
+            Session::from_engine(b"TRTXMOCK\x01\x01\x10".to_vec(), SessionOptions::default())
+                .unwrap()
+                .with_inference_memory_budget((1 << 20) + (1 << 19));
+        let result = session.infer(&inputs);
+        #[cfg(feature = "mock")]
+        {
+            assert!(result.is_ok());
+            let engine = &session.state.as_ref().unwrap().engine;
+            assert!(engine.is_weight_streaming_enabled().unwrap());
+        }
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_session_reuses_engine() {
+        let mut session = Session::from_engine(vec![0u8; 16], SessionOptions::default()).unwrap();
+        let mut engine: Option<*const CudaEngine> = None;
+
+        for batch in [2, 1, 3] {
+            let values: Vec<f32> = (0..batch * 4).map(|v| v as f32).collect();
+            let inputs = vec![TensorInput {
+                name: "input".to_string(),
+                shape: vec![batch, 4].into(),
+                data: values.clone().into(),
+            }];
+            let outputs = session.infer(&inputs).unwrap();
+            assert_eq!(outputs[0].data.as_f32(), Some(values.as_slice()));
+
+            let state = session.state.as_ref().unwrap();
+            assert!(state.context.is_some());
+            let current = &*state.engine as *const CudaEngine;
+            assert_eq!(*engine.get_or_insert(current), current);
+        }
+    }
+
+    #[test]
+    fn test_session_infer_single() {
+        let inputs = vec![TensorInput {
+            name: "input".to_string(),
+            shape: vec![1, 4].into(),
+            data: vec![1.0f32, 2.0, 3.0, 4.0].into(),
+        }];
+
+        let mut session = Session::from_engine(vec![0u8; 16], SessionOptions::default()).unwrap();
+        let result = session.infer_single(&inputs);
+        #[cfg(feature = "mock")]
+        {
+            let output = result.unwrap();
+            assert_eq!(output.name, "output");
+            assert_eq!(output.data, TensorData::F32(vec![1.0, 2.0, 3.0, 4.0]));
+        }
+
+        let mut session =
+            Session::from_engine(b"TRTXMOCK\x01\x02".to_vec(), SessionOptions::default()).unwrap();
+        let result = session.infer_single(&inputs);
+        #[cfg(feature = "mock")]
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+        #[cfg(not(feature = "mock"))]
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_identity_inference() {
+        let mut session = Session::from_engine(vec![0u8; 16], SessionOptions::default()).unwrap();
+        let values = vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let inputs = vec![TensorInput {
+            name: "input".to_string(),
+            shape: vec![2, 4].into(),
+            data: values.clone().into(),
+        }];
+
+        let result = session.infer(&inputs);
+        #[cfg(feature = "mock")]
+        {
+            // The mock engine copies its input to its output
+            let outputs = result.unwrap();
+            assert_eq!(outputs.len(), 1);
+            assert_eq!(outputs[0].name, "output");
+            assert_eq!(outputs[0].shape, vec![2, 4]);
+            assert_eq!(outputs[0].data.as_f32(), Some(values.as_slice()));
+        }
+
+        let result = session.infer(&[]);
+        #[cfg(feature = "mock")]
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_output_stats() {
+        let output = TensorOutput {
+            name: "output".to_string(),
+            shape: vec![5].into(),
+            data: vec![1.0f32, -3.0, 4.0, f32::NAN, 2.0].into(),
+        };
+        let stats = output.stats();
+        assert_eq!(stats.min, -3.0);
+        assert_eq!(stats.max, 4.0);
+        assert_eq!(stats.mean, 1.0);
+        assert!(stats.has_nan);
+        assert!(!stats.has_inf);
+
+        let empty = TensorOutput {
+            name: "output".to_string(),
+            shape: vec![0].into(),
+            data: Vec::<f32>::new().into(),
+        };
+        assert!(empty.stats().mean.is_nan());
+    }
+
+    #[test]
+    fn test_output_softmax_and_top_k() {
+        let close = |a: &[f32], b: &[f32]| {
+            a.len() == b.len() && a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-6)
+        };
+
+        // softmax([0, ln 2, ln 3, ln 4]) = [0.1, 0.2, 0.3, 0.4]
+        let logits = TensorOutput {
+            name: "logits".to_string(),
+            shape: vec![1, 4].into(),
+            data: vec![0.0f32, 2f32.ln(), 3f32.ln(), 4f32.ln()].into(),
+        };
+        assert!(close(&logits.softmax(), &[0.1, 0.2, 0.3, 0.4]));
+        let top = logits.top_k(2);
+        assert_eq!(top.iter().map(|t| t.0).collect::<Vec<_>>(), vec![3, 2]);
+        assert_eq!(logits.top_k(10).len(), 4);
+        assert!(logits.top_k(0).is_empty());
+
+        // Large logits do not overflow, and each row is normalized separately
+        let batch = TensorOutput {
+            name: "logits".to_string(),
+            shape: vec![2, 2].into(),
+            data: vec![1000.0f32, 1000.0, 0.0, 0.0].into(),
+        };
+        assert!(close(&batch.softmax(), &[0.5, 0.5, 0.5, 0.5]));
+        assert_eq!(batch.softmax(), batch.softmax_axis(1).unwrap());
+
+        // Along the first axis, columns sum to 1
+        let columns = TensorOutput {
+            name: "logits".to_string(),
+            shape: vec![2, 2].into(),
+            data: vec![0.0f32, 0.0, 3f32.ln(), 0.0].into(),
+        };
+        assert!(close(
+            &columns.softmax_axis(0).unwrap(),
+            &[0.25, 0.5, 0.75, 0.5]
+        ));
+        assert!(matches!(
+            columns.softmax_axis(2),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        let with_nan = TensorOutput {
+            name: "scores".to_string(),
+            shape: vec![3].into(),
+            data: vec![f32::NAN, 1.0, 2.0].into(),
+        };
+        assert_eq!(with_nan.top_k(2), vec![(2, 2.0), (1, 1.0)]);
+    }
+
+    #[test]
+    fn test_output_dequantize() {
+        let output = TensorOutput {
+            name: "logits".to_string(),
+            shape: vec![5].into(),
+            data: TensorData::I8(vec![-128, -1, 0, 1, 127]),
+        };
+        assert_eq!(output.dequantize(0.5, 0), vec![-64.0, -0.5, 0.0, 0.5, 63.5]);
+        assert_eq!(
+            output.dequantize(0.25, -1),
+            vec![-31.75, 0.0, 0.25, 0.5, 32.0]
+        );
+
+        let asymmetric = TensorOutput {
+            name: "probs".to_string(),
+            shape: vec![3].into(),
+            data: TensorData::U8(vec![0, 128, 255]),
+        };
+        assert_eq!(
+            asymmetric.dequantize(1.0 / 255.0, 0),
+            vec![0.0, 128.0 / 255.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_zero_input_engine() {
+        // Mock engine header: no inputs, one output
+        let engine_data = b"TRTXMOCK\x00\x01".to_vec();
+        let mut session = Session::from_engine(engine_data, SessionOptions::default()).unwrap();
+
+        let result = session.infer(&[]);
+        #[cfg(feature = "mock")]
+        {
+            let outputs = result.unwrap();
+            assert_eq!(outputs.len(), 1);
+            assert_eq!(outputs[0].shape, vec![1, 4]);
+            assert_eq!(outputs[0].data.as_f32(), Some(&[1.0f32; 4][..]));
+        }
+    }
+
+    #[cfg(all(feature = "memmap2", feature = "mock"))]
+    #[test]
+    fn test_tensor_input_from_mmap() {
+        let path = std::env::temp_dir().join(format!("trtx_input_{}.bin", std::process::id()));
+        let values = [0.5f32, -1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0];
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_ne_bytes()).collect();
+        std::fs::write(&path, &bytes).unwrap();
+
+        let input = TensorInput::from_mmap("input", vec![2, 4], DataType::Float, &path);
+        let wrong_shape = TensorInput::from_mmap("input", vec![3, 4], DataType::Float, &path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(wrong_shape, Err(Error::InvalidArgument(_))));
+        let input = input.unwrap();
+        assert_eq!(input.data.len(), 8);
+        assert_eq!(input.data.type_name(), "f32");
+        assert_eq!(input.data.as_bytes(), bytes.as_slice());
+
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let outputs = Executor::new(&engine).unwrap().run(&[input]).unwrap();
+        assert_eq!(outputs[0].data.as_f32(), Some(&values[..]));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_executor_reuses_context() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut executor = Executor::new(&engine).unwrap();
+
+        for batch in [1, 3, 2] {
+            let values: Vec<f32> = (0..batch * 4).map(|v| v as f32).collect();
+            let inputs = vec![TensorInput {
+                name: "input".to_string(),
+                shape: vec![batch, 4].into(),
+                data: values.clone().into(),
+            }];
+            let outputs = executor.run(&inputs).unwrap();
+            assert_eq!(outputs[0].shape, vec![batch, 4]);
+            assert_eq!(outputs[0].data.as_f32(), Some(values.as_slice()));
+        }
+
+        let unknown = vec![TensorInput {
+            name: "bogus".to_string(),
+            shape: vec![1].into(),
+            data: vec![0.0f32].into(),
+        }];
+        assert!(matches!(
+            executor.run(&unknown),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(executor.run(&[]), Err(Error::InvalidArgument(_))));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_executor_set_batch_size() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        // Mock identity engine: "input" [-1, 4] to "output"
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut executor = Executor::new(&engine).unwrap();
+
+        executor.set_batch_size(4).unwrap();
+        assert_eq!(executor.context.get_tensor_shape("input").unwrap(), [4, 4]);
+        assert_eq!(executor.context.get_tensor_shape("output").unwrap(), [4, 4]);
+
+        for n in [0, -1] {
+            assert!(matches!(
+                executor.set_batch_size(n),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+
+        // Mock engine whose input is [-1, -1]: the batch is not the only
+        // dynamic dimension
+        let engine = runtime
+            .deserialize_cuda_engine(b"TRTXMOCK\x01\x01\x04")
+            .unwrap();
+        assert_eq!(engine.get_tensor_shape("input").unwrap(), vec![-1, -1]);
+        let mut executor = Executor::new(&engine).unwrap();
+        assert!(matches!(
+            executor.set_batch_size(4),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_executor_data_dependent_output() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        // Mock NonZero: keeps rows whose first element is non-zero
+        let engine = runtime
+            .deserialize_cuda_engine(b"TRTXMOCK\x01\x01\x01")
+            .unwrap();
+        let mut executor = Executor::new(&engine).unwrap();
+
+        for (rows, kept) in [(vec![1.0f32, 0.0, 2.0], 2), (vec![0.0, 0.0], 0)] {
+            let values: Vec<f32> = rows
+                .iter()
+                .flat_map(|&first| [first, 1.0, 2.0, 3.0])
+                .collect();
+            let inputs = vec![TensorInput {
+                name: "input".to_string(),
+                shape: vec![rows.len() as i64, 4].into(),
+                data: values.into(),
+            }];
+            let outputs = executor.run(&inputs).unwrap();
+            assert_eq!(outputs[0].shape, vec![kept, 4]);
+            let firsts: Vec<f32> = outputs[0]
+                .data
+                .as_f32()
+                .unwrap()
+                .chunks(4)
+                .map(|r| r[0])
+                .collect();
+            assert!(firsts.iter().all(|&first| first != 0.0));
+        }
+    }
+
+    #[cfg(all(feature = "async", feature = "mock"))]
+    #[tokio::test]
+    async fn test_executor_enqueue_async() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut executor = Executor::new(&engine).unwrap();
+
+        let values = vec![1.0f32, 2.0, 3.0, 4.0];
+        let inputs = vec![TensorInput {
+            name: "input".to_string(),
+            shape: vec![1, 4].into(),
+            data: values.clone().into(),
+        }];
+        let outputs = executor.enqueue_async(&inputs).await;
+
+        // Input errors are reported by the future
+        let missing = executor.enqueue_async(&[]).await;
+        assert!(matches!(missing, Err(Error::InvalidArgument(_))));
+
+        let outputs = outputs.unwrap();
+        assert_eq!(outputs[0].shape, vec![1, 4]);
+        assert_eq!(outputs[0].data.as_f32(), Some(values.as_slice()));
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_executor_infer_ndarray() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut executor = Executor::new(&engine).unwrap();
+
+        let input = ndarray::Array2::from_shape_fn((2, 4), |(i, j)| (i * 4 + j) as f32).into_dyn();
+        let inputs = HashMap::from([("input".to_string(), input.view())]);
+        let outputs = executor.infer(inputs).unwrap();
+        #[cfg(feature = "mock")]
+        assert_eq!(outputs["output"], input);
+    }
+
+    #[test]
+    fn test_build_from_pytorch_onnx() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+        let mut parser = OnnxParser::new(&network, &logger).unwrap();
+        let mut config = builder.create_config().unwrap();
+
+        apply_pytorch_defaults(&builder, &mut parser, &mut config).unwrap();
+        assert!(parser.get_flag(OnnxParserFlag::NativeInstanceNorm).unwrap());
+        // The mock device has fast FP16
+        #[cfg(feature = "mock")]
+        assert!(config.get_flag(crate::builder::BuilderFlag::Fp16).unwrap());
+        assert_eq!(
+            config
+                .get_memory_pool_limit(MemoryPoolType::Workspace)
+                .unwrap(),
+            DEFAULT_WORKSPACE_SIZE
+        );
+
+        assert!(matches!(
+            build_from_pytorch_onnx(b"dummy onnx model"),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        let onnx = std::fs::read(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/super-resolution-10.onnx"
+        ))
+        .unwrap();
+        let result = build_from_pytorch_onnx(&onnx);
+        #[cfg(feature = "mock")]
+        assert!(!result.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_lossy_conversion_count() {
+        let (data, lossy) = HostKind::F32.convert(&[0.5, 0.1, f64::NAN]);
+        assert_eq!(data.len(), 3);
+        assert_eq!(lossy, 1);
+    }
+
+    #[test]
+    fn test_executor_rejects_invalid_onnx() {
+        let inputs = vec![("input".to_string(), Dims::from([1, 4]))];
+
+        for bytes in [&[0u8; 100][..], &[], b"not a model"] {
+            match run_onnx_zeroed(bytes, &inputs) {
+                Err(Error::InvalidArgument(msg)) => assert_eq!(msg, "not a valid ONNX model"),
+                other => panic!("expected InvalidArgument, got {:?}", other.map(|_| ())),
+            }
+        }
+    }
+
+    #[test]
+    fn test_looks_like_onnx() {
+        let model = std::fs::read(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/super-resolution-10.onnx"
+        ))
+        .unwrap();
+        assert!(looks_like_onnx(&model));
+        assert!(!looks_like_onnx(&model[..model.len() / 2]));
+        // ir_version only, no graph
+        assert!(!looks_like_onnx(&[0x08, 0x07]));
+        assert!(!looks_like_onnx(&[0u8; 100]));
     }
 }
@@ -0,0 +1,116 @@
+//! Batched launches of several execution contexts through one CUDA graph
+
+use crate::cuda::{CudaGraph, CudaStream};
+use crate::error::Result;
+use crate::runtime::ExecutionContext;
+use std::marker::PhantomData;
+
+/// Inference of several execution contexts captured into a single CUDA graph
+///
+/// Launching the batch replays every captured enqueue with one graph launch,
+/// which amortizes per-enqueue overhead when running many small models
+/// together. The contexts stay mutably borrowed for the batch's lifetime, so
+/// their bindings cannot change while the graph refers to them.
+pub struct GraphBatch<'a> {
+    graph: CudaGraph,
+    len: usize,
+    _contexts: PhantomData<&'a mut ()>,
+}
+
+impl<'a> GraphBatch<'a> {
+    /// Capture one enqueue of each context, in order, on `stream`
+    ///
+    /// # Safety
+    ///
+    /// Every context must have its input shapes and tensor addresses set, and
+    /// the bound device buffers must outlive the returned batch: the graph
+    /// reads and writes those addresses on every launch.
+    pub unsafe fn capture<'e: 'a, I>(stream: &CudaStream, contexts: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'a mut ExecutionContext<'e>>,
+    {
+        stream.begin_capture()?;
+
+        let mut len = 0;
+        for context in contexts {
+            if let Err(e) = context.enqueue_v3(stream.as_ptr()) {
+                // Leave the stream usable; the partial graph is discarded
+                let _ = stream.end_capture();
+                return Err(e);
+            }
+            len += 1;
+        }
+
+        Ok(GraphBatch {
+            graph: stream.end_capture()?,
+            len,
+            _contexts: PhantomData,
+        })
+    }
+
+    /// Number of contexts in the batch
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the batch captured no contexts
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Launch all captured inferences on `stream`
+    ///
+    /// The launch is asynchronous; call [`CudaStream::synchronize`] before
+    /// reading outputs.
+    pub fn launch(&self, stream: &CudaStream) -> Result<()> {
+        self.graph.launch(stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Logger, Runtime, TypedDeviceBuffer};
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_graph_batch_two_contexts() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut first = engine.create_execution_context().unwrap();
+        let mut second = engine.create_execution_context().unwrap();
+
+        let inputs = [
+            TypedDeviceBuffer::from_slice(&[1.0f32, 2.0, 3.0, 4.0]).unwrap(),
+            TypedDeviceBuffer::from_slice(&[5.0f32, 6.0, 7.0, 8.0]).unwrap(),
+        ];
+        let outputs = [
+            TypedDeviceBuffer::from_slice(&[0.0f32; 4]).unwrap(),
+            TypedDeviceBuffer::from_slice(&[0.0f32; 4]).unwrap(),
+        ];
+        for (context, (input, output)) in [&mut first, &mut second]
+            .into_iter()
+            .zip(inputs.iter().zip(&outputs))
+        {
+            context.set_input_shape("input", &[1, 4]).unwrap();
+            unsafe {
+                context.set_tensor_address("input", input.as_ptr()).unwrap();
+                context
+                    .set_tensor_address("output", output.as_ptr())
+                    .unwrap();
+            }
+        }
+
+        let stream = CudaStream::new().unwrap();
+        let batch = unsafe { GraphBatch::capture(&stream, [&mut first, &mut second]) }.unwrap();
+        assert_eq!(batch.len(), 2);
+        assert_eq!(outputs[0].to_vec().unwrap(), vec![0.0; 4]);
+
+        batch.launch(&stream).unwrap();
+        stream.synchronize().unwrap();
+        for (input, output) in inputs.iter().zip(&outputs) {
+            assert_eq!(output.to_vec().unwrap(), input.to_vec().unwrap());
+        }
+    }
+}
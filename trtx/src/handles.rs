@@ -0,0 +1,70 @@
+//! Debug-build tracking of live FFI handles
+//!
+//! Owning wrappers register their handle when created and release it just
+//! before destroying it. In debug builds, releasing a handle that is not
+//! live (destroyed twice, or never registered) fails a debug assertion
+//! instead of corrupting the heap; release builds compile this away.
+
+#[cfg(debug_assertions)]
+use std::collections::HashSet;
+#[cfg(debug_assertions)]
+use std::sync::Mutex;
+
+#[cfg(debug_assertions)]
+static LIVE: Mutex<Option<HashSet<usize>>> = Mutex::new(None);
+
+/// Record that `ptr` was created and is owned by a wrapper
+pub(crate) fn register<T>(ptr: *mut T) {
+    #[cfg(debug_assertions)]
+    {
+        let fresh = LIVE
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_or_insert_with(HashSet::new)
+            .insert(ptr as usize);
+        debug_assert!(fresh, "handle {:p} registered twice", ptr);
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = ptr;
+}
+
+/// Record that `ptr` is about to be destroyed
+pub(crate) fn release<T>(ptr: *mut T) {
+    #[cfg(debug_assertions)]
+    {
+        let live = LIVE
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_or_insert_with(HashSet::new)
+            .remove(&(ptr as usize));
+        debug_assert!(live, "handle {:p} destroyed twice", ptr);
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = ptr;
+}
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_release() {
+        let mut value = 0u8;
+        let ptr: *mut u8 = &mut value;
+        register(ptr);
+        release(ptr);
+        // Registering again after a release is a new handle at a reused address
+        register(ptr);
+        release(ptr);
+    }
+
+    #[test]
+    #[should_panic(expected = "destroyed twice")]
+    fn test_double_release_panics() {
+        let mut value = 0u16;
+        let ptr: *mut u16 = &mut value;
+        register(ptr);
+        release(ptr);
+        release(ptr);
+    }
+}
@@ -0,0 +1,500 @@
+//! Engine inspection for auditing layer fusions and precisions
+
+use crate::error::{Error, Result};
+use crate::runtime::CudaEngine;
+use std::ffi::CStr;
+use trtx_sys::*;
+
+#[cfg(feature = "json")]
+use crate::tensor::DataType;
+#[cfg(feature = "json")]
+use serde_json::Value;
+#[cfg(feature = "json")]
+use std::collections::HashMap;
+#[cfg(feature = "json")]
+use std::fmt;
+
+/// Output format for inspector queries (mirrors `nvinfer1::LayerInformationFormat`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum LayerInformationFormat {
+    /// One line per layer, names only unless built with detailed profiling verbosity
+    OneLine = 0,
+    /// JSON with per-layer details
+    Json = 1,
+}
+
+/// Inspector over a deserialized engine's layers
+pub struct EngineInspector<'a> {
+    inner: *mut TrtxEngineInspector,
+    _engine: std::marker::PhantomData<&'a CudaEngine>,
+}
+
+impl EngineInspector<'_> {
+    pub(crate) fn from_raw(inner: *mut TrtxEngineInspector) -> Self {
+        EngineInspector {
+            inner,
+            _engine: std::marker::PhantomData,
+        }
+    }
+
+    /// Get information about the whole engine in the given format
+    pub fn get_engine_information(&self, format: LayerInformationFormat) -> Result<String> {
+        let mut info_ptr: *const i8 = std::ptr::null();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_engine_inspector_get_engine_information(
+                self.inner,
+                format as i32,
+                &mut info_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        let info = unsafe { CStr::from_ptr(info_ptr) }.to_str()?.to_string();
+
+        Ok(info)
+    }
+
+    /// Get information about one layer in the given format
+    ///
+    /// `index` ranges over [`CudaEngine::get_nb_layers`].
+    pub fn get_layer_information(
+        &self,
+        index: i32,
+        format: LayerInformationFormat,
+    ) -> Result<String> {
+        let mut info_ptr: *const i8 = std::ptr::null();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_engine_inspector_get_layer_information(
+                self.inner,
+                index,
+                format as i32,
+                &mut info_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        let info = unsafe { CStr::from_ptr(info_ptr) }.to_str()?.to_string();
+
+        Ok(info)
+    }
+
+    /// Get the engine's layers, parsed from its JSON information
+    #[cfg(feature = "json")]
+    pub fn layers(&self) -> Result<Vec<LayerInfo>> {
+        LayerInfo::from_json(&self.get_engine_information(LayerInformationFormat::Json)?)
+    }
+
+    /// Map each layer name to the tactic (kernel) TensorRT chose for it
+    ///
+    /// Only engines built with detailed profiling verbosity report tactics;
+    /// layers without one are left out.
+    #[cfg(feature = "json")]
+    pub fn tactics(&self) -> Result<HashMap<String, String>> {
+        Ok(self
+            .layers()?
+            .into_iter()
+            .filter_map(|layer| layer.tactic.map(|tactic| (layer.name, tactic)))
+            .collect())
+    }
+
+    /// Compare this engine's layers against another engine's
+    ///
+    /// Layers are matched by name. Field-level changes are only reported for
+    /// engines built with detailed profiling verbosity; otherwise the inspector
+    /// exposes layer names only.
+    #[cfg(feature = "json")]
+    pub fn diff(&self, other: &EngineInspector) -> Result<EngineDiff> {
+        let before = self.get_engine_information(LayerInformationFormat::Json)?;
+        let after = other.get_engine_information(LayerInformationFormat::Json)?;
+        EngineDiff::from_json(&before, &after)
+    }
+}
+
+impl Drop for EngineInspector<'_> {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe {
+                trtx_engine_inspector_destroy(self.inner);
+            }
+        }
+    }
+}
+
+/// One layer of an engine, as reported by the inspector
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerInfo {
+    /// Layer name
+    pub name: String,
+    /// Layer type (e.g. `"CaskConvolution"`), with detailed verbosity only
+    pub layer_type: Option<String>,
+    /// Chosen tactic: its `TacticName`, falling back to its `TacticValue`,
+    /// with detailed verbosity only
+    pub tactic: Option<String>,
+    /// Precision the layer ran at, read from its first output's format,
+    /// with detailed verbosity only
+    pub precision: Option<DataType>,
+}
+
+#[cfg(feature = "json")]
+impl LayerInfo {
+    /// Parse the layers of an engine information JSON document
+    pub fn from_json(json: &str) -> Result<Vec<Self>> {
+        Ok(parse_layers(json)?
+            .into_iter()
+            .map(|(name, layer)| {
+                let field = |key: &str| layer.get(key).and_then(Value::as_str).map(str::to_string);
+                let precision = layer
+                    .get("Outputs")
+                    .and_then(|outputs| outputs.get(0))
+                    .and_then(|output| output.get("Format/Datatype"))
+                    .and_then(Value::as_str)
+                    .and_then(parse_precision);
+                LayerInfo {
+                    layer_type: field("LayerType"),
+                    tactic: field("TacticName").or_else(|| field("TacticValue")),
+                    precision,
+                    name,
+                }
+            })
+            .collect())
+    }
+}
+
+/// Data type named in a tensor format such as `"Row major linear FP16 format"`
+#[cfg(feature = "json")]
+fn parse_precision(format: &str) -> Option<DataType> {
+    format
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .find_map(|token| match token.to_ascii_lowercase().as_str() {
+            "fp32" | "float" => Some(DataType::Float),
+            "fp16" | "half" => Some(DataType::Half),
+            "bf16" => Some(DataType::Bf16),
+            "fp8" => Some(DataType::Fp8),
+            "int8" => Some(DataType::Int8),
+            "uint8" => Some(DataType::Uint8),
+            "int32" => Some(DataType::Int32),
+            "int64" => Some(DataType::Int64),
+            "bool" => Some(DataType::Bool),
+            _ => None,
+        })
+}
+
+/// A layer present in both engines whose details differ
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerChange {
+    /// Layer name
+    pub name: String,
+    /// Names of the fields that differ (e.g. `"Outputs"`, `"TacticValue"`)
+    pub fields: Vec<String>,
+}
+
+/// Layer-level differences between two engines
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EngineDiff {
+    /// Layers only in the other engine
+    pub added: Vec<String>,
+    /// Layers only in this engine
+    pub removed: Vec<String>,
+    /// Layers in both engines with differing details
+    pub changed: Vec<LayerChange>,
+}
+
+#[cfg(feature = "json")]
+impl EngineDiff {
+    /// Diff two engine information JSON documents
+    pub fn from_json(before: &str, after: &str) -> Result<Self> {
+        let before = parse_layers(before)?;
+        let after = parse_layers(after)?;
+
+        let find = |layers: &[(String, Value)], name: &str| {
+            layers
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, layer)| layer.clone())
+        };
+
+        let mut diff = EngineDiff::default();
+
+        for (name, layer) in &before {
+            match find(&after, name) {
+                None => diff.removed.push(name.clone()),
+                Some(other) => {
+                    let fields = changed_fields(layer, &other);
+                    if !fields.is_empty() {
+                        diff.changed.push(LayerChange {
+                            name: name.clone(),
+                            fields,
+                        });
+                    }
+                }
+            }
+        }
+
+        for (name, _) in &after {
+            if find(&before, name).is_none() {
+                diff.added.push(name.clone());
+            }
+        }
+
+        Ok(diff)
+    }
+
+    /// Whether the two engines have identical layer structure
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[cfg(feature = "json")]
+impl fmt::Display for EngineDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for name in &self.removed {
+            writeln!(f, "- {}", name)?;
+        }
+        for name in &self.added {
+            writeln!(f, "+ {}", name)?;
+        }
+        for change in &self.changed {
+            writeln!(f, "~ {} ({})", change.name, change.fields.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Extract `(name, layer)` pairs from the `Layers` array
+///
+/// Layers are plain strings in one-line output and objects with a `Name`
+/// field in detailed output.
+#[cfg(feature = "json")]
+fn parse_layers(json: &str) -> Result<Vec<(String, Value)>> {
+    let root: Value = serde_json::from_str(json)?;
+    let layers = root
+        .get("Layers")
+        .and_then(Value::as_array)
+        .ok_or_else(|| Error::InvalidArgument("Engine information has no Layers".to_string()))?;
+
+    layers
+        .iter()
+        .map(|layer| {
+            let name = match layer {
+                Value::String(name) => Some(name.clone()),
+                Value::Object(fields) => fields
+                    .get("Name")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                _ => None,
+            };
+            name.map(|name| (name, layer.clone()))
+                .ok_or_else(|| Error::InvalidArgument(format!("Unnamed layer: {}", layer)))
+        })
+        .collect()
+}
+
+#[cfg(feature = "json")]
+fn changed_fields(before: &Value, after: &Value) -> Vec<String> {
+    let (Value::Object(before), Value::Object(after)) = (before, after) else {
+        return Vec::new();
+    };
+
+    let mut fields: Vec<String> = before
+        .iter()
+        .filter(|(key, value)| after.get(*key) != Some(*value))
+        .map(|(key, _)| key.clone())
+        .collect();
+    fields.extend(
+        after
+            .keys()
+            .filter(|key| !before.contains_key(*key))
+            .cloned(),
+    );
+    fields
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+
+    const BEFORE: &str = r#"{"Layers": [
+        {"Name": "conv1", "LayerType": "CaskConvolution", "TacticValue": "0x1"},
+        {"Name": "relu1", "LayerType": "PointWiseV2"},
+        {"Name": "fc", "LayerType": "CaskGemm", "Outputs": [{"Format/Datatype": "FP32"}]}
+    ], "Bindings": ["input", "output"]}"#;
+
+    const AFTER: &str = r#"{"Layers": [
+        {"Name": "conv1 + relu1", "LayerType": "CaskConvolution", "TacticValue": "0x2"},
+        {"Name": "fc", "LayerType": "CaskGemm", "Outputs": [{"Format/Datatype": "FP16"}]}
+    ], "Bindings": ["input", "output"]}"#;
+
+    #[test]
+    fn test_diff_layers() {
+        let diff = EngineDiff::from_json(BEFORE, AFTER).unwrap();
+        assert_eq!(diff.removed, vec!["conv1", "relu1"]);
+        assert_eq!(diff.added, vec!["conv1 + relu1"]);
+        assert_eq!(
+            diff.changed,
+            vec![LayerChange {
+                name: "fc".to_string(),
+                fields: vec!["Outputs".to_string()],
+            }]
+        );
+        assert!(diff.to_string().contains("~ fc (Outputs)"));
+    }
+
+    #[test]
+    fn test_diff_identical_and_oneline() {
+        assert!(EngineDiff::from_json(AFTER, AFTER).unwrap().is_empty());
+
+        let diff =
+            EngineDiff::from_json(r#"{"Layers": ["a", "b"]}"#, r#"{"Layers": ["b"]}"#).unwrap();
+        assert_eq!(diff.removed, vec!["a"]);
+        assert!(diff.added.is_empty() && diff.changed.is_empty());
+
+        assert!(EngineDiff::from_json("{}", AFTER).is_err());
+    }
+
+    #[test]
+    fn test_layer_info_tactics() {
+        let layers = LayerInfo::from_json(
+            r#"{"Layers": [
+                {"Name": "conv1", "LayerType": "CaskConvolution",
+                 "TacticName": "sm80_xmma_fprop_implicit_gemm", "TacticValue": "0x1"},
+                {"Name": "fc", "LayerType": "CaskGemm", "TacticValue": "0x2"},
+                {"Name": "reshape", "LayerType": "NoOp"}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            layers[0].tactic.as_deref(),
+            Some("sm80_xmma_fprop_implicit_gemm")
+        );
+        assert_eq!(layers[1].tactic.as_deref(), Some("0x2"));
+        assert_eq!(layers[2].layer_type.as_deref(), Some("NoOp"));
+        assert_eq!(layers[2].tactic, None);
+
+        let oneline = LayerInfo::from_json(r#"{"Layers": ["conv1"]}"#).unwrap();
+        assert_eq!(oneline[0].name, "conv1");
+        assert_eq!(oneline[0].tactic, None);
+    }
+
+    #[test]
+    fn test_layer_info_precision() {
+        let layers = LayerInfo::from_json(
+            r#"{"Layers": [
+                {"Name": "conv1", "Outputs": [
+                    {"Name": "a", "Format/Datatype": "Channel major FP16 format where channel % 8 == 0"}]},
+                {"Name": "fc", "Outputs": [{"Name": "b", "Format/Datatype": "Row major Int8 format"}]},
+                {"Name": "cast", "Outputs": [{"Name": "c", "Format/Datatype": "Row major linear BF16 format"}]},
+                {"Name": "reshape", "Outputs": []}
+            ]}"#,
+        )
+        .unwrap();
+        let precisions: Vec<_> = layers.iter().map(|layer| layer.precision).collect();
+        assert_eq!(
+            precisions,
+            vec![
+                Some(DataType::Half),
+                Some(DataType::Int8),
+                Some(DataType::Bf16),
+                None
+            ]
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_inspector_tactics() {
+        let logger = crate::Logger::stderr().unwrap();
+        let runtime = crate::Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let inspector = engine.create_inspector().unwrap();
+
+        let tactics = inspector.tactics().unwrap();
+        assert_eq!(tactics["mock_layer"], "mock_identity_kernel");
+        assert!(tactics.len() <= inspector.layers().unwrap().len());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_engine_layer_precisions() {
+        let logger = crate::Logger::stderr().unwrap();
+        let runtime = crate::Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+
+        let precisions = engine.get_layer_precisions().unwrap();
+        assert_eq!(
+            precisions,
+            vec![("mock_layer".to_string(), DataType::Float)]
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_engine_layer_names() {
+        let logger = crate::Logger::stderr().unwrap();
+        let runtime = crate::Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+
+        let names = engine.get_layer_names().unwrap();
+        assert_eq!(names.len() as i32, engine.get_nb_layers().unwrap());
+        assert_eq!(names, vec!["mock_layer".to_string()]);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_inspector_diff() {
+        let logger = crate::Logger::stderr().unwrap();
+        let runtime = crate::Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let inspector = engine.create_inspector().unwrap();
+
+        let info = inspector
+            .get_engine_information(LayerInformationFormat::Json)
+            .unwrap();
+        let _: Value = serde_json::from_str(&info).unwrap();
+
+        let other = engine.create_inspector().unwrap();
+        assert!(inspector.diff(&other).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_layer_information() {
+        let logger = crate::Logger::stderr().unwrap();
+        let runtime = crate::Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let inspector = engine.create_inspector().unwrap();
+
+        for index in 0..engine.get_nb_layers().unwrap() {
+            let info = inspector
+                .get_layer_information(index, LayerInformationFormat::Json)
+                .unwrap();
+            // An object with detailed verbosity, otherwise just the layer name
+            let layer: Value = serde_json::from_str(&info).unwrap();
+            assert!(layer.is_object() || layer.is_string());
+            assert_eq!(layer["Name"], "mock_layer");
+        }
+
+        assert!(matches!(
+            inspector.get_layer_information(1, LayerInformationFormat::OneLine),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
@@ -36,6 +36,9 @@
 //! 4. Bind input/output tensors
 //! 5. Execute inference with [`ExecutionContext::enqueue_v3`]
 //!
+//! For the common case of running an ONNX model, [`OnnxModel`] wraps both
+//! phases behind a single type.
+//!
 //! # Example
 //!
 //! ```rust,no_run
@@ -64,10 +67,9 @@
 //! let context = engine.create_execution_context()?;
 //!
 //! // List I/O tensors
-//! let num_tensors = engine.get_nb_io_tensors()?;
-//! for i in 0..num_tensors {
-//!     let name = engine.get_tensor_name(i)?;
-//!     println!("Tensor {}: {}", i, name);
+//! for tensor in engine.tensors() {
+//!     let tensor = tensor?;
+//!     println!("{:?} {}: {:?}", tensor.io_mode, tensor.name, tensor.shape);
 //! }
 //! # Ok(())
 //! # }
@@ -79,6 +81,18 @@
 //! some operations (like setting tensor addresses and enqueueing inference)
 //! require careful management of CUDA memory and are marked as `unsafe`.
 //!
+//! # Thread Safety
+//!
+//! The `Send`/`Sync` implementations follow TensorRT's threading rules:
+//!
+//! - [`CudaEngine`] and [`Logger`] are `Send + Sync`; an engine may be shared
+//!   between threads, e.g. to create one execution context per thread.
+//! - [`ExecutionContext`] is `Send` but not `Sync`: a context may move to
+//!   another thread, but must not be used from two threads at once. Wrap it in
+//!   a `Mutex` to share it.
+//! - [`Runtime`], [`Builder`], [`BuilderConfig`], [`NetworkDefinition`] and
+//!   [`OnnxParser`] are `Send` only.
+//!
 //! # Prerequisites
 //!
 //! - NVIDIA TensorRT-RTX library installed
@@ -87,23 +101,126 @@
 //!
 //! Set the `TENSORRT_RTX_DIR` environment variable to the installation path
 //! if TensorRT-RTX is not in a standard location.
+//!
+//! # Versioning
+//!
+//! The TensorRT-RTX version found at build time is available from [`version`].
+//! APIs that only exist in newer releases are gated on the
+//! `trtx_rtx_at_least = "MAJOR.MINOR"` cfg set by the build script.
 
 // Allow unnecessary casts - they're needed for real mode (u32) but not mock mode (i32)
 #![cfg_attr(feature = "mock", allow(clippy::unnecessary_cast))]
 
 pub mod builder;
 pub mod cuda;
+pub mod debug_listener;
+pub mod engine_header;
 pub mod error;
+pub mod error_recorder;
 pub mod executor;
+pub mod graph;
+mod handles;
+pub mod inspector;
 pub mod logger;
+pub mod model;
+pub mod network;
 pub mod onnx_parser;
+pub mod output_allocator;
+pub mod plugin;
+#[cfg(feature = "image")]
+pub mod preprocess;
+pub mod profiler;
+pub mod progress;
+pub mod refitter;
+#[cfg(feature = "json")]
+pub mod replay;
 pub mod runtime;
+pub mod serving;
+pub mod shapes;
+pub mod tensor;
+pub mod timing_cache;
 
 // Re-export commonly used types
-pub use builder::{Builder, BuilderConfig, NetworkDefinition};
-pub use cuda::{synchronize, DeviceBuffer};
+pub use builder::{
+    BuildStats, Builder, BuilderConfig, EngineBuilder, FallbackBuild, InputProfile, ModelConfig,
+    NetworkDefinition, OptimizationProfile, PrecisionConfig,
+};
+pub use cuda::{
+    current_device, ensure_context, last_error, peek_last_error, set_device, stream_priority_range,
+    synchronize, synchronize_stream, CudaError, CudaEvent, CudaGraph, CudaStream, DeviceAttribute,
+    DeviceBuffer, DeviceLimit, ManagedBuffer, MemPool, TypedDeviceBuffer,
+};
+pub use debug_listener::{DebugListener, MinMaxListener};
+pub use engine_header::{EngineHeader, TensorInfo};
 pub use error::{Error, Result};
-pub use executor::{run_onnx_with_tensorrt, run_onnx_zeroed, TensorInput, TensorOutput};
-pub use logger::{LogHandler, Logger, Severity, StderrLogger};
-pub use onnx_parser::OnnxParser;
+pub use error_recorder::{ErrorCode, ErrorRecorder, RecordedError};
+#[cfg(feature = "async")]
+pub use executor::InferenceFuture;
+#[cfg(feature = "memmap2")]
+pub use executor::MappedTensor;
+pub use executor::{
+    build_from_pytorch_onnx, run_onnx_with_tensorrt, run_onnx_zeroed, Executor, HostKind,
+    OutputStats, Session, SessionOptions, TensorData, TensorInput, TensorOutput,
+};
+pub use graph::GraphBatch;
+#[cfg(feature = "json")]
+pub use inspector::{EngineDiff, LayerChange, LayerInfo};
+pub use inspector::{EngineInspector, LayerInformationFormat};
+#[cfg(feature = "log")]
+pub use logger::LogCrateLogger;
+pub use logger::{LogHandler, Logger, NullLogger, Severity, StderrLogger};
+pub use model::OnnxModel;
+pub use network::{
+    ActivationType, ElementWiseOperation, LayerRef, MatrixOperation, TensorRef, Weights,
+};
+pub use onnx_parser::{OnnxParser, OnnxParserFlag, Subgraph, SupportResult};
+pub use output_allocator::{DeviceOutputAllocator, OutputAllocator};
+pub use plugin::{
+    load_plugin_library, load_plugins_from_dir, PluginCreator, PluginField, PluginInstance,
+    PluginRegistry,
+};
+#[cfg(feature = "image")]
+pub use preprocess::{ImageLayout, ImagePreprocess};
+pub use profiler::{AggregatingProfiler, LayerProfiler};
+pub use progress::{PhaseTimer, ProgressMonitor};
+pub use refitter::Refitter;
+#[cfg(feature = "json")]
+pub use replay::replay_inference;
 pub use runtime::{CudaEngine, ExecutionContext, Runtime};
+pub use serving::Scheduler;
+pub use shapes::Dims;
+pub use tensor::{DataType, OptProfileSelector, TensorIoMode};
+pub use timing_cache::{SharedTimingCache, TimingCache};
+
+/// TensorRT-RTX version this crate was built against, as `(major, minor, patch)`
+///
+/// Read from `NvInferVersion.h` at build time. Returns `(0, 0, 0)` in mock mode.
+pub fn version() -> (u32, u32, u32) {
+    trtx_sys::TRTX_VERSION
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version() {
+        let version = version();
+        #[cfg(feature = "mock")]
+        assert_eq!(version, (0, 0, 0));
+        #[cfg(not(feature = "mock"))]
+        assert_ne!(version, (0, 0, 0));
+    }
+
+    #[test]
+    fn test_thread_safety_markers() {
+        fn assert_send<T: Send>() {}
+        fn assert_sync<T: Sync>() {}
+
+        assert_send::<CudaEngine>();
+        assert_sync::<CudaEngine>();
+        assert_send::<ExecutionContext<'_>>();
+        assert_send::<Runtime<'_>>();
+        assert_sync::<Logger>();
+    }
+}
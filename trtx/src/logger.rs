@@ -1,12 +1,16 @@
 //! Logger interface for TensorRT-RTX
 
 use crate::error::Result;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::ffi::{c_void, CStr};
+use std::hash::{Hash, Hasher};
 use std::os::raw::c_char;
+use std::sync::Mutex;
 use trtx_sys::*;
 
 /// Severity level for log messages
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(i32)]
 pub enum Severity {
     /// Internal error (most severe)
@@ -36,6 +40,38 @@ pub enum Severity {
     Verbose = TrtxLoggerSeverity_TRTX_SEVERITY_VERBOSE,
 }
 
+/// Maps TensorRT severities onto `log` levels
+///
+/// `InternalError` and `Error` become [`log::Level::Error`], `Warning`
+/// becomes `Warn`, `Info` stays `Info` and `Verbose` becomes `Trace`.
+#[cfg(feature = "log")]
+impl From<Severity> for log::Level {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::InternalError | Severity::Error => log::Level::Error,
+            Severity::Warning => log::Level::Warn,
+            Severity::Info => log::Level::Info,
+            Severity::Verbose => log::Level::Trace,
+        }
+    }
+}
+
+/// Maps `log` levels back onto TensorRT severities
+///
+/// The inverse of the `From<Severity>` mapping, with `Debug` also becoming
+/// `Verbose`.
+#[cfg(feature = "log")]
+impl From<log::Level> for Severity {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => Severity::Error,
+            log::Level::Warn => Severity::Warning,
+            log::Level::Info => Severity::Info,
+            log::Level::Debug | log::Level::Trace => Severity::Verbose,
+        }
+    }
+}
+
 /// Trait for handling log messages from TensorRT
 pub trait LogHandler: Send + Sync {
     /// Called when TensorRT emits a log message
@@ -52,59 +88,197 @@ impl LogHandler for StderrLogger {
     }
 }
 
+/// Logger that discards every message
+///
+/// Useful for benchmarks, where printing and formatting log messages would
+/// add noise to latency measurements.
+#[derive(Debug)]
+pub struct NullLogger;
+
+impl LogHandler for NullLogger {
+    fn log(&self, _severity: Severity, _message: &str) {}
+}
+
+/// Logger forwarding messages to the [`log`] facade under the `trtx` target
+#[cfg(feature = "log")]
+#[derive(Debug)]
+pub struct LogCrateLogger;
+
+#[cfg(feature = "log")]
+impl LogHandler for LogCrateLogger {
+    fn log(&self, severity: Severity, message: &str) {
+        log::log!(target: "trtx", severity.into(), "{}", message);
+    }
+}
+
+/// Adapter letting a closure act as a [`LogHandler`]
+struct FnLogHandler<F>(F);
+
+impl<F: Fn(Severity, &str) + Send + Sync> LogHandler for FnLogHandler<F> {
+    fn log(&self, severity: Severity, message: &str) {
+        (self.0)(severity, message)
+    }
+}
+
+/// Times a message was seen, and its text once it starts being suppressed
+type MessageCounts = HashMap<(Severity, u64), (usize, Option<String>)>;
+
+/// Forwards each distinct message at most `max_per_key` times
+struct RateLimited<H: LogHandler> {
+    handler: H,
+    max_per_key: usize,
+    counts: Mutex<MessageCounts>,
+}
+
+impl<H: LogHandler> LogHandler for RateLimited<H> {
+    fn log(&self, severity: Severity, message: &str) {
+        let mut hasher = DefaultHasher::new();
+        message.hash(&mut hasher);
+
+        let forward = {
+            let mut counts = self.counts.lock().unwrap_or_else(|e| e.into_inner());
+            let (count, suppressed) = counts.entry((severity, hasher.finish())).or_default();
+            *count += 1;
+            if *count > self.max_per_key && suppressed.is_none() {
+                *suppressed = Some(message.to_string());
+            }
+            *count <= self.max_per_key
+        };
+        // Outside the lock, in case the handler logs through this logger
+        if forward {
+            self.handler.log(severity, message);
+        }
+    }
+}
+
+impl<H: LogHandler> Drop for RateLimited<H> {
+    fn drop(&mut self) {
+        let counts = std::mem::take(self.counts.get_mut().unwrap_or_else(|e| e.into_inner()));
+        let mut suppressed: Vec<_> = counts
+            .into_iter()
+            .filter_map(|((severity, _), (count, message))| {
+                Some((severity, message?, count - self.max_per_key))
+            })
+            .collect();
+        suppressed.sort();
+        for (severity, message, count) in suppressed {
+            self.handler.log(
+                severity,
+                &format!("(suppressed {} more) {}", count, message),
+            );
+        }
+    }
+}
+
 /// Logger wrapper that interfaces with TensorRT-RTX
 pub struct Logger {
     inner: *mut TrtxLogger,
-    // Keep the handler alive
-    _handler: Box<dyn LogHandler>,
+    // The callback's user data points at the inner box, so it must stay at
+    // the same address for as long as `inner` lives
+    handler: Box<Box<dyn LogHandler>>,
 }
 
 impl Logger {
     /// Create a new logger with a custom handler
     pub fn new<H: LogHandler + 'static>(handler: H) -> Result<Self> {
-        let handler_box: Box<dyn LogHandler> = Box::new(handler);
-        let user_data = Box::into_raw(Box::new(handler_box)) as *mut c_void;
+        Self::create(
+            Box::new(Box::new(handler)),
+            |user_data, logger_ptr, error_msg| unsafe {
+                trtx_logger_create(
+                    Some(Self::log_callback),
+                    user_data,
+                    logger_ptr,
+                    error_msg.as_mut_ptr(),
+                    error_msg.len(),
+                )
+            },
+        )
+    }
+
+    /// Register `handler` as the user data of the logger `create` makes
+    ///
+    /// The handler stays owned by Rust throughout, so it is dropped normally
+    /// if creation fails or panics.
+    fn create(
+        handler: Box<Box<dyn LogHandler>>,
+        create: impl FnOnce(*mut c_void, &mut *mut TrtxLogger, &mut [i8]) -> i32,
+    ) -> Result<Self> {
+        let user_data = &*handler as *const Box<dyn LogHandler> as *mut c_void;
 
         let mut logger_ptr: *mut TrtxLogger = std::ptr::null_mut();
         let mut error_msg = [0i8; 1024];
 
-        let result = unsafe {
-            trtx_logger_create(
-                Some(Self::log_callback),
-                user_data,
-                &mut logger_ptr,
-                error_msg.as_mut_ptr(),
-                error_msg.len(),
-            )
-        };
+        let result = create(user_data, &mut logger_ptr, &mut error_msg);
 
         if result != TRTX_SUCCESS as i32 {
-            // Clean up user_data
-            unsafe {
-                let _ = Box::from_raw(user_data as *mut Box<dyn LogHandler>);
-            }
             return Err(crate::error::Error::from_ffi(result, &error_msg));
         }
 
-        // Reconstruct the handler box for keeping alive
-        let handler_box = unsafe { Box::from_raw(user_data as *mut Box<dyn LogHandler>) };
-
         Ok(Logger {
             inner: logger_ptr,
-            _handler: *handler_box,
+            handler,
         })
     }
 
+    /// Create a logger that forwards each distinct message at most
+    /// `max_per_key` times
+    ///
+    /// Messages are keyed by severity and a hash of their text. Repeats
+    /// beyond the limit are counted instead of forwarded, and when the logger
+    /// is dropped, `handler` gets one "suppressed N more" note per repeated
+    /// message. Keeps builds that emit thousands of identical warnings
+    /// readable.
+    pub fn with_rate_limit<H: LogHandler + 'static>(
+        handler: H,
+        max_per_key: usize,
+    ) -> Result<Self> {
+        Self::new(RateLimited {
+            handler,
+            max_per_key,
+            counts: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Create a logger that forwards messages to a closure
+    ///
+    /// ```no_run
+    /// # use trtx::Logger;
+    /// let logger = Logger::from_fn(|severity, msg| eprintln!("{severity:?}: {msg}"))?;
+    /// # Ok::<(), trtx::Error>(())
+    /// ```
+    pub fn from_fn<F>(f: F) -> Result<Self>
+    where
+        F: Fn(Severity, &str) + Send + Sync + 'static,
+    {
+        Self::new(FnLogHandler(f))
+    }
+
     /// Create a logger that prints to stderr
     pub fn stderr() -> Result<Self> {
         Self::new(StderrLogger)
     }
 
+    /// Create a logger that forwards to the [`log`] facade
+    #[cfg(feature = "log")]
+    pub fn log_crate() -> Result<Self> {
+        Self::new(LogCrateLogger)
+    }
+
+    /// Create a logger that discards all messages
+    pub fn null() -> Result<Self> {
+        Self::new(NullLogger)
+    }
+
     /// Get the raw pointer (for internal use)
     pub(crate) fn as_ptr(&self) -> *mut TrtxLogger {
         self.inner
     }
 
+    /// Emit a message from the Rust side through the same handler TensorRT uses
+    pub(crate) fn log(&self, severity: Severity, message: &str) {
+        self.handler.log(severity, message);
+    }
+
     /// C callback function that bridges to Rust trait
     extern "C" fn log_callback(
         user_data: *mut c_void,
@@ -147,9 +321,10 @@ impl Logger {
 
 impl Drop for Logger {
     fn drop(&mut self) {
-        if !self.inner.is_null() {
+        let inner = std::mem::replace(&mut self.inner, std::ptr::null_mut());
+        if !inner.is_null() {
             unsafe {
-                trtx_logger_destroy(self.inner);
+                trtx_logger_destroy(inner);
             }
         }
     }
@@ -162,6 +337,7 @@ unsafe impl Sync for Logger {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::{Arc, Mutex};
 
     #[allow(dead_code)]
@@ -192,6 +368,133 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_logger_from_fn() {
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let sink = messages.clone();
+        let logger = Logger::from_fn(move |severity, message| {
+            sink.lock().unwrap().push((severity, message.to_string()));
+        })
+        .unwrap();
+
+        logger.log(Severity::Warning, "low on memory");
+        assert_eq!(
+            *messages.lock().unwrap(),
+            vec![(Severity::Warning, "low on memory".to_string())]
+        );
+    }
+
+    /// Counts how many times it is dropped
+    struct DropCounter(Arc<AtomicUsize>);
+
+    impl LogHandler for DropCounter {
+        fn log(&self, _severity: Severity, _message: &str) {}
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_failed_logger_creation_drops_handler() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let handler: Box<dyn LogHandler> = Box::new(DropCounter(drops.clone()));
+        let result = Logger::create(Box::new(handler), |_, _, _| TRTX_ERROR_RUNTIME_ERROR as i32);
+        assert!(result.is_err());
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+
+        let logger = Logger::new(DropCounter(drops.clone())).unwrap();
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+        drop(logger);
+        assert_eq!(drops.load(Ordering::SeqCst), 2);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_callback_user_data_outlives_creation() {
+        let handler = TestLogger::new();
+        let mut registered = std::ptr::null_mut();
+        let logger = Logger::create(
+            Box::new(Box::new(handler.clone())),
+            |user_data, ptr, err| {
+                registered = user_data;
+                unsafe {
+                    trtx_logger_create(
+                        Some(Logger::log_callback),
+                        user_data,
+                        ptr,
+                        err.as_mut_ptr(),
+                        err.len(),
+                    )
+                }
+            },
+        )
+        .unwrap();
+
+        // TensorRT keeps calling back with the user data it was given
+        Logger::log_callback(
+            registered,
+            TrtxLoggerSeverity::TRTX_SEVERITY_WARNING,
+            c"still here".as_ptr(),
+        );
+        assert_eq!(
+            handler.get_messages(),
+            vec![(Severity::Warning, "still here".to_string())]
+        );
+        drop(logger);
+    }
+
+    #[test]
+    fn test_rate_limited_logger() {
+        let handler = TestLogger::new();
+        let logger = Logger::with_rate_limit(handler.clone(), 2).unwrap();
+        for _ in 0..5 {
+            logger.log(Severity::Warning, "weights are denormal");
+        }
+        logger.log(Severity::Error, "weights are denormal");
+        logger.log(Severity::Warning, "tactic skipped");
+
+        let mut expected = vec![
+            (Severity::Warning, "weights are denormal".to_string()),
+            (Severity::Warning, "weights are denormal".to_string()),
+            (Severity::Error, "weights are denormal".to_string()),
+            (Severity::Warning, "tactic skipped".to_string()),
+        ];
+        assert_eq!(handler.get_messages(), expected);
+
+        drop(logger);
+        expected.push((
+            Severity::Warning,
+            "(suppressed 3 more) weights are denormal".to_string(),
+        ));
+        assert_eq!(handler.get_messages(), expected);
+    }
+
+    #[test]
+    fn test_null_logger() {
+        let logger = Logger::null().unwrap();
+        logger.log(Severity::Error, "discarded");
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn test_log_level_mapping() {
+        assert_eq!(log::Level::from(Severity::InternalError), log::Level::Error);
+        assert_eq!(log::Level::from(Severity::Error), log::Level::Error);
+        assert_eq!(log::Level::from(Severity::Warning), log::Level::Warn);
+        assert_eq!(log::Level::from(Severity::Info), log::Level::Info);
+        assert_eq!(log::Level::from(Severity::Verbose), log::Level::Trace);
+
+        assert_eq!(Severity::from(log::Level::Warn), Severity::Warning);
+        assert_eq!(Severity::from(log::Level::Debug), Severity::Verbose);
+
+        // No `log` backend is installed, so messages are dropped
+        let logger = Logger::log_crate().unwrap();
+        logger.log(Severity::Info, "forwarded");
+    }
+
     #[test]
     fn test_severity_ordering() {
         assert!(Severity::InternalError < Severity::Error);
@@ -0,0 +1,103 @@
+//! High-level ONNX model combining parsing, building and inference
+
+use crate::engine_header::{EngineHeader, TensorInfo};
+use crate::error::Result;
+use crate::executor::{Session, SessionOptions, TensorInput, TensorOutput};
+use std::path::Path;
+
+/// An ONNX model built into a TensorRT engine, ready to run
+///
+/// Building is slow, so build once with [`from_bytes`](Self::from_bytes),
+/// [`save_engine`](Self::save_engine) the result and load it with
+/// [`from_engine_file`](Self::from_engine_file) on later runs.
+///
+/// ```no_run
+/// # use trtx::{OnnxModel, SessionOptions};
+/// let onnx = std::fs::read("model.onnx")?;
+/// let model = OnnxModel::from_bytes(&onnx, SessionOptions::default())?;
+/// for input in model.inputs() {
+///     println!("{}: {:?} {:?}", input.name, input.dtype, input.shape);
+/// }
+/// model.save_engine("model.engine")?;
+/// # Ok::<(), trtx::Error>(())
+/// ```
+pub struct OnnxModel {
+    session: Session,
+    header: EngineHeader,
+}
+
+impl OnnxModel {
+    /// Parse an ONNX model and build an engine for it
+    pub fn from_bytes(onnx_model_bytes: &[u8], options: SessionOptions) -> Result<Self> {
+        Self::from_session(Session::from_onnx(onnx_model_bytes, options)?)
+    }
+
+    /// Load an engine saved with [`save_engine`](Self::save_engine)
+    pub fn from_engine_file(path: impl AsRef<Path>, options: SessionOptions) -> Result<Self> {
+        let engine_data = std::fs::read(path)?;
+        Self::from_session(Session::from_engine(engine_data, options)?)
+    }
+
+    fn from_session(session: Session) -> Result<Self> {
+        let header = EngineHeader::from_bytes(session.engine_data())?;
+        Ok(OnnxModel { session, header })
+    }
+
+    /// Input tensors, in engine order
+    pub fn inputs(&self) -> impl Iterator<Item = &TensorInfo> {
+        self.header.inputs()
+    }
+
+    /// Output tensors, in engine order
+    pub fn outputs(&self) -> impl Iterator<Item = &TensorInfo> {
+        self.header.outputs()
+    }
+
+    /// Run inference (see [`Session::infer`])
+    pub fn run(&mut self, inputs: &[TensorInput]) -> Result<Vec<TensorOutput>> {
+        self.session.infer(inputs)
+    }
+
+    /// Write the serialized engine to `path`
+    pub fn save_engine(&self, path: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(path, self.session.engine_data())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_onnx_model_round_trip() {
+        // ir_version 7 and an empty graph: the smallest bytes shaped like ONNX
+        let result = OnnxModel::from_bytes(b"\x08\x07\x3a\x00", SessionOptions::default());
+        #[cfg(feature = "mock")]
+        {
+            let mut model = result.unwrap();
+            let inputs: Vec<_> = model.inputs().map(|t| t.name.clone()).collect();
+            let outputs: Vec<_> = model.outputs().map(|t| t.name.clone()).collect();
+            assert_eq!(inputs, vec!["input"]);
+            assert_eq!(outputs, vec!["output"]);
+
+            let values = vec![1.0f32, 2.0, 3.0, 4.0];
+            let run = vec![TensorInput {
+                name: "input".to_string(),
+                shape: vec![1, 4].into(),
+                data: values.clone().into(),
+            }];
+            let output = model.run(&run).unwrap();
+            assert_eq!(output[0].data.as_f32(), Some(values.as_slice()));
+
+            let path =
+                std::env::temp_dir().join(format!("trtx_model_{}.engine", std::process::id()));
+            model.save_engine(&path).unwrap();
+            let reloaded = OnnxModel::from_engine_file(&path, SessionOptions::default());
+            std::fs::remove_file(&path).unwrap();
+            assert_eq!(reloaded.unwrap().inputs().count(), 1);
+        }
+        #[cfg(not(feature = "mock"))]
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,490 @@
+//! Programmatic network construction: weights, tensors and layers
+//!
+//! Networks are usually filled by the ONNX parser; these types let callers
+//! add inputs and layers to a [`NetworkDefinition`] directly.
+
+use crate::builder::NetworkDefinition;
+use crate::error::{Error, Result};
+use crate::shapes::Dims;
+use crate::tensor::DataType;
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use trtx_sys::*;
+
+/// Activation function of an activation layer (mirrors `nvinfer1::ActivationType`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum ActivationType {
+    /// `max(x, 0)`
+    Relu = 0,
+    /// `1 / (1 + exp(-x))`
+    Sigmoid = 1,
+    /// Hyperbolic tangent
+    Tanh = 2,
+    /// `x` if `x >= 0`, else `alpha * x`
+    LeakyRelu = 3,
+    /// `x` if `x >= 0`, else `alpha * (exp(x) - 1)`
+    Elu = 4,
+    /// Scaled ELU
+    Selu = 5,
+    /// `x / (1 + |x|)`
+    Softsign = 6,
+    /// `alpha * log(exp(beta * x) + 1)`
+    Softplus = 7,
+    /// `x` clamped to `[alpha, beta]`
+    Clip = 8,
+    /// `max(0, min(1, alpha * x + beta))`
+    HardSigmoid = 9,
+    /// `alpha * tanh(beta * x)`
+    ScaledTanh = 10,
+    /// `x` if `x > alpha`, else 0
+    ThresholdedRelu = 11,
+    /// GELU computed with the error function
+    GeluErf = 12,
+    /// GELU approximated with tanh
+    GeluTanh = 13,
+}
+
+/// Operation of an elementwise layer (mirrors `nvinfer1::ElementWiseOperation`)
+///
+/// Inputs must have the same rank; dimensions of size 1 are broadcast.
+/// Logical and comparison operations produce [`DataType::Bool`] outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum ElementWiseOperation {
+    /// `a + b`
+    Sum = 0,
+    /// `a * b`
+    Prod = 1,
+    /// `max(a, b)`
+    Max = 2,
+    /// `min(a, b)`
+    Min = 3,
+    /// `a - b`
+    Sub = 4,
+    /// `a / b`
+    Div = 5,
+    /// `a` raised to the power `b`
+    Pow = 6,
+    /// `floor(a / b)`
+    FloorDiv = 7,
+    /// Logical `a && b`
+    And = 8,
+    /// Logical `a || b`
+    Or = 9,
+    /// Logical `a != b`
+    Xor = 10,
+    /// `a == b`
+    Equal = 11,
+    /// `a > b`
+    Greater = 12,
+    /// `a < b`
+    Less = 13,
+}
+
+/// How a matrix multiply layer treats an operand (mirrors `nvinfer1::MatrixOperation`)
+///
+/// Operands are batches of matrices in their last two dimensions; leading
+/// dimensions must have the same rank and broadcast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum MatrixOperation {
+    /// Use the matrix as is
+    None = 0,
+    /// Transpose the last two dimensions
+    Transpose = 1,
+    /// Treat the last dimension as a vector; the output drops that dimension
+    Vector = 2,
+}
+
+/// Host weights for a constant layer (mirrors `nvinfer1::Weights`)
+///
+/// Borrows the data; [`NetworkDefinition::add_constant`] copies it into the
+/// network, so the source can be dropped once the layer is added.
+#[derive(Debug, Clone, Copy)]
+pub struct Weights<'a> {
+    dtype: DataType,
+    data: &'a [u8],
+}
+
+impl<'a> Weights<'a> {
+    /// Wrap raw bytes holding elements of `dtype`
+    ///
+    /// Fails with [`Error::InvalidArgument`] if the length is not a whole
+    /// number of elements.
+    pub fn new(dtype: DataType, data: &'a [u8]) -> Result<Self> {
+        if !data.len().is_multiple_of(dtype.size()) {
+            return Err(Error::InvalidArgument(format!(
+                "{} bytes is not a whole number of {:?} elements",
+                data.len(),
+                dtype
+            )));
+        }
+        Ok(Weights { dtype, data })
+    }
+
+    /// Wrap float32 values
+    pub fn from_f32(values: &'a [f32]) -> Self {
+        let data = unsafe {
+            std::slice::from_raw_parts(values.as_ptr() as *const u8, std::mem::size_of_val(values))
+        };
+        Weights {
+            dtype: DataType::Float,
+            data,
+        }
+    }
+
+    /// Element type
+    pub fn dtype(&self) -> DataType {
+        self.dtype
+    }
+
+    /// Number of elements
+    pub fn count(&self) -> usize {
+        self.data.len() / self.dtype.size()
+    }
+
+    /// Raw bytes
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+/// A tensor of a [`NetworkDefinition`] (mirrors `nvinfer1::ITensor`)
+///
+/// Owned by the network, which it borrows.
+#[derive(Debug, Clone, Copy)]
+pub struct TensorRef<'n> {
+    inner: *mut TrtxTensor,
+    _network: PhantomData<&'n NetworkDefinition>,
+}
+
+impl TensorRef<'_> {
+    pub(crate) fn from_ptr(inner: *mut TrtxTensor) -> Self {
+        TensorRef {
+            inner,
+            _network: PhantomData,
+        }
+    }
+
+    /// Get the tensor name
+    pub fn name(&self) -> Result<String> {
+        let mut name_ptr: *const i8 = std::ptr::null();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_tensor_get_name(
+                self.inner,
+                &mut name_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        let name = unsafe { CStr::from_ptr(name_ptr) }.to_str()?.to_string();
+
+        Ok(name)
+    }
+
+    /// Get the tensor dimensions (`-1` marks a dynamic dimension)
+    pub fn dimensions(&self) -> Result<Dims> {
+        let mut dims = [0i64; TRTX_MAX_DIMS as usize];
+        let mut nb_dims: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_tensor_get_dimensions(
+                self.inner,
+                dims.as_mut_ptr(),
+                dims.len() as i32,
+                &mut nb_dims,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(Dims::from(&dims[..nb_dims as usize]))
+    }
+
+    /// Get the raw pointer (for internal use)
+    pub(crate) fn as_ptr(&self) -> *mut TrtxTensor {
+        self.inner
+    }
+
+    /// Get the tensor element type
+    pub fn data_type(&self) -> Result<DataType> {
+        let mut dtype: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_tensor_get_type(
+                self.inner,
+                &mut dtype,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        DataType::from_raw(dtype)
+    }
+}
+
+/// A layer of a [`NetworkDefinition`] (mirrors `nvinfer1::ILayer`)
+///
+/// Owned by the network, which it borrows.
+#[derive(Debug, Clone, Copy)]
+pub struct LayerRef<'n> {
+    inner: *mut TrtxLayer,
+    _network: PhantomData<&'n NetworkDefinition>,
+}
+
+impl<'n> LayerRef<'n> {
+    pub(crate) fn from_ptr(inner: *mut TrtxLayer) -> Self {
+        LayerRef {
+            inner,
+            _network: PhantomData,
+        }
+    }
+
+    /// Get the layer name
+    pub fn name(&self) -> Result<String> {
+        let mut name_ptr: *const i8 = std::ptr::null();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_layer_get_name(
+                self.inner,
+                &mut name_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        let name = unsafe { CStr::from_ptr(name_ptr) }.to_str()?.to_string();
+
+        Ok(name)
+    }
+
+    /// Get the number of layer outputs
+    pub fn get_nb_outputs(&self) -> Result<i32> {
+        let mut count: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_layer_get_nb_outputs(
+                self.inner,
+                &mut count,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(count)
+    }
+
+    /// Get the output at `index`
+    pub fn get_output(&self, index: i32) -> Result<TensorRef<'n>> {
+        let mut tensor_ptr: *mut TrtxTensor = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_layer_get_output(
+                self.inner,
+                index,
+                &mut tensor_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(TensorRef::from_ptr(tensor_ptr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{network_flags, Builder};
+    use crate::logger::Logger;
+
+    #[test]
+    fn test_weights() {
+        let values = [1.0f32, 2.0, 3.0];
+        let weights = Weights::from_f32(&values);
+        assert_eq!(weights.dtype(), DataType::Float);
+        assert_eq!(weights.count(), 3);
+        assert_eq!(weights.as_bytes().len(), 12);
+
+        assert_eq!(Weights::new(DataType::Half, &[0u8; 6]).unwrap().count(), 3);
+        assert!(matches!(
+            Weights::new(DataType::Float, &[0u8; 6]),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_add_input_and_constant() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+
+        let bias = [0.5f32; 8];
+        let constant = network.add_constant(&[2, 4], &Weights::from_f32(&bias));
+        // Weight count must match the dimensions
+        let mismatched = network.add_constant(&[3, 4], &Weights::from_f32(&bias));
+        assert!(matches!(mismatched, Err(Error::InvalidArgument(_))));
+
+        #[cfg(feature = "mock")]
+        {
+            let before = network.get_nb_inputs().unwrap();
+            let input = network
+                .add_input("tokens", DataType::Int32, &[-1, 128])
+                .unwrap();
+            assert_eq!(input.name().unwrap(), "tokens");
+            assert_eq!(input.dimensions().unwrap(), vec![-1, 128]);
+            assert_eq!(input.data_type().unwrap(), DataType::Int32);
+            assert_eq!(network.get_nb_inputs().unwrap(), before + 1);
+            assert_eq!(network.get_input_name(before).unwrap(), "tokens");
+            assert!(network.add_input("tokens", DataType::Int32, &[1]).is_err());
+
+            let layer = constant.as_ref().unwrap();
+            assert_eq!(layer.get_nb_outputs().unwrap(), 1);
+            let output = layer.get_output(0).unwrap();
+            assert_eq!(output.dimensions().unwrap(), vec![2, 4]);
+            assert_eq!(output.data_type().unwrap(), DataType::Float);
+            assert!(layer.get_output(1).is_err());
+        }
+        drop(constant);
+    }
+
+    #[test]
+    fn test_relu_of_sum() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+
+        let a = network.add_input("a", DataType::Float, &[1, 16]).unwrap();
+        let b = network.add_input("b", DataType::Float, &[1, 16]).unwrap();
+        let sum = network
+            .add_elementwise(a, b, ElementWiseOperation::Sum)
+            .unwrap();
+        let relu = network
+            .add_activation(sum.get_output(0).unwrap(), ActivationType::Relu)
+            .unwrap();
+        let output = relu.get_output(0).unwrap();
+        assert!(output.name().is_ok());
+
+        #[cfg(feature = "mock")]
+        {
+            assert_eq!(output.dimensions().unwrap(), vec![1, 16]);
+            assert_eq!(output.data_type().unwrap(), DataType::Float);
+            assert!(relu.name().unwrap().ends_with("[Activation]"));
+
+            let c = network.add_input("c", DataType::Float, &[4, 1]).unwrap();
+            let greater = network
+                .add_elementwise(a, c, ElementWiseOperation::Greater)
+                .unwrap();
+            let mask = greater.get_output(0).unwrap();
+            assert_eq!(mask.dimensions().unwrap(), vec![4, 16]);
+            assert_eq!(mask.data_type().unwrap(), DataType::Bool);
+
+            let d = network.add_input("d", DataType::Float, &[2, 16]).unwrap();
+            let mismatched = network.add_elementwise(c, d, ElementWiseOperation::Sum);
+            assert!(matches!(mismatched, Err(Error::InvalidArgument(_))));
+        }
+    }
+
+    #[test]
+    fn test_matmul_with_bias() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+
+        let kernel = [0.1f32; 16 * 8];
+        let bias = [0.5f32; 8];
+        let x = network.add_input("x", DataType::Float, &[1, 16]).unwrap();
+        let w = network
+            .add_constant(&[16, 8], &Weights::from_f32(&kernel))
+            .unwrap();
+        let b = network
+            .add_constant(&[1, 8], &Weights::from_f32(&bias))
+            .unwrap();
+        let matmul = network
+            .add_matrix_multiply(
+                x,
+                MatrixOperation::None,
+                w.get_output(0).unwrap(),
+                MatrixOperation::None,
+            )
+            .unwrap();
+        let y = network
+            .add_elementwise(
+                matmul.get_output(0).unwrap(),
+                b.get_output(0).unwrap(),
+                ElementWiseOperation::Sum,
+            )
+            .unwrap()
+            .get_output(0)
+            .unwrap();
+        network.mark_output(y).unwrap();
+
+        #[cfg(feature = "mock")]
+        {
+            assert_eq!(y.dimensions().unwrap(), vec![1, 8]);
+
+            // Transposed [8, 16] kernel gives the same shape
+            let wt = network
+                .add_constant(&[8, 16], &Weights::from_f32(&kernel))
+                .unwrap();
+            let transposed = network
+                .add_matrix_multiply(
+                    x,
+                    MatrixOperation::None,
+                    wt.get_output(0).unwrap(),
+                    MatrixOperation::Transpose,
+                )
+                .unwrap();
+            let out = transposed.get_output(0).unwrap();
+            assert_eq!(out.dimensions().unwrap(), vec![1, 8]);
+
+            // Inner dimensions must agree
+            let mismatched = network.add_matrix_multiply(
+                x,
+                MatrixOperation::None,
+                wt.get_output(0).unwrap(),
+                MatrixOperation::None,
+            );
+            assert!(matches!(mismatched, Err(Error::InvalidArgument(_))));
+        }
+    }
+}
@@ -5,6 +5,39 @@ use crate::error::{Error, Result};
 use crate::logger::Logger;
 use trtx_sys::*;
 
+/// ONNX parser flags (mirrors `nvonnxparser::OnnxParserFlag`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum OnnxParserFlag {
+    /// Parse InstanceNormalization with TensorRT's native layer instead of the plugin
+    NativeInstanceNorm = 0,
+}
+
+/// Result of [`OnnxParser::supports_model`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupportResult {
+    /// Whether the whole model can be parsed and built
+    pub supported: bool,
+    /// The model's partition into subgraphs, each supported or not
+    pub subgraphs: Vec<Subgraph>,
+}
+
+impl SupportResult {
+    /// Subgraphs TensorRT cannot run, e.g. to hand to another backend
+    pub fn unsupported(&self) -> impl Iterator<Item = &Subgraph> {
+        self.subgraphs.iter().filter(|subgraph| !subgraph.supported)
+    }
+}
+
+/// A group of connected ONNX nodes that TensorRT either supports or not
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Subgraph {
+    /// Whether TensorRT supports every node of the subgraph
+    pub supported: bool,
+    /// Indices of the subgraph's nodes in the ONNX graph's node list
+    pub nodes: Vec<usize>,
+}
+
 /// ONNX model parser
 pub struct OnnxParser {
     inner: *mut TrtxOnnxParser,
@@ -33,6 +66,58 @@ impl OnnxParser {
         Ok(OnnxParser { inner: parser_ptr })
     }
 
+    /// Enable a parser flag; must be called before [`parse`](Self::parse)
+    pub fn set_flag(&mut self, flag: OnnxParserFlag) -> Result<()> {
+        self.update_flag(flag, true)
+    }
+
+    /// Disable a parser flag
+    pub fn clear_flag(&mut self, flag: OnnxParserFlag) -> Result<()> {
+        self.update_flag(flag, false)
+    }
+
+    /// Check whether a parser flag is enabled
+    pub fn get_flag(&self, flag: OnnxParserFlag) -> Result<bool> {
+        let mut enabled = false;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_onnx_parser_get_flag(
+                self.inner,
+                flag as i32,
+                &mut enabled,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(enabled)
+    }
+
+    fn update_flag(&mut self, flag: OnnxParserFlag, enabled: bool) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_onnx_parser_set_flag(
+                self.inner,
+                flag as i32,
+                enabled,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
     /// Parse an ONNX model from bytes
     pub fn parse(&self, model_bytes: &[u8]) -> Result<()> {
         let mut error_msg = [0i8; 1024];
@@ -53,6 +138,76 @@ impl OnnxParser {
 
         Ok(())
     }
+
+    /// Check which parts of an ONNX model TensorRT supports, without
+    /// populating the network
+    ///
+    /// Unsupported operators otherwise only show up when [`parse`](Self::parse)
+    /// fails partway through.
+    pub fn supports_model(&self, model_bytes: &[u8]) -> Result<SupportResult> {
+        let mut supported = false;
+        let mut nb_subgraphs: i64 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_onnx_parser_supports_model(
+                self.inner,
+                model_bytes.as_ptr() as *const std::ffi::c_void,
+                model_bytes.len(),
+                &mut supported,
+                &mut nb_subgraphs,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        let subgraphs = (0..nb_subgraphs)
+            .map(|index| self.get_subgraph(index))
+            .collect::<Result<_>>()?;
+
+        Ok(SupportResult {
+            supported,
+            subgraphs,
+        })
+    }
+
+    fn get_subgraph(&self, index: i64) -> Result<Subgraph> {
+        let mut supported = false;
+        let mut nodes_ptr: *const i64 = std::ptr::null();
+        let mut nb_nodes: i64 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_onnx_parser_get_subgraph(
+                self.inner,
+                index,
+                &mut supported,
+                &mut nodes_ptr,
+                &mut nb_nodes,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        let nodes = if nodes_ptr.is_null() || nb_nodes <= 0 {
+            Vec::new()
+        } else {
+            unsafe { std::slice::from_raw_parts(nodes_ptr, nb_nodes as usize) }
+                .iter()
+                .map(|&node| node as usize)
+                .collect()
+        };
+
+        Ok(Subgraph { supported, nodes })
+    }
 }
 
 impl Drop for OnnxParser {
@@ -87,6 +242,32 @@ mod tests {
         assert!(parser.is_ok());
     }
 
+    #[test]
+    fn test_supports_model() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+        let parser = OnnxParser::new(&network, &logger).unwrap();
+
+        let support = parser.supports_model(b"mock onnx model");
+        #[cfg(feature = "mock")]
+        {
+            let support = support.as_ref().unwrap();
+            assert!(support.supported);
+            assert_eq!(
+                support.subgraphs,
+                vec![Subgraph {
+                    supported: true,
+                    nodes: vec![0],
+                }]
+            );
+            assert_eq!(support.unsupported().count(), 0);
+        }
+        drop(support);
+    }
+
     #[test]
     #[ignore] // Requires GPU and TensorRT runtime - run with: cargo test --ignored test_onnx_parser_with_real_model
     fn test_onnx_parser_with_real_model() {
@@ -0,0 +1,300 @@
+//! Device allocation for outputs with data-dependent shapes
+
+use crate::cuda::DeviceBuffer;
+use crate::error::{Error, Result};
+use crate::shapes::Dims;
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr};
+use std::os::raw::c_char;
+use std::sync::{Arc, Mutex};
+use trtx_sys::*;
+
+/// Trait for allocating outputs whose size is only known during inference
+/// (mirrors `nvinfer1::IOutputAllocator`)
+///
+/// Register with [`ExecutionContext::set_output_allocator`]. Outputs of ops
+/// like NonZero or NMS get their size during execution: TensorRT then asks
+/// for memory with [`reallocate_output`](Self::reallocate_output) and reports
+/// the final shape with [`notify_shape`](Self::notify_shape).
+///
+/// [`ExecutionContext::set_output_allocator`]: crate::ExecutionContext::set_output_allocator
+pub trait OutputAllocator: Send + Sync {
+    /// Return device memory of at least `size` bytes aligned to `alignment`
+    /// for `tensor_name`, or null if it cannot be allocated
+    ///
+    /// `current` is the memory previously used for the tensor, which may be
+    /// returned again if it is large enough.
+    fn reallocate_output(
+        &self,
+        tensor_name: &str,
+        current: *mut c_void,
+        size: usize,
+        alignment: usize,
+    ) -> *mut c_void;
+
+    /// Called with the output's shape once execution has resolved it
+    fn notify_shape(&self, tensor_name: &str, dims: &[i64]);
+}
+
+#[derive(Default)]
+struct Allocation {
+    buffer: Option<DeviceBuffer>,
+    shape: Option<Dims>,
+}
+
+/// Output allocator backed by [`DeviceBuffer`]s that grow on demand
+///
+/// Each output keeps its buffer across inferences, and it is only
+/// reallocated when a result no longer fits. One allocator can serve several
+/// outputs.
+#[derive(Default)]
+pub struct DeviceOutputAllocator {
+    allocations: Mutex<HashMap<String, Allocation>>,
+}
+
+impl DeviceOutputAllocator {
+    /// Create an allocator with no buffers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shape of `tensor_name` reported by the last inference
+    pub fn shape(&self, tensor_name: &str) -> Option<Dims> {
+        self.allocations
+            .lock()
+            .unwrap()
+            .get(tensor_name)
+            .and_then(|allocation| allocation.shape.clone())
+    }
+
+    /// Size in bytes of the buffer currently held for `tensor_name`
+    pub fn capacity(&self, tensor_name: &str) -> Option<usize> {
+        self.allocations
+            .lock()
+            .unwrap()
+            .get(tensor_name)
+            .and_then(|allocation| allocation.buffer.as_ref())
+            .map(DeviceBuffer::size)
+    }
+
+    /// Copy the start of `tensor_name`'s buffer to host memory
+    pub fn copy_to_host(&self, tensor_name: &str, data: &mut [u8]) -> Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let allocations = self.allocations.lock().unwrap();
+        match allocations
+            .get(tensor_name)
+            .and_then(|allocation| allocation.buffer.as_ref())
+        {
+            Some(buffer) => buffer.copy_to_host(data),
+            None => Err(Error::InvalidArgument(format!(
+                "Output '{}' has not been allocated",
+                tensor_name
+            ))),
+        }
+    }
+}
+
+impl OutputAllocator for DeviceOutputAllocator {
+    fn reallocate_output(
+        &self,
+        tensor_name: &str,
+        _current: *mut c_void,
+        size: usize,
+        alignment: usize,
+    ) -> *mut c_void {
+        let Ok(mut allocations) = self.allocations.lock() else {
+            return std::ptr::null_mut();
+        };
+        let allocation = allocations.entry(tensor_name.to_string()).or_default();
+
+        // A zero-sized output still needs a valid address
+        let size = size.max(1);
+        let fits = allocation
+            .buffer
+            .as_ref()
+            .is_some_and(|buffer| buffer.size() >= size);
+        if !fits {
+            allocation.buffer = None;
+            match DeviceBuffer::new(size) {
+                Ok(buffer) => allocation.buffer = Some(buffer),
+                Err(_) => return std::ptr::null_mut(),
+            }
+        }
+
+        let ptr = allocation.buffer.as_ref().unwrap().as_ptr();
+        if alignment > 1 && !(ptr as usize).is_multiple_of(alignment) {
+            return std::ptr::null_mut();
+        }
+        ptr
+    }
+
+    fn notify_shape(&self, tensor_name: &str, dims: &[i64]) {
+        if let Ok(mut allocations) = self.allocations.lock() {
+            allocations
+                .entry(tensor_name.to_string())
+                .or_default()
+                .shape = Some(Dims::from(dims));
+        }
+    }
+}
+
+/// Output allocator registered with TensorRT, forwarding to an [`OutputAllocator`]
+pub(crate) struct Allocator {
+    inner: *mut TrtxOutputAllocator,
+    // Boxed so the address passed to TensorRT as user data stays stable
+    _handler: Box<Arc<dyn OutputAllocator>>,
+}
+
+impl Allocator {
+    pub(crate) fn new(handler: Arc<dyn OutputAllocator>) -> Result<Self> {
+        let handler = Box::new(handler);
+        let user_data = &*handler as *const Arc<dyn OutputAllocator> as *mut c_void;
+
+        let mut allocator_ptr: *mut TrtxOutputAllocator = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_output_allocator_create(
+                Some(Self::reallocate_callback),
+                Some(Self::notify_shape_callback),
+                user_data,
+                &mut allocator_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(Allocator {
+            inner: allocator_ptr,
+            _handler: handler,
+        })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut TrtxOutputAllocator {
+        self.inner
+    }
+
+    /// C callbacks that bridge to the Rust trait
+    extern "C" fn reallocate_callback(
+        user_data: *mut c_void,
+        tensor_name: *const c_char,
+        current_memory: *mut c_void,
+        size: u64,
+        alignment: u64,
+    ) -> *mut c_void {
+        if user_data.is_null() || tensor_name.is_null() {
+            return std::ptr::null_mut();
+        }
+
+        unsafe {
+            let handler = &*(user_data as *const Arc<dyn OutputAllocator>);
+            match CStr::from_ptr(tensor_name).to_str() {
+                Ok(name) => handler.reallocate_output(
+                    name,
+                    current_memory,
+                    size as usize,
+                    alignment as usize,
+                ),
+                Err(_) => std::ptr::null_mut(),
+            }
+        }
+    }
+
+    extern "C" fn notify_shape_callback(
+        user_data: *mut c_void,
+        tensor_name: *const c_char,
+        dims: *const i64,
+        nb_dims: i32,
+    ) {
+        if user_data.is_null() || tensor_name.is_null() || dims.is_null() || nb_dims < 0 {
+            return;
+        }
+
+        unsafe {
+            let handler = &*(user_data as *const Arc<dyn OutputAllocator>);
+            if let Ok(name) = CStr::from_ptr(tensor_name).to_str() {
+                handler.notify_shape(name, std::slice::from_raw_parts(dims, nb_dims as usize));
+            }
+        }
+    }
+}
+
+impl Drop for Allocator {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe {
+                trtx_output_allocator_destroy(self.inner);
+            }
+        }
+    }
+}
+
+unsafe impl Send for Allocator {}
+unsafe impl Sync for Allocator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Logger, Runtime};
+
+    #[test]
+    fn test_device_output_allocator_grows() {
+        let allocator = DeviceOutputAllocator::new();
+        let small = allocator.reallocate_output("boxes", std::ptr::null_mut(), 16, 256);
+        assert!(!small.is_null());
+        // Fits in the existing buffer
+        assert_eq!(allocator.reallocate_output("boxes", small, 8, 256), small);
+        allocator.reallocate_output("boxes", small, 64, 256);
+        assert_eq!(allocator.capacity("boxes"), Some(64));
+
+        allocator.notify_shape("boxes", &[4, 4]);
+        assert_eq!(allocator.shape("boxes"), Some(Dims::from([4, 4])));
+        assert_eq!(allocator.shape("scores"), None);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_context_output_allocator() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        // Mock NonZero: keeps rows whose first element is non-zero
+        let engine = runtime
+            .deserialize_cuda_engine(b"TRTXMOCK\x01\x01\x01")
+            .unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+
+        let allocator = Arc::new(DeviceOutputAllocator::new());
+        context
+            .set_output_allocator("output", allocator.clone())
+            .unwrap();
+
+        for (rows, kept) in [(vec![1.0f32, 0.0], 1), (vec![1.0, 2.0, 3.0], 3)] {
+            let values: Vec<f32> = rows.iter().flat_map(|&first| [first; 4]).collect();
+            let mut input = DeviceBuffer::new(values.len() * 4).unwrap();
+            input
+                .copy_from_host(unsafe {
+                    std::slice::from_raw_parts(values.as_ptr() as *const u8, values.len() * 4)
+                })
+                .unwrap();
+            context
+                .set_input_shape("input", &[rows.len() as i64, 4])
+                .unwrap();
+            unsafe {
+                context.set_tensor_address("input", input.as_ptr()).unwrap();
+                context
+                    .enqueue_v3(crate::cuda::get_default_stream())
+                    .unwrap();
+            }
+            crate::synchronize().unwrap();
+
+            assert_eq!(allocator.shape("output"), Some(Dims::from([kept, 4])));
+            assert_eq!(allocator.capacity("output"), Some(kept as usize * 16));
+        }
+    }
+}
@@ -0,0 +1,501 @@
+//! Plugins for custom layers: loading plugin libraries, or registering
+//! Rust-native elementwise plugins
+
+use crate::error::{Error, Result};
+use crate::logger::{Logger, Severity};
+use crate::tensor::DataType;
+use std::ffi::{c_void, CStr, CString};
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use trtx_sys::*;
+
+/// TensorRT's plugin registry, as seen by a [`Runtime`]
+///
+/// Obtained with [`Runtime::get_plugin_registry`]. The registry is shared by
+/// the whole process, so plugins loaded here are also found by
+/// [`OnnxParser::parse`] and the builder: load them before parsing a model
+/// that uses custom ops.
+///
+/// A plugin library must be compiled against the same TensorRT-RTX major
+/// version this crate links (see [`crate::version`]); loading one built for
+/// another version fails or crashes when its plugins are created.
+///
+/// [`Runtime`]: crate::Runtime
+/// [`Runtime::get_plugin_registry`]: crate::Runtime::get_plugin_registry
+/// [`OnnxParser::parse`]: crate::OnnxParser::parse
+pub struct PluginRegistry<'r> {
+    runtime: *mut TrtxRuntime,
+    _runtime: PhantomData<&'r ()>,
+}
+
+impl PluginRegistry<'_> {
+    pub(crate) fn new(runtime: *mut TrtxRuntime) -> Self {
+        PluginRegistry {
+            runtime,
+            _runtime: PhantomData,
+        }
+    }
+
+    /// Load a shared library of plugins
+    ///
+    /// Same as [`load_plugin_library`], since the registry is process-wide.
+    /// The library stays loaded for the rest of the process.
+    pub fn load_library(&self, path: &Path) -> Result<()> {
+        load_plugin_library(path)
+    }
+
+    /// Register a Rust-native plugin creator under `name` and `version`
+    ///
+    /// Layers of that plugin type, whether added by the ONNX parser for a
+    /// custom op or found while deserializing an engine, are created through
+    /// `creator`. Creators stay registered for the rest of the process;
+    /// registering the same name and version twice fails.
+    ///
+    /// Only elementwise plugins are supported: one FP32 or FP16 input, one
+    /// output of the same shape and type. See [`PluginInstance`] for the
+    /// cost of running them.
+    pub fn register_creator(
+        &self,
+        name: &str,
+        version: &str,
+        creator: Arc<dyn PluginCreator>,
+    ) -> Result<()> {
+        let creator = Creator::new(name, version, creator)?;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_runtime_register_plugin_creator(
+                self.runtime,
+                creator.inner,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        // The registry now refers to the creator, so it must never be freed
+        REGISTERED_CREATORS.lock().unwrap().push(creator);
+
+        Ok(())
+    }
+}
+
+/// A field a plugin layer was created with, e.g. an ONNX node attribute
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginField {
+    /// Field name
+    pub name: String,
+    /// Raw field data, in the field's native layout
+    pub data: Vec<u8>,
+}
+
+/// Trait for creating Rust-native plugin instances
+///
+/// Register implementations with [`PluginRegistry::register_creator`].
+pub trait PluginCreator: Send + Sync {
+    /// Create an instance for the layer `name` from its fields
+    ///
+    /// Also called when an engine using the plugin is deserialized, with the
+    /// fields the layer was originally created with.
+    fn create(&self, name: &str, fields: &[PluginField]) -> Result<Arc<dyn PluginInstance>>;
+}
+
+/// A plugin layer's implementation
+///
+/// Instances are shared between the copies TensorRT makes of a plugin for
+/// each engine and execution context, so `enqueue` may run concurrently.
+///
+/// # Performance
+///
+/// Every inference crosses the FFI boundary into `enqueue` on the thread
+/// calling `enqueue_v3`, and the layer cannot be fused with its neighbours.
+/// `enqueue` should only queue work on `stream`; synchronizing there stalls
+/// the whole inference. Keep Rust plugins for cheap or rare operations.
+pub trait PluginInstance: Send + Sync {
+    /// Queue the operation on `stream`, reading `input` and writing `output`
+    ///
+    /// Both are device memory holding a tensor of `shape` and `dtype`.
+    fn enqueue(
+        &self,
+        input: *const c_void,
+        output: *mut c_void,
+        dtype: DataType,
+        shape: &[i64],
+        stream: *mut c_void,
+    ) -> Result<()>;
+}
+
+// Creators handed to TensorRT's registry, which has no way to drop them
+static REGISTERED_CREATORS: Mutex<Vec<Creator>> = Mutex::new(Vec::new());
+
+/// Plugin creator registered with TensorRT, forwarding to a [`PluginCreator`]
+struct Creator {
+    inner: *mut TrtxPluginCreator,
+    // Boxed so the address passed to TensorRT as user data stays stable
+    _handler: Box<Arc<dyn PluginCreator>>,
+}
+
+impl Creator {
+    fn new(name: &str, version: &str, handler: Arc<dyn PluginCreator>) -> Result<Self> {
+        let name_cstr = CString::new(name)?;
+        let version_cstr = CString::new(version)?;
+        let handler = Box::new(handler);
+        let user_data = &*handler as *const Arc<dyn PluginCreator> as *mut c_void;
+
+        let mut creator_ptr: *mut TrtxPluginCreator = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_plugin_creator_create(
+                name_cstr.as_ptr(),
+                version_cstr.as_ptr(),
+                Some(Self::create_callback),
+                Some(Self::clone_callback),
+                Some(Self::enqueue_callback),
+                Some(Self::destroy_callback),
+                user_data,
+                &mut creator_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(Creator {
+            inner: creator_ptr,
+            _handler: handler,
+        })
+    }
+
+    /// C callback creating an instance, boxed so TensorRT can hold it
+    extern "C" fn create_callback(
+        user_data: *mut c_void,
+        name: *const c_char,
+        fields: *const TrtxPluginField,
+        nb_fields: i32,
+    ) -> *mut c_void {
+        if user_data.is_null() || name.is_null() || (fields.is_null() && nb_fields > 0) {
+            return std::ptr::null_mut();
+        }
+
+        unsafe {
+            let handler = &*(user_data as *const Arc<dyn PluginCreator>);
+            let Ok(name) = CStr::from_ptr(name).to_str() else {
+                return std::ptr::null_mut();
+            };
+            let raw_fields = if nb_fields > 0 {
+                std::slice::from_raw_parts(fields, nb_fields as usize)
+            } else {
+                &[]
+            };
+            let fields: Vec<PluginField> = raw_fields
+                .iter()
+                .map(|field| PluginField {
+                    name: CStr::from_ptr(field.name).to_string_lossy().into_owned(),
+                    data: if field.data.is_null() {
+                        Vec::new()
+                    } else {
+                        std::slice::from_raw_parts(field.data as *const u8, field.size).to_vec()
+                    },
+                })
+                .collect();
+
+            match handler.create(name, &fields) {
+                Ok(instance) => Box::into_raw(Box::new(instance)) as *mut c_void,
+                Err(_) => std::ptr::null_mut(),
+            }
+        }
+    }
+
+    /// C callback sharing an instance with a copy of the plugin
+    extern "C" fn clone_callback(instance: *mut c_void) -> *mut c_void {
+        if instance.is_null() {
+            return std::ptr::null_mut();
+        }
+
+        let instance = unsafe { &*(instance as *const Arc<dyn PluginInstance>) };
+        Box::into_raw(Box::new(instance.clone())) as *mut c_void
+    }
+
+    /// C callback function that bridges to Rust trait
+    extern "C" fn enqueue_callback(
+        instance: *mut c_void,
+        input: *const c_void,
+        output: *mut c_void,
+        data_type: i32,
+        dims: *const i64,
+        nb_dims: i32,
+        stream: *mut c_void,
+    ) -> bool {
+        if instance.is_null() || (dims.is_null() && nb_dims > 0) {
+            return false;
+        }
+
+        unsafe {
+            let instance = &*(instance as *const Arc<dyn PluginInstance>);
+            let shape = if nb_dims > 0 {
+                std::slice::from_raw_parts(dims, nb_dims as usize)
+            } else {
+                &[]
+            };
+            match DataType::from_raw(data_type) {
+                Ok(dtype) => instance
+                    .enqueue(input, output, dtype, shape, stream)
+                    .is_ok(),
+                Err(_) => false,
+            }
+        }
+    }
+
+    /// C callback releasing an instance when TensorRT drops a plugin
+    extern "C" fn destroy_callback(instance: *mut c_void) {
+        if !instance.is_null() {
+            drop(unsafe { Box::from_raw(instance as *mut Arc<dyn PluginInstance>) });
+        }
+    }
+}
+
+impl Drop for Creator {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe {
+                trtx_plugin_creator_destroy(self.inner);
+            }
+        }
+    }
+}
+
+unsafe impl Send for Creator {}
+unsafe impl Sync for Creator {}
+
+fn plugin_path(path: &Path) -> Result<CString> {
+    let path_str = path.to_str().ok_or_else(|| {
+        Error::InvalidArgument(format!("Plugin path {:?} is not valid UTF-8", path))
+    })?;
+    Ok(CString::new(path_str)?)
+}
+
+/// Load a shared library of plugins into TensorRT's plugin registry
+///
+/// Plugins must be loaded before building or deserializing engines that use
+/// them. The library stays loaded for the rest of the process.
+pub fn load_plugin_library(path: &Path) -> Result<()> {
+    let path_cstr = plugin_path(path)?;
+    let mut error_msg = [0i8; 1024];
+
+    let result = unsafe {
+        trtx_load_plugin_library(path_cstr.as_ptr(), error_msg.as_mut_ptr(), error_msg.len())
+    };
+
+    if result != TRTX_SUCCESS as i32 {
+        return Err(Error::from_ffi(result, &error_msg));
+    }
+
+    Ok(())
+}
+
+/// Load every shared library in `dir` with [`load_plugin_library`]
+///
+/// Files with the platform's library extension (`.so`, `.dll` or `.dylib`)
+/// are loaded in name order; other files are ignored. Returns the libraries
+/// that loaded. A library that fails to load does not stop the others; the
+/// failure is reported as an error through `logger` instead, which is why
+/// this takes a logger besides the directory. Only reading the directory
+/// itself fails the call.
+pub fn load_plugins_from_dir(dir: &Path, logger: &Logger) -> Result<Vec<PathBuf>> {
+    let mut libraries: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<_>>()?;
+    libraries.retain(|path| {
+        path.is_file()
+            && path.extension().and_then(|ext| ext.to_str())
+                == Some(std::env::consts::DLL_EXTENSION)
+    });
+    libraries.sort();
+
+    libraries.retain(|path| match load_plugin_library(path) {
+        Ok(()) => true,
+        Err(e) => {
+            logger.log(
+                Severity::Error,
+                &format!("Skipping plugin library {}: {}", path.display(), e),
+            );
+            false
+        }
+    });
+    Ok(libraries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Runtime;
+
+    #[test]
+    fn test_plugin_registry_load_library() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let registry = runtime.get_plugin_registry();
+
+        let path = std::env::temp_dir().join(format!(
+            "trtx_registry_{}.{}",
+            std::process::id(),
+            std::env::consts::DLL_EXTENSION
+        ));
+        std::fs::write(&path, b"\x7fELF").unwrap();
+        let loaded = registry.load_library(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        // Only a non-empty file loads in mock mode; the stub is not a real library
+        #[cfg(feature = "mock")]
+        loaded.unwrap();
+        #[cfg(not(feature = "mock"))]
+        assert!(loaded.is_err());
+        assert!(registry.load_library(&path).is_err());
+    }
+
+    struct IdentityCreator;
+
+    struct IdentityPlugin;
+
+    impl PluginCreator for IdentityCreator {
+        fn create(&self, name: &str, fields: &[PluginField]) -> Result<Arc<dyn PluginInstance>> {
+            if name != "identity_0" || fields.len() != 1 || fields[0].name != "alpha" {
+                return Err(Error::InvalidArgument(name.to_string()));
+            }
+            Ok(Arc::new(IdentityPlugin))
+        }
+    }
+
+    impl PluginInstance for IdentityPlugin {
+        fn enqueue(
+            &self,
+            input: *const c_void,
+            output: *mut c_void,
+            dtype: DataType,
+            shape: &[i64],
+            _stream: *mut c_void,
+        ) -> Result<()> {
+            let size = shape.iter().product::<i64>() as usize * dtype.size();
+            let mut error_msg = [0i8; 1024];
+
+            let result = unsafe {
+                trtx_cuda_memcpy_device_to_device(
+                    output,
+                    input,
+                    size,
+                    error_msg.as_mut_ptr(),
+                    error_msg.len(),
+                )
+            };
+
+            if result != TRTX_SUCCESS as i32 {
+                return Err(Error::from_ffi(result, &error_msg));
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_register_identity_plugin() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let registry = runtime.get_plugin_registry();
+
+        let registered = registry.register_creator("TrtxIdentity", "1", Arc::new(IdentityCreator));
+        let again = registry.register_creator("TrtxIdentity", "1", Arc::new(IdentityCreator));
+        #[cfg(feature = "mock")]
+        {
+            assert!(registered.is_ok());
+            assert!(again.is_err());
+        }
+        drop((registered, again));
+
+        // Drive the callbacks the way TensorRT does
+        let creator = Creator::new("TrtxIdentity", "2", Arc::new(IdentityCreator)).unwrap();
+        let user_data = &*creator._handler as *const Arc<dyn PluginCreator> as *mut c_void;
+        let field_name = CString::new("alpha").unwrap();
+        let alpha = 1.0f32.to_ne_bytes();
+        let fields = [TrtxPluginField {
+            name: field_name.as_ptr(),
+            data: alpha.as_ptr() as *const c_void,
+            size: alpha.len(),
+        }];
+        let layer_name = CString::new("identity_0").unwrap();
+        let other_name = CString::new("other").unwrap();
+        assert!(
+            Creator::create_callback(user_data, other_name.as_ptr(), fields.as_ptr(), 1).is_null()
+        );
+        let instance = Creator::create_callback(user_data, layer_name.as_ptr(), fields.as_ptr(), 1);
+        assert!(!instance.is_null());
+        let copy = Creator::clone_callback(instance);
+        Creator::destroy_callback(instance);
+
+        let values = [1.0f32, -2.0, 3.5, 0.0];
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_ne_bytes()).collect();
+        let mut input = crate::DeviceBuffer::new(bytes.len()).unwrap();
+        input.copy_from_host(&bytes).unwrap();
+        let output = crate::DeviceBuffer::new(bytes.len()).unwrap();
+        let dims = [2i64, 2];
+        assert!(Creator::enqueue_callback(
+            copy,
+            input.as_ptr(),
+            output.as_ptr(),
+            DataType::Float as i32,
+            dims.as_ptr(),
+            2,
+            std::ptr::null_mut(),
+        ));
+        Creator::destroy_callback(copy);
+
+        let mut result = vec![0u8; bytes.len()];
+        output.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_load_plugins_from_dir() {
+        let dir = std::env::temp_dir().join(format!("trtx_plugins_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let library =
+            |name: &str| dir.join(format!("{}.{}", name, std::env::consts::DLL_EXTENSION));
+
+        // Stub libraries: only a non-empty one loads in mock mode
+        std::fs::write(library("libgood"), b"\x7fELF").unwrap();
+        std::fs::write(library("libbroken"), b"").unwrap();
+        std::fs::write(dir.join("README.txt"), b"not a library").unwrap();
+
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let sink = messages.clone();
+        let logger = Logger::from_fn(move |severity, message| {
+            sink.lock().unwrap().push((severity, message.to_string()))
+        })
+        .unwrap();
+        let loaded = load_plugins_from_dir(&dir, &logger);
+        let missing = load_plugins_from_dir(&dir.join("missing"), &logger);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(missing.is_err());
+        let loaded = loaded.unwrap();
+        #[cfg(feature = "mock")]
+        assert_eq!(loaded, [library("libgood")]);
+        // Stubs are not real libraries
+        #[cfg(not(feature = "mock"))]
+        assert!(loaded.is_empty());
+
+        let broken = library("libbroken").display().to_string();
+        let messages = messages.lock().unwrap();
+        assert!(messages
+            .iter()
+            .any(|(severity, message)| *severity == Severity::Error && message.contains(&broken)));
+    }
+}
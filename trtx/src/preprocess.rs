@@ -0,0 +1,219 @@
+//! Image preprocessing for vision model inputs (requires the `image` feature)
+
+use crate::error::{Error, Result};
+use crate::executor::{TensorData, TensorInput};
+use crate::shapes::Dims;
+use image::imageops::FilterType;
+use std::path::Path;
+
+/// Order of dimensions in an image tensor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageLayout {
+    /// `[1, 3, height, width]`, as most PyTorch exports expect
+    Nchw,
+    /// `[1, height, width, 3]`, as most TensorFlow exports expect
+    Nhwc,
+}
+
+/// How to turn an image file into a normalized float32 tensor
+///
+/// Pixels are scaled to `[0, 1]`, then each channel has `mean` subtracted and
+/// is divided by `std`. The default matches ImageNet classifiers: 224x224,
+/// NCHW, with the usual ImageNet mean and standard deviation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImagePreprocess {
+    /// Target `(width, height)`; images are resized to exactly this size
+    pub size: (u32, u32),
+    /// Per-channel (RGB) mean subtracted after scaling to `[0, 1]`
+    pub mean: [f32; 3],
+    /// Per-channel (RGB) standard deviation divided by after the mean
+    pub std: [f32; 3],
+    /// Dimension order of the tensor
+    pub layout: ImageLayout,
+}
+
+impl Default for ImagePreprocess {
+    fn default() -> Self {
+        ImagePreprocess {
+            size: (224, 224),
+            mean: [0.485, 0.456, 0.406],
+            std: [0.229, 0.224, 0.225],
+            layout: ImageLayout::Nchw,
+        }
+    }
+}
+
+impl ImagePreprocess {
+    /// Shape of the tensors this preprocessing produces
+    pub fn shape(&self) -> Dims {
+        let (width, height) = (i64::from(self.size.0), i64::from(self.size.1));
+        match self.layout {
+            ImageLayout::Nchw => Dims::from([1, 3, height, width]),
+            ImageLayout::Nhwc => Dims::from([1, height, width, 3]),
+        }
+    }
+
+    /// Check the produced shape against a model input shape
+    ///
+    /// Pass the engine's shape for the input, e.g. from
+    /// [`CudaEngine::get_tensor_shape`](crate::CudaEngine::get_tensor_shape);
+    /// dynamic (`-1`) dimensions match any size. Fails with
+    /// [`Error::InvalidArgument`] if the size or layout does not fit.
+    pub fn check_input_shape(&self, input_shape: &[i64]) -> Result<()> {
+        let shape = self.shape();
+        let fits = input_shape.len() == shape.len()
+            && input_shape
+                .iter()
+                .zip(&shape)
+                .all(|(&expected, &actual)| expected < 0 || expected == actual);
+        if !fits {
+            return Err(Error::InvalidArgument(format!(
+                "Preprocessed image shape {} does not match model input shape {:?}",
+                shape, input_shape
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl TensorInput {
+    /// Input holding the image at `path`, decoded, resized and normalized
+    ///
+    /// Any format the `image` crate can decode is accepted; images are
+    /// converted to RGB and resized with a bilinear filter, ignoring aspect
+    /// ratio. Use [`ImagePreprocess::check_input_shape`] to check the result
+    /// fits the model before running it.
+    pub fn from_image(
+        name: impl Into<String>,
+        path: &Path,
+        preprocess: &ImagePreprocess,
+    ) -> Result<Self> {
+        let (width, height) = preprocess.size;
+        if width == 0 || height == 0 || preprocess.std.contains(&0.0) {
+            return Err(Error::InvalidArgument(format!(
+                "Invalid image preprocessing {:?}",
+                preprocess
+            )));
+        }
+
+        let image = image::open(path).map_err(|e| match e {
+            image::ImageError::IoError(e) => Error::Io(e),
+            e => Error::InvalidArgument(format!("Cannot decode {}: {}", path.display(), e)),
+        })?;
+        let mut rgb = image.to_rgb8();
+        if rgb.dimensions() != preprocess.size {
+            rgb = image::imageops::resize(&rgb, width, height, FilterType::Triangle);
+        }
+
+        let pixels = (width * height) as usize;
+        let mut data = vec![0f32; pixels * 3];
+        for (i, pixel) in rgb.pixels().enumerate() {
+            for c in 0..3 {
+                let value = (pixel[c] as f32 / 255.0 - preprocess.mean[c]) / preprocess.std[c];
+                let index = match preprocess.layout {
+                    ImageLayout::Nchw => c * pixels + i,
+                    ImageLayout::Nhwc => i * 3 + c,
+                };
+                data[index] = value;
+            }
+        }
+
+        Ok(TensorInput {
+            name: name.into(),
+            shape: preprocess.shape(),
+            data: TensorData::F32(data),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_image() -> &'static Path {
+        Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/rgb-2x2.png"
+        ))
+    }
+
+    #[test]
+    fn test_from_image_layouts() {
+        // Red, green / blue, white
+        let unnormalized = ImagePreprocess {
+            size: (2, 2),
+            mean: [0.0; 3],
+            std: [1.0; 3],
+            layout: ImageLayout::Nchw,
+        };
+        let nchw = TensorInput::from_image("image", test_image(), &unnormalized).unwrap();
+        assert_eq!(nchw.shape, vec![1, 3, 2, 2]);
+        assert_eq!(
+            nchw.data,
+            TensorData::F32(vec![
+                1.0, 0.0, 0.0, 1.0, // R
+                0.0, 1.0, 0.0, 1.0, // G
+                0.0, 0.0, 1.0, 1.0, // B
+            ])
+        );
+
+        let nhwc = ImagePreprocess {
+            layout: ImageLayout::Nhwc,
+            ..unnormalized
+        };
+        let nhwc = TensorInput::from_image("image", test_image(), &nhwc).unwrap();
+        assert_eq!(nhwc.shape, vec![1, 2, 2, 3]);
+        assert_eq!(
+            nhwc.data,
+            TensorData::F32(vec![
+                1.0, 0.0, 0.0, 0.0, 1.0, 0.0, // top row
+                0.0, 0.0, 1.0, 1.0, 1.0, 1.0, // bottom row
+            ])
+        );
+    }
+
+    #[test]
+    fn test_from_image_normalizes_and_resizes() {
+        let preprocess = ImagePreprocess {
+            size: (8, 4),
+            mean: [0.5; 3],
+            std: [0.5; 3],
+            layout: ImageLayout::Nchw,
+        };
+        let input = TensorInput::from_image("image", test_image(), &preprocess).unwrap();
+        assert_eq!(input.shape, vec![1, 3, 4, 8]);
+        let TensorData::F32(values) = &input.data else {
+            panic!("expected f32 data");
+        };
+        assert_eq!(values.len(), 3 * 4 * 8);
+        assert!(values.iter().all(|v| (-1.0..=1.0).contains(v)));
+        // Top-left corner stays red
+        assert_eq!(values[0], 1.0);
+        assert_eq!(values[32], -1.0);
+    }
+
+    #[test]
+    fn test_check_input_shape() {
+        let preprocess = ImagePreprocess::default();
+        assert!(preprocess.check_input_shape(&[1, 3, 224, 224]).is_ok());
+        assert!(preprocess.check_input_shape(&[-1, 3, 224, 224]).is_ok());
+        assert!(matches!(
+            preprocess.check_input_shape(&[1, 3, 256, 256]),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(preprocess.check_input_shape(&[1, 224, 224, 3]).is_err());
+
+        assert!(matches!(
+            TensorInput::from_image("image", Path::new("/nonexistent.png"), &preprocess),
+            Err(Error::Io(_))
+        ));
+        let zero_std = ImagePreprocess {
+            std: [0.0; 3],
+            ..preprocess
+        };
+        assert!(matches!(
+            TensorInput::from_image("image", test_image(), &zero_std),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
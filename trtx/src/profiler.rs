@@ -0,0 +1,171 @@
+//! Per-layer execution time profiling
+
+use crate::error::{Error, Result};
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr};
+use std::os::raw::c_char;
+use std::sync::{Arc, Mutex};
+use trtx_sys::*;
+
+/// Trait for receiving per-layer timings from TensorRT
+///
+/// Attach with [`ExecutionContext::set_profiler`]. Timings are reported once
+/// per layer after each inference completes.
+///
+/// [`ExecutionContext::set_profiler`]: crate::ExecutionContext::set_profiler
+pub trait LayerProfiler: Send + Sync {
+    /// Called with the execution time of one layer, in milliseconds
+    fn report_layer_time(&self, layer_name: &str, ms: f32);
+}
+
+/// Profiler that sums the time spent in each layer across inferences
+#[derive(Debug, Default)]
+pub struct AggregatingProfiler {
+    totals: Mutex<HashMap<String, f32>>,
+}
+
+impl AggregatingProfiler {
+    /// Create an empty profiler
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Total milliseconds recorded per layer
+    pub fn totals(&self) -> HashMap<String, f32> {
+        self.totals.lock().unwrap().clone()
+    }
+
+    /// Discard all recorded timings
+    pub fn reset(&self) {
+        self.totals.lock().unwrap().clear();
+    }
+}
+
+impl LayerProfiler for AggregatingProfiler {
+    fn report_layer_time(&self, layer_name: &str, ms: f32) {
+        *self
+            .totals
+            .lock()
+            .unwrap()
+            .entry(layer_name.to_string())
+            .or_insert(0.0) += ms;
+    }
+}
+
+/// Profiler registered with TensorRT, forwarding to a [`LayerProfiler`]
+pub(crate) struct Profiler {
+    inner: *mut TrtxProfiler,
+    // Boxed so the address passed to TensorRT as user data stays stable
+    _handler: Box<Arc<dyn LayerProfiler>>,
+}
+
+impl Profiler {
+    pub(crate) fn new(handler: Arc<dyn LayerProfiler>) -> Result<Self> {
+        let handler = Box::new(handler);
+        let user_data = &*handler as *const Arc<dyn LayerProfiler> as *mut c_void;
+
+        let mut profiler_ptr: *mut TrtxProfiler = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_profiler_create(
+                Some(Self::report_callback),
+                user_data,
+                &mut profiler_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(Profiler {
+            inner: profiler_ptr,
+            _handler: handler,
+        })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut TrtxProfiler {
+        self.inner
+    }
+
+    /// C callback function that bridges to Rust trait
+    extern "C" fn report_callback(user_data: *mut c_void, layer_name: *const c_char, ms: f32) {
+        if user_data.is_null() || layer_name.is_null() {
+            return;
+        }
+
+        unsafe {
+            let handler = &*(user_data as *const Arc<dyn LayerProfiler>);
+            if let Ok(name) = CStr::from_ptr(layer_name).to_str() {
+                handler.report_layer_time(name, ms);
+            }
+        }
+    }
+}
+
+impl Drop for Profiler {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe {
+                trtx_profiler_destroy(self.inner);
+            }
+        }
+    }
+}
+
+unsafe impl Send for Profiler {}
+unsafe impl Sync for Profiler {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DeviceBuffer, Logger, Runtime};
+
+    #[test]
+    fn test_aggregating_profiler() {
+        let profiler = AggregatingProfiler::new();
+        profiler.report_layer_time("conv1", 1.5);
+        profiler.report_layer_time("conv1", 0.5);
+        profiler.report_layer_time("fc", 0.25);
+
+        let totals = profiler.totals();
+        assert_eq!(totals["conv1"], 2.0);
+        assert_eq!(totals["fc"], 0.25);
+
+        profiler.reset();
+        assert!(profiler.totals().is_empty());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_context_profiler() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+
+        let profiler = Arc::new(AggregatingProfiler::new());
+        context.set_profiler(profiler.clone()).unwrap();
+
+        let input = DeviceBuffer::new(16).unwrap();
+        let output = DeviceBuffer::new(16).unwrap();
+        context.set_input_shape("input", &[1, 4]).unwrap();
+        for _ in 0..2 {
+            unsafe {
+                context.set_tensor_address("input", input.as_ptr()).unwrap();
+                context
+                    .set_tensor_address("output", output.as_ptr())
+                    .unwrap();
+                context
+                    .enqueue_v3(crate::cuda::get_default_stream())
+                    .unwrap();
+            }
+            crate::synchronize().unwrap();
+        }
+
+        assert_eq!(profiler.totals()["mock_layer"], 1.0);
+    }
+}
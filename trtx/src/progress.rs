@@ -0,0 +1,251 @@
+//! Build progress reporting
+
+use crate::error::{Error, Result};
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr};
+use std::os::raw::c_char;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use trtx_sys::*;
+
+/// Trait for following the phases of an engine build
+///
+/// Attach with [`BuilderConfig::set_progress_monitor`]. Phases nest: each
+/// phase other than the top-level ones names its parent.
+///
+/// [`BuilderConfig::set_progress_monitor`]: crate::BuilderConfig::set_progress_monitor
+pub trait ProgressMonitor: Send + Sync {
+    /// Called when a phase starts; `nb_steps` is how many steps it will report
+    fn phase_start(&self, phase: &str, parent: Option<&str>, nb_steps: i32) {
+        let _ = (phase, parent, nb_steps);
+    }
+
+    /// Called when a phase completes a step; return `false` to cancel the build
+    fn step_complete(&self, phase: &str, step: i32) -> bool {
+        let _ = (phase, step);
+        true
+    }
+
+    /// Called when a phase finishes
+    fn phase_finish(&self, phase: &str) {
+        let _ = phase;
+    }
+}
+
+/// Progress monitor that measures how long each build phase takes
+#[derive(Debug, Default)]
+pub struct PhaseTimer {
+    started: Mutex<HashMap<String, Instant>>,
+    durations: Mutex<HashMap<String, Duration>>,
+}
+
+impl PhaseTimer {
+    /// Create a timer with no recorded phases
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Time spent in each finished phase; repeated phases are summed
+    pub fn timings(&self) -> HashMap<String, Duration> {
+        self.durations.lock().unwrap().clone()
+    }
+}
+
+impl ProgressMonitor for PhaseTimer {
+    fn phase_start(&self, phase: &str, _parent: Option<&str>, _nb_steps: i32) {
+        self.started
+            .lock()
+            .unwrap()
+            .insert(phase.to_string(), Instant::now());
+    }
+
+    fn phase_finish(&self, phase: &str) {
+        if let Some(start) = self.started.lock().unwrap().remove(phase) {
+            *self
+                .durations
+                .lock()
+                .unwrap()
+                .entry(phase.to_string())
+                .or_default() += start.elapsed();
+        }
+    }
+}
+
+/// Progress monitor aborting the build once a flag is set
+///
+/// Forwards to the monitor it wraps, if any, so that an existing monitor
+/// keeps working while the build can be cancelled.
+pub(crate) struct Cancellation {
+    cancel: Arc<AtomicBool>,
+    inner: Option<Arc<dyn ProgressMonitor>>,
+}
+
+impl Cancellation {
+    pub(crate) fn new(cancel: Arc<AtomicBool>, inner: Option<Arc<dyn ProgressMonitor>>) -> Self {
+        Cancellation { cancel, inner }
+    }
+}
+
+impl ProgressMonitor for Cancellation {
+    fn phase_start(&self, phase: &str, parent: Option<&str>, nb_steps: i32) {
+        if let Some(inner) = &self.inner {
+            inner.phase_start(phase, parent, nb_steps);
+        }
+    }
+
+    fn step_complete(&self, phase: &str, step: i32) -> bool {
+        let keep_going = self
+            .inner
+            .as_ref()
+            .is_none_or(|inner| inner.step_complete(phase, step));
+        keep_going && !self.cancel.load(Ordering::Relaxed)
+    }
+
+    fn phase_finish(&self, phase: &str) {
+        if let Some(inner) = &self.inner {
+            inner.phase_finish(phase);
+        }
+    }
+}
+
+/// Progress monitor registered with TensorRT, forwarding to a [`ProgressMonitor`]
+pub(crate) struct Monitor {
+    inner: *mut TrtxProgressMonitor,
+    // Boxed so the address passed to TensorRT as user data stays stable
+    handler: Box<Arc<dyn ProgressMonitor>>,
+}
+
+impl Monitor {
+    pub(crate) fn new(handler: Arc<dyn ProgressMonitor>) -> Result<Self> {
+        let handler = Box::new(handler);
+        let user_data = &*handler as *const Arc<dyn ProgressMonitor> as *mut c_void;
+
+        let mut monitor_ptr: *mut TrtxProgressMonitor = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_progress_monitor_create(
+                Some(Self::phase_start_callback),
+                Some(Self::step_complete_callback),
+                Some(Self::phase_finish_callback),
+                user_data,
+                &mut monitor_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(Monitor {
+            inner: monitor_ptr,
+            handler,
+        })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut TrtxProgressMonitor {
+        self.inner
+    }
+
+    /// The monitor this forwards to
+    pub(crate) fn handler(&self) -> Arc<dyn ProgressMonitor> {
+        Arc::clone(&self.handler)
+    }
+
+    unsafe fn handler_from<'h>(user_data: *mut c_void) -> &'h Arc<dyn ProgressMonitor> {
+        &*(user_data as *const Arc<dyn ProgressMonitor>)
+    }
+
+    unsafe fn str_from<'s>(ptr: *const c_char) -> Option<&'s str> {
+        if ptr.is_null() {
+            return None;
+        }
+        CStr::from_ptr(ptr).to_str().ok()
+    }
+
+    extern "C" fn phase_start_callback(
+        user_data: *mut c_void,
+        phase_name: *const c_char,
+        parent_phase: *const c_char,
+        nb_steps: i32,
+    ) {
+        unsafe {
+            if let Some(phase) = Self::str_from(phase_name) {
+                Self::handler_from(user_data).phase_start(
+                    phase,
+                    Self::str_from(parent_phase),
+                    nb_steps,
+                );
+            }
+        }
+    }
+
+    extern "C" fn step_complete_callback(
+        user_data: *mut c_void,
+        phase_name: *const c_char,
+        step: i32,
+    ) -> bool {
+        unsafe {
+            match Self::str_from(phase_name) {
+                Some(phase) => Self::handler_from(user_data).step_complete(phase, step),
+                None => true,
+            }
+        }
+    }
+
+    extern "C" fn phase_finish_callback(user_data: *mut c_void, phase_name: *const c_char) {
+        unsafe {
+            if let Some(phase) = Self::str_from(phase_name) {
+                Self::handler_from(user_data).phase_finish(phase);
+            }
+        }
+    }
+}
+
+impl Drop for Monitor {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe {
+                trtx_progress_monitor_destroy(self.inner);
+            }
+        }
+    }
+}
+
+unsafe impl Send for Monitor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase_timer() {
+        let timer = PhaseTimer::new();
+        timer.phase_start("build", None, 1);
+        timer.phase_start("tactics", Some("build"), 0);
+        timer.phase_finish("tactics");
+        timer.phase_finish("build");
+        timer.phase_finish("never started");
+
+        let timings = timer.timings();
+        assert_eq!(timings.len(), 2);
+        assert!(timings["build"] >= timings["tactics"]);
+    }
+
+    #[test]
+    fn test_cancellation() {
+        let cancel = Arc::new(AtomicBool::new(false));
+        let timer = Arc::new(PhaseTimer::new());
+        let monitor = Cancellation::new(cancel.clone(), Some(timer.clone()));
+
+        monitor.phase_start("build", None, 2);
+        assert!(monitor.step_complete("build", 0));
+        cancel.store(true, Ordering::Relaxed);
+        assert!(!monitor.step_complete("build", 1));
+        monitor.phase_finish("build");
+        assert!(timer.timings().contains_key("build"));
+    }
+}
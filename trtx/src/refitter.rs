@@ -0,0 +1,184 @@
+//! Refitting engine weights without rebuilding
+
+use crate::error::{Error, Result};
+use crate::network::Weights;
+use crate::runtime::CudaEngine;
+use std::ffi::{CStr, CString};
+use trtx_sys::*;
+
+/// Updates the weights of a refittable engine (mirrors `nvinfer1::IRefitter`)
+///
+/// Created with [`CudaEngine::create_refitter`]. Set new values with
+/// [`set_named_weights`](Self::set_named_weights), then apply them with
+/// [`refit_cuda_engine`](Self::refit_cuda_engine). The first refit must set
+/// every weight listed by [`get_all_weights`](Self::get_all_weights).
+pub struct Refitter<'a> {
+    inner: *mut TrtxRefitter,
+    // TensorRT reads the values at refit time, so they are kept until then
+    weights: Vec<Box<[u8]>>,
+    _engine: std::marker::PhantomData<&'a CudaEngine>,
+}
+
+impl Refitter<'_> {
+    pub(crate) fn from_raw(inner: *mut TrtxRefitter) -> Self {
+        Refitter {
+            inner,
+            weights: Vec::new(),
+            _engine: std::marker::PhantomData,
+        }
+    }
+
+    /// Get the names of all weights that can be refitted
+    pub fn get_all_weights(&self) -> Result<Vec<String>> {
+        let mut count: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_refitter_get_all_weights(
+                self.inner,
+                std::ptr::null_mut(),
+                0,
+                &mut count,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        let mut name_ptrs: Vec<*const i8> = vec![std::ptr::null(); count as usize];
+        let result = unsafe {
+            trtx_refitter_get_all_weights(
+                self.inner,
+                name_ptrs.as_mut_ptr(),
+                count,
+                &mut count,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        name_ptrs
+            .into_iter()
+            .map(|ptr| Ok(unsafe { CStr::from_ptr(ptr) }.to_str()?.to_string()))
+            .collect()
+    }
+
+    /// Set new values for the weights called `name`
+    ///
+    /// The values are copied, so `weights` need not outlive the call. Fails
+    /// if the engine has no refittable weights by that name.
+    pub fn set_named_weights(&mut self, name: &str, weights: &Weights) -> Result<()> {
+        let name_cstr = CString::new(name)?;
+        let values: Box<[u8]> = weights.as_bytes().into();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_refitter_set_named_weights(
+                self.inner,
+                name_cstr.as_ptr(),
+                weights.dtype() as i32,
+                values.as_ptr() as *const std::ffi::c_void,
+                weights.count() as i64,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        self.weights.push(values);
+        Ok(())
+    }
+
+    /// Apply the weights set so far to the engine
+    ///
+    /// No execution context of the engine may be running while it refits.
+    pub fn refit_cuda_engine(&mut self) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_refitter_refit_cuda_engine(self.inner, error_msg.as_mut_ptr(), error_msg.len())
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        self.weights.clear();
+        Ok(())
+    }
+}
+
+impl Drop for Refitter<'_> {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe {
+                trtx_refitter_destroy(self.inner);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{network_flags, Builder, BuilderFlag};
+    use crate::logger::Logger;
+    use crate::runtime::Runtime;
+
+    #[test]
+    fn test_refitter_weights() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let network = builder
+            .create_network(network_flags::EXPLICIT_BATCH)
+            .unwrap();
+        let mut config = builder.create_config().unwrap();
+        config.set_flag(BuilderFlag::Refit).unwrap();
+        let engine_data = builder.build_serialized_network(&network, &config).unwrap();
+
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&engine_data).unwrap();
+        assert!(engine.is_refittable().unwrap());
+
+        let mut refitter = engine.create_refitter(&logger).unwrap();
+        let names = refitter.get_all_weights().unwrap();
+        #[cfg(feature = "mock")]
+        assert_eq!(names, vec!["mock_layer.weight", "mock_layer.bias"]);
+
+        let values = [0.5f32; 4];
+        assert!(refitter
+            .set_named_weights("missing", &Weights::from_f32(&values))
+            .is_err());
+        for name in &names {
+            refitter
+                .set_named_weights(name, &Weights::from_f32(&values))
+                .unwrap();
+        }
+        #[cfg(feature = "mock")]
+        refitter.refit_cuda_engine().unwrap();
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_refitter_requires_refittable_engine() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+
+        assert!(!engine.is_refittable().unwrap());
+        assert!(matches!(
+            engine.create_refitter(&logger),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,198 @@
+//! Recording inference requests to files and replaying them for bug reports
+
+use crate::error::{Error, Result};
+use crate::executor::{HostKind, Session, SessionOptions, TensorData, TensorInput, TensorOutput};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+
+/// Write a recording of `inputs` for the engine in `engine_data` to `path`
+///
+/// The file is JSON with an engine hash, the options that affect how inputs
+/// are accepted and outputs returned, and each input's name, shape, type and hex-encoded bytes.
+pub(crate) fn record(
+    path: &Path,
+    engine_data: &[u8],
+    inputs: &[TensorInput],
+    options: &SessionOptions,
+) -> Result<()> {
+    let inputs: Vec<Value> = inputs
+        .iter()
+        .map(|input| {
+            json!({
+                "name": input.name,
+                "shape": input.shape.as_slice(),
+                "dtype": input.data.type_name(),
+                "data": to_hex(input.data.as_bytes()),
+            })
+        })
+        .collect();
+
+    let recording = json!({
+        "engine_hash": engine_hash(engine_data),
+        "coerce_dtypes": options.coerce_dtypes,
+        "output_types": options
+            .output_types
+            .iter()
+            .map(|(name, kind)| (name.clone(), kind.name()))
+            .collect::<HashMap<_, _>>(),
+        "inputs": inputs,
+    });
+    std::fs::write(path, serde_json::to_vec_pretty(&recording)?)?;
+    Ok(())
+}
+
+/// Re-run an inference saved with [`Session::record_next_inference`]
+///
+/// `engine_data` must be the serialized engine the inference was recorded
+/// with; a different engine fails with [`Error::InvalidArgument`].
+pub fn replay_inference(path: impl AsRef<Path>, engine_data: &[u8]) -> Result<Vec<TensorOutput>> {
+    let recording: Value = serde_json::from_slice(&std::fs::read(path)?)?;
+    let invalid = |what: &str| Error::InvalidArgument(format!("Invalid recording: {}", what));
+
+    let recorded_hash = recording["engine_hash"]
+        .as_str()
+        .ok_or_else(|| invalid("missing engine_hash"))?;
+    if recorded_hash != engine_hash(engine_data) {
+        return Err(Error::InvalidArgument(format!(
+            "Recording was made with engine {}, not {}",
+            recorded_hash,
+            engine_hash(engine_data)
+        )));
+    }
+
+    let inputs = recording["inputs"]
+        .as_array()
+        .ok_or_else(|| invalid("missing inputs"))?
+        .iter()
+        .map(|input| {
+            let name = input["name"]
+                .as_str()
+                .ok_or_else(|| invalid("input name"))?;
+            let shape = input["shape"]
+                .as_array()
+                .and_then(|dims| dims.iter().map(|d| d.as_u64().map(|d| d as i64)).collect())
+                .ok_or_else(|| invalid("input shape"))?;
+            let bytes = input["data"]
+                .as_str()
+                .and_then(from_hex)
+                .ok_or_else(|| invalid("input data"))?;
+            let dtype = input["dtype"].as_str().unwrap_or_default();
+            let data = TensorData::from_type_name(dtype, &bytes)
+                .ok_or_else(|| invalid(&format!("unsupported dtype '{}'", dtype)))?;
+            Ok(TensorInput {
+                name: name.to_string(),
+                shape,
+                data,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let output_types = match recording["output_types"].as_object() {
+        Some(types) => types
+            .iter()
+            .map(|(name, kind)| {
+                let kind = kind
+                    .as_str()
+                    .and_then(HostKind::from_name)
+                    .ok_or_else(|| invalid(&format!("output type of '{}'", name)))?;
+                Ok((name.clone(), kind))
+            })
+            .collect::<Result<_>>()?,
+        None => HashMap::new(),
+    };
+    let options = SessionOptions {
+        coerce_dtypes: recording["coerce_dtypes"].as_bool().unwrap_or(false),
+        output_types,
+        ..SessionOptions::default()
+    };
+    Session::from_engine(engine_data.to_vec(), options)?.infer(&inputs)
+}
+
+/// FNV-1a hash of a serialized engine, stable across Rust versions
+fn engine_hash(engine_data: &[u8]) -> String {
+    let hash = engine_data
+        .iter()
+        .fold(0xcbf2_9ce4_8422_2325u64, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{:016x}", hash)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+            hex
+        })
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_round_trip() {
+        let bytes = [0x00, 0x7f, 0xff, 0x10];
+        assert_eq!(to_hex(&bytes), "007fff10");
+        assert_eq!(from_hex("007fff10").unwrap(), bytes);
+        assert!(from_hex("abc").is_none());
+        assert!(from_hex("zz").is_none());
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let path = std::env::temp_dir().join(format!("trtx_replay_{}.json", std::process::id()));
+        let engine_data = vec![0u8; 16];
+        let values = vec![1.0f32, -2.0, 3.5, 0.25];
+        let inputs = vec![TensorInput {
+            name: "input".to_string(),
+            shape: vec![1, 4].into(),
+            data: values.clone().into(),
+        }];
+
+        let options = SessionOptions {
+            output_types: HashMap::from([("output".to_string(), HostKind::F64)]),
+            ..SessionOptions::default()
+        };
+        let mut session = Session::from_engine(engine_data.clone(), options).unwrap();
+        session.record_next_inference(&path);
+        let original = session.infer(&inputs);
+        let recorded = std::fs::read(&path).unwrap();
+
+        // Only the next call is recorded
+        let mut zeros = inputs.clone();
+        zeros[0].data = vec![0.0f32; 4].into();
+        let second = session.infer(&zeros);
+        assert_eq!(second.is_ok(), original.is_ok());
+        assert_eq!(std::fs::read(&path).unwrap(), recorded);
+
+        let replayed = replay_inference(&path, &engine_data);
+        let other_engine = replay_inference(&path, b"TRTXMOCK\x01\x02");
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(other_engine, Err(Error::InvalidArgument(_))));
+
+        #[cfg(feature = "mock")]
+        {
+            let (original, replayed) = (original.unwrap(), replayed.unwrap());
+            assert_eq!(replayed[0].shape, original[0].shape);
+            // The recorded output type is restored
+            let expected: Vec<f64> = values.iter().map(|&v| v as f64).collect();
+            assert_eq!(replayed[0].data, TensorData::F64(expected));
+        }
+        #[cfg(not(feature = "mock"))]
+        assert_eq!(original.is_ok(), replayed.is_ok());
+    }
+}
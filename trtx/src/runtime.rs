@@ -1,11 +1,35 @@
 //! Runtime for deserializing and managing TensorRT engines
 
+use crate::builder::ProfilingVerbosity;
+use crate::cuda::{CudaStream, DeviceBuffer};
+use crate::debug_listener::{DebugListener, Listener};
+use crate::engine_header::TensorInfo;
 use crate::error::{Error, Result};
+use crate::inspector::EngineInspector;
 use crate::logger::Logger;
+use crate::output_allocator::{Allocator, OutputAllocator};
+use crate::plugin::PluginRegistry;
+use crate::profiler::{LayerProfiler, Profiler};
+use crate::refitter::Refitter;
+use crate::shapes::Dims;
+use crate::tensor::{DataType, OptProfileSelector, TensorIoMode};
+use std::collections::HashMap;
 use std::ffi::CStr;
+use std::path::Path;
+use std::sync::Arc;
 use trtx_sys::*;
 
+/// `nvinfer1::EngineValidity::kINVALID`
+const ENGINE_INVALID: i32 = 2;
+/// `nvinfer1::EngineInvalidityDiagnostics::kVERSION_MISMATCH`
+const ENGINE_VERSION_MISMATCH: u64 = 1 << 0;
+/// `nvinfer1::EngineInvalidityDiagnostics::kMALFORMED_ENGINE`
+const ENGINE_MALFORMED: u64 = 1 << 5;
+
 /// A CUDA engine containing optimized inference code
+///
+/// Engines are `Send + Sync`: one engine can serve execution contexts on
+/// several threads.
 pub struct CudaEngine {
     inner: *mut TrtxCudaEngine,
 }
@@ -24,6 +48,94 @@ impl CudaEngine {
         Ok(count)
     }
 
+    /// Get the number of layers in the engine
+    pub fn get_nb_layers(&self) -> Result<i32> {
+        let mut count: i32 = 0;
+
+        let result = unsafe { trtx_cuda_engine_get_nb_layers(self.inner, &mut count) };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &[]));
+        }
+
+        Ok(count)
+    }
+
+    /// Get the name of the network the engine was built from
+    ///
+    /// Set with [`NetworkDefinition::set_name`] before building; empty if
+    /// none was set.
+    ///
+    /// [`NetworkDefinition::set_name`]: crate::NetworkDefinition::set_name
+    pub fn get_name(&self) -> Result<String> {
+        let mut name_ptr: *const i8 = std::ptr::null();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_engine_get_name(
+                self.inner,
+                &mut name_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        let name = unsafe { CStr::from_ptr(name_ptr) }.to_str()?.to_string();
+
+        Ok(name)
+    }
+
+    /// Get the number of optimization profiles the engine was built with
+    pub fn get_nb_optimization_profiles(&self) -> Result<i32> {
+        let mut count: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_engine_get_nb_optimization_profiles(
+                self.inner,
+                &mut count,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(count)
+    }
+
+    /// Get the names of all I/O tensors, in engine order
+    pub fn io_tensor_names(&self) -> Result<Vec<String>> {
+        (0..self.get_nb_io_tensors()?)
+            .map(|i| self.get_tensor_name(i))
+            .collect()
+    }
+
+    /// Iterate over the I/O tensors with their name, shape, type and I/O mode
+    ///
+    /// Fails on the first item if the tensor count cannot be read.
+    pub fn tensors(&self) -> impl Iterator<Item = Result<TensorInfo>> + '_ {
+        let (count, error) = match self.get_nb_io_tensors() {
+            Ok(count) => (count, None),
+            Err(error) => (0, Some(error)),
+        };
+        error.map(Err).into_iter().chain((0..count).map(move |i| {
+            let name = self.get_tensor_name(i)?;
+            Ok(TensorInfo {
+                io_mode: self.get_tensor_io_mode(&name)?,
+                dtype: self.get_tensor_dtype(&name)?,
+                shape: self.get_tensor_shape(&name)?,
+                name,
+            })
+        }))
+    }
+
     /// Get the name of a tensor by index
     pub fn get_tensor_name(&self, index: i32) -> Result<String> {
         let mut name_ptr: *const i8 = std::ptr::null();
@@ -48,15 +160,17 @@ impl CudaEngine {
         Ok(name)
     }
 
-    /// Create an execution context for inference
-    pub fn create_execution_context(&self) -> Result<ExecutionContext<'_>> {
-        let mut context_ptr: *mut TrtxExecutionContext = std::ptr::null_mut();
+    /// Get the data type of a tensor by name
+    pub fn get_tensor_dtype(&self, name: &str) -> Result<DataType> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut dtype: i32 = 0;
         let mut error_msg = [0i8; 1024];
 
         let result = unsafe {
-            trtx_cuda_engine_create_execution_context(
+            trtx_cuda_engine_get_tensor_dtype(
                 self.inner,
-                &mut context_ptr,
+                name_cstr.as_ptr(),
+                &mut dtype,
                 error_msg.as_mut_ptr(),
                 error_msg.len(),
             )
@@ -66,118 +180,223 @@ impl CudaEngine {
             return Err(Error::from_ffi(result, &error_msg));
         }
 
-        Ok(ExecutionContext {
-            inner: context_ptr,
-            _engine: std::marker::PhantomData,
-        })
+        DataType::from_raw(dtype)
     }
-}
 
-impl Drop for CudaEngine {
-    fn drop(&mut self) {
-        if !self.inner.is_null() {
-            unsafe {
-                trtx_cuda_engine_destroy(self.inner);
-            }
+    /// Get the shape of a tensor by name
+    ///
+    /// Dynamic dimensions are reported as -1; resolve them on an execution
+    /// context with [`ExecutionContext::set_input_shape`].
+    pub fn get_tensor_shape(&self, name: &str) -> Result<Dims> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut dims = [0i64; TRTX_MAX_DIMS as usize];
+        let mut nb_dims: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_engine_get_tensor_shape(
+                self.inner,
+                name_cstr.as_ptr(),
+                dims.as_mut_ptr(),
+                dims.len() as i32,
+                &mut nb_dims,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
         }
+
+        Ok(Dims::from(&dims[..nb_dims as usize]))
     }
-}
 
-unsafe impl Send for CudaEngine {}
-unsafe impl Sync for CudaEngine {}
+    /// Get whether a tensor is an input or an output
+    pub fn get_tensor_io_mode(&self, name: &str) -> Result<TensorIoMode> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut mode: i32 = 0;
+        let mut error_msg = [0i8; 1024];
 
-/// Execution context for running inference
-pub struct ExecutionContext<'a> {
-    inner: *mut TrtxExecutionContext,
-    _engine: std::marker::PhantomData<&'a CudaEngine>,
-}
+        let result = unsafe {
+            trtx_cuda_engine_get_tensor_io_mode(
+                self.inner,
+                name_cstr.as_ptr(),
+                &mut mode,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
 
-impl<'a> ExecutionContext<'a> {
-    /// Set the address of a tensor for input or output
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        TensorIoMode::from_raw(mode)
+    }
+
+    /// Check whether a tensor is a shape tensor or feeds shape inference
     ///
-    /// # Safety
+    /// Such tensors are read on the host: bind inputs with
+    /// [`ExecutionContext::set_input_shape_tensor`] instead of device memory.
+    pub fn is_shape_inference_io(&self, name: &str) -> Result<bool> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut is_shape_io = false;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_engine_is_shape_inference_io(
+                self.inner,
+                name_cstr.as_ptr(),
+                &mut is_shape_io,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(is_shape_io)
+    }
+
+    /// Get the min, opt or max shape of an input in an optimization profile
     ///
-    /// The caller must ensure:
-    /// - `data` points to valid CUDA device memory
-    /// - The memory remains valid for the lifetime of inference
-    /// - The memory is large enough for the tensor's size
-    pub unsafe fn set_tensor_address(
-        &mut self,
+    /// Fails with [`Error::InvalidArgument`] if `name` is not an input or
+    /// `profile` is out of range.
+    pub fn get_profile_shape(
+        &self,
         name: &str,
-        data: *mut std::ffi::c_void,
-    ) -> Result<()> {
+        profile: i32,
+        selector: OptProfileSelector,
+    ) -> Result<Dims> {
         let name_cstr = std::ffi::CString::new(name)?;
+        let mut dims = [0i64; TRTX_MAX_DIMS as usize];
+        let mut nb_dims: i32 = 0;
         let mut error_msg = [0i8; 1024];
 
-        let result = trtx_execution_context_set_tensor_address(
-            self.inner,
-            name_cstr.as_ptr(),
-            data,
-            error_msg.as_mut_ptr(),
-            error_msg.len(),
-        );
+        let result = unsafe {
+            trtx_cuda_engine_get_profile_shape(
+                self.inner,
+                name_cstr.as_ptr(),
+                profile,
+                selector as i32,
+                dims.as_mut_ptr(),
+                dims.len() as i32,
+                &mut nb_dims,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
 
         if result != TRTX_SUCCESS as i32 {
             return Err(Error::from_ffi(result, &error_msg));
         }
 
-        Ok(())
+        Ok(Dims::from(&dims[..nb_dims as usize]))
     }
 
-    /// Enqueue inference work on a CUDA stream
+    /// Get the alignment in bytes required of the address bound to a tensor
     ///
-    /// # Safety
+    /// TensorRT does not report a per-tensor alignment, so this is the
+    /// crate-wide [`TENSOR_ALIGNMENT`] that every CUDA allocation made by the
+    /// crate guarantees, for every I/O tensor. Fails with
+    /// [`Error::InvalidArgument`] if the engine has no tensor called `name`.
     ///
-    /// The caller must ensure:
-    /// - `cuda_stream` is a valid CUDA stream handle (or null for default stream)
-    /// - All tensor addresses have been set
-    /// - CUDA context is properly initialized
-    pub unsafe fn enqueue_v3(&mut self, cuda_stream: *mut std::ffi::c_void) -> Result<()> {
+    /// [`TENSOR_ALIGNMENT`]: crate::cuda::TENSOR_ALIGNMENT
+    pub fn get_tensor_alignment(&self, name: &str) -> Result<usize> {
+        if self.get_tensor_io_mode(name)? == TensorIoMode::None {
+            return Err(Error::InvalidArgument(format!("Unknown tensor '{}'", name)));
+        }
+
+        Ok(crate::cuda::TENSOR_ALIGNMENT)
+    }
+
+    /// Get the device memory an execution context needs for activations, in bytes
+    pub fn get_device_memory_size(&self) -> Result<i64> {
+        let mut size: i64 = 0;
         let mut error_msg = [0i8; 1024];
 
-        let result = trtx_execution_context_enqueue_v3(
-            self.inner,
-            cuda_stream,
-            error_msg.as_mut_ptr(),
-            error_msg.len(),
-        );
+        let result = unsafe {
+            trtx_cuda_engine_get_device_memory_size(
+                self.inner,
+                &mut size,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
 
         if result != TRTX_SUCCESS as i32 {
             return Err(Error::from_ffi(result, &error_msg));
         }
 
-        Ok(())
+        Ok(size)
     }
-}
 
-impl Drop for ExecutionContext<'_> {
-    fn drop(&mut self) {
-        if !self.inner.is_null() {
-            unsafe {
-                trtx_execution_context_destroy(self.inner);
-            }
+    /// Get the total size of weights that can be streamed, in bytes
+    ///
+    /// Returns 0 if the engine was not built with weight streaming enabled.
+    pub fn get_streamable_weights_size(&self) -> Result<i64> {
+        let mut size: i64 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_engine_get_streamable_weights_size(
+                self.inner,
+                &mut size,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
         }
+
+        Ok(size)
     }
-}
 
-unsafe impl Send for ExecutionContext<'_> {}
+    /// Whether the engine streams weights
+    ///
+    /// True when the engine was built with [`BuilderFlag::WeightStreaming`]
+    /// and its current budget keeps only part of the streamable weights
+    /// resident on the device.
+    ///
+    /// [`BuilderFlag::WeightStreaming`]: crate::builder::BuilderFlag::WeightStreaming
+    pub fn is_weight_streaming_enabled(&self) -> Result<bool> {
+        let mut enabled = false;
+        let mut error_msg = [0i8; 1024];
 
-/// Runtime for deserializing engines
-pub struct Runtime<'a> {
-    inner: *mut TrtxRuntime,
-    _logger: &'a Logger,
-}
+        let result = unsafe {
+            trtx_cuda_engine_is_weight_streaming_enabled(
+                self.inner,
+                &mut enabled,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
 
-impl<'a> Runtime<'a> {
-    /// Create a new runtime
-    pub fn new(logger: &'a Logger) -> Result<Self> {
-        let mut runtime_ptr: *mut TrtxRuntime = std::ptr::null_mut();
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(enabled)
+    }
+
+    /// Get the smallest weight streaming budget the engine accepts, in bytes
+    ///
+    /// This is the limit of the budget API used by
+    /// [`set_weight_streaming_budget`](Self::set_weight_streaming_budget),
+    /// which accepts any budget down to 0.
+    pub fn get_minimum_weight_streaming_budget(&self) -> Result<i64> {
+        let mut budget: i64 = 0;
         let mut error_msg = [0i8; 1024];
 
         let result = unsafe {
-            trtx_runtime_create(
-                logger.as_ptr(),
-                &mut runtime_ptr,
+            trtx_cuda_engine_get_minimum_weight_streaming_budget(
+                self.inner,
+                &mut budget,
                 error_msg.as_mut_ptr(),
                 error_msg.len(),
             )
@@ -187,23 +406,31 @@ impl<'a> Runtime<'a> {
             return Err(Error::from_ffi(result, &error_msg));
         }
 
-        Ok(Runtime {
-            inner: runtime_ptr,
-            _logger: logger,
-        })
+        Ok(budget)
     }
 
-    /// Deserialize a CUDA engine from serialized data
-    pub fn deserialize_cuda_engine(&self, data: &[u8]) -> Result<CudaEngine> {
-        let mut engine_ptr: *mut TrtxCudaEngine = std::ptr::null_mut();
+    /// Set how many bytes of streamable weights may stay resident on the device
+    ///
+    /// Must be called before creating execution contexts. The budget belongs
+    /// to the engine, not to a context: to adjust it at runtime, drop the
+    /// engine's contexts, set the new budget and create them again. Fails with
+    /// [`Error::InvalidArgument`] if `budget` is below
+    /// [`get_minimum_weight_streaming_budget`](Self::get_minimum_weight_streaming_budget).
+    pub fn set_weight_streaming_budget(&mut self, budget: i64) -> Result<()> {
+        let minimum = self.get_minimum_weight_streaming_budget()?;
+        if budget < minimum {
+            return Err(Error::InvalidArgument(format!(
+                "Weight streaming budget of {} bytes is below the engine minimum of {} bytes",
+                budget, minimum
+            )));
+        }
+
         let mut error_msg = [0i8; 1024];
 
         let result = unsafe {
-            trtx_runtime_deserialize_cuda_engine(
+            trtx_cuda_engine_set_weight_streaming_budget(
                 self.inner,
-                data.as_ptr() as *const std::ffi::c_void,
-                data.len(),
-                &mut engine_ptr,
+                budget,
                 error_msg.as_mut_ptr(),
                 error_msg.len(),
             )
@@ -213,18 +440,1717 @@ impl<'a> Runtime<'a> {
             return Err(Error::from_ffi(result, &error_msg));
         }
 
-        Ok(CudaEngine { inner: engine_ptr })
+        Ok(())
     }
-}
 
-impl Drop for Runtime<'_> {
-    fn drop(&mut self) {
-        if !self.inner.is_null() {
-            unsafe {
-                trtx_runtime_destroy(self.inner);
-            }
+    /// Get the current weight streaming budget in bytes
+    pub fn get_weight_streaming_budget(&self) -> Result<i64> {
+        let mut budget: i64 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_engine_get_weight_streaming_budget(
+                self.inner,
+                &mut budget,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(budget)
+    }
+
+    /// Get the budget TensorRT would choose from the currently free device memory
+    ///
+    /// Useful for a scheduler that raises or lowers the budget with
+    /// [`set_weight_streaming_budget`](Self::set_weight_streaming_budget) as
+    /// memory frees up.
+    pub fn get_weight_streaming_automatic_budget(&self) -> Result<i64> {
+        let mut budget: i64 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_engine_get_weight_streaming_automatic_budget(
+                self.inner,
+                &mut budget,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(budget)
+    }
+
+    /// Create an inspector for querying layer information
+    pub fn create_inspector(&self) -> Result<EngineInspector<'_>> {
+        let mut inspector_ptr: *mut TrtxEngineInspector = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_engine_create_engine_inspector(
+                self.inner,
+                &mut inspector_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(EngineInspector::from_raw(inspector_ptr))
+    }
+
+    /// Whether the engine was built with [`BuilderFlag::Refit`]
+    ///
+    /// [`BuilderFlag::Refit`]: crate::builder::BuilderFlag::Refit
+    pub fn is_refittable(&self) -> Result<bool> {
+        let mut refittable = false;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_engine_is_refittable(
+                self.inner,
+                &mut refittable,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(refittable)
+    }
+
+    /// Create a refitter for updating the engine's weights in place
+    ///
+    /// Fails with [`Error::InvalidArgument`] if the engine is not
+    /// [refittable](Self::is_refittable).
+    pub fn create_refitter<'a>(&'a self, logger: &'a Logger) -> Result<Refitter<'a>> {
+        if !self.is_refittable()? {
+            return Err(Error::InvalidArgument(
+                "Engine is not refittable; build it with BuilderFlag::Refit".to_string(),
+            ));
+        }
+
+        let mut refitter_ptr: *mut TrtxRefitter = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_refitter_create(
+                self.inner,
+                logger.as_ptr(),
+                &mut refitter_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(Refitter::from_raw(refitter_ptr))
+    }
+
+    /// Get the names of the engine's layers, in execution order
+    ///
+    /// Read from the inspector, so it works at any profiling verbosity except
+    /// [`ProfilingVerbosity::None`]. Comparing the count against an expected
+    /// value catches changes in how a model fuses.
+    ///
+    /// [`ProfilingVerbosity::None`]: crate::builder::ProfilingVerbosity::None
+    #[cfg(feature = "json")]
+    pub fn get_layer_names(&self) -> Result<Vec<String>> {
+        Ok(self
+            .create_inspector()?
+            .layers()?
+            .into_iter()
+            .map(|layer| layer.name)
+            .collect())
+    }
+
+    /// Get the precision each layer ran at, in execution order
+    ///
+    /// Read from the inspector, so only engines built with
+    /// [`ProfilingVerbosity::Detailed`] report anything; layers whose
+    /// precision is not reported are left out.
+    ///
+    /// [`ProfilingVerbosity::Detailed`]: crate::builder::ProfilingVerbosity::Detailed
+    #[cfg(feature = "json")]
+    pub fn get_layer_precisions(&self) -> Result<Vec<(String, DataType)>> {
+        Ok(self
+            .create_inspector()?
+            .layers()?
+            .into_iter()
+            .filter_map(|layer| layer.precision.map(|precision| (layer.name, precision)))
+            .collect())
+    }
+
+    /// Create an execution context pinned to optimization profile `profile`
+    ///
+    /// Equivalent to [`create_execution_context`](Self::create_execution_context)
+    /// followed by [`ExecutionContext::set_optimization_profile`], before any
+    /// shape or address is set. Useful when serving each profile from its own
+    /// context.
+    pub fn create_execution_context_for_profile(
+        &self,
+        profile: i32,
+    ) -> Result<ExecutionContext<'_>> {
+        let nb_profiles = self.get_nb_optimization_profiles()?;
+        if !(0..nb_profiles).contains(&profile) {
+            return Err(Error::InvalidArgument(format!(
+                "Optimization profile {} out of range (engine has {})",
+                profile, nb_profiles
+            )));
+        }
+
+        let mut context = self.create_execution_context()?;
+        context.set_optimization_profile(profile)?;
+        Ok(context)
+    }
+
+    /// Create an execution context for inference
+    pub fn create_execution_context(&self) -> Result<ExecutionContext<'_>> {
+        let mut context_ptr: *mut TrtxExecutionContext = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_cuda_engine_create_execution_context(
+                self.inner,
+                &mut context_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        crate::handles::register(context_ptr);
+        Ok(ExecutionContext {
+            inner: context_ptr,
+            profiler: None,
+            debug_listener: None,
+            engine: self,
+            capacities: HashMap::new(),
+            output_allocators: HashMap::new(),
+            shape_tensors: HashMap::new(),
+        })
+    }
+}
+
+impl Drop for CudaEngine {
+    fn drop(&mut self) {
+        let inner = std::mem::replace(&mut self.inner, std::ptr::null_mut());
+        if !inner.is_null() {
+            crate::handles::release(inner);
+            unsafe {
+                trtx_cuda_engine_destroy(inner);
+            }
+        }
+    }
+}
+
+unsafe impl Send for CudaEngine {}
+unsafe impl Sync for CudaEngine {}
+
+/// Execution context for running inference
+///
+/// Contexts are `Send` but not `Sync`, as TensorRT does not allow a context
+/// to be used concurrently. Create one context per thread instead of sharing.
+pub struct ExecutionContext<'a> {
+    inner: *mut TrtxExecutionContext,
+    // Dropped after the context, which holds a pointer to it
+    profiler: Option<Profiler>,
+    debug_listener: Option<Listener>,
+    engine: &'a CudaEngine,
+    // Byte sizes of buffers bound with set_tensor_buffer
+    capacities: HashMap<String, usize>,
+    output_allocators: HashMap<String, Allocator>,
+    // Host values of shape tensors, which the context reads at enqueue
+    shape_tensors: HashMap<String, Box<[i32]>>,
+}
+
+impl<'a> ExecutionContext<'a> {
+    /// Set the address of a tensor for input or output
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure:
+    /// - `data` points to valid CUDA device memory
+    /// - The memory remains valid for the lifetime of inference
+    /// - The memory is large enough for the tensor's size
+    ///
+    /// Fails with [`Error::InvalidArgument`] if the engine has no tensor
+    /// called `name`, or if `data` is not aligned to
+    /// [`CudaEngine::get_tensor_alignment`].
+    pub unsafe fn set_tensor_address(
+        &mut self,
+        name: &str,
+        data: *mut std::ffi::c_void,
+    ) -> Result<()> {
+        let alignment = self.engine.get_tensor_alignment(name)?;
+        if !(data as usize).is_multiple_of(alignment) {
+            return Err(Error::InvalidArgument(format!(
+                "Address {:p} for tensor '{}' is not {}-byte aligned",
+                data, name, alignment
+            )));
+        }
+
+        self.bind_address(name, data)?;
+        self.capacities.remove(name);
+        self.shape_tensors.remove(name);
+        Ok(())
+    }
+
+    /// Set the values of an Int32 shape tensor input
+    ///
+    /// Shape tensors are read on the host, so the values are copied into a
+    /// buffer owned by the context and stay valid across inferences until
+    /// replaced. Fails with [`Error::InvalidArgument`] if `name` is not an
+    /// engine input for which [`CudaEngine::is_shape_inference_io`] holds;
+    /// other inputs are read on the device and need
+    /// [`set_tensor_address`](Self::set_tensor_address).
+    pub fn set_input_shape_tensor(&mut self, name: &str, values: &[i32]) -> Result<()> {
+        if self.engine.get_tensor_io_mode(name)? != TensorIoMode::Input {
+            return Err(Error::InvalidArgument(format!(
+                "'{}' is not an input tensor",
+                name
+            )));
+        }
+        if !self.engine.is_shape_inference_io(name)? {
+            return Err(Error::InvalidArgument(format!(
+                "'{}' is not a shape tensor input; bind it with set_tensor_address",
+                name
+            )));
+        }
+
+        let values: Box<[i32]> = values.into();
+        // SAFETY: the box is kept in shape_tensors until the binding is
+        // replaced, and moving it does not move its heap allocation
+        unsafe { self.bind_address(name, values.as_ptr() as *mut std::ffi::c_void)? };
+        self.capacities.remove(name);
+        self.shape_tensors.insert(name.to_string(), values);
+        Ok(())
+    }
+
+    /// Get the address bound to a tensor, or null if none is set
+    pub fn get_tensor_address(&self, name: &str) -> Result<*const std::ffi::c_void> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut data: *const std::ffi::c_void = std::ptr::null();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_get_tensor_address(
+                self.inner,
+                name_cstr.as_ptr(),
+                &mut data,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(data)
+    }
+
+    // Callers guarantee data stays valid while bound; alignment is not checked
+    unsafe fn bind_address(&mut self, name: &str, data: *mut std::ffi::c_void) -> Result<()> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut error_msg = [0i8; 1024];
+
+        let result = trtx_execution_context_set_tensor_address(
+            self.inner,
+            name_cstr.as_ptr(),
+            data,
+            error_msg.as_mut_ptr(),
+            error_msg.len(),
+        );
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Bind a device buffer to a tensor, remembering its size
+    ///
+    /// Like [`set_tensor_address`](Self::set_tensor_address), but lets
+    /// [`validate_output_capacity`](Self::validate_output_capacity) check the
+    /// buffer against the tensor's resolved size.
+    ///
+    /// # Safety
+    ///
+    /// `buffer` must outlive every inference that uses this binding.
+    pub unsafe fn set_tensor_buffer(&mut self, name: &str, buffer: &DeviceBuffer) -> Result<()> {
+        self.set_tensor_address(name, buffer.as_ptr())?;
+        self.capacities.insert(name.to_string(), buffer.size());
+        Ok(())
+    }
+
+    /// Check that every output bound with [`set_tensor_buffer`](Self::set_tensor_buffer)
+    /// can hold its resolved shape
+    ///
+    /// Call after setting input shapes: outputs of dynamic-shape engines only
+    /// get a size once their inputs' shapes are known. Fails with
+    /// [`Error::InvalidArgument`] if a buffer is too small or an output shape
+    /// is still unresolved.
+    pub fn validate_output_capacity(&self) -> Result<()> {
+        for name in self.engine.io_tensor_names()? {
+            let Some(&capacity) = self.capacities.get(&name) else {
+                continue;
+            };
+            if self.engine.get_tensor_io_mode(&name)? != TensorIoMode::Output {
+                continue;
+            }
+
+            let shape = self.get_tensor_shape(&name)?;
+            let Some(elements) = shape.product() else {
+                return Err(Error::InvalidArgument(format!(
+                    "Output '{}' has unresolved shape {}; set input shapes first",
+                    name, shape
+                )));
+            };
+            let required = elements * self.engine.get_tensor_dtype(&name)?.size();
+            if capacity < required {
+                return Err(Error::InvalidArgument(format!(
+                    "Output '{}' with shape {} needs {} bytes but its buffer holds {}",
+                    name, shape, required, capacity
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set the shape of an input tensor with dynamic dimensions
+    pub fn set_input_shape(&mut self, name: &str, dims: &[i64]) -> Result<()> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_set_input_shape(
+                self.inner,
+                name_cstr.as_ptr(),
+                dims.as_ptr(),
+                dims.len() as i32,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get the index of the optimization profile this context uses
+    pub fn get_optimization_profile(&self) -> Result<i32> {
+        let mut profile: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_get_optimization_profile(
+                self.inner,
+                &mut profile,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(profile)
+    }
+
+    /// Switch this context to optimization profile `profile`
+    ///
+    /// Input shapes and tensor addresses must be set again afterwards. The
+    /// switch runs on the default stream and has completed on return.
+    /// Fails with [`Error::InvalidArgument`] if `profile` is out of range or
+    /// already used by another context of the engine.
+    pub fn set_optimization_profile(&mut self, profile: i32) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_set_optimization_profile(
+                self.inner,
+                profile,
+                crate::cuda::get_default_stream(),
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Set how much detail the NVTX ranges of each inference carry
+    ///
+    /// Nsight Systems shows layer names under each inference with
+    /// [`ProfilingVerbosity::LayerNamesOnly`] (the default). The verbosity
+    /// cannot exceed the one the engine was built with (see
+    /// [`BuilderConfig::set_profiling_verbosity`]); higher levels fail with
+    /// [`Error::InvalidArgument`].
+    ///
+    /// [`BuilderConfig::set_profiling_verbosity`]: crate::BuilderConfig::set_profiling_verbosity
+    pub fn set_nvtx_verbosity(&mut self, verbosity: ProfilingVerbosity) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_set_nvtx_verbosity(
+                self.inner,
+                verbosity as i32,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get the NVTX verbosity of this context
+    pub fn get_nvtx_verbosity(&self) -> Result<ProfilingVerbosity> {
+        let mut verbosity: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_get_nvtx_verbosity(
+                self.inner,
+                &mut verbosity,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        ProfilingVerbosity::from_raw(verbosity)
+    }
+
+    /// Run the engine's auxiliary work on `streams` instead of streams
+    /// TensorRT creates
+    ///
+    /// Useful to label streams for profiling or to control their priority.
+    /// TensorRT uses as many streams as the engine was built for (see
+    /// [`BuilderConfig::set_max_aux_streams`]) and creates the rest itself.
+    ///
+    /// # Safety
+    ///
+    /// The streams must outlive every inference enqueued on this context
+    /// afterwards.
+    ///
+    /// [`BuilderConfig::set_max_aux_streams`]: crate::BuilderConfig::set_max_aux_streams
+    pub unsafe fn set_aux_streams(&mut self, streams: &[&CudaStream]) -> Result<()> {
+        let mut handles: Vec<*mut std::ffi::c_void> =
+            streams.iter().map(|stream| stream.as_ptr()).collect();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_set_aux_streams(
+                self.inner,
+                handles.as_mut_ptr(),
+                handles.len() as i32,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Check whether input shapes fall within this context's optimization profile
+    ///
+    /// Each `(name, dims)` pair must match the rank of the input and lie
+    /// between the profile's min and max shapes. Unlike
+    /// [`set_input_shape`](Self::set_input_shape), nothing on the context
+    /// changes. Fails if a name is not an engine input.
+    pub fn is_shape_supported(&self, inputs: &[(String, Vec<i64>)]) -> Result<bool> {
+        let profile = self.get_optimization_profile()?;
+
+        for (name, dims) in inputs {
+            let min = self
+                .engine
+                .get_profile_shape(name, profile, OptProfileSelector::Min)?;
+            let max = self
+                .engine
+                .get_profile_shape(name, profile, OptProfileSelector::Max)?;
+            let in_range = dims.len() == min.len()
+                && dims
+                    .iter()
+                    .zip(min.iter().zip(&max))
+                    .all(|(dim, (lo, hi))| lo <= dim && dim <= hi);
+            if !in_range {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Get the shape of a tensor, resolved from the input shapes set so far
+    pub fn get_tensor_shape(&self, name: &str) -> Result<Dims> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut dims = [0i64; TRTX_MAX_DIMS as usize];
+        let mut nb_dims: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_get_tensor_shape(
+                self.inner,
+                name_cstr.as_ptr(),
+                dims.as_mut_ptr(),
+                dims.len() as i32,
+                &mut nb_dims,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(Dims::from(&dims[..nb_dims as usize]))
+    }
+
+    /// Get the strides of a tensor in elements, one per dimension
+    ///
+    /// For linear-format tensors these are the row-major strides of
+    /// [`get_tensor_shape`](Self::get_tensor_shape), e.g. `[12, 4, 1]` for a
+    /// `[2, 3, 4]` tensor. Vectorized formats pad the vectorized dimension,
+    /// so use these strides rather than the shape to index their raw bytes.
+    /// Strides depending on a dynamic dimension are `-1` until the input
+    /// shapes are set.
+    pub fn get_tensor_strides(&self, name: &str) -> Result<Vec<i64>> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut strides = [0i64; TRTX_MAX_DIMS as usize];
+        let mut nb_dims: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_get_tensor_strides(
+                self.inner,
+                name_cstr.as_ptr(),
+                strides.as_mut_ptr(),
+                strides.len() as i32,
+                &mut nb_dims,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(strides[..nb_dims as usize].to_vec())
+    }
+
+    /// Get an upper bound in bytes on an output's size
+    ///
+    /// For outputs with data-dependent shapes (e.g. NonZero or NMS), the exact
+    /// size is only known after execution. The bound is derived from the
+    /// current input shapes and the optimization profile's max dimensions, so
+    /// it can be far larger than a typical result; set input shapes first.
+    pub fn get_max_output_size(&self, name: &str) -> Result<usize> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut size: i64 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_get_max_output_size(
+                self.inner,
+                name_cstr.as_ptr(),
+                &mut size,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(size as usize)
+    }
+
+    /// Let `allocator` provide the memory for output `name` during execution
+    ///
+    /// Required for outputs with data-dependent shapes, whose size is only
+    /// known once the inference runs; TensorRT then ignores any address set
+    /// with [`set_tensor_address`](Self::set_tensor_address). Replaces any
+    /// previous allocator for the output.
+    pub fn set_output_allocator(
+        &mut self,
+        name: &str,
+        allocator: Arc<dyn OutputAllocator>,
+    ) -> Result<()> {
+        let allocator = Allocator::new(allocator)?;
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_set_output_allocator(
+                self.inner,
+                name_cstr.as_ptr(),
+                allocator.as_ptr(),
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        self.output_allocators.insert(name.to_string(), allocator);
+        Ok(())
+    }
+
+    /// Name the context so concurrent contexts can be told apart in TensorRT's log
+    pub fn set_name(&mut self, name: &str) -> Result<()> {
+        let name_cstr = std::ffi::CString::new(name)?;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_set_name(
+                self.inner,
+                name_cstr.as_ptr(),
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get the name set with [`set_name`](Self::set_name) (empty by default)
+    pub fn get_name(&self) -> Result<String> {
+        let mut name_ptr: *const i8 = std::ptr::null();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_get_name(
+                self.inner,
+                &mut name_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        let name = unsafe { CStr::from_ptr(name_ptr) }.to_str()?.to_string();
+
+        Ok(name)
+    }
+
+    /// Report per-layer execution times to `profiler` after each inference
+    ///
+    /// Profiling adds synchronization overhead to every inference, so only
+    /// enable it while tuning, and synchronize the stream after
+    /// [`enqueue_v3`](Self::enqueue_v3) before reading results. Layer names
+    /// are only meaningful for engines built with detailed profiling verbosity.
+    pub fn set_profiler(&mut self, profiler: Arc<dyn LayerProfiler>) -> Result<()> {
+        let profiler = Profiler::new(profiler)?;
+        self.update_profiler(profiler.as_ptr())?;
+        self.profiler = Some(profiler);
+        Ok(())
+    }
+
+    /// Stop reporting per-layer timings
+    pub fn clear_profiler(&mut self) -> Result<()> {
+        self.update_profiler(std::ptr::null_mut())?;
+        self.profiler = None;
+        Ok(())
+    }
+
+    fn update_profiler(&mut self, profiler: *mut TrtxProfiler) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_set_profiler(
+                self.inner,
+                profiler,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Report the engine's debug tensors to `listener` during each inference
+    ///
+    /// Tensors must be marked with [`NetworkDefinition::mark_debug`] before
+    /// the engine is built. Reporting synchronizes the stream at every debug
+    /// tensor, so only attach a listener while investigating.
+    ///
+    /// [`NetworkDefinition::mark_debug`]: crate::NetworkDefinition::mark_debug
+    pub fn set_debug_listener(&mut self, listener: Arc<dyn DebugListener>) -> Result<()> {
+        let listener = Listener::new(listener)?;
+        self.update_debug_listener(listener.as_ptr())?;
+        self.debug_listener = Some(listener);
+        Ok(())
+    }
+
+    /// Stop reporting debug tensors
+    pub fn clear_debug_listener(&mut self) -> Result<()> {
+        self.update_debug_listener(std::ptr::null_mut())?;
+        self.debug_listener = None;
+        Ok(())
+    }
+
+    fn update_debug_listener(&mut self, listener: *mut TrtxDebugListener) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_execution_context_set_debug_listener(
+                self.inner,
+                listener,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Enqueue inference work on a CUDA stream
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure:
+    /// - `cuda_stream` is a valid CUDA stream handle (or null for default stream)
+    /// - All tensor addresses have been set
+    /// - CUDA context is properly initialized
+    pub unsafe fn enqueue_v3(&mut self, cuda_stream: *mut std::ffi::c_void) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = trtx_execution_context_enqueue_v3(
+            self.inner,
+            cuda_stream,
+            error_msg.as_mut_ptr(),
+            error_msg.len(),
+        );
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Bind device buffers to every IO tensor and run inference on `stream`
+    ///
+    /// Each binding is checked against the engine's IO tensors: unknown
+    /// names, duplicates and unbound tensors are rejected with
+    /// [`Error::InvalidArgument`], as are output buffers too small for their
+    /// resolved shape, so set input shapes first. Shape tensors set with
+    /// [`set_input_shape_tensor`](Self::set_input_shape_tensor) need no
+    /// binding.
+    ///
+    /// Waits for the inference to finish, so the buffers are only borrowed
+    /// for the call. To overlap work on several streams, bind with
+    /// [`set_tensor_buffer`](Self::set_tensor_buffer) and call
+    /// [`enqueue_v3`](Self::enqueue_v3) instead.
+    pub fn run(&mut self, bindings: &[(&str, &DeviceBuffer)], stream: &CudaStream) -> Result<()> {
+        let names = self.engine.io_tensor_names()?;
+        for (index, (name, _)) in bindings.iter().enumerate() {
+            if !names.iter().any(|n| n == name) {
+                return Err(Error::InvalidArgument(format!(
+                    "'{}' is not an IO tensor of the engine",
+                    name
+                )));
+            }
+            if bindings[..index].iter().any(|(n, _)| n == name) {
+                return Err(Error::InvalidArgument(format!(
+                    "Tensor '{}' is bound more than once",
+                    name
+                )));
+            }
+        }
+        for name in &names {
+            let bound = bindings.iter().any(|(n, _)| n == name);
+            if !bound
+                && !self.shape_tensors.contains_key(name)
+                && self.engine.get_tensor_io_mode(name)? != TensorIoMode::None
+            {
+                return Err(Error::InvalidArgument(format!(
+                    "Tensor '{}' is not bound",
+                    name
+                )));
+            }
+        }
+
+        for (name, buffer) in bindings {
+            // SAFETY: the bindings are only used by the enqueue below, which
+            // is waited for before the buffers' borrow ends
+            unsafe { self.set_tensor_buffer(name, buffer)? };
+        }
+        self.validate_output_capacity()?;
+
+        unsafe { self.enqueue_v3(stream.as_ptr())? };
+        stream.synchronize()
+    }
+}
+
+impl Drop for ExecutionContext<'_> {
+    fn drop(&mut self) {
+        let inner = std::mem::replace(&mut self.inner, std::ptr::null_mut());
+        if !inner.is_null() {
+            crate::handles::release(inner);
+            unsafe {
+                trtx_execution_context_destroy(inner);
+            }
+        }
+    }
+}
+
+unsafe impl Send for ExecutionContext<'_> {}
+
+/// Runtime for deserializing engines
+pub struct Runtime<'a> {
+    inner: *mut TrtxRuntime,
+    _logger: &'a Logger,
+    // Device made current before deserializing, if created with new_on_device
+    device: Option<i32>,
+}
+
+impl<'a> Runtime<'a> {
+    /// Create a new runtime
+    pub fn new(logger: &'a Logger) -> Result<Self> {
+        let mut runtime_ptr: *mut TrtxRuntime = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_runtime_create(
+                logger.as_ptr(),
+                &mut runtime_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(Runtime {
+            inner: runtime_ptr,
+            _logger: logger,
+            device: None,
+        })
+    }
+
+    /// Create a new runtime on a chosen CUDA device
+    ///
+    /// Makes `device` current before creating the runtime and again before
+    /// each deserialization, so engines load on the device they were built
+    /// for. Buffer allocations and enqueues for those engines must also
+    /// happen with `device` current; see [`set_device`](crate::cuda::set_device).
+    pub fn new_on_device(logger: &'a Logger, device: i32) -> Result<Self> {
+        crate::cuda::set_device(device)?;
+        let mut runtime = Self::new(logger)?;
+        runtime.device = Some(device);
+        Ok(runtime)
+    }
+
+    /// Get the device this runtime was created on with
+    /// [`new_on_device`](Self::new_on_device)
+    pub fn device(&self) -> Option<i32> {
+        self.device
+    }
+
+    /// Allow deserializing engines that contain host executable code
+    ///
+    /// Engines built with [`BuilderFlag::VersionCompatible`] embed the lean
+    /// runtime as host code and are rejected unless this is enabled first.
+    /// Only enable it for engines from a trusted source.
+    ///
+    /// [`BuilderFlag::VersionCompatible`]: crate::builder::BuilderFlag::VersionCompatible
+    pub fn set_engine_host_code_allowed(&mut self, allowed: bool) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_runtime_set_engine_host_code_allowed(
+                self.inner,
+                allowed,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Check whether engines containing host code may be deserialized
+    pub fn get_engine_host_code_allowed(&self) -> Result<bool> {
+        let mut allowed = false;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_runtime_get_engine_host_code_allowed(
+                self.inner,
+                &mut allowed,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(allowed)
+    }
+
+    /// Limit how many threads deserializing engines may use
+    ///
+    /// Defaults to 1 (the calling thread only). Fails with
+    /// [`Error::InvalidArgument`] if `nb_threads` is less than 1.
+    pub fn set_max_threads(&mut self, nb_threads: i32) -> Result<()> {
+        if nb_threads < 1 {
+            return Err(Error::InvalidArgument(format!(
+                "Thread count must be at least 1, got {}",
+                nb_threads
+            )));
+        }
+
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_runtime_set_max_threads(
+                self.inner,
+                nb_threads,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    /// Get the maximum number of threads deserializing engines may use
+    pub fn get_max_threads(&self) -> Result<i32> {
+        let mut nb_threads: i32 = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_runtime_get_max_threads(
+                self.inner,
+                &mut nb_threads,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(nb_threads)
+    }
+
+    /// Load the lean runtime library at `path` as a new runtime
+    ///
+    /// Engines built with both [`BuilderFlag::VersionCompatible`] and
+    /// [`BuilderFlag::ExcludeLeanRuntime`] leave the lean runtime out of the
+    /// plan to keep it small. They are deserialized by the lean runtime of
+    /// the TensorRT-RTX version that built them, which this loads, so an
+    /// application can run engines built by an older minor version than the
+    /// one it links. The returned runtime shares this runtime's logger and
+    /// device, and borrows it: it cannot outlive the runtime that loaded it.
+    ///
+    /// ```no_run
+    /// # use std::path::Path;
+    /// # use trtx::{Logger, Runtime};
+    /// # fn main() -> trtx::Result<()> {
+    /// let logger = Logger::stderr()?;
+    /// let runtime = Runtime::new(&logger)?;
+    /// // Lean runtime library shipped with the release that built the engine
+    /// let lean = runtime.load_runtime(Path::new("/path/to/older/lean_runtime.so"))?;
+    /// let engine = lean.deserialize_cuda_engine(&std::fs::read("model.engine")?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Fails with [`Error::InvalidArgument`] if `path` does not exist, and
+    /// with [`Error::Runtime`] if the file is not a lean runtime library
+    /// TensorRT-RTX can load.
+    ///
+    /// [`BuilderFlag::VersionCompatible`]: crate::builder::BuilderFlag::VersionCompatible
+    /// [`BuilderFlag::ExcludeLeanRuntime`]: crate::builder::BuilderFlag::ExcludeLeanRuntime
+    pub fn load_runtime(&self, path: &Path) -> Result<Runtime<'_>> {
+        if !path.exists() {
+            return Err(Error::InvalidArgument(format!(
+                "Lean runtime library {:?} does not exist",
+                path
+            )));
+        }
+        let path_str = path.to_str().ok_or_else(|| {
+            Error::InvalidArgument(format!("Lean runtime path {:?} is not valid UTF-8", path))
+        })?;
+        let path_cstr = std::ffi::CString::new(path_str)?;
+        let mut runtime_ptr: *mut TrtxRuntime = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_runtime_load_runtime(
+                self.inner,
+                path_cstr.as_ptr(),
+                &mut runtime_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(match Error::from_ffi(result, &error_msg) {
+                Error::Runtime(msg) => Error::Runtime(format!(
+                    "{} (expected the lean runtime library of the TensorRT-RTX version \
+                     that built the engine)",
+                    msg
+                )),
+                other => other,
+            });
+        }
+
+        Ok(Runtime {
+            inner: runtime_ptr,
+            _logger: self._logger,
+            device: self.device,
+        })
+    }
+
+    /// Get the plugin registry, to load plugin libraries for custom ops
+    pub fn get_plugin_registry(&self) -> PluginRegistry<'_> {
+        PluginRegistry::new(self.inner)
+    }
+
+    /// Deserialize a CUDA engine from serialized data
+    ///
+    /// Engines only load in the TensorRT-RTX version that built them unless
+    /// built with [`BuilderFlag::VersionCompatible`]; failures mention the
+    /// version this crate links against.
+    ///
+    /// [`BuilderFlag::VersionCompatible`]: crate::builder::BuilderFlag::VersionCompatible
+    pub fn deserialize_cuda_engine(&self, data: &[u8]) -> Result<CudaEngine> {
+        let (major, minor, patch) = crate::version();
+        let hint = format!(
+            " (engines must be built with TensorRT-RTX {}.{}.{} unless version-compatible)",
+            major, minor, patch
+        );
+        self.deserialize(data).map_err(|error| match error {
+            Error::InvalidArgument(msg) => Error::InvalidArgument(msg + &hint),
+            Error::Runtime(msg) => Error::Runtime(msg + &hint),
+            other => other,
+        })
+    }
+
+    /// Deserialize an engine from any source, such as a `trtexec` plan,
+    /// explaining why it cannot be loaded
+    ///
+    /// Plans written by `trtexec --saveEngine` are the same format as
+    /// [`Builder::build_serialized_network`](crate::Builder::build_serialized_network)
+    /// output and also load with [`deserialize_cuda_engine`](Self::deserialize_cuda_engine).
+    /// Either way, a plan only loads in the TensorRT-RTX version that built it
+    /// (see [`version`](crate::version)), unless it was built with
+    /// `trtexec --versionCompatible` or [`BuilderFlag::VersionCompatible`];
+    /// such plans embed host code and need
+    /// [`set_engine_host_code_allowed`](Self::set_engine_host_code_allowed)
+    /// first. Plans using plugins also need their plugin library loaded
+    /// through [`get_plugin_registry`](Self::get_plugin_registry).
+    ///
+    /// The plan is first checked with TensorRT's `getEngineValidity`, which
+    /// reads the version recorded in it. Empty data, plans built by another
+    /// TensorRT-RTX version and truncated or corrupted plans fail with
+    /// [`Error::InvalidArgument`] before deserializing. Other failures, such
+    /// as running out of device memory, are returned unchanged.
+    ///
+    /// [`BuilderFlag::VersionCompatible`]: crate::builder::BuilderFlag::VersionCompatible
+    pub fn deserialize_with_version_check(&self, data: &[u8]) -> Result<CudaEngine> {
+        if data.is_empty() {
+            return Err(Error::InvalidArgument("Engine data is empty".to_string()));
+        }
+
+        let mut validity = 0i32;
+        let mut diagnostics = 0u64;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_runtime_get_engine_validity(
+                self.inner,
+                data.as_ptr() as *const std::ffi::c_void,
+                data.len(),
+                &mut validity,
+                &mut diagnostics,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        if validity == ENGINE_INVALID {
+            let (major, minor, patch) = crate::version();
+            if diagnostics & ENGINE_VERSION_MISMATCH != 0 {
+                return Err(Error::InvalidArgument(format!(
+                    "Engine plan was built by another TensorRT-RTX version than {}.{}.{}; \
+                     rebuild it, or build it as version-compatible and allow engine host code",
+                    major, minor, patch
+                )));
+            }
+            if diagnostics & ENGINE_MALFORMED != 0 {
+                return Err(Error::InvalidArgument(format!(
+                    "Engine plan of {} bytes is truncated or corrupted",
+                    data.len()
+                )));
+            }
+        }
+
+        self.deserialize(data)
+    }
+
+    fn deserialize(&self, data: &[u8]) -> Result<CudaEngine> {
+        if let Some(device) = self.device {
+            crate::cuda::set_device(device)?;
+        }
+
+        let mut engine_ptr: *mut TrtxCudaEngine = std::ptr::null_mut();
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_runtime_deserialize_cuda_engine(
+                self.inner,
+                data.as_ptr() as *const std::ffi::c_void,
+                data.len(),
+                &mut engine_ptr,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        crate::handles::register(engine_ptr);
+        Ok(CudaEngine { inner: engine_ptr })
+    }
+}
+
+impl Drop for Runtime<'_> {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe {
+                trtx_runtime_destroy(self.inner);
+            }
         }
     }
 }
 
 unsafe impl Send for Runtime<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::BuilderFlag;
+    use crate::Builder;
+
+    #[test]
+    fn test_version_compatible_engine() {
+        let logger = Logger::stderr().unwrap();
+
+        let builder = Builder::new(&logger).unwrap();
+        let mut config = builder.create_config().unwrap();
+        config.set_flag(BuilderFlag::VersionCompatible).unwrap();
+        assert!(config.get_flag(BuilderFlag::VersionCompatible).unwrap());
+
+        let mut runtime = Runtime::new(&logger).unwrap();
+        assert!(!runtime.get_engine_host_code_allowed().unwrap());
+        runtime.set_engine_host_code_allowed(true).unwrap();
+        assert!(runtime.get_engine_host_code_allowed().unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_with_version_check() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+
+        assert!(matches!(
+            runtime.deserialize_with_version_check(&[]),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        // Truncated plan
+        assert!(matches!(
+            runtime.deserialize_with_version_check(b"TRTXMOCK\x01"),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_deserialize_with_version_check_mismatch() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+
+        // Mock plan from another TensorRT version
+        let Err(Error::InvalidArgument(msg)) =
+            runtime.deserialize_with_version_check(b"TRTXOLDV\x00\x00")
+        else {
+            panic!("plan from another version was not rejected");
+        };
+        let (major, minor, patch) = crate::version();
+        assert!(msg.contains(&format!("{}.{}.{}", major, minor, patch)));
+
+        let engine = runtime.deserialize_with_version_check(&[0u8; 16]).unwrap();
+        assert_eq!(engine.get_nb_io_tensors().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_runtime_max_threads() {
+        let logger = Logger::stderr().unwrap();
+        let mut runtime = Runtime::new(&logger).unwrap();
+
+        runtime.set_max_threads(4).unwrap();
+        #[cfg(feature = "mock")]
+        assert_eq!(runtime.get_max_threads().unwrap(), 4);
+        assert!(matches!(
+            runtime.set_max_threads(0),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_load_runtime() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "trtx_lean_{}.{}",
+            std::process::id(),
+            std::env::consts::DLL_EXTENSION
+        ));
+        assert!(matches!(
+            runtime.load_runtime(&path),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        std::fs::write(&path, b"\x7fELF").unwrap();
+        let lean = runtime.load_runtime(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        // Only a non-empty file loads in mock mode; the stub is not a real library
+        #[cfg(feature = "mock")]
+        {
+            let lean = lean.unwrap();
+            let engine = lean.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+            assert_eq!(engine.get_nb_io_tensors().unwrap(), 2);
+        }
+        #[cfg(not(feature = "mock"))]
+        assert!(matches!(lean, Err(Error::Runtime(_))));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_nvtx_verbosity_and_aux_streams() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+
+        context
+            .set_nvtx_verbosity(ProfilingVerbosity::None)
+            .unwrap();
+        assert_eq!(
+            context.get_nvtx_verbosity().unwrap(),
+            ProfilingVerbosity::None
+        );
+
+        let streams = [CudaStream::new().unwrap(), CudaStream::new().unwrap()];
+        let streams: Vec<&CudaStream> = streams.iter().collect();
+        unsafe {
+            context.set_aux_streams(&streams).unwrap();
+        }
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_create_execution_context_for_profile() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let nb_profiles = engine.get_nb_optimization_profiles().unwrap();
+        assert_eq!(nb_profiles, 1);
+
+        let context = engine.create_execution_context_for_profile(0).unwrap();
+        assert_eq!(context.get_optimization_profile().unwrap(), 0);
+        assert!(matches!(
+            engine.create_execution_context_for_profile(nb_profiles),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_is_shape_supported() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let context = engine.create_execution_context().unwrap();
+        let shape = |dims: &[i64]| vec![("input".to_string(), dims.to_vec())];
+
+        // Mock profile 0 accepts [1, 4] through [64, 4]
+        assert_eq!(
+            engine
+                .get_profile_shape("input", 0, OptProfileSelector::Max)
+                .unwrap(),
+            vec![64, 4]
+        );
+        assert!(context.is_shape_supported(&shape(&[8, 4])).unwrap());
+        assert!(!context.is_shape_supported(&shape(&[65, 4])).unwrap());
+        assert!(!context.is_shape_supported(&shape(&[0, 4])).unwrap());
+        assert!(!context.is_shape_supported(&shape(&[8, 4, 1])).unwrap());
+        assert!(context
+            .is_shape_supported(&[("output".to_string(), vec![8, 4])])
+            .is_err());
+        // The context's shapes are untouched
+        assert_eq!(context.get_tensor_shape("input").unwrap(), vec![-1, 4]);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_context_name() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+
+        context.set_name("worker-3").unwrap();
+        assert_eq!(context.get_name().unwrap(), "worker-3");
+        assert!(context.set_name("bad\0name").is_err());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_weight_streaming_budget() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let mut engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+
+        assert!(!engine.is_weight_streaming_enabled().unwrap());
+        let minimum = engine.get_minimum_weight_streaming_budget().unwrap();
+        assert_eq!(minimum, 0);
+
+        engine.set_weight_streaming_budget(minimum).unwrap();
+        let result = engine.set_weight_streaming_budget(minimum - 1);
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        // Streamable weights start out resident and are streamed once the
+        // budget is below their size
+        let mut engine = runtime
+            .deserialize_cuda_engine(b"TRTXMOCK\x01\x01\x10")
+            .unwrap();
+        let streamable = engine.get_streamable_weights_size().unwrap();
+        assert!(streamable > 0);
+        assert!(!engine.is_weight_streaming_enabled().unwrap());
+        engine.set_weight_streaming_budget(streamable / 2).unwrap();
+        assert!(engine.is_weight_streaming_enabled().unwrap());
+        engine.set_weight_streaming_budget(0).unwrap();
+        assert!(engine.is_weight_streaming_enabled().unwrap());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_weight_streaming_budget_between_contexts() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let mut engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+
+        let context = engine.create_execution_context().unwrap();
+        drop(context);
+
+        let budget = engine.get_weight_streaming_automatic_budget().unwrap();
+        engine.set_weight_streaming_budget(budget).unwrap();
+        assert_eq!(engine.get_weight_streaming_budget().unwrap(), budget);
+        engine.create_execution_context().unwrap();
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_tensor_shapes() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+
+        assert_eq!(
+            engine.get_tensor_io_mode("input").unwrap(),
+            TensorIoMode::Input
+        );
+        assert_eq!(
+            engine.get_tensor_io_mode("output").unwrap(),
+            TensorIoMode::Output
+        );
+        assert_eq!(engine.get_tensor_shape("output").unwrap(), vec![-1, 4]);
+
+        let mut context = engine.create_execution_context().unwrap();
+        assert!(context.set_input_shape("input", &[2, 3]).is_err());
+        context.set_input_shape("input", &[2, 4]).unwrap();
+        assert_eq!(context.get_tensor_shape("output").unwrap(), vec![2, 4]);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_tensor_strides() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+
+        context.set_input_shape("input", &[2, 4]).unwrap();
+        assert_eq!(context.get_tensor_strides("output").unwrap(), vec![4, 1]);
+        assert!(context.get_tensor_strides("missing").is_err());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_validate_output_capacity() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+
+        let input = DeviceBuffer::new(32).unwrap();
+        let output = DeviceBuffer::new(16).unwrap();
+        unsafe {
+            context.set_tensor_buffer("input", &input).unwrap();
+            context.set_tensor_buffer("output", &output).unwrap();
+        }
+        assert!(context.validate_output_capacity().is_err());
+
+        context.set_input_shape("input", &[1, 4]).unwrap();
+        context.validate_output_capacity().unwrap();
+
+        context.set_input_shape("input", &[2, 4]).unwrap();
+        assert!(matches!(
+            context.validate_output_capacity(),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_context_run() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        // Mock identity engine: "input" [-1, 4] to "output"
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+        let stream = CudaStream::new().unwrap();
+
+        let values: Vec<u8> = (0..8u8).flat_map(|v| (v as f32).to_le_bytes()).collect();
+        let input = DeviceBuffer::from_host_slice(&values).unwrap();
+        let output = DeviceBuffer::new_zeroed(32).unwrap();
+        context.set_input_shape("input", &[2, 4]).unwrap();
+
+        for bindings in [
+            &[("input", &input)][..],
+            &[("input", &input), ("output", &output), ("extra", &output)],
+            &[("input", &input), ("input", &input), ("output", &output)],
+        ] {
+            assert!(matches!(
+                context.run(bindings, &stream),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+
+        context
+            .run(&[("input", &input), ("output", &output)], &stream)
+            .unwrap();
+        let mut result = vec![0u8; 32];
+        output.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, values);
+
+        // Outputs too small for the resolved shape are rejected
+        let small = DeviceBuffer::new(16).unwrap();
+        assert!(matches!(
+            context.run(&[("input", &input), ("output", &small)], &stream),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_max_output_size() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+
+        context.set_input_shape("input", &[3, 4]).unwrap();
+        let size = context.get_max_output_size("output").unwrap();
+        assert!(size >= 3 * 4 * std::mem::size_of::<f32>());
+        assert!(matches!(
+            context.get_max_output_size("input"),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_engine_tensors() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+
+        let names = engine.io_tensor_names().unwrap();
+        let tensors: Vec<TensorInfo> = engine.tensors().collect::<Result<_>>().unwrap();
+        assert_eq!(names.len() as i32, engine.get_nb_io_tensors().unwrap());
+        assert_eq!(
+            tensors.iter().map(|t| &t.name).collect::<Vec<_>>(),
+            names.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            tensors,
+            vec![
+                TensorInfo {
+                    name: "input".to_string(),
+                    io_mode: TensorIoMode::Input,
+                    dtype: DataType::Float,
+                    shape: vec![-1, 4].into(),
+                },
+                TensorInfo {
+                    name: "output".to_string(),
+                    io_mode: TensorIoMode::Output,
+                    dtype: DataType::Float,
+                    shape: vec![-1, 4].into(),
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_set_input_shape_tensor() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        // Mock engine whose input is a shape tensor
+        let engine = runtime
+            .deserialize_cuda_engine(b"TRTXMOCK\x01\x01\x08")
+            .unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+
+        assert!(engine.is_shape_inference_io("input").unwrap());
+        context.set_input_shape_tensor("input", &[2, 4]).unwrap();
+        // The context keeps its own copy of the values
+        let address = context.get_tensor_address("input").unwrap() as *const i32;
+        assert!(!address.is_null());
+        assert_eq!(unsafe { std::slice::from_raw_parts(address, 2) }, &[2, 4]);
+
+        assert!(matches!(
+            context.set_input_shape_tensor("output", &[1]),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        // Execution tensors are read on the device, not from host memory
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+        assert!(matches!(
+            context.set_input_shape_tensor("input", &[2, 4]),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(context.get_tensor_address("input").unwrap().is_null());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_tensor_alignment() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let mut context = engine.create_execution_context().unwrap();
+
+        let alignment = engine.get_tensor_alignment("input").unwrap();
+        assert!(engine.get_tensor_alignment("bogus").is_err());
+
+        let buffer = crate::DeviceBuffer::new(64).unwrap();
+        assert_eq!(buffer.as_ptr() as usize % alignment, 0);
+        unsafe {
+            context
+                .set_tensor_address("input", buffer.as_ptr())
+                .unwrap();
+            let misaligned = buffer.as_ptr().cast::<u8>().add(4).cast();
+            assert!(matches!(
+                context.set_tensor_address("input", misaligned),
+                Err(Error::InvalidArgument(_))
+            ));
+            // An aligned address is no use for a tensor the engine lacks
+            assert!(matches!(
+                context.set_tensor_address("bogus", buffer.as_ptr()),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+    }
+}
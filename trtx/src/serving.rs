@@ -0,0 +1,186 @@
+//! Multi-context scheduling for serving inference requests
+
+use crate::error::{Error, Result};
+use crate::executor::{Executor, TensorInput, TensorOutput};
+use crate::runtime::CudaEngine;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+/// A submitted request and where to send its outputs
+type Job = (Vec<TensorInput>, Sender<Result<Vec<TensorOutput>>>);
+
+/// Runs requests on a fixed set of execution contexts of one engine
+///
+/// Each context has its own worker thread, CUDA stream and device buffers
+/// (an [`Executor`]), so one request's host/device copies overlap another's
+/// compute. [`submit`](Self::submit) hands requests to the contexts in
+/// round-robin order and returns immediately; each context works through its
+/// requests in submission order.
+///
+/// Dropping the scheduler waits for every submitted request to finish.
+///
+/// ```no_run
+/// # use trtx::{Logger, Runtime, TensorInput};
+/// # use trtx::serving::Scheduler;
+/// # fn main() -> trtx::Result<()> {
+/// # let logger = Logger::stderr()?;
+/// # let runtime = Runtime::new(&logger)?;
+/// # let engine_data = std::fs::read("model.engine")?;
+/// # let inputs: Vec<TensorInput> = Vec::new();
+/// let engine = runtime.deserialize_cuda_engine(&engine_data)?;
+/// let scheduler = Scheduler::new(engine, 4)?;
+/// let pending = scheduler.submit(inputs);
+/// let outputs = pending.recv().expect("scheduler stopped")?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Scheduler {
+    queues: Vec<Sender<Job>>,
+    next: AtomicUsize,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl Scheduler {
+    /// Start `nb_contexts` execution contexts on `engine`
+    ///
+    /// Workers use the CUDA device current on the calling thread. Fails with
+    /// [`Error::InvalidArgument`] if `nb_contexts` is 0, or with the error of
+    /// the first context that cannot be created.
+    pub fn new(engine: CudaEngine, nb_contexts: usize) -> Result<Self> {
+        if nb_contexts == 0 {
+            return Err(Error::InvalidArgument(
+                "A scheduler needs at least one execution context".to_string(),
+            ));
+        }
+
+        let device = crate::cuda::current_device()?;
+        let engine = Arc::new(engine);
+        let mut scheduler = Scheduler {
+            queues: Vec::with_capacity(nb_contexts),
+            next: AtomicUsize::new(0),
+            workers: Vec::with_capacity(nb_contexts),
+        };
+
+        for _ in 0..nb_contexts {
+            let (queue, jobs) = mpsc::channel::<Job>();
+            let (ready, started) = mpsc::channel();
+            let engine = engine.clone();
+            scheduler.workers.push(std::thread::spawn(move || {
+                let executor = crate::cuda::set_device(device).and_then(|_| Executor::new(&engine));
+                let mut executor = match executor {
+                    Ok(executor) => {
+                        let _ = ready.send(Ok(()));
+                        executor
+                    }
+                    Err(error) => {
+                        let _ = ready.send(Err(error));
+                        return;
+                    }
+                };
+                for (inputs, reply) in jobs {
+                    // The caller may have stopped waiting for the outputs
+                    let _ = reply.send(executor.run(&inputs));
+                }
+            }));
+            scheduler.queues.push(queue);
+
+            // Dropping the scheduler on error stops the workers started so far
+            started
+                .recv()
+                .map_err(|_| Error::Runtime("Scheduler worker exited".to_string()))??;
+        }
+
+        Ok(scheduler)
+    }
+
+    /// Number of execution contexts requests are spread over
+    pub fn nb_contexts(&self) -> usize {
+        self.queues.len()
+    }
+
+    /// Queue a request, returning a channel that receives its outputs
+    ///
+    /// Input errors are reported through the channel, like
+    /// [`Executor::run`] reports them.
+    pub fn submit(&self, inputs: Vec<TensorInput>) -> Receiver<Result<Vec<TensorOutput>>> {
+        let (reply, outputs) = mpsc::channel();
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.queues.len();
+        if let Err(mpsc::SendError((_, reply))) = self.queues[index].send((inputs, reply)) {
+            let _ = reply.send(Err(Error::Runtime("Scheduler worker exited".to_string())));
+        }
+        outputs
+    }
+}
+
+impl Drop for Scheduler {
+    fn drop(&mut self) {
+        // Closing the queues ends each worker once its requests are done
+        self.queues.clear();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::TensorData;
+    use crate::logger::Logger;
+    use crate::runtime::Runtime;
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_scheduler_concurrent_requests() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        // Mock identity engine: "input" [-1, 4] to "output"
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        let scheduler = Arc::new(Scheduler::new(engine, 3).unwrap());
+        assert_eq!(scheduler.nb_contexts(), 3);
+
+        let clients: Vec<_> = (0..4)
+            .map(|client| {
+                let scheduler = scheduler.clone();
+                std::thread::spawn(move || {
+                    let pending: Vec<_> = (0..5)
+                        .map(|request| {
+                            let value = (client * 10 + request) as f32;
+                            let inputs = vec![TensorInput {
+                                name: "input".to_string(),
+                                shape: vec![2, 4].into(),
+                                data: TensorData::F32(vec![value; 8]),
+                            }];
+                            (value, scheduler.submit(inputs))
+                        })
+                        .collect();
+                    for (value, outputs) in pending {
+                        let outputs = outputs.recv().unwrap().unwrap();
+                        assert_eq!(outputs[0].data, TensorData::F32(vec![value; 8]));
+                    }
+                })
+            })
+            .collect();
+        for client in clients {
+            client.join().unwrap();
+        }
+
+        // Input errors come back through the channel
+        let result = scheduler.submit(Vec::new()).recv().unwrap();
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_scheduler_needs_contexts() {
+        let logger = Logger::stderr().unwrap();
+        let runtime = Runtime::new(&logger).unwrap();
+        let engine = runtime.deserialize_cuda_engine(&[0u8; 16]).unwrap();
+        assert!(matches!(
+            Scheduler::new(engine, 0),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,193 @@
+//! Tensor shapes
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+/// Dimensions of a tensor, outermost first
+///
+/// Dimensions are signed like TensorRT's `Dims`: engine and network shapes
+/// use `-1` for dynamic dimensions. Host tensors such as
+/// [`TensorInput`](crate::TensorInput) always have concrete shapes.
+///
+/// Derefs to `[i64]`, so it can be passed wherever a `&[i64]` shape is
+/// expected, and compares equal to plain vectors and arrays:
+///
+/// ```
+/// # use trtx::Dims;
+/// let dims = Dims::from([-1, 3, 224, 224]);
+/// assert!(dims.is_dynamic());
+/// assert_eq!(dims.product(), None);
+/// assert_eq!(dims.to_string(), "[-1,3,224,224]");
+/// assert_eq!(dims, [-1, 3, 224, 224]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Dims(Vec<i64>);
+
+impl Dims {
+    /// Number of elements, or `None` if any dimension is dynamic (negative)
+    ///
+    /// Scalars (no dimensions) hold one element.
+    pub fn product(&self) -> Option<usize> {
+        self.0.iter().map(|&d| usize::try_from(d).ok()).product()
+    }
+
+    /// Whether any dimension is dynamic (`-1`)
+    pub fn is_dynamic(&self) -> bool {
+        self.0.iter().any(|&d| d < 0)
+    }
+
+    /// Dimensions as `usize`, or `None` if any dimension is dynamic
+    ///
+    /// For interop with host-side crates such as `ndarray`.
+    pub fn to_usize(&self) -> Option<Vec<usize>> {
+        self.0.iter().map(|&d| usize::try_from(d).ok()).collect()
+    }
+
+    /// The dimensions as a slice
+    pub fn as_slice(&self) -> &[i64] {
+        &self.0
+    }
+
+    /// Unwrap into the underlying vector
+    pub fn into_vec(self) -> Vec<i64> {
+        self.0
+    }
+}
+
+impl Deref for Dims {
+    type Target = [i64];
+
+    fn deref(&self) -> &[i64] {
+        &self.0
+    }
+}
+
+impl DerefMut for Dims {
+    fn deref_mut(&mut self) -> &mut [i64] {
+        &mut self.0
+    }
+}
+
+impl AsRef<[i64]> for Dims {
+    fn as_ref(&self) -> &[i64] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Dims {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, dim) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}", dim)?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl From<Vec<i64>> for Dims {
+    fn from(dims: Vec<i64>) -> Self {
+        Dims(dims)
+    }
+}
+
+impl From<&[i64]> for Dims {
+    fn from(dims: &[i64]) -> Self {
+        Dims(dims.to_vec())
+    }
+}
+
+impl<const N: usize> From<[i64; N]> for Dims {
+    fn from(dims: [i64; N]) -> Self {
+        Dims(dims.to_vec())
+    }
+}
+
+impl From<&Dims> for Dims {
+    fn from(dims: &Dims) -> Self {
+        dims.clone()
+    }
+}
+
+impl From<Dims> for Vec<i64> {
+    fn from(dims: Dims) -> Self {
+        dims.0
+    }
+}
+
+impl FromIterator<i64> for Dims {
+    fn from_iter<I: IntoIterator<Item = i64>>(iter: I) -> Self {
+        Dims(iter.into_iter().collect())
+    }
+}
+
+impl<'a> IntoIterator for &'a Dims {
+    type Item = &'a i64;
+    type IntoIter = std::slice::Iter<'a, i64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl PartialEq<Vec<i64>> for Dims {
+    fn eq(&self, other: &Vec<i64>) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<[i64]> for Dims {
+    fn eq(&self, other: &[i64]) -> bool {
+        self.0 == other
+    }
+}
+
+impl<const N: usize> PartialEq<[i64; N]> for Dims {
+    fn eq(&self, other: &[i64; N]) -> bool {
+        self.0 == other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_static_dims() {
+        let dims = Dims::from(vec![1, 3, 224, 224]);
+        assert!(!dims.is_dynamic());
+        assert_eq!(dims.product(), Some(3 * 224 * 224));
+        assert_eq!(dims.to_usize(), Some(vec![1, 3, 224, 224]));
+        assert_eq!(dims.to_string(), "[1,3,224,224]");
+        assert_eq!(dims.len(), 4);
+        assert_eq!(dims[1], 3);
+
+        assert_eq!(Dims::default().product(), Some(1));
+        assert_eq!(Dims::from([2, 0]).product(), Some(0));
+        assert_eq!(Dims::default().to_string(), "[]");
+    }
+
+    #[test]
+    fn test_dynamic_dims() {
+        let slice: &[i64] = &[-1, 4];
+        let dims = Dims::from(slice);
+        assert!(dims.is_dynamic());
+        assert_eq!(dims.product(), None);
+        assert_eq!(dims.to_usize(), None);
+        assert_eq!(dims.to_string(), "[-1,4]");
+        assert_eq!(dims, vec![-1, 4]);
+        assert_eq!(Vec::from(dims.clone()), vec![-1, 4]);
+
+        // Resolving the dynamic dimension makes the shape concrete
+        let resolved: Dims = dims.iter().map(|&d| if d < 0 { 8 } else { d }).collect();
+        assert_eq!(resolved, [8, 4]);
+        assert_eq!(resolved.product(), Some(32));
+    }
+}
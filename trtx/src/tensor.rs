@@ -0,0 +1,114 @@
+//! Tensor metadata types shared by the builder, runtime and executor
+
+use crate::error::{Error, Result};
+
+/// Element type of a tensor (mirrors `nvinfer1::DataType`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum DataType {
+    /// 32-bit floating point
+    Float = 0,
+    /// 16-bit floating point
+    Half = 1,
+    /// Signed 8-bit integer (quantized)
+    Int8 = 2,
+    /// Signed 32-bit integer
+    Int32 = 3,
+    /// Boolean, stored as one byte
+    Bool = 4,
+    /// Unsigned 8-bit integer
+    Uint8 = 5,
+    /// 8-bit floating point (E4M3)
+    Fp8 = 6,
+    /// Brain floating point (16-bit)
+    Bf16 = 7,
+    /// Signed 64-bit integer
+    Int64 = 8,
+}
+
+impl DataType {
+    /// Size of one element in bytes
+    pub fn size(&self) -> usize {
+        match self {
+            DataType::Float | DataType::Int32 => 4,
+            DataType::Half | DataType::Bf16 => 2,
+            DataType::Int8 | DataType::Bool | DataType::Uint8 | DataType::Fp8 => 1,
+            DataType::Int64 => 8,
+        }
+    }
+
+    /// Convert a raw `nvinfer1::DataType` value returned over FFI
+    pub(crate) fn from_raw(value: i32) -> Result<Self> {
+        match value {
+            0 => Ok(DataType::Float),
+            1 => Ok(DataType::Half),
+            2 => Ok(DataType::Int8),
+            3 => Ok(DataType::Int32),
+            4 => Ok(DataType::Bool),
+            5 => Ok(DataType::Uint8),
+            6 => Ok(DataType::Fp8),
+            7 => Ok(DataType::Bf16),
+            8 => Ok(DataType::Int64),
+            _ => Err(Error::Unknown(format!("Unsupported data type: {}", value))),
+        }
+    }
+}
+
+/// Whether a tensor is an engine input or output (mirrors `nvinfer1::TensorIOMode`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum TensorIoMode {
+    /// Not an I/O tensor
+    None = 0,
+    /// Engine input
+    Input = 1,
+    /// Engine output
+    Output = 2,
+}
+
+impl TensorIoMode {
+    /// Convert a raw `nvinfer1::TensorIOMode` value returned over FFI
+    pub(crate) fn from_raw(value: i32) -> Result<Self> {
+        match value {
+            0 => Ok(TensorIoMode::None),
+            1 => Ok(TensorIoMode::Input),
+            2 => Ok(TensorIoMode::Output),
+            _ => Err(Error::Unknown(format!(
+                "Unsupported tensor I/O mode: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Which shape of an optimization profile to query (mirrors `nvinfer1::OptProfileSelector`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum OptProfileSelector {
+    /// Smallest shape the profile accepts
+    Min = 0,
+    /// Shape the profile's kernels are tuned for
+    Opt = 1,
+    /// Largest shape the profile accepts
+    Max = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_type_round_trip() {
+        for dtype in [DataType::Float, DataType::Half, DataType::Int64] {
+            assert_eq!(DataType::from_raw(dtype as i32).unwrap(), dtype);
+        }
+        assert!(DataType::from_raw(42).is_err());
+    }
+
+    #[test]
+    fn test_data_type_size() {
+        assert_eq!(DataType::Float.size(), 4);
+        assert_eq!(DataType::Half.size(), 2);
+        assert_eq!(DataType::Int64.size(), 8);
+    }
+}
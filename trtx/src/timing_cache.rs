@@ -0,0 +1,213 @@
+//! Timing caches for reusing tactic measurements across builds
+
+use crate::builder::BuilderConfig;
+use crate::error::{Error, Result};
+use std::sync::{Arc, Mutex};
+use trtx_sys::*;
+
+/// Tactic timings recorded by builds (wraps `nvinfer1::ITimingCache`)
+///
+/// Create one with [`BuilderConfig::create_timing_cache`] and attach it with
+/// [`BuilderConfig::set_timing_cache`]; builds then reuse the timings it holds
+/// and add the ones they measure. Serialize it to skip tactic timing on
+/// later builds for the same device.
+pub struct TimingCache {
+    inner: *mut TrtxTimingCache,
+}
+
+impl TimingCache {
+    pub(crate) fn from_raw(inner: *mut TrtxTimingCache) -> Self {
+        TimingCache { inner }
+    }
+
+    /// Serialize the cache, for [`BuilderConfig::create_timing_cache`]
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        let mut data_ptr: *mut std::ffi::c_void = std::ptr::null_mut();
+        let mut size: usize = 0;
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_timing_cache_serialize(
+                self.inner,
+                &mut data_ptr,
+                &mut size,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        let data = unsafe {
+            let vec = std::slice::from_raw_parts(data_ptr as *const u8, size).to_vec();
+            trtx_free_buffer(data_ptr);
+            vec
+        };
+
+        Ok(data)
+    }
+
+    /// Add the timings from `other` that this cache does not have yet
+    ///
+    /// Fails if `other` was recorded on a different device, unless
+    /// `ignore_mismatch` is set.
+    pub fn combine(&mut self, other: &TimingCache, ignore_mismatch: bool) -> Result<()> {
+        let mut error_msg = [0i8; 1024];
+
+        let result = unsafe {
+            trtx_timing_cache_combine(
+                self.inner,
+                other.inner,
+                ignore_mismatch,
+                error_msg.as_mut_ptr(),
+                error_msg.len(),
+            )
+        };
+
+        if result != TRTX_SUCCESS as i32 {
+            return Err(Error::from_ffi(result, &error_msg));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut TrtxTimingCache {
+        self.inner
+    }
+}
+
+impl Drop for TimingCache {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe {
+                trtx_timing_cache_destroy(self.inner);
+            }
+        }
+    }
+}
+
+unsafe impl Send for TimingCache {}
+
+/// A timing cache shared by builds running on several threads
+///
+/// TensorRT updates an attached cache in place while building, so concurrent
+/// builds must not share one [`TimingCache`]. Instead, [`attach`](Self::attach)
+/// gives each build a private copy of the shared timings, and
+/// [`merge`](Self::merge) combines what the build measured back into the
+/// shared cache once it completes. Clones share the same cache.
+///
+/// ```no_run
+/// # use trtx::{builder::network_flags, Builder, Logger, SharedTimingCache};
+/// # fn build(shared: &SharedTimingCache) -> trtx::Result<Vec<u8>> {
+/// # let logger = Logger::stderr()?;
+/// # let builder = Builder::new(&logger)?;
+/// # let network = builder.create_network(network_flags::EXPLICIT_BATCH)?;
+/// let mut config = builder.create_config()?;
+/// shared.attach(&mut config)?;
+/// let engine = builder.build_serialized_network(&network, &config)?;
+/// shared.merge(&config)?;
+/// # Ok(engine)
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct SharedTimingCache {
+    // Kept serialized: a live cache belongs to the config that created it
+    data: Arc<Mutex<Vec<u8>>>,
+}
+
+impl SharedTimingCache {
+    /// Create an empty shared cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a shared cache from a serialized [`TimingCache`]
+    pub fn from_serialized(data: Vec<u8>) -> Self {
+        SharedTimingCache {
+            data: Arc::new(Mutex::new(data)),
+        }
+    }
+
+    /// Snapshot of the shared timings, e.g. to save to disk
+    pub fn serialize(&self) -> Vec<u8> {
+        self.data.lock().unwrap().clone()
+    }
+
+    /// Attach a private copy of the shared timings to `config`
+    pub fn attach(&self, config: &mut BuilderConfig) -> Result<()> {
+        let data = self.serialize();
+        let cache = config.create_timing_cache(&data)?;
+        config.set_timing_cache(cache, false)
+    }
+
+    /// Combine the timings measured by builds using `config` into the shared cache
+    ///
+    /// `config` must have been passed to [`attach`](Self::attach).
+    pub fn merge(&self, config: &BuilderConfig) -> Result<()> {
+        let measured = config.get_timing_cache().ok_or_else(|| {
+            Error::InvalidArgument("Builder config has no timing cache attached".to_string())
+        })?;
+
+        let mut data = self.data.lock().unwrap();
+        let mut merged = config.create_timing_cache(&data)?;
+        merged.combine(measured, false)?;
+        *data = merged.serialize()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::network_flags;
+    use crate::{Builder, Logger};
+
+    fn build_with(shared: &SharedTimingCache) -> Result<()> {
+        let logger = Logger::stderr()?;
+        let builder = Builder::new(&logger)?;
+        let network = builder.create_network(network_flags::EXPLICIT_BATCH)?;
+        let mut config = builder.create_config()?;
+        shared.attach(&mut config)?;
+        builder.build_serialized_network(&network, &config)?;
+        shared.merge(&config)
+    }
+
+    #[test]
+    fn test_shared_timing_cache_concurrent_builds() {
+        let shared = SharedTimingCache::new();
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let shared = shared.clone();
+                std::thread::spawn(move || build_with(&shared))
+            })
+            .collect();
+        let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+        #[cfg(feature = "mock")]
+        {
+            for result in results {
+                result.unwrap();
+            }
+            // Each mock build times one new tactic, and neither may be lost
+            let data = shared.serialize();
+            let tactics = u32::from_ne_bytes(data.as_slice().try_into().unwrap());
+            assert_eq!(tactics.count_ones(), 2);
+        }
+        #[cfg(not(feature = "mock"))]
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_requires_attached_cache() {
+        let logger = Logger::stderr().unwrap();
+        let builder = Builder::new(&logger).unwrap();
+        let config = builder.create_config().unwrap();
+        assert!(matches!(
+            SharedTimingCache::new().merge(&config),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,7 @@
+//! Compile-fail tests for API misuse the type system should reject
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,15 @@
+// Sharing a context between scoped threads needs a Mutex
+
+use trtx::{Logger, Runtime};
+
+fn main() {
+    let logger = Logger::stderr().unwrap();
+    let runtime = Runtime::new(&logger).unwrap();
+    let engine = runtime.deserialize_cuda_engine(&[]).unwrap();
+    let context = engine.create_execution_context().unwrap();
+
+    std::thread::scope(|s| {
+        s.spawn(|| context.get_tensor_shape("input"));
+        s.spawn(|| context.get_tensor_shape("output"));
+    });
+}